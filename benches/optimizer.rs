@@ -0,0 +1,93 @@
+//! Searches a generated vault of about 600 armor pieces, the size the
+//! optimizer has to handle in a few seconds.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use guardian_multi_tool::{
+    bnet::entities::destiny::{hashes::Hash, DestinyClass},
+    tools::optimizer::{optimize, ArmorPiece, OptimizerQuery, ARMOR_BUCKET_HASHES},
+};
+
+/// Legendaries of each class in each slot, which makes 600 pieces.
+const LEGENDARIES_PER_SLOT: usize = 40;
+
+/// Exotics of each class in each slot.
+const EXOTICS_PER_SLOT: usize = 3;
+
+/// A linear congruential generator, so the vault is the same on every run.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self, below: i32) -> i32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.0 >> 33) % below as u64) as i32
+    }
+}
+
+/// Every class gets the same number of pieces, with stats rolled between 2
+/// and 30 each, and one in three pieces masterworked.
+fn vault() -> Vec<ArmorPiece> {
+    let mut random = Random(0x5eed);
+    let mut pieces = Vec::new();
+    for class in [
+        DestinyClass::Titan,
+        DestinyClass::Hunter,
+        DestinyClass::Warlock,
+    ] {
+        for slot in 0..ARMOR_BUCKET_HASHES.len() {
+            for index in 0..LEGENDARIES_PER_SLOT + EXOTICS_PER_SLOT {
+                let exotic = index >= LEGENDARIES_PER_SLOT;
+                let hash = if exotic {
+                    10_000 + (slot * EXOTICS_PER_SLOT + index) as u32
+                } else {
+                    20_000
+                };
+                let stats = [0; 6].map(|_| 2 + random.next(29));
+                pieces.push(ArmorPiece::new(
+                    pieces.len() as i64,
+                    Hash::new(hash),
+                    slot,
+                    class,
+                    exotic,
+                    random.next(3) == 0,
+                    stats,
+                ));
+            }
+        }
+    }
+    pieces
+}
+
+fn optimizer(c: &mut Criterion) {
+    let pieces = vault();
+    let exotic = pieces
+        .iter()
+        .find(|piece| piece.exotic() && piece.class() == DestinyClass::Hunter)
+        .map(|piece| piece.item_hash());
+    let mut group = c.benchmark_group("optimizer");
+    group.sample_size(10);
+
+    let any_exotic = OptimizerQuery::new(DestinyClass::Hunter, None).with_limit(50);
+    group.bench_function("any_exotic", |b| b.iter(|| optimize(&pieces, &any_exotic)));
+
+    let fixed_exotic = OptimizerQuery::new(DestinyClass::Hunter, exotic)
+        .with_min_tiers([10, 0, 8, 0, 6, 0])
+        .with_stat_mods(5)
+        .assume_masterworked(true);
+    group.bench_function("fixed_exotic_with_targets", |b| {
+        b.iter(|| optimize(&pieces, &fixed_exotic))
+    });
+
+    let high_targets =
+        OptimizerQuery::new(DestinyClass::Hunter, None).with_min_tiers([10, 10, 10, 10, 10, 10]);
+    group.bench_function("unreachable_targets", |b| {
+        b.iter(|| optimize(&pieces, &high_targets))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, optimizer);
+criterion_main!(benches);
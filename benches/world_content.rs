@@ -0,0 +1,208 @@
+//! Compares deserializing the world content eagerly into
+//! `DestinyWorldContent` with indexing it into a `LazyWorldContent`, on a
+//! generated world content shaped like the real one.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use serde_json::{json, Map, Value};
+
+use guardian_multi_tool::bnet::entities::destiny::{
+    definitions::DestinyInventoryItemDefinition, hashes::Hash, world_content::LazyWorldContent,
+    DestinyWorldContent,
+};
+
+/// Every table of the world content, as the eager deserialization requires
+/// most of them to be there.
+const TABLES: &[&str] = &[
+    "DestinyAchievementDefinition",
+    "DestinyActivityDefinition",
+    "DestinyActivityGraphDefinition",
+    "DestinyActivityInteractableDefinition",
+    "DestinyActivityModeDefinition",
+    "DestinyActivityModifierDefinition",
+    "DestinyActivityTypeDefinition",
+    "DestinyArtDyeChannelDefinition",
+    "DestinyArtDyeReferenceDefinition",
+    "DestinyArtifactDefinition",
+    "DestinyBondDefinition",
+    "DestinyBreakerTypeDefinition",
+    "DestinyCharacterCustomizationCategoryDefinition",
+    "DestinyCharacterCustomizationOptionDefinition",
+    "DestinyChecklistDefinition",
+    "DestinyClassDefinition",
+    "DestinyCollectibleDefinition",
+    "DestinyDamageTypeDefinition",
+    "DestinyDestinationDefinition",
+    "DestinyEnergyTypeDefinition",
+    "DestinyEntitlementOfferDefinition",
+    "DestinyEquipmentSlotDefinition",
+    "DestinyFactionDefinition",
+    "DestinyGenderDefinition",
+    "DestinyInventoryBucketDefinition",
+    "DestinyInventoryItemDefinition",
+    "DestinyInventoryItemLiteDefinition",
+    "DestinyItemCategoryDefinition",
+    "DestinyItemTierTypeDefinition",
+    "DestinyLoadoutColorDefinition",
+    "DestinyLoadoutConstantsDefinition",
+    "DestinyLoadoutIconDefinition",
+    "DestinyLoadoutNameDefinition",
+    "DestinyLocationDefinition",
+    "DestinyLoreDefinition",
+    "DestinyMaterialRequirementSetDefinition",
+    "DestinyMedalTierDefinition",
+    "DestinyMetricDefinition",
+    "DestinyMilestoneDefinition",
+    "DestinyNodeStepSummaryDefinition",
+    "DestinyObjectiveDefinition",
+    "DestinyPlaceDefinition",
+    "DestinyPlatformBucketMappingDefinition",
+    "DestinyPlugSetDefinition",
+    "DestinyPowerCapDefinition",
+    "DestinyPresentationNodeDefinition",
+    "DestinyProgressionDefinition",
+    "DestinyProgressionLevelRequirementDefinition",
+    "DestinyProgressionMappingDefinition",
+    "DestinyRaceDefinition",
+    "DestinyRecordDefinition",
+    "DestinyReportReasonCategoryDefinition",
+    "DestinyRewardAdjusterPointerDefinition",
+    "DestinyRewardAdjusterProgressionMapDefinition",
+    "DestinyRewardItemListDefinition",
+    "DestinyRewardMappingDefinition",
+    "DestinyRewardSheetDefinition",
+    "DestinyRewardSourceDefinition",
+    "DestinySackRewardItemListDefinition",
+    "DestinySandboxPatternDefinition",
+    "DestinySandboxPerkDefinition",
+    "DestinySeasonDefinition",
+    "DestinySeasonPassDefinition",
+    "DestinySocketCategoryDefinition",
+    "DestinySocketTypeDefinition",
+    "DestinyStatDefinition",
+    "DestinyStatGroupDefinition",
+    "DestinyTalentGridDefinition",
+    "DestinyTraitCategoryDefinition",
+    "DestinyTraitDefinition",
+    "DestinyUnlockCountMappingDefinition",
+    "DestinyUnlockDefinition",
+    "DestinyUnlockEventDefinition",
+    "DestinyUnlockExpressionMappingDefinition",
+    "DestinyUnlockValueDefinition",
+    "DestinyVendorDefinition",
+    "DestinyVendorGroupDefinition",
+];
+
+/// How many items the fixture has. The real item table holds a few tens of
+/// thousands.
+const ITEMS: u32 = 20_000;
+
+/// The hash of the fixture's first item.
+const FIRST_ITEM_HASH: u32 = 1_000_000;
+
+fn item(hash: u32) -> Value {
+    json!({
+        "displayProperties": {
+            "description": "A weapon generated for benchmarking, with a description \
+                about as long as the ones in the real manifest.",
+            "name": format!("Item {}", hash),
+            "icon": format!("/common/destiny2_content/icons/{:032x}.jpg", hash),
+            "hasIcon": true
+        },
+        "flavorText": "Every story needs an ending.",
+        "itemTypeDisplayName": "Auto Rifle",
+        "itemTypeAndTierDisplayName": "Legendary Auto Rifle",
+        "inventory": {
+            "maxStackSize": 1,
+            "bucketTypeHash": 1498876634,
+            "tierTypeHash": 4008398120u32,
+            "tierType": 5,
+            "tierTypeName": "Legendary"
+        },
+        "stats": {
+            "statGroupHash": 1939609830,
+            "stats": {
+                "4284893193": { "statHash": 4284893193u32, "value": 600 },
+                "4043523819": { "statHash": 4043523819u32, "value": 33 },
+                "1240592695": { "statHash": 1240592695, "value": 54 }
+            }
+        },
+        "sockets": {
+            "socketEntries": (0..8).map(|i| json!({
+                "socketTypeHash": 2218962841u32 + i,
+                "singleInitialItemHash": 3250034553u32 + i,
+                "reusablePlugItems": [],
+                "preventInitializationOnVendorPurchase": false,
+                "hidePerksInItemTooltip": false,
+                "plugSources": 2,
+                "defaultVisible": true
+            })).collect::<Vec<_>>()
+        },
+        "itemCategoryHashes": [2, 5, 1],
+        "itemType": 3,
+        "itemSubType": 6,
+        "classType": 3,
+        "equippable": true,
+        "defaultDamageType": 1,
+        "hash": hash,
+        "index": hash - FIRST_ITEM_HASH,
+        "redacted": false,
+        "blacklisted": false
+    })
+}
+
+fn fixture() -> Vec<u8> {
+    let mut content: Map<String, Value> = TABLES
+        .iter()
+        .map(|table| (table.to_string(), Value::Object(Map::new())))
+        .collect();
+    let items: Map<String, Value> = (FIRST_ITEM_HASH..FIRST_ITEM_HASH + ITEMS)
+        .map(|hash| (hash.to_string(), item(hash)))
+        .collect();
+    content.insert(
+        "DestinyInventoryItemDefinition".to_string(),
+        Value::Object(items),
+    );
+    serde_json::to_vec(&content).unwrap()
+}
+
+fn world_content(c: &mut Criterion) {
+    let json = fixture();
+    let mut group = c.benchmark_group("world_content");
+    group.sample_size(10);
+
+    group.bench_function("eager", |b| {
+        b.iter(|| serde_json::from_slice::<DestinyWorldContent>(&json).unwrap())
+    });
+    group.bench_function("lazy_index", |b| {
+        b.iter_batched(
+            || json.clone(),
+            |json| LazyWorldContent::from_json(json).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("lazy_index_and_lookups", |b| {
+        b.iter_batched(
+            || json.clone(),
+            |json| {
+                let content = LazyWorldContent::from_json(json).unwrap();
+                for hash in (FIRST_ITEM_HASH..FIRST_ITEM_HASH + ITEMS).step_by(100) {
+                    content
+                        .try_get(Hash::<DestinyInventoryItemDefinition>::new(hash))
+                        .unwrap();
+                }
+                content
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    let content = LazyWorldContent::from_json(json.clone()).unwrap();
+    let hash = Hash::<DestinyInventoryItemDefinition>::new(FIRST_ITEM_HASH);
+    group.bench_function("lazy_cached_lookup", |b| {
+        b.iter(|| content.try_get(hash).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, world_content);
+criterion_main!(benches);
@@ -1,9 +1,17 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, error::Error, fmt};
 
 use serde::{Deserialize, Serialize};
 
 pub mod destiny;
+pub mod fireteam;
+pub mod groups;
+pub mod user;
 
+/// PlatformErrorCodes.Success
+pub const PLATFORM_ERROR_SUCCESS: i32 = 1;
+
+/// The envelope every response comes in. Bungie leaves the response out when
+/// a call fails, such as when it's throttled.
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Response<T> {
@@ -12,7 +20,8 @@ pub struct Response<T> {
     error_status: String,
     message: String,
     message_data: HashMap<String, String>,
-    response: T,
+    #[serde(default = "Option::default")]
+    response: Option<T>,
     throttle_seconds: i32,
 }
 
@@ -37,11 +46,88 @@ impl<T> Response<T> {
         &self.message_data
     }
 
+    /// The response of a call that succeeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no response, as when the call failed. Use
+    /// [`Response::try_response`] or [`Response::into_result`] for calls that
+    /// may fail.
     pub fn response(&self) -> &T {
-        &self.response
+        self.try_response().unwrap_or_else(|| {
+            panic!(
+                "no response to a call that failed with {} ({}): {}",
+                self.error_status, self.error_code, self.message
+            )
+        })
+    }
+
+    /// The response, if there is one. It may be there for a call that
+    /// failed, so check the error code or use [`Response::into_result`].
+    pub fn try_response(&self) -> Option<&T> {
+        self.response.as_ref()
+    }
+
+    /// Takes the response out of its envelope, failing unless the error code
+    /// is success.
+    pub fn into_result(self) -> Result<T, PlatformError> {
+        match self.response {
+            Some(response) if self.error_code == PLATFORM_ERROR_SUCCESS => Ok(response),
+            _ => Err(PlatformError {
+                error_code: self.error_code,
+                error_status: self.error_status,
+                message: self.message,
+                throttle_seconds: self.throttle_seconds,
+            }),
+        }
+    }
+
+    pub fn throttle_seconds(&self) -> i32 {
+        self.throttle_seconds
+    }
+}
+
+/// A call the API didn't succeed at.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformError {
+    error_code: i32,
+    error_status: String,
+    message: String,
+    throttle_seconds: i32,
+}
+
+impl PlatformError {
+    /// As a PlatformErrorCodes value. Success when the call succeeded but
+    /// came back without a response.
+    pub fn error_code(&self) -> i32 {
+        self.error_code
+    }
+
+    pub fn error_status(&self) -> &str {
+        self.error_status.as_ref()
     }
 
+    pub fn message(&self) -> &str {
+        self.message.as_ref()
+    }
+
+    /// How long to wait before calling again, when throttled.
     pub fn throttle_seconds(&self) -> i32 {
         self.throttle_seconds
     }
 }
+
+impl fmt::Display for PlatformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.error_code == PLATFORM_ERROR_SUCCESS {
+            return write!(f, "the API succeeded but sent no response");
+        }
+        write!(
+            f,
+            "the API failed with {} ({}): {}",
+            self.error_status, self.error_code, self.message
+        )
+    }
+}
+
+impl Error for PlatformError {}
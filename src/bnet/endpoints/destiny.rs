@@ -1,11 +1,128 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
 use reqwest::Client;
 
-use crate::{Result, bnet::entities::destiny::config::DestinyManifest};
+use crate::{
+    bnet::entities::{
+        destiny::{
+            config::DestinyManifest,
+            historical_stats::{
+                definitions::{
+                    DestinyActivityModeType, DestinyHistoricalStatsDefinition,
+                    DestinyStatsGroupType, PeriodType,
+                },
+                DestinyActivityHistoryResults, DestinyAggregateActivityResults,
+                DestinyHistoricalStatsAccountResult, DestinyHistoricalStatsByPeriod,
+                DestinyHistoricalWeaponStatsData, DestinyPostGameCarnageReportData, StatId,
+            },
+            requests::{
+                actions::{
+                    DestinyInsertPlugsFreeActionRequest, DestinyItemActionRequest,
+                    DestinyItemSetActionRequest, DestinyItemStateRequest,
+                    DestinyLoadoutActionRequest, DestinyLoadoutUpdateActionRequest,
+                    DestinyPostmasterTransferRequest,
+                },
+                DestinyItemTransferRequest,
+            },
+            responses::{DestinyItemChangeResponse, DestinyLinkedProfilesResponse},
+            DestinyEquipItemResults,
+        },
+        user::{ExactSearchRequest, UserInfoCard},
+        BungieMembershipType,
+    },
+    Result,
+};
 
 use super::Response;
 
 pub struct Destiny<'a>(pub &'a Client);
 
+/// What GetHistoricalStats should report on. Leaving everything unset asks
+/// for the general all-time stats of every mode.
+#[derive(Clone, Debug, Default)]
+pub struct HistoricalStatsQuery {
+    day_end: Option<NaiveDate>,
+    day_start: Option<NaiveDate>,
+    groups: Vec<DestinyStatsGroupType>,
+    modes: Vec<DestinyActivityModeType>,
+    period_type: Option<PeriodType>,
+}
+
+impl HistoricalStatsQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks for daily stats between two days, inclusive. The API refuses
+    /// ranges longer than 31 days.
+    pub fn with_days(mut self, day_start: NaiveDate, day_end: NaiveDate) -> Self {
+        self.day_start = Some(day_start);
+        self.day_end = Some(day_end);
+        self.period_type = Some(PeriodType::Daily);
+        self
+    }
+
+    pub fn with_group(mut self, group: DestinyStatsGroupType) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    pub fn with_mode(mut self, mode: DestinyActivityModeType) -> Self {
+        self.modes.push(mode);
+        self
+    }
+
+    pub fn with_period_type(mut self, period_type: PeriodType) -> Self {
+        self.period_type = Some(period_type);
+        self
+    }
+
+    pub fn day_end(&self) -> Option<NaiveDate> {
+        self.day_end
+    }
+
+    pub fn day_start(&self) -> Option<NaiveDate> {
+        self.day_start
+    }
+
+    pub fn groups(&self) -> &[DestinyStatsGroupType] {
+        self.groups.as_ref()
+    }
+
+    pub fn modes(&self) -> &[DestinyActivityModeType] {
+        self.modes.as_ref()
+    }
+
+    pub fn period_type(&self) -> Option<PeriodType> {
+        self.period_type
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(day_end) = self.day_end {
+            query.push(("dayend", day_end.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(day_start) = self.day_start {
+            query.push(("daystart", day_start.format("%Y-%m-%d").to_string()));
+        }
+        if !self.groups.is_empty() {
+            query.push(("groups", join(self.groups.iter().map(|g| i32::from(*g)))));
+        }
+        if !self.modes.is_empty() {
+            query.push(("modes", join(self.modes.iter().map(|m| i32::from(*m)))));
+        }
+        if let Some(period_type) = self.period_type {
+            query.push(("periodType", i32::from(period_type).to_string()));
+        }
+        query
+    }
+}
+
+fn join(values: impl Iterator<Item = i32>) -> String {
+    values.map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+}
+
 impl Destiny<'_> {
     /// Returns the current version of the manifest as a json object.
     pub async fn get_destiny_manifest(&self) -> Result<Response<DestinyManifest>> {
@@ -14,4 +131,378 @@ impl Destiny<'_> {
             Err(e) => Err(Box::new(e)),
         }
     }
+
+    /// Gets activity history stats for indicated character, most recent
+    /// activity first. At most 250 activities can be requested per page.
+    pub async fn get_activity_history(
+        &self,
+        membership_type: BungieMembershipType,
+        destiny_membership_id: i64,
+        character_id: i64,
+        count: i32,
+        mode: Option<DestinyActivityModeType>,
+        page: i32,
+    ) -> Result<Response<DestinyActivityHistoryResults>> {
+        let url = format!(
+            "https://www.bungie.net/platform/destiny2/{}/account/{}/character/{}/stats/activities/",
+            i32::from(membership_type), destiny_membership_id, character_id
+        );
+        let mut query = vec![("count", count), ("page", page)];
+        if let Some(mode) = mode {
+            query.push(("mode", i32::from(mode)));
+        }
+        match self.0.get(url).query(&query).send().await {
+            Ok(resp) => Ok(resp.json::<Response<DestinyActivityHistoryResults>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Gets the available post game carnage report for the activity ID.
+    pub async fn get_post_game_carnage_report(
+        &self,
+        activity_id: i64,
+    ) -> Result<Response<DestinyPostGameCarnageReportData>> {
+        let url = format!(
+            "https://stats.bungie.net/platform/destiny2/stats/postgamecarnagereport/{}/",
+            activity_id
+        );
+        match self.0.get(url).send().await {
+            Ok(resp) => Ok(resp.json::<Response<DestinyPostGameCarnageReportData>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Gets historical stats definitions, keyed by stat id.
+    pub async fn get_historical_stats_definition(
+        &self,
+    ) -> Result<Response<HashMap<StatId, DestinyHistoricalStatsDefinition>>> {
+        match self
+            .0
+            .get("https://www.bungie.net/platform/destiny2/stats/definition/")
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp
+                .json::<Response<HashMap<StatId, DestinyHistoricalStatsDefinition>>>()
+                .await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Gets historical stats for indicated character, keyed by the name of
+    /// the mode they cover. A character_id of 0 gets the stats of every
+    /// character on the account.
+    pub async fn get_historical_stats(
+        &self,
+        membership_type: BungieMembershipType,
+        destiny_membership_id: i64,
+        character_id: i64,
+        query: &HistoricalStatsQuery,
+    ) -> Result<Response<HashMap<String, DestinyHistoricalStatsByPeriod>>> {
+        let url = format!(
+            "https://www.bungie.net/platform/destiny2/{}/account/{}/character/{}/stats/",
+            i32::from(membership_type), destiny_membership_id, character_id
+        );
+        match self.0.get(url).query(&query.query()).send().await {
+            Ok(resp) => Ok(resp
+                .json::<Response<HashMap<String, DestinyHistoricalStatsByPeriod>>>()
+                .await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Gets aggregate historical stats organized around each character for a
+    /// given account.
+    pub async fn get_historical_stats_for_account(
+        &self,
+        membership_type: BungieMembershipType,
+        destiny_membership_id: i64,
+        groups: &[DestinyStatsGroupType],
+    ) -> Result<Response<DestinyHistoricalStatsAccountResult>> {
+        let url = format!(
+            "https://www.bungie.net/platform/destiny2/{}/account/{}/stats/",
+            i32::from(membership_type), destiny_membership_id
+        );
+        let mut query = Vec::new();
+        if !groups.is_empty() {
+            query.push(("groups", join(groups.iter().map(|g| i32::from(*g)))));
+        }
+        match self.0.get(url).query(&query).send().await {
+            Ok(resp) => Ok(resp.json::<Response<DestinyHistoricalStatsAccountResult>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Gets details about unique weapon usage, including all exotic weapons.
+    pub async fn get_unique_weapon_history(
+        &self,
+        membership_type: BungieMembershipType,
+        destiny_membership_id: i64,
+        character_id: i64,
+    ) -> Result<Response<DestinyHistoricalWeaponStatsData>> {
+        let url = format!(
+            "https://www.bungie.net/platform/destiny2/{}/account/{}/character/{}/stats/uniqueweapons/",
+            i32::from(membership_type), destiny_membership_id, character_id
+        );
+        match self.0.get(url).send().await {
+            Ok(resp) => Ok(resp.json::<Response<DestinyHistoricalWeaponStatsData>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Gets all activities the character has participated in together with
+    /// aggregate statistics for those activities.
+    pub async fn get_destiny_aggregate_activity_stats(
+        &self,
+        membership_type: BungieMembershipType,
+        destiny_membership_id: i64,
+        character_id: i64,
+    ) -> Result<Response<DestinyAggregateActivityResults>> {
+        let url = format!(
+            "https://www.bungie.net/platform/destiny2/{}/account/{}/character/{}/stats/aggregateactivitystats/",
+            i32::from(membership_type), destiny_membership_id, character_id
+        );
+        match self.0.get(url).send().await {
+            Ok(resp) => Ok(resp.json::<Response<DestinyAggregateActivityResults>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Returns a summary information about all profiles linked to the
+    /// requesting membership type/membership ID that have valid Destiny
+    /// information. With `get_all_memberships`, memberships overridden by
+    /// cross save are returned too.
+    pub async fn get_linked_profiles(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: i64,
+        get_all_memberships: bool,
+    ) -> Result<Response<DestinyLinkedProfilesResponse>> {
+        let url = format!(
+            "https://www.bungie.net/platform/destiny2/{}/profile/{}/linkedprofiles/",
+            i32::from(membership_type), membership_id
+        );
+        match self
+            .0
+            .get(url)
+            .query(&[("getAllMemberships", get_all_memberships)])
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<DestinyLinkedProfilesResponse>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Returns a list of Destiny memberships given a global Bungie Display
+    /// Name. This method will hide overridden memberships due to cross save.
+    /// BungieMembershipType::All searches every platform.
+    pub async fn search_destiny_player_by_bungie_name(
+        &self,
+        membership_type: BungieMembershipType,
+        request: &ExactSearchRequest,
+    ) -> Result<Response<Vec<UserInfoCard>>> {
+        let url = format!(
+            "https://www.bungie.net/platform/destiny2/searchdestinyplayerbybungiename/{}/",
+            i32::from(membership_type)
+        );
+        match self.0.post(url).json(request).send().await {
+            Ok(resp) => Ok(resp.json::<Response<Vec<UserInfoCard>>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Transfer an item to/from your vault. Instanced items must pass both
+    /// their reference hash and their instance id.
+    pub async fn transfer_item(
+        &self,
+        request: &DestinyItemTransferRequest,
+    ) -> Result<Response<i32>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/items/transferitem/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Extract an item from the Postmaster, with whatever implications that
+    /// may entail.
+    pub async fn pull_from_postmaster(
+        &self,
+        request: &DestinyPostmasterTransferRequest,
+    ) -> Result<Response<i32>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/items/pullfrompostmaster/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Equip an item. The character must be in a social space, orbit, or
+    /// offline while performing this operation.
+    pub async fn equip_item(&self, request: &DestinyItemActionRequest) -> Result<Response<i32>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/items/equipitem/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Equip a list of items by itemInstanceIds. Any items not found on your
+    /// character will be ignored, and the result of each equip is reported
+    /// individually.
+    pub async fn equip_items(
+        &self,
+        request: &DestinyItemSetActionRequest,
+    ) -> Result<Response<DestinyEquipItemResults>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/items/equipitems/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<DestinyEquipItemResults>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Set the Lock State for an instanced item.
+    pub async fn set_item_lock_state(
+        &self,
+        request: &DestinyItemStateRequest,
+    ) -> Result<Response<i32>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/items/setlockstate/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Set the Tracking State for an instanced item, if that item is a Quest
+    /// or Bounty.
+    pub async fn set_tracked_state(
+        &self,
+        request: &DestinyItemStateRequest,
+    ) -> Result<Response<i32>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/items/settrackedstate/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Insert a 'free' plug into an item's socket. This does not require
+    /// 'Advanced Write Action' authorization and is available to 3rd-party
+    /// apps, but will only work on 'free and reversible' socket actions
+    /// (perks, armor mods, shaders, ornaments, etc.)
+    pub async fn insert_socket_plug_free(
+        &self,
+        request: &DestinyInsertPlugsFreeActionRequest,
+    ) -> Result<Response<DestinyItemChangeResponse>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/items/insertsocketplugfree/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<DestinyItemChangeResponse>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Equip a loadout. The character must be in a social space, orbit, or
+    /// offline while performing this operation.
+    pub async fn equip_loadout(
+        &self,
+        request: &DestinyLoadoutActionRequest,
+    ) -> Result<Response<i32>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/loadouts/equiploadout/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Snapshot a loadout with the currently equipped items.
+    pub async fn snapshot_loadout(
+        &self,
+        request: &DestinyLoadoutUpdateActionRequest,
+    ) -> Result<Response<i32>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/loadouts/snapshotloadout/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Update the color, icon, and name of a loadout.
+    pub async fn update_loadout_identifiers(
+        &self,
+        request: &DestinyLoadoutUpdateActionRequest,
+    ) -> Result<Response<i32>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/loadouts/updateloadoutidentifiers/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Clear the identifiers and items of a loadout.
+    pub async fn clear_loadout(
+        &self,
+        request: &DestinyLoadoutActionRequest,
+    ) -> Result<Response<i32>> {
+        match self
+            .0
+            .post("https://www.bungie.net/platform/destiny2/actions/loadouts/clearloadout/")
+            .json(request)
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
 }
@@ -0,0 +1,43 @@
+// @generated by `cargo xtask codegen` from xtask/openapi.json. Do not edit.
+
+use reqwest::Client;
+
+use crate::{bnet::entities::fireteam::FireteamResponse, Result};
+
+use super::Response;
+
+pub struct Fireteam<'a>(pub &'a Client);
+
+impl Fireteam<'_> {
+    /// Gets a count of all active non-public fireteams for the specified clan.
+    /// Note that this endpoint is always unauthenticated.
+    pub async fn get_active_private_clan_fireteam_count(
+        &self,
+        group_id: i64,
+    ) -> Result<Response<i32>> {
+        let url = format!(
+            "https://www.bungie.net/platform/fireteam/clan/{}/activecount/",
+            group_id
+        );
+        match self.0.get(url).send().await {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Gets a specific fireteam.
+    pub async fn get_clan_fireteam(
+        &self,
+        group_id: i64,
+        fireteam_id: i64,
+    ) -> Result<Response<FireteamResponse>> {
+        let url = format!(
+            "https://www.bungie.net/platform/fireteam/clan/{}/summary/{}/",
+            group_id, fireteam_id
+        );
+        match self.0.get(url).send().await {
+            Ok(resp) => Ok(resp.json::<Response<FireteamResponse>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use reqwest::Client;
+
+use crate::{
+    bnet::entities::{
+        groups_v2::{
+            GetGroupsForMemberResponse, GroupApplicationListRequest, GroupApplicationRequest,
+            GroupBanRequest, GroupMember, GroupMemberApplication, GroupMemberLeaveResult,
+            GroupResponse, RuntimeGroupMemberType,
+        },
+        queries::SearchResult,
+        BungieMembershipType, EntityActionResult,
+    },
+    Result,
+};
+
+use super::Response;
+
+pub struct Groups<'a>(pub &'a Client);
+
+impl Groups<'_> {
+    /// Get information about a specific group of the given ID.
+    pub async fn get_group(&self, group_id: i64) -> Result<Response<GroupResponse>> {
+        let url = format!("https://www.bungie.net/platform/groupv2/{}/", group_id);
+        match self.0.get(url).send().await {
+            Ok(resp) => Ok(resp.json::<Response<GroupResponse>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Get information about the groups that a given member has joined.
+    /// `filter` is a GroupsForMemberFilter value and `group_type` a GroupType
+    /// value, 1 being clans.
+    pub async fn get_groups_for_member(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: i64,
+        filter: i32,
+        group_type: i32,
+    ) -> Result<Response<GetGroupsForMemberResponse>> {
+        let url = format!(
+            "https://www.bungie.net/platform/groupv2/user/{}/{}/{}/{}/",
+            i32::from(membership_type),
+            membership_id,
+            filter,
+            group_type
+        );
+        match self.0.get(url).send().await {
+            Ok(resp) => Ok(resp.json::<Response<GetGroupsForMemberResponse>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Get the list of members in a given group. Pages start at 1, and
+    /// `member_type` narrows the list down to members of that rank.
+    pub async fn get_members_of_group(
+        &self,
+        group_id: i64,
+        current_page: i32,
+        member_type: Option<RuntimeGroupMemberType>,
+        name_search: Option<&str>,
+    ) -> Result<Response<SearchResult<GroupMember>>> {
+        let url = format!(
+            "https://www.bungie.net/platform/groupv2/{}/members/",
+            group_id
+        );
+        let mut query = vec![("currentpage", current_page.to_string())];
+        if let Some(member_type) = member_type {
+            query.push(("memberType", i32::from(member_type).to_string()));
+        }
+        if let Some(name_search) = name_search {
+            query.push(("nameSearch", name_search.to_string()));
+        }
+        match self.0.get(url).query(&query).send().await {
+            Ok(resp) => Ok(resp.json::<Response<SearchResult<GroupMember>>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Get the list of users who are awaiting a decision on their application
+    /// to join a given group. Pages start at 1.
+    pub async fn get_pending_memberships(
+        &self,
+        group_id: i64,
+        current_page: i32,
+    ) -> Result<Response<SearchResult<GroupMemberApplication>>> {
+        let url = format!(
+            "https://www.bungie.net/platform/groupv2/{}/members/pending/",
+            group_id
+        );
+        match self
+            .0
+            .get(url)
+            .query(&[("currentpage", current_page)])
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp
+                .json::<Response<SearchResult<GroupMemberApplication>>>()
+                .await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Approve the given membershipId to join the group/clan as long as they
+    /// have applied.
+    pub async fn approve_pending(
+        &self,
+        group_id: i64,
+        membership_type: BungieMembershipType,
+        membership_id: i64,
+        request: &GroupApplicationRequest,
+    ) -> Result<Response<bool>> {
+        let url = format!(
+            "https://www.bungie.net/platform/groupv2/{}/members/approve/{}/{}/",
+            group_id,
+            i32::from(membership_type),
+            membership_id
+        );
+        match self.0.post(url).json(request).send().await {
+            Ok(resp) => Ok(resp.json::<Response<bool>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Approve all of the pending users for the given group.
+    pub async fn approve_pending_for_list(
+        &self,
+        group_id: i64,
+        request: &GroupApplicationListRequest,
+    ) -> Result<Response<Vec<EntityActionResult>>> {
+        let url = format!(
+            "https://www.bungie.net/platform/groupv2/{}/members/approvelist/",
+            group_id
+        );
+        match self.0.post(url).json(request).send().await {
+            Ok(resp) => Ok(resp.json::<Response<Vec<EntityActionResult>>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Deny all of the pending users for the given group that match the
+    /// passed-in.
+    pub async fn deny_pending_for_list(
+        &self,
+        group_id: i64,
+        request: &GroupApplicationListRequest,
+    ) -> Result<Response<Vec<EntityActionResult>>> {
+        let url = format!(
+            "https://www.bungie.net/platform/groupv2/{}/members/denylist/",
+            group_id
+        );
+        match self.0.post(url).json(request).send().await {
+            Ok(resp) => Ok(resp.json::<Response<Vec<EntityActionResult>>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Kick a member from the given group, forcing them to reapply if they
+    /// wish to re-join the group. You must have suitable permissions in the
+    /// group to perform this operation.
+    pub async fn kick_member(
+        &self,
+        group_id: i64,
+        membership_type: BungieMembershipType,
+        membership_id: i64,
+    ) -> Result<Response<GroupMemberLeaveResult>> {
+        let url = format!(
+            "https://www.bungie.net/platform/groupv2/{}/members/{}/{}/kick/",
+            group_id,
+            i32::from(membership_type),
+            membership_id
+        );
+        match self.0.post(url).send().await {
+            Ok(resp) => Ok(resp.json::<Response<GroupMemberLeaveResult>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Bans the requested member from the requested group for the specified
+    /// period of time.
+    pub async fn ban_member(
+        &self,
+        group_id: i64,
+        membership_type: BungieMembershipType,
+        membership_id: i64,
+        request: &GroupBanRequest,
+    ) -> Result<Response<i32>> {
+        let url = format!(
+            "https://www.bungie.net/platform/groupv2/{}/members/{}/{}/ban/",
+            group_id,
+            i32::from(membership_type),
+            membership_id
+        );
+        match self.0.post(url).json(request).send().await {
+            Ok(resp) => Ok(resp.json::<Response<i32>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+}
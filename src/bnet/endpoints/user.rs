@@ -0,0 +1,68 @@
+use reqwest::Client;
+
+use crate::{
+    bnet::entities::{
+        user::{UserMembershipData, UserSearchPrefixRequest, UserSearchResponse},
+        BungieMembershipType,
+    },
+    Result,
+};
+
+use super::Response;
+
+pub struct User<'a>(pub &'a Client);
+
+impl User<'_> {
+    /// Returns a list of accounts associated with the supplied membership ID
+    /// and membership type. This will include all linked accounts (even when
+    /// hidden) if supplied credentials permit it.
+    pub async fn get_membership_data_by_id(
+        &self,
+        membership_id: i64,
+        membership_type: BungieMembershipType,
+    ) -> Result<Response<UserMembershipData>> {
+        let url = format!(
+            "https://www.bungie.net/platform/user/getmembershipsbyid/{}/{}/",
+            membership_id,
+            i32::from(membership_type)
+        );
+        match self.0.get(url).send().await {
+            Ok(resp) => Ok(resp.json::<Response<UserMembershipData>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Returns a list of accounts associated with signed in user. This is
+    /// useful for OAuth implementations that do not give you access to the
+    /// token response.
+    pub async fn get_membership_data_for_current_user(
+        &self,
+    ) -> Result<Response<UserMembershipData>> {
+        match self
+            .0
+            .get("https://www.bungie.net/platform/user/getmembershipsforcurrentuser/")
+            .send()
+            .await
+        {
+            Ok(resp) => Ok(resp.json::<Response<UserMembershipData>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Given the prefix of a global display name, returns all users who share
+    /// that name. Pages start at 0.
+    pub async fn search_by_global_name_post(
+        &self,
+        page: i32,
+        request: &UserSearchPrefixRequest,
+    ) -> Result<Response<UserSearchResponse>> {
+        let url = format!(
+            "https://www.bungie.net/platform/user/search/globalname/{}/",
+            page
+        );
+        match self.0.post(url).json(request).send().await {
+            Ok(resp) => Ok(resp.json::<Response<UserSearchResponse>>().await?),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+}
@@ -1,28 +1,147 @@
-use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub mod components;
 pub mod dates;
 pub mod destiny;
+pub mod fireteam;
+pub mod groups_v2;
 pub mod interpolation;
 pub mod links;
+pub mod queries;
 pub mod user;
+mod wire;
 
 /// The types of membership the Accounts system supports. This is the external
 /// facing enum used in place of the internal-only Bungie.SharedDefinitions.MembershipType.
 ///
 /// https://bungie-net.github.io/#/components/schemas/BungieMembershipType
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum BungieMembershipType {
-    None = 0,
-    TigerXbox = 1,
-    TigerPsn = 2,
-    TigerSteam = 3,
-    TigerBlizzard = 4,
-    TigerStadia = 5,
-    TigerDemon = 10,
-    BungieNext = 254,
-
+    None,
+    TigerXbox,
+    TigerPsn,
+    TigerSteam,
+    TigerBlizzard,
+    TigerStadia,
+    TigerEgs,
+    TigerDemon,
+    BungieNext,
     /// "All" is only valid for searching capabilities: you need to pass the
     /// actual matching BungieMembershipType for any query where you pass a
     /// known membershipId.
-    All = -1,
+    All,
+    /// A membership type added after this was written, kept as it was sent.
+    Unknown(i32),
+}
+
+impl fmt::Display for BungieMembershipType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BungieMembershipType::None => write!(f, "None"),
+            BungieMembershipType::TigerXbox => write!(f, "TigerXbox"),
+            BungieMembershipType::TigerPsn => write!(f, "TigerPsn"),
+            BungieMembershipType::TigerSteam => write!(f, "TigerSteam"),
+            BungieMembershipType::TigerBlizzard => write!(f, "TigerBlizzard"),
+            BungieMembershipType::TigerStadia => write!(f, "TigerStadia"),
+            BungieMembershipType::TigerEgs => write!(f, "TigerEgs"),
+            BungieMembershipType::TigerDemon => write!(f, "TigerDemon"),
+            BungieMembershipType::BungieNext => write!(f, "BungieNext"),
+            BungieMembershipType::All => write!(f, "All"),
+            BungieMembershipType::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<BungieMembershipType> for i32 {
+    fn from(value: BungieMembershipType) -> Self {
+        match value {
+            BungieMembershipType::None => 0,
+            BungieMembershipType::TigerXbox => 1,
+            BungieMembershipType::TigerPsn => 2,
+            BungieMembershipType::TigerSteam => 3,
+            BungieMembershipType::TigerBlizzard => 4,
+            BungieMembershipType::TigerStadia => 5,
+            BungieMembershipType::TigerEgs => 6,
+            BungieMembershipType::TigerDemon => 10,
+            BungieMembershipType::BungieNext => 254,
+            BungieMembershipType::All => -1,
+            BungieMembershipType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for BungieMembershipType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => BungieMembershipType::None,
+            1 => BungieMembershipType::TigerXbox,
+            2 => BungieMembershipType::TigerPsn,
+            3 => BungieMembershipType::TigerSteam,
+            4 => BungieMembershipType::TigerBlizzard,
+            5 => BungieMembershipType::TigerStadia,
+            6 => BungieMembershipType::TigerEgs,
+            10 => BungieMembershipType::TigerDemon,
+            254 => BungieMembershipType::BungieNext,
+            -1 => BungieMembershipType::All,
+            _ => BungieMembershipType::Unknown(value),
+        }
+    }
+}
+
+impl FromStr for BungieMembershipType {
+    type Err = String;
+
+    /// Parses the variant's name or integer value.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "None" => Ok(BungieMembershipType::None),
+            "TigerXbox" => Ok(BungieMembershipType::TigerXbox),
+            "TigerPsn" => Ok(BungieMembershipType::TigerPsn),
+            "TigerSteam" => Ok(BungieMembershipType::TigerSteam),
+            "TigerBlizzard" => Ok(BungieMembershipType::TigerBlizzard),
+            "TigerStadia" => Ok(BungieMembershipType::TigerStadia),
+            "TigerEgs" => Ok(BungieMembershipType::TigerEgs),
+            "TigerDemon" => Ok(BungieMembershipType::TigerDemon),
+            "BungieNext" => Ok(BungieMembershipType::BungieNext),
+            "All" => Ok(BungieMembershipType::All),
+            _ => s
+                .parse::<i32>()
+                .map(BungieMembershipType::from)
+                .map_err(|_| format!("invalid BungieMembershipType {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BungieMembershipType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        wire::deserialize_enum(deserializer)
+    }
+}
+
+impl Serialize for BungieMembershipType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(i32::from(*self))
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Entities.EntityActionResult
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityActionResult {
+    #[serde(deserialize_with = "wire::deserialize_int64")]
+    entity_id: i64,
+    result: i32,
+}
+
+impl EntityActionResult {
+    pub fn entity_id(&self) -> i64 {
+        self.entity_id
+    }
+
+    /// As a PlatformErrorCodes value, 1 being success.
+    pub fn result(&self) -> i32 {
+        self.result
+    }
 }
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+/// The base class for any component-returning object that may need to
+/// indicate information about the state of the component being returned.
+///
+/// Every SingleComponentResponseOf* and DictionaryComponentResponseOf* schema
+/// shares this shape, with the component (or the dictionary of components) in
+/// `data`.
+///
+/// https://bungie-net.github.io/#/components/schemas/Components.ComponentResponse
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentResponse<T> {
+    data: Option<T>,
+    disabled: Option<bool>,
+    privacy: i32,
+}
+
+impl<T> ComponentResponse<T> {
+    /// The component, when it could be returned.
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    /// If true, this component is disabled.
+    pub fn disabled(&self) -> Option<bool> {
+        self.disabled
+    }
+
+    /// A ComponentPrivacySetting value.
+    pub fn privacy(&self) -> i32 {
+        self.privacy
+    }
+}
@@ -1,6 +1,6 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt, ops::BitOr, str::FromStr};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use self::definitions::{
     activity_modifiers::DestinyActivityModifierDefinition,
@@ -11,6 +11,10 @@ use self::definitions::{
     director::DestinyActivityGraphDefinition,
     energy_types::DestinyEnergyTypeDefinition,
     items::DestinyItemTierTypeDefinition,
+    loadouts::{
+        DestinyLoadoutColorDefinition, DestinyLoadoutConstantsDefinition,
+        DestinyLoadoutIconDefinition, DestinyLoadoutNameDefinition,
+    },
     lore::DestinyLoreDefinition,
     metrics::DestinyMetricDefinition,
     milestones::DestinyMilestoneDefinition,
@@ -24,6 +28,18 @@ use self::definitions::{
         DestinyPlugSetDefinition, DestinySocketCategoryDefinition, DestinySocketTypeDefinition,
     },
     traits::{DestinyTraitCategoryDefinition, DestinyTraitDefinition},
+    undocumented::{
+        DestinyAchievementDefinition, DestinyActivityInteractableDefinition,
+        DestinyArtDyeChannelDefinition, DestinyBondDefinition,
+        DestinyCharacterCustomizationCategoryDefinition,
+        DestinyCharacterCustomizationOptionDefinition, DestinyEntitlementOfferDefinition,
+        DestinyInventoryItemLiteDefinition, DestinyNodeStepSummaryDefinition,
+        DestinyPlatformBucketMappingDefinition, DestinyRewardAdjusterPointerDefinition,
+        DestinyRewardAdjusterProgressionMapDefinition, DestinyRewardItemListDefinition,
+        DestinyRewardMappingDefinition, DestinyRewardSheetDefinition,
+        DestinySackRewardItemListDefinition, DestinyUnlockCountMappingDefinition,
+        DestinyUnlockEventDefinition, DestinyUnlockExpressionMappingDefinition,
+    },
     DestinyActivityDefinition, DestinyActivityModeDefinition, DestinyActivityTypeDefinition,
     DestinyArtDyeReference, DestinyClassDefinition, DestinyDamageTypeDefinition,
     DestinyDestinationDefinition, DestinyEquipmentSlotDefinition, DestinyFactionDefinition,
@@ -37,14 +53,23 @@ use self::definitions::{
     DestinyUnlockValueDefinition, DestinyVendorDefinition, DestinyVendorGroupDefinition,
 };
 
+use self::hashes::Hash;
+
+use super::wire;
+
 pub mod components;
 pub mod config;
 pub mod constants;
 pub mod definitions;
 pub mod entities;
+pub mod hashes;
+pub mod historical_stats;
 pub mod misc;
 pub mod quests;
+pub mod requests;
 pub mod responses;
+pub mod sockets;
+pub mod world_content;
 
 /// Where all the deserialized game content lives.
 ///
@@ -53,27 +78,37 @@ pub mod responses;
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DestinyWorldContent {
-    // destiny_achievement_definition: HashMap<String, DestinyAchievementDefinition>,
+    #[serde(default)]
+    destiny_achievement_definition: HashMap<String, DestinyAchievementDefinition>,
     destiny_activity_definition: HashMap<String, DestinyActivityDefinition>,
     destiny_activity_graph_definition: HashMap<String, DestinyActivityGraphDefinition>,
-    // destiny_activity_interactable_definition: HashMap<String, DestinyActivityInteractableDefinition>,
+    #[serde(default)]
+    destiny_activity_interactable_definition:
+        HashMap<String, DestinyActivityInteractableDefinition>,
     destiny_activity_mode_definition: HashMap<String, DestinyActivityModeDefinition>,
     destiny_activity_modifier_definition: HashMap<String, DestinyActivityModifierDefinition>,
     destiny_activity_type_definition: HashMap<String, DestinyActivityTypeDefinition>,
-    // destiny_art_dye_channel_definition: HashMap<String, DestinyArtDyeChannelDefinition>,
+    #[serde(default)]
+    destiny_art_dye_channel_definition: HashMap<String, DestinyArtDyeChannelDefinition>,
     destiny_art_dye_reference_definition: HashMap<String, DestinyArtDyeReference>,
     destiny_artifact_definition: HashMap<String, DestinyArtifactDefinition>,
-    // destiny_bond_definition: HashMap<String, DestinyBondDefinition>,
+    #[serde(default)]
+    destiny_bond_definition: HashMap<String, DestinyBondDefinition>,
     destiny_breaker_type_definition: HashMap<String, DestinyBreakerTypeDefinition>,
-    // destiny_character_customization_category_definition: HashMap<String, DestinyCharacterCustomizationCategoryDefinition>,
-    // destiny_character_customization_option_definition: HashMap<String, DestinyCharacterCustomizationOptionDefinition>,
+    #[serde(default)]
+    destiny_character_customization_category_definition:
+        HashMap<String, DestinyCharacterCustomizationCategoryDefinition>,
+    #[serde(default)]
+    destiny_character_customization_option_definition:
+        HashMap<String, DestinyCharacterCustomizationOptionDefinition>,
     destiny_checklist_definition: HashMap<String, DestinyChecklistDefinition>,
     destiny_class_definition: HashMap<String, DestinyClassDefinition>,
     destiny_collectible_definition: HashMap<String, DestinyCollectibleDefinition>,
     destiny_damage_type_definition: HashMap<String, DestinyDamageTypeDefinition>,
     destiny_destination_definition: HashMap<String, DestinyDestinationDefinition>,
     destiny_energy_type_definition: HashMap<String, DestinyEnergyTypeDefinition>,
-    // destiny_entitlement_offer_definition: HashMap<String, DestinyEntitlementOfferDefinition>,
+    #[serde(default)]
+    destiny_entitlement_offer_definition: HashMap<String, DestinyEntitlementOfferDefinition>,
     destiny_equipment_slot_definition: HashMap<String, DestinyEquipmentSlotDefinition>,
     destiny_faction_definition: HashMap<String, DestinyFactionDefinition>,
     destiny_gender_definition: HashMap<String, DestinyGenderDefinition>,
@@ -81,7 +116,12 @@ pub struct DestinyWorldContent {
     destiny_item_tier_type_definition: HashMap<String, DestinyItemTierTypeDefinition>,
     destiny_inventory_bucket_definition: HashMap<String, DestinyInventoryBucketDefinition>,
     destiny_inventory_item_definition: HashMap<String, DestinyInventoryItemDefinition>,
-    // destiny_inventory_item_lite_definition: HashMap<String, DestinyInventoryItemLiteDefinition>,
+    #[serde(default)]
+    destiny_inventory_item_lite_definition: HashMap<String, DestinyInventoryItemLiteDefinition>,
+    destiny_loadout_color_definition: HashMap<String, DestinyLoadoutColorDefinition>,
+    destiny_loadout_constants_definition: HashMap<String, DestinyLoadoutConstantsDefinition>,
+    destiny_loadout_icon_definition: HashMap<String, DestinyLoadoutIconDefinition>,
+    destiny_loadout_name_definition: HashMap<String, DestinyLoadoutNameDefinition>,
     destiny_location_definition: HashMap<String, DestinyLocationDefinition>,
     destiny_lore_definition: HashMap<String, DestinyLoreDefinition>,
     destiny_material_requirement_set_definition:
@@ -89,10 +129,13 @@ pub struct DestinyWorldContent {
     destiny_medal_tier_definition: HashMap<String, DestinyMedalTierDefinition>,
     destiny_metric_definition: HashMap<String, DestinyMetricDefinition>,
     destiny_milestone_definition: HashMap<String, DestinyMilestoneDefinition>,
-    // destiny_node_step_summary_definition: HashMap<String, DestinyNodeStepSummaryDefinition>,
+    #[serde(default)]
+    destiny_node_step_summary_definition: HashMap<String, DestinyNodeStepSummaryDefinition>,
     destiny_objective_definition: HashMap<String, DestinyObjectiveDefinition>,
     destiny_place_definition: HashMap<String, DestinyPlaceDefinition>,
-    // destiny_platform_bucket_mapping_definition: HashMap<String, DestinyPlatformBucketMappingDefinition>,
+    #[serde(default)]
+    destiny_platform_bucket_mapping_definition:
+        HashMap<String, DestinyPlatformBucketMappingDefinition>,
     destiny_plug_set_definition: HashMap<String, DestinyPlugSetDefinition>,
     destiny_power_cap_definition: HashMap<String, DestinyPowerCapDefinition>,
     destiny_presentation_node_definition: HashMap<String, DestinyPresentationNodeDefinition>,
@@ -104,13 +147,21 @@ pub struct DestinyWorldContent {
     destiny_record_definition: HashMap<String, DestinyRecordDefinition>,
     destiny_report_reason_category_definition:
         HashMap<String, DestinyReportReasonCategoryDefinition>,
-    // destiny_reward_adjuster_pointer_definition: HashMap<String, DestinyRewardAdjusterPointerDefinition>,
-    // destiny_reward_adjuster_progression_map_definition: HashMap<String, DestinyRewardAdjusterProgressionMapDefinition>,
-    // destiny_reward_item_list_definition: HashMap<String, DestinyRewardItemListDefinition>,
-    // destiny_reward_mapping_definition: HashMap<String, DestinyRewardMappingDefinition>,
-    // destiny_reward_sheet_definition: HashMap<String, DestinyRewardSheetDefinition>,
+    #[serde(default)]
+    destiny_reward_adjuster_pointer_definition:
+        HashMap<String, DestinyRewardAdjusterPointerDefinition>,
+    #[serde(default)]
+    destiny_reward_adjuster_progression_map_definition:
+        HashMap<String, DestinyRewardAdjusterProgressionMapDefinition>,
+    #[serde(default)]
+    destiny_reward_item_list_definition: HashMap<String, DestinyRewardItemListDefinition>,
+    #[serde(default)]
+    destiny_reward_mapping_definition: HashMap<String, DestinyRewardMappingDefinition>,
+    #[serde(default)]
+    destiny_reward_sheet_definition: HashMap<String, DestinyRewardSheetDefinition>,
     destiny_reward_source_definition: HashMap<String, DestinyRewardSourceDefinition>,
-    // destiny_sack_reward_item_list_definition: HashMap<String, DestinySackRewardItemListDefinition>,
+    #[serde(default)]
+    destiny_sack_reward_item_list_definition: HashMap<String, DestinySackRewardItemListDefinition>,
     destiny_sandbox_pattern_definition: HashMap<String, DestinySandboxPatternDefinition>,
     destiny_sandbox_perk_definition: HashMap<String, DestinySandboxPerkDefinition>,
     destiny_season_definition: HashMap<String, DestinySeasonDefinition>,
@@ -122,19 +173,23 @@ pub struct DestinyWorldContent {
     destiny_talent_grid_definition: HashMap<String, DestinyTalentGridDefinition>,
     destiny_trait_definition: HashMap<String, DestinyTraitDefinition>,
     destiny_trait_category_definition: HashMap<String, DestinyTraitCategoryDefinition>,
-    // destiny_unlock_count_mapping_definition: HashMap<String, DestinyUnlockCountMappingDefinition>,
+    #[serde(default)]
+    destiny_unlock_count_mapping_definition: HashMap<String, DestinyUnlockCountMappingDefinition>,
     destiny_unlock_definition: HashMap<String, DestinyUnlockDefinition>,
-    // destiny_unlock_event_definition: HashMap<String, DestinyUnlockEventDefinition>,
-    // destiny_unlock_expression_mapping_definition: HashMap<String, DestinyUnlockExpressionMappingDefinition>,
+    #[serde(default)]
+    destiny_unlock_event_definition: HashMap<String, DestinyUnlockEventDefinition>,
+    #[serde(default)]
+    destiny_unlock_expression_mapping_definition:
+        HashMap<String, DestinyUnlockExpressionMappingDefinition>,
     destiny_unlock_value_definition: HashMap<String, DestinyUnlockValueDefinition>,
     destiny_vendor_definition: HashMap<String, DestinyVendorDefinition>,
     destiny_vendor_group_definition: HashMap<String, DestinyVendorGroupDefinition>,
 }
 
 impl DestinyWorldContent {
-    // pub fn destiny_achievement_definition(&self) -> &HashMap<String, DestinyAchievementDefinition> {
-    //     &self.destiny_achievement_definition
-    // }
+    pub fn destiny_achievement_definition(&self) -> &HashMap<String, DestinyAchievementDefinition> {
+        &self.destiny_achievement_definition
+    }
 
     pub fn destiny_activity_definition(&self) -> &HashMap<String, DestinyActivityDefinition> {
         &self.destiny_activity_definition
@@ -146,9 +201,11 @@ impl DestinyWorldContent {
         &self.destiny_activity_graph_definition
     }
 
-    // pub fn destiny_activity_interactable_definition(&self) -> &HashMap<String, DestinyActivityInteractableDefinition> {
-    //     &self.destiny_activity_interactable_definition
-    // }
+    pub fn destiny_activity_interactable_definition(
+        &self,
+    ) -> &HashMap<String, DestinyActivityInteractableDefinition> {
+        &self.destiny_activity_interactable_definition
+    }
 
     pub fn destiny_activity_mode_definition(
         &self,
@@ -168,9 +225,11 @@ impl DestinyWorldContent {
         &self.destiny_activity_type_definition
     }
 
-    // pub fn destiny_art_dye_channel_definition(&self) -> &HashMap<String, DestinyArtDyeChannelDefinition> {
-    //     &self.destiny_art_dye_channel_definition
-    // }
+    pub fn destiny_art_dye_channel_definition(
+        &self,
+    ) -> &HashMap<String, DestinyArtDyeChannelDefinition> {
+        &self.destiny_art_dye_channel_definition
+    }
 
     pub fn destiny_art_dye_reference_definition(&self) -> &HashMap<String, DestinyArtDyeReference> {
         &self.destiny_art_dye_reference_definition
@@ -180,9 +239,9 @@ impl DestinyWorldContent {
         &self.destiny_artifact_definition
     }
 
-    // pub fn destiny_bond_definition(&self) -> &HashMap<String, DestinyBondDefinition> {
-    //     &self.destiny_bond_definition
-    // }
+    pub fn destiny_bond_definition(&self) -> &HashMap<String, DestinyBondDefinition> {
+        &self.destiny_bond_definition
+    }
 
     pub fn destiny_breaker_type_definition(
         &self,
@@ -190,13 +249,17 @@ impl DestinyWorldContent {
         &self.destiny_breaker_type_definition
     }
 
-    // pub fn destiny_character_customization_category_definition(&self) -> &HashMap<String, DestinyCharacterCustomizationCategoryDefinition> {
-    //     &self.destiny_character_customization_category_definition
-    // }
+    pub fn destiny_character_customization_category_definition(
+        &self,
+    ) -> &HashMap<String, DestinyCharacterCustomizationCategoryDefinition> {
+        &self.destiny_character_customization_category_definition
+    }
 
-    // pub fn destiny_character_customization_option_definition(&self) -> &HashMap<String, DestinyCharacterCustomizationOptionDefinition> {
-    //     &self.destiny_character_customization_option_definition
-    // }
+    pub fn destiny_character_customization_option_definition(
+        &self,
+    ) -> &HashMap<String, DestinyCharacterCustomizationOptionDefinition> {
+        &self.destiny_character_customization_option_definition
+    }
 
     pub fn destiny_checklist_definition(&self) -> &HashMap<String, DestinyChecklistDefinition> {
         &self.destiny_checklist_definition
@@ -222,9 +285,11 @@ impl DestinyWorldContent {
         &self.destiny_energy_type_definition
     }
 
-    // pub fn destiny_entitlement_offer_definition(&self) -> &HashMap<String, DestinyEntitlementOfferDefinition> {
-    //     &self.destiny_entitlement_offer_definition
-    // }
+    pub fn destiny_entitlement_offer_definition(
+        &self,
+    ) -> &HashMap<String, DestinyEntitlementOfferDefinition> {
+        &self.destiny_entitlement_offer_definition
+    }
 
     pub fn destiny_equipment_slot_definition(
         &self,
@@ -264,9 +329,35 @@ impl DestinyWorldContent {
         &self.destiny_inventory_item_definition
     }
 
-    // pub fn destiny_inventory_item_lite_definition(&self) -> &HashMap<String, DestinyInventoryItemLiteDefinition> {
-    //     &self.destiny_inventory_item_lite_definition
-    // }
+    pub fn destiny_inventory_item_lite_definition(
+        &self,
+    ) -> &HashMap<String, DestinyInventoryItemLiteDefinition> {
+        &self.destiny_inventory_item_lite_definition
+    }
+
+    pub fn destiny_loadout_color_definition(
+        &self,
+    ) -> &HashMap<String, DestinyLoadoutColorDefinition> {
+        &self.destiny_loadout_color_definition
+    }
+
+    pub fn destiny_loadout_constants_definition(
+        &self,
+    ) -> &HashMap<String, DestinyLoadoutConstantsDefinition> {
+        &self.destiny_loadout_constants_definition
+    }
+
+    pub fn destiny_loadout_icon_definition(
+        &self,
+    ) -> &HashMap<String, DestinyLoadoutIconDefinition> {
+        &self.destiny_loadout_icon_definition
+    }
+
+    pub fn destiny_loadout_name_definition(
+        &self,
+    ) -> &HashMap<String, DestinyLoadoutNameDefinition> {
+        &self.destiny_loadout_name_definition
+    }
 
     pub fn destiny_location_definition(&self) -> &HashMap<String, DestinyLocationDefinition> {
         &self.destiny_location_definition
@@ -294,9 +385,11 @@ impl DestinyWorldContent {
         &self.destiny_milestone_definition
     }
 
-    // pub fn destiny_node_step_summary_definition(&self) -> &HashMap<String, DestinyNodeStepSummaryDefinition> {
-    //     &self.destiny_node_step_summary_definition
-    // }
+    pub fn destiny_node_step_summary_definition(
+        &self,
+    ) -> &HashMap<String, DestinyNodeStepSummaryDefinition> {
+        &self.destiny_node_step_summary_definition
+    }
 
     pub fn destiny_objective_definition(&self) -> &HashMap<String, DestinyObjectiveDefinition> {
         &self.destiny_objective_definition
@@ -306,9 +399,11 @@ impl DestinyWorldContent {
         &self.destiny_place_definition
     }
 
-    // pub fn destiny_platform_bucket_mapping_definition(&self) -> &HashMap<String, DestinyPlatformBucketMappingDefinition> {
-    //     &self.destiny_platform_bucket_mapping_definition
-    // }
+    pub fn destiny_platform_bucket_mapping_definition(
+        &self,
+    ) -> &HashMap<String, DestinyPlatformBucketMappingDefinition> {
+        &self.destiny_platform_bucket_mapping_definition
+    }
 
     pub fn destiny_plug_set_definition(&self) -> &HashMap<String, DestinyPlugSetDefinition> {
         &self.destiny_plug_set_definition
@@ -354,25 +449,35 @@ impl DestinyWorldContent {
         &self.destiny_report_reason_category_definition
     }
 
-    // pub fn destiny_reward_adjuster_pointer_definition(&self) -> &HashMap<String, DestinyRewardAdjusterPointerDefinition> {
-    //     &self.destiny_reward_adjuster_pointer_definition
-    // }
+    pub fn destiny_reward_adjuster_pointer_definition(
+        &self,
+    ) -> &HashMap<String, DestinyRewardAdjusterPointerDefinition> {
+        &self.destiny_reward_adjuster_pointer_definition
+    }
 
-    // pub fn destiny_reward_adjuster_progression_map_definition(&self) -> &HashMap<String, DestinyRewardAdjusterProgressionMapDefinition> {
-    //     &self.destiny_reward_adjuster_progression_map_definition
-    // }
+    pub fn destiny_reward_adjuster_progression_map_definition(
+        &self,
+    ) -> &HashMap<String, DestinyRewardAdjusterProgressionMapDefinition> {
+        &self.destiny_reward_adjuster_progression_map_definition
+    }
 
-    // pub fn destiny_reward_item_list_definition(&self) -> &HashMap<String, DestinyRewardItemListDefinition> {
-    //     &self.destiny_reward_item_list_definition
-    // }
+    pub fn destiny_reward_item_list_definition(
+        &self,
+    ) -> &HashMap<String, DestinyRewardItemListDefinition> {
+        &self.destiny_reward_item_list_definition
+    }
 
-    // pub fn destiny_reward_mapping_definition(&self) -> &HashMap<String, DestinyRewardMappingDefinition> {
-    //     &self.destiny_reward_mapping_definition
-    // }
+    pub fn destiny_reward_mapping_definition(
+        &self,
+    ) -> &HashMap<String, DestinyRewardMappingDefinition> {
+        &self.destiny_reward_mapping_definition
+    }
 
-    // pub fn destiny_reward_sheet_definition(&self) -> &HashMap<String, DestinyRewardSheetDefinition> {
-    //     &self.destiny_reward_sheet_definition
-    // }
+    pub fn destiny_reward_sheet_definition(
+        &self,
+    ) -> &HashMap<String, DestinyRewardSheetDefinition> {
+        &self.destiny_reward_sheet_definition
+    }
 
     pub fn destiny_reward_source_definition(
         &self,
@@ -380,9 +485,11 @@ impl DestinyWorldContent {
         &self.destiny_reward_source_definition
     }
 
-    // pub fn destiny_sack_reward_item_list_definition(&self) -> &HashMap<String, DestinySackRewardItemListDefinition> {
-    //     &self.destiny_sack_reward_item_list_definition
-    // }
+    pub fn destiny_sack_reward_item_list_definition(
+        &self,
+    ) -> &HashMap<String, DestinySackRewardItemListDefinition> {
+        &self.destiny_sack_reward_item_list_definition
+    }
 
     pub fn destiny_sandbox_pattern_definition(
         &self,
@@ -436,21 +543,27 @@ impl DestinyWorldContent {
         &self.destiny_trait_category_definition
     }
 
-    // pub fn destiny_unlock_count_mapping_definition(&self) -> &HashMap<String, DestinyUnlockCountMappingDefinition> {
-    //     &self.destiny_unlock_count_mapping_definition
-    // }
+    pub fn destiny_unlock_count_mapping_definition(
+        &self,
+    ) -> &HashMap<String, DestinyUnlockCountMappingDefinition> {
+        &self.destiny_unlock_count_mapping_definition
+    }
 
     pub fn destiny_unlock_definition(&self) -> &HashMap<String, DestinyUnlockDefinition> {
         &self.destiny_unlock_definition
     }
 
-    // pub fn destiny_unlock_event_definition(&self) -> &HashMap<String, DestinyUnlockEventDefinition> {
-    //     &self.destiny_unlock_event_definition
-    // }
+    pub fn destiny_unlock_event_definition(
+        &self,
+    ) -> &HashMap<String, DestinyUnlockEventDefinition> {
+        &self.destiny_unlock_event_definition
+    }
 
-    // pub fn destiny_unlock_expression_mapping_definition(&self) -> &HashMap<String, DestinyUnlockExpressionMappingDefinition> {
-    //     &self.destiny_unlock_expression_mapping_definition
-    // }
+    pub fn destiny_unlock_expression_mapping_definition(
+        &self,
+    ) -> &HashMap<String, DestinyUnlockExpressionMappingDefinition> {
+        &self.destiny_unlock_expression_mapping_definition
+    }
 
     pub fn destiny_unlock_value_definition(
         &self,
@@ -469,21 +582,384 @@ impl DestinyWorldContent {
     }
 }
 
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyAmmunitionType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum DestinyAmmunitionType {
+    None,
+    Primary,
+    Special,
+    Heavy,
+    /// Any other value, including the API's own Unknown (4).
+    Unknown(i32),
+}
+
+impl From<DestinyAmmunitionType> for i32 {
+    fn from(value: DestinyAmmunitionType) -> Self {
+        match value {
+            DestinyAmmunitionType::None => 0,
+            DestinyAmmunitionType::Primary => 1,
+            DestinyAmmunitionType::Special => 2,
+            DestinyAmmunitionType::Heavy => 3,
+            DestinyAmmunitionType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for DestinyAmmunitionType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DestinyAmmunitionType::None,
+            1 => DestinyAmmunitionType::Primary,
+            2 => DestinyAmmunitionType::Special,
+            3 => DestinyAmmunitionType::Heavy,
+            _ => DestinyAmmunitionType::Unknown(value),
+        }
+    }
+}
+
+/// The champion a weapon or ability can break.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyBreakerType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum DestinyBreakerType {
+    None,
+    /// Anti-Barrier.
+    ShieldPiercing,
+    /// Overload.
+    Disruption,
+    /// Unstoppable.
+    Stagger,
+    /// A value not known of when this was written, kept as it was sent.
+    Unknown(i32),
+}
+
+impl From<DestinyBreakerType> for i32 {
+    fn from(value: DestinyBreakerType) -> Self {
+        match value {
+            DestinyBreakerType::None => 0,
+            DestinyBreakerType::ShieldPiercing => 1,
+            DestinyBreakerType::Disruption => 2,
+            DestinyBreakerType::Stagger => 3,
+            DestinyBreakerType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for DestinyBreakerType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DestinyBreakerType::None,
+            1 => DestinyBreakerType::ShieldPiercing,
+            2 => DestinyBreakerType::Disruption,
+            3 => DestinyBreakerType::Stagger,
+            _ => DestinyBreakerType::Unknown(value),
+        }
+    }
+}
+
 /// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyClass
-#[derive(Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum DestinyClass {
-    Titan = 0,
-    Hunter = 1,
-    Warlock = 2,
-    Unknown = 3,
+    Titan,
+    Hunter,
+    Warlock,
+    /// Any other value, including the API's own Unknown (3).
+    Unknown(i32),
+}
+
+impl fmt::Display for DestinyClass {
+    /// The variant's name, which is how the manifest keys maps by it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DestinyClass::Titan => write!(f, "Titan"),
+            DestinyClass::Hunter => write!(f, "Hunter"),
+            DestinyClass::Warlock => write!(f, "Warlock"),
+            DestinyClass::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<DestinyClass> for i32 {
+    fn from(value: DestinyClass) -> Self {
+        match value {
+            DestinyClass::Titan => 0,
+            DestinyClass::Hunter => 1,
+            DestinyClass::Warlock => 2,
+            DestinyClass::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for DestinyClass {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DestinyClass::Titan,
+            1 => DestinyClass::Hunter,
+            2 => DestinyClass::Warlock,
+            _ => DestinyClass::Unknown(value),
+        }
+    }
+}
+
+impl FromStr for DestinyClass {
+    type Err = String;
+
+    /// Parses the variant's name or integer value.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Titan" => Ok(DestinyClass::Titan),
+            "Hunter" => Ok(DestinyClass::Hunter),
+            "Warlock" => Ok(DestinyClass::Warlock),
+            _ => s
+                .parse::<i32>()
+                .map(DestinyClass::from)
+                .map_err(|_| format!("invalid DestinyClass {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DestinyClass {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        wire::deserialize_enum(deserializer)
+    }
+}
+
+impl Serialize for DestinyClass {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(i32::from(*self))
+    }
+}
+
+/// A Flags Enumeration/bitmask where each bit represents a different state
+/// that the Collectible can be in. A collectible can be in any number of these
+/// states, and you can choose to use or ignore any or all of them when making
+/// your own UI that shows Collectible info. Bits Bungie adds later are kept as
+/// they were sent.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyCollectibleState
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct DestinyCollectibleState(i32);
+
+impl DestinyCollectibleState {
+    /// The collectible has been acquired, and nothing prevents reacquiring
+    /// it.
+    pub const NONE: Self = Self(0);
+    /// If this flag is set, you have not yet obtained this collectible.
+    pub const NOT_ACQUIRED: Self = Self(1);
+    /// If this flag is set, the item is "obscured" to you: you can/should
+    /// use the alternate item hash found in
+    /// DestinyCollectibleDefinition.stateInfo.obscuredOverrideItemHash when
+    /// displaying this collectible instead of the default display info.
+    pub const OBSCURED: Self = Self(2);
+    /// If this flag is set, the collectible should not be shown to the user.
+    pub const INVISIBLE: Self = Self(4);
+    /// If this flag is set, the collectible requires payment for creating an
+    /// instance of the item, and you are lacking in currency.
+    pub const CANNOT_AFFORD_MATERIAL_REQUIREMENTS: Self = Self(8);
+    /// If this flag is set, you can't pull this item out of your collection
+    /// because there's no room left in your inventory.
+    pub const INVENTORY_SPACE_UNAVAILABLE: Self = Self(16);
+    /// If this flag is set, you already have one of these items and can't
+    /// have a second one.
+    pub const UNIQUENESS_VIOLATION: Self = Self(32);
+    /// If this flag is set, the ability to pull this item out of your
+    /// collection has been disabled.
+    pub const PURCHASE_DISABLED: Self = Self(64);
+
+    pub fn bits(&self) -> i32 {
+        self.0
+    }
+
+    /// Whether every flag set in `flags` is set.
+    pub fn contains(&self, flags: Self) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+
+    /// Whether any flag set in `flags` is set.
+    pub fn intersects(&self, flags: Self) -> bool {
+        self.0 & flags.0 != 0
+    }
+}
+
+impl BitOr for DestinyCollectibleState {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl From<DestinyCollectibleState> for i32 {
+    fn from(value: DestinyCollectibleState) -> Self {
+        value.0
+    }
+}
+
+impl From<i32> for DestinyCollectibleState {
+    fn from(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+/// The kind of energy an armor piece holds and a mod costs. Only mods of the
+/// armor's energy type or of Any fit in it.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyEnergyType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum DestinyEnergyType {
+    Any,
+    Arc,
+    Thermal,
+    Void,
+    Ghost,
+    Subclass,
+    Stasis,
+    /// A value not known of when this was written, kept as it was sent.
+    Unknown(i32),
+}
+
+impl From<DestinyEnergyType> for i32 {
+    fn from(value: DestinyEnergyType) -> Self {
+        match value {
+            DestinyEnergyType::Any => 0,
+            DestinyEnergyType::Arc => 1,
+            DestinyEnergyType::Thermal => 2,
+            DestinyEnergyType::Void => 3,
+            DestinyEnergyType::Ghost => 4,
+            DestinyEnergyType::Subclass => 5,
+            DestinyEnergyType::Stasis => 6,
+            DestinyEnergyType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for DestinyEnergyType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DestinyEnergyType::Any,
+            1 => DestinyEnergyType::Arc,
+            2 => DestinyEnergyType::Thermal,
+            3 => DestinyEnergyType::Void,
+            4 => DestinyEnergyType::Ghost,
+            5 => DestinyEnergyType::Subclass,
+            6 => DestinyEnergyType::Stasis,
+            _ => DestinyEnergyType::Unknown(value),
+        }
+    }
+}
+
+/// The results of a bulk Equipping operation performed through the Destiny
+/// API.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyEquipItemResults
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyEquipItemResults {
+    equip_results: Vec<DestinyEquipItemResult>,
+}
+
+impl DestinyEquipItemResults {
+    pub fn equip_results(&self) -> &[DestinyEquipItemResult] {
+        self.equip_results.as_ref()
+    }
+}
+
+/// The results of an Equipping operation performed through the Destiny API.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyEquipItemResult
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyEquipItemResult {
+    equip_status: i32,
+    #[serde(deserialize_with = "wire::deserialize_int64")]
+    item_instance_id: i64,
+}
+
+impl DestinyEquipItemResult {
+    /// A PlatformErrorCodes enum indicating whether it succeeded, and if it
+    /// failed why.
+    pub fn equip_status(&self) -> i32 {
+        self.equip_status
+    }
+
+    /// The instance ID of the item in question (all items that can be
+    /// equipped must, by definition, be Instanced and thus have an Instance
+    /// ID that you can use to refer to them)
+    pub fn item_instance_id(&self) -> i64 {
+        self.item_instance_id
+    }
 }
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyGender
-#[derive(Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum DestinyGender {
-    Male = 0,
-    Female = 1,
-    Unknown = 2,
+    Male,
+    Female,
+    /// Any other value, including the API's own Unknown (2).
+    Unknown(i32),
+}
+
+impl fmt::Display for DestinyGender {
+    /// The variant's name, which is how the manifest keys maps by it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DestinyGender::Male => write!(f, "Male"),
+            DestinyGender::Female => write!(f, "Female"),
+            DestinyGender::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<DestinyGender> for i32 {
+    fn from(value: DestinyGender) -> Self {
+        match value {
+            DestinyGender::Male => 0,
+            DestinyGender::Female => 1,
+            DestinyGender::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for DestinyGender {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DestinyGender::Male,
+            1 => DestinyGender::Female,
+            _ => DestinyGender::Unknown(value),
+        }
+    }
+}
+
+impl FromStr for DestinyGender {
+    type Err = String;
+
+    /// Parses the variant's name or integer value.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Male" => Ok(DestinyGender::Male),
+            "Female" => Ok(DestinyGender::Female),
+            _ => s
+                .parse::<i32>()
+                .map(DestinyGender::from)
+                .map_err(|_| format!("invalid DestinyGender {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DestinyGender {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        wire::deserialize_enum(deserializer)
+    }
+}
+
+impl Serialize for DestinyGender {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(i32::from(*self))
+    }
 }
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyItemQuantity
@@ -491,7 +967,8 @@ pub enum DestinyGender {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemQuantity {
     has_conditional_visibility: Option<bool>,
-    item_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    #[serde(default, deserialize_with = "wire::deserialize_optional_int64")]
     item_instance_id: Option<i64>,
     quantity: Option<i32>,
 }
@@ -501,7 +978,7 @@ impl DestinyItemQuantity {
         self.has_conditional_visibility
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
@@ -514,25 +991,538 @@ impl DestinyItemQuantity {
     }
 }
 
+/// This Enumeration further classifies items by more specific categorizations
+/// than DestinyItemType. The "Sub-Type" is where we classify and categorize
+/// items one step further in specificity: "Auto Rifle" instead of just "Weapon"
+/// for example, or "Vanguard Bounty" instead of merely "Bounty".
+///
+/// These sub-types are provided for historical compatibility with Destiny 1,
+/// but an ideal alternative is to use DestinyItemCategoryDefinitions and the
+/// DestinyItemDefinition.itemCategories property instead.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyItemSubType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum DestinyItemSubType {
+    None,
+    /// DEPRECATED. Items can be both "Crucible" and something else interesting.
+    Crucible,
+    /// DEPRECATED. An item can both be "Vanguard" and something else.
+    Vanguard,
+    /// DEPRECATED. An item can both be Exotic and something else.
+    Exotic,
+    AutoRifle,
+    Shotgun,
+    Machinegun,
+    HandCannon,
+    RocketLauncher,
+    FusionRifle,
+    SniperRifle,
+    PulseRifle,
+    ScoutRifle,
+    /// DEPRECATED. An item can both be CRM and something else.
+    Crm,
+    Sidearm,
+    Sword,
+    Mask,
+    Shader,
+    Ornament,
+    FusionRifleLine,
+    GrenadeLauncher,
+    SubmachineGun,
+    TraceRifle,
+    HelmetArmor,
+    GauntletsArmor,
+    ChestArmor,
+    LegArmor,
+    ClassArmor,
+    Bow,
+    DummyRepeatableBounty,
+    Glaive,
+    /// A value not known of when this was written, kept as it was sent.
+    Unknown(i32),
+}
+
+impl From<DestinyItemSubType> for i32 {
+    fn from(value: DestinyItemSubType) -> Self {
+        match value {
+            DestinyItemSubType::None => 0,
+            DestinyItemSubType::Crucible => 1,
+            DestinyItemSubType::Vanguard => 2,
+            DestinyItemSubType::Exotic => 5,
+            DestinyItemSubType::AutoRifle => 6,
+            DestinyItemSubType::Shotgun => 7,
+            DestinyItemSubType::Machinegun => 8,
+            DestinyItemSubType::HandCannon => 9,
+            DestinyItemSubType::RocketLauncher => 10,
+            DestinyItemSubType::FusionRifle => 11,
+            DestinyItemSubType::SniperRifle => 12,
+            DestinyItemSubType::PulseRifle => 13,
+            DestinyItemSubType::ScoutRifle => 14,
+            DestinyItemSubType::Crm => 16,
+            DestinyItemSubType::Sidearm => 17,
+            DestinyItemSubType::Sword => 18,
+            DestinyItemSubType::Mask => 19,
+            DestinyItemSubType::Shader => 20,
+            DestinyItemSubType::Ornament => 21,
+            DestinyItemSubType::FusionRifleLine => 22,
+            DestinyItemSubType::GrenadeLauncher => 23,
+            DestinyItemSubType::SubmachineGun => 24,
+            DestinyItemSubType::TraceRifle => 25,
+            DestinyItemSubType::HelmetArmor => 26,
+            DestinyItemSubType::GauntletsArmor => 27,
+            DestinyItemSubType::ChestArmor => 28,
+            DestinyItemSubType::LegArmor => 29,
+            DestinyItemSubType::ClassArmor => 30,
+            DestinyItemSubType::Bow => 31,
+            DestinyItemSubType::DummyRepeatableBounty => 32,
+            DestinyItemSubType::Glaive => 33,
+            DestinyItemSubType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for DestinyItemSubType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DestinyItemSubType::None,
+            1 => DestinyItemSubType::Crucible,
+            2 => DestinyItemSubType::Vanguard,
+            5 => DestinyItemSubType::Exotic,
+            6 => DestinyItemSubType::AutoRifle,
+            7 => DestinyItemSubType::Shotgun,
+            8 => DestinyItemSubType::Machinegun,
+            9 => DestinyItemSubType::HandCannon,
+            10 => DestinyItemSubType::RocketLauncher,
+            11 => DestinyItemSubType::FusionRifle,
+            12 => DestinyItemSubType::SniperRifle,
+            13 => DestinyItemSubType::PulseRifle,
+            14 => DestinyItemSubType::ScoutRifle,
+            16 => DestinyItemSubType::Crm,
+            17 => DestinyItemSubType::Sidearm,
+            18 => DestinyItemSubType::Sword,
+            19 => DestinyItemSubType::Mask,
+            20 => DestinyItemSubType::Shader,
+            21 => DestinyItemSubType::Ornament,
+            22 => DestinyItemSubType::FusionRifleLine,
+            23 => DestinyItemSubType::GrenadeLauncher,
+            24 => DestinyItemSubType::SubmachineGun,
+            25 => DestinyItemSubType::TraceRifle,
+            26 => DestinyItemSubType::HelmetArmor,
+            27 => DestinyItemSubType::GauntletsArmor,
+            28 => DestinyItemSubType::ChestArmor,
+            29 => DestinyItemSubType::LegArmor,
+            30 => DestinyItemSubType::ClassArmor,
+            31 => DestinyItemSubType::Bow,
+            32 => DestinyItemSubType::DummyRepeatableBounty,
+            33 => DestinyItemSubType::Glaive,
+            _ => DestinyItemSubType::Unknown(value),
+        }
+    }
+}
+
+/// An enumeration that indicates the high-level "type" of the item, attempting
+/// to iron out the context specific differences for specific instances of an
+/// entity. For instance, though a weapon may be of various weapon "Types", in
+/// DestinyItemType they are all classified as "Weapon". This allows for better
+/// filtering on a higher level of abstraction for the concept of types.
+///
+/// This enum is provided for historical compatibility with Destiny 1, but an
+/// ideal alternative is to use DestinyItemCategoryDefinitions and the
+/// DestinyItemDefinition.itemCategories property instead.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyItemType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum DestinyItemType {
+    None,
+    Currency,
+    Armor,
+    Weapon,
+    Message,
+    Engram,
+    Consumable,
+    ExchangeMaterial,
+    MissionReward,
+    QuestStep,
+    QuestStepComplete,
+    Emblem,
+    Quest,
+    Subclass,
+    ClanBanner,
+    Aura,
+    Mod,
+    Dummy,
+    Ship,
+    Vehicle,
+    Emote,
+    Ghost,
+    Package,
+    Bounty,
+    Wrapper,
+    SeasonalArtifact,
+    Finisher,
+    Pattern,
+    /// A value not known of when this was written, kept as it was sent.
+    Unknown(i32),
+}
+
+impl From<DestinyItemType> for i32 {
+    fn from(value: DestinyItemType) -> Self {
+        match value {
+            DestinyItemType::None => 0,
+            DestinyItemType::Currency => 1,
+            DestinyItemType::Armor => 2,
+            DestinyItemType::Weapon => 3,
+            DestinyItemType::Message => 7,
+            DestinyItemType::Engram => 8,
+            DestinyItemType::Consumable => 9,
+            DestinyItemType::ExchangeMaterial => 10,
+            DestinyItemType::MissionReward => 11,
+            DestinyItemType::QuestStep => 12,
+            DestinyItemType::QuestStepComplete => 13,
+            DestinyItemType::Emblem => 14,
+            DestinyItemType::Quest => 15,
+            DestinyItemType::Subclass => 16,
+            DestinyItemType::ClanBanner => 17,
+            DestinyItemType::Aura => 18,
+            DestinyItemType::Mod => 19,
+            DestinyItemType::Dummy => 20,
+            DestinyItemType::Ship => 21,
+            DestinyItemType::Vehicle => 22,
+            DestinyItemType::Emote => 23,
+            DestinyItemType::Ghost => 24,
+            DestinyItemType::Package => 25,
+            DestinyItemType::Bounty => 26,
+            DestinyItemType::Wrapper => 27,
+            DestinyItemType::SeasonalArtifact => 28,
+            DestinyItemType::Finisher => 29,
+            DestinyItemType::Pattern => 30,
+            DestinyItemType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for DestinyItemType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DestinyItemType::None,
+            1 => DestinyItemType::Currency,
+            2 => DestinyItemType::Armor,
+            3 => DestinyItemType::Weapon,
+            7 => DestinyItemType::Message,
+            8 => DestinyItemType::Engram,
+            9 => DestinyItemType::Consumable,
+            10 => DestinyItemType::ExchangeMaterial,
+            11 => DestinyItemType::MissionReward,
+            12 => DestinyItemType::QuestStep,
+            13 => DestinyItemType::QuestStepComplete,
+            14 => DestinyItemType::Emblem,
+            15 => DestinyItemType::Quest,
+            16 => DestinyItemType::Subclass,
+            17 => DestinyItemType::ClanBanner,
+            18 => DestinyItemType::Aura,
+            19 => DestinyItemType::Mod,
+            20 => DestinyItemType::Dummy,
+            21 => DestinyItemType::Ship,
+            22 => DestinyItemType::Vehicle,
+            23 => DestinyItemType::Emote,
+            24 => DestinyItemType::Ghost,
+            25 => DestinyItemType::Package,
+            26 => DestinyItemType::Bounty,
+            27 => DestinyItemType::Wrapper,
+            28 => DestinyItemType::SeasonalArtifact,
+            29 => DestinyItemType::Finisher,
+            30 => DestinyItemType::Pattern,
+            _ => DestinyItemType::Unknown(value),
+        }
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyPresentationNodeType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum DestinyPresentationNodeType {
+    Default,
+    Category,
+    Collectibles,
+    Records,
+    Metric,
+    Craftable,
+    /// A value not known of when this was written, kept as it was sent.
+    Unknown(i32),
+}
+
+impl From<DestinyPresentationNodeType> for i32 {
+    fn from(value: DestinyPresentationNodeType) -> Self {
+        match value {
+            DestinyPresentationNodeType::Default => 0,
+            DestinyPresentationNodeType::Category => 1,
+            DestinyPresentationNodeType::Collectibles => 2,
+            DestinyPresentationNodeType::Records => 3,
+            DestinyPresentationNodeType::Metric => 4,
+            DestinyPresentationNodeType::Craftable => 5,
+            DestinyPresentationNodeType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for DestinyPresentationNodeType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DestinyPresentationNodeType::Default,
+            1 => DestinyPresentationNodeType::Category,
+            2 => DestinyPresentationNodeType::Collectibles,
+            3 => DestinyPresentationNodeType::Records,
+            4 => DestinyPresentationNodeType::Metric,
+            5 => DestinyPresentationNodeType::Craftable,
+            _ => DestinyPresentationNodeType::Unknown(value),
+        }
+    }
+}
+
 /// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyRace
-#[derive(Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum DestinyRace {
-    Human = 0,
-    Awoken = 1,
-    Exo = 2,
-    Unknown = 3,
+    Human,
+    Awoken,
+    Exo,
+    /// Any other value, including the API's own Unknown (3).
+    Unknown(i32),
+}
+
+impl fmt::Display for DestinyRace {
+    /// The variant's name, which is how the manifest keys maps by it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DestinyRace::Human => write!(f, "Human"),
+            DestinyRace::Awoken => write!(f, "Awoken"),
+            DestinyRace::Exo => write!(f, "Exo"),
+            DestinyRace::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<DestinyRace> for i32 {
+    fn from(value: DestinyRace) -> Self {
+        match value {
+            DestinyRace::Human => 0,
+            DestinyRace::Awoken => 1,
+            DestinyRace::Exo => 2,
+            DestinyRace::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for DestinyRace {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DestinyRace::Human,
+            1 => DestinyRace::Awoken,
+            2 => DestinyRace::Exo,
+            _ => DestinyRace::Unknown(value),
+        }
+    }
+}
+
+impl FromStr for DestinyRace {
+    type Err = String;
+
+    /// Parses the variant's name or integer value.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Human" => Ok(DestinyRace::Human),
+            "Awoken" => Ok(DestinyRace::Awoken),
+            "Exo" => Ok(DestinyRace::Exo),
+            _ => s
+                .parse::<i32>()
+                .map(DestinyRace::from)
+                .map_err(|_| format!("invalid DestinyRace {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DestinyRace {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        wire::deserialize_enum(deserializer)
+    }
+}
+
+impl Serialize for DestinyRace {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(i32::from(*self))
+    }
+}
+
+/// A Flags enumeration/bitmask where each bit represents a possible state that
+/// a Record/Triumph can be in. Bits Bungie adds later are kept as they were
+/// sent.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyRecordState
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct DestinyRecordState(i32);
+
+impl DestinyRecordState {
+    /// The record has been completed and its rewards claimed.
+    pub const NONE: Self = Self(0);
+    /// If this is set, the completed record has been redeemed.
+    pub const RECORD_REDEEMED: Self = Self(1);
+    /// If this is set, there's a reward available from this Record but it's
+    /// unavailable for redemption.
+    pub const REWARD_UNAVAILABLE: Self = Self(2);
+    /// If this is set, the objective for this Record has not yet been
+    /// completed.
+    pub const OBJECTIVE_NOT_COMPLETED: Self = Self(4);
+    /// If this is set, the game recommends that you replace the display text
+    /// of this Record with DestinyRecordDefinition.stateInfo.obscuredString.
+    pub const OBSCURED: Self = Self(8);
+    /// If this is set, the game recommends that you not show this record.
+    pub const INVISIBLE: Self = Self(16);
+    /// If this is set, you can't complete this record because you lack some
+    /// permission that's required to complete it.
+    pub const ENTITLEMENT_UNOWNED: Self = Self(32);
+    /// If this is set, the record has a title (check
+    /// DestinyRecordDefinition.titleInfo) and you can equip it.
+    pub const CAN_EQUIP_TITLE: Self = Self(64);
+
+    pub fn bits(&self) -> i32 {
+        self.0
+    }
+
+    /// Whether every flag set in `flags` is set.
+    pub fn contains(&self, flags: Self) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+
+    /// Whether any flag set in `flags` is set.
+    pub fn intersects(&self, flags: Self) -> bool {
+        self.0 & flags.0 != 0
+    }
+}
+
+impl BitOr for DestinyRecordState {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl From<DestinyRecordState> for i32 {
+    fn from(value: DestinyRecordState) -> Self {
+        value.0
+    }
+}
+
+impl From<i32> for DestinyRecordState {
+    fn from(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+/// There's a lot of places where we need to know scope on more than just a
+/// profile or character level. For everything else, there's this more generic
+/// sense of scope.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyScope
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum DestinyScope {
+    Profile,
+    Character,
+    /// A value not known of when this was written, kept as it was sent.
+    Unknown(i32),
+}
+
+impl From<DestinyScope> for i32 {
+    fn from(value: DestinyScope) -> Self {
+        match value {
+            DestinyScope::Profile => 0,
+            DestinyScope::Character => 1,
+            DestinyScope::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for DestinyScope {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DestinyScope::Profile,
+            1 => DestinyScope::Character,
+            _ => DestinyScope::Unknown(value),
+        }
+    }
+}
+
+/// If you look in the DestinyInventoryItemDefinition's "sockets" property,
+/// you'll see that there are two types of sockets: intrinsic, and "socketEntry."
+///
+/// Unfortunately, because Intrinsic sockets are a whole separate array, it is
+/// no longer sufficient to know the index into that array to know which socket
+/// we're talking about. You have to know whether it's in the default
+/// "socketEntries" or if it's in the "intrinsic" list.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinySocketArrayType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "i32", try_from = "i32")]
+pub enum DestinySocketArrayType {
+    Default = 0,
+    Intrinsic = 1,
+}
+
+impl From<DestinySocketArrayType> for i32 {
+    fn from(array_type: DestinySocketArrayType) -> Self {
+        array_type as i32
+    }
+}
+
+impl TryFrom<i32> for DestinySocketArrayType {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(DestinySocketArrayType::Default),
+            1 => Ok(DestinySocketArrayType::Intrinsic),
+            _ => Err(format!("invalid DestinySocketArrayType {}", value)),
+        }
+    }
+}
+
+/// Represents a stat on an item *or* Character (NOT a Historical Stat, but a
+/// physical attribute stat like Attack, Defense etc...)
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.DestinyStat
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyStat {
+    stat_hash: Hash<DestinyStatDefinition>,
+    value: i32,
+}
+
+impl DestinyStat {
+    /// The hash identifier for the Stat. Use it to look up the
+    /// DestinyStatDefinition for static data about the stat.
+    pub fn stat_hash(&self) -> Hash<DestinyStatDefinition> {
+        self.stat_hash
+    }
+
+    /// The current value of the Stat.
+    pub fn value(&self) -> i32 {
+        self.value
+    }
 }
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.DyeReference
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DyeReference {
-    channel_hash: Option<u32>,
+    channel_hash: Option<Hash<DestinyArtDyeChannelDefinition>>,
     dye_hash: Option<u32>,
 }
 
 impl DyeReference {
-    pub fn channel_hash(&self) -> Option<u32> {
+    pub fn channel_hash(&self) -> Option<Hash<DestinyArtDyeChannelDefinition>> {
         self.channel_hash
     }
 
@@ -540,3 +1530,244 @@ impl DyeReference {
         self.dye_hash
     }
 }
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.ItemBindStatus
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum ItemBindStatus {
+    NotBound,
+    BoundToCharacter,
+    BoundToAccount,
+    BoundToGuild,
+    /// A value not known of when this was written, kept as it was sent.
+    Unknown(i32),
+}
+
+impl From<ItemBindStatus> for i32 {
+    fn from(value: ItemBindStatus) -> Self {
+        match value {
+            ItemBindStatus::NotBound => 0,
+            ItemBindStatus::BoundToCharacter => 1,
+            ItemBindStatus::BoundToAccount => 2,
+            ItemBindStatus::BoundToGuild => 3,
+            ItemBindStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for ItemBindStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => ItemBindStatus::NotBound,
+            1 => ItemBindStatus::BoundToCharacter,
+            2 => ItemBindStatus::BoundToAccount,
+            3 => ItemBindStatus::BoundToGuild,
+            _ => ItemBindStatus::Unknown(value),
+        }
+    }
+}
+
+/// Whether the item is in the inventory, the vault, a vendor or the postmaster.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.ItemLocation
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum ItemLocation {
+    Inventory,
+    Vault,
+    Vendor,
+    Postmaster,
+    /// Any other value, including the API's own Unknown (0).
+    Unknown(i32),
+}
+
+impl From<ItemLocation> for i32 {
+    fn from(value: ItemLocation) -> Self {
+        match value {
+            ItemLocation::Inventory => 1,
+            ItemLocation::Vault => 2,
+            ItemLocation::Vendor => 3,
+            ItemLocation::Postmaster => 4,
+            ItemLocation::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for ItemLocation {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => ItemLocation::Inventory,
+            2 => ItemLocation::Vault,
+            3 => ItemLocation::Vendor,
+            4 => ItemLocation::Postmaster,
+            _ => ItemLocation::Unknown(value),
+        }
+    }
+}
+
+/// A flags enumeration/bitmask where each bit represents a different possible
+/// state that the item can be in that may effect how the item is displayed to
+/// the user and what actions can be performed against it. Bits Bungie adds
+/// later are kept as they were sent.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.ItemState
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct ItemState(i32);
+
+impl ItemState {
+    pub const NONE: Self = Self(0);
+    /// If this bit is set, the item has been "locked" by the user and cannot be
+    /// deleted.
+    pub const LOCKED: Self = Self(1);
+    /// If this bit is set, the item is a quest that's being tracked by the
+    /// user.
+    pub const TRACKED: Self = Self(2);
+    /// If this bit is set, the item has a Masterwork plug inserted.
+    pub const MASTERWORK: Self = Self(4);
+    /// If this bit is set, the item has been 'crafted' by the player.
+    pub const CRAFTED: Self = Self(8);
+    /// If this bit is set, the item has a 'highlighted' objective.
+    pub const HIGHLIGHTED_OBJECTIVE: Self = Self(16);
+
+    pub fn bits(&self) -> i32 {
+        self.0
+    }
+
+    /// Whether every flag set in `flags` is set.
+    pub fn contains(&self, flags: Self) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+}
+
+impl BitOr for ItemState {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl From<ItemState> for i32 {
+    fn from(value: ItemState) -> Self {
+        value.0
+    }
+}
+
+impl From<i32> for ItemState {
+    fn from(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+/// As you run into items that need to be classified for Milestone purposes in
+/// ways that we cannot infer via direct data, add a new classification here and
+/// use a string constant to represent it in the local item config file.
+///
+/// NOTE: This is not all of the item types available, and some of these are
+/// holdovers from Destiny 1 that may or may not still exist.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.SpecialItemType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum SpecialItemType {
+    None,
+    SpecialCurrency,
+    Armor,
+    Weapon,
+    Engram,
+    Consumable,
+    ExchangeMaterial,
+    MissionReward,
+    Currency,
+    /// A value not known of when this was written, kept as it was sent.
+    Unknown(i32),
+}
+
+impl From<SpecialItemType> for i32 {
+    fn from(value: SpecialItemType) -> Self {
+        match value {
+            SpecialItemType::None => 0,
+            SpecialItemType::SpecialCurrency => 1,
+            SpecialItemType::Armor => 8,
+            SpecialItemType::Weapon => 9,
+            SpecialItemType::Engram => 23,
+            SpecialItemType::Consumable => 24,
+            SpecialItemType::ExchangeMaterial => 25,
+            SpecialItemType::MissionReward => 27,
+            SpecialItemType::Currency => 29,
+            SpecialItemType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for SpecialItemType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => SpecialItemType::None,
+            1 => SpecialItemType::SpecialCurrency,
+            8 => SpecialItemType::Armor,
+            9 => SpecialItemType::Weapon,
+            23 => SpecialItemType::Engram,
+            24 => SpecialItemType::Consumable,
+            25 => SpecialItemType::ExchangeMaterial,
+            27 => SpecialItemType::MissionReward,
+            29 => SpecialItemType::Currency,
+            _ => SpecialItemType::Unknown(value),
+        }
+    }
+}
+
+/// Whether you can transfer an item, and why not if you can't.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.TransferStatuses
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct TransferStatuses(i32);
+
+impl TransferStatuses {
+    /// The item can be transferred.
+    pub const CAN_TRANSFER: Self = Self(0);
+    /// You can't transfer the item because it is equipped on a character.
+    pub const ITEM_IS_EQUIPPED: Self = Self(1);
+    /// The item is defined as not transferrable in its
+    /// DestinyInventoryItemDefinition.nonTransferrable property.
+    pub const NOT_TRANSFERRABLE: Self = Self(2);
+    /// You could transfer the item, but the place you're trying to put it has
+    /// run out of room!
+    pub const NO_ROOM_IN_DESTINATION: Self = Self(4);
+
+    pub fn bits(&self) -> i32 {
+        self.0
+    }
+
+    /// Whether every flag set in `flags` is set.
+    pub fn contains(&self, flags: Self) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+
+    /// Whether nothing stops the item being transferred.
+    pub fn can_transfer(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for TransferStatuses {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl From<TransferStatuses> for i32 {
+    fn from(value: TransferStatuses) -> Self {
+        value.0
+    }
+}
+
+impl From<i32> for TransferStatuses {
+    fn from(value: i32) -> Self {
+        Self(value)
+    }
+}
@@ -1 +1,5 @@
+pub mod collectibles;
 pub mod inventory;
+pub mod items;
+pub mod loadouts;
+pub mod records;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::destiny::DestinyCollectibleState;
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Components.Collectibles.DestinyCollectibleComponent
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyCollectibleComponent {
+    state: DestinyCollectibleState,
+}
+
+impl DestinyCollectibleComponent {
+    /// A value of NONE means the collectible has been acquired and nothing
+    /// prevents reacquiring it.
+    pub fn state(&self) -> DestinyCollectibleState {
+        self.state
+    }
+}
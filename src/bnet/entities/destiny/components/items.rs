@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::destiny::sockets::DestinyItemPlugBase;
+
+/// Plugs are non-instanced items that can provide Stat and Perk benefits when
+/// socketed into an instanced item. Items have Sockets, and Plugs are inserted
+/// into Sockets.
+///
+/// This component finds all items that are considered "Reusable Plugs" that
+/// can be inserted into a Socket.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Components.Items.DestinyItemReusablePlugsComponent
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemReusablePlugsComponent {
+    plugs: HashMap<i32, Vec<DestinyItemPlugBase>>,
+}
+
+impl DestinyItemReusablePlugsComponent {
+    /// If the item supports reusable plugs, this is the list of plugs that are
+    /// allowed to be used for the socket, and any relevant information about
+    /// whether they are "enabled", whether they are allowed to be inserted,
+    /// and any other information such as objectives.
+    ///
+    /// The key is the socket index on the item, the value is the list of
+    /// plugs available for that socket.
+    pub fn plugs(&self) -> &HashMap<i32, Vec<DestinyItemPlugBase>> {
+        &self.plugs
+    }
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::{
+    destiny::{
+        definitions::{
+            loadouts::{
+                DestinyLoadoutColorDefinition, DestinyLoadoutIconDefinition,
+                DestinyLoadoutNameDefinition,
+            },
+            DestinyInventoryItemDefinition,
+        },
+        hashes::Hash,
+    },
+    wire::deserialize_int64,
+};
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Components.Loadouts.DestinyLoadoutsComponent
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyLoadoutsComponent {
+    loadouts: Vec<DestinyLoadoutComponent>,
+}
+
+impl DestinyLoadoutsComponent {
+    /// The character's loadouts, in the order of their loadout index.
+    pub fn loadouts(&self) -> &[DestinyLoadoutComponent] {
+        self.loadouts.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Components.Loadouts.DestinyLoadoutComponent
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyLoadoutComponent {
+    color_hash: Hash<DestinyLoadoutColorDefinition>,
+    icon_hash: Hash<DestinyLoadoutIconDefinition>,
+    items: Vec<DestinyLoadoutItemComponent>,
+    name_hash: Hash<DestinyLoadoutNameDefinition>,
+}
+
+impl DestinyLoadoutComponent {
+    pub fn new(
+        color_hash: Hash<DestinyLoadoutColorDefinition>,
+        icon_hash: Hash<DestinyLoadoutIconDefinition>,
+        name_hash: Hash<DestinyLoadoutNameDefinition>,
+        items: Vec<DestinyLoadoutItemComponent>,
+    ) -> Self {
+        Self {
+            color_hash,
+            icon_hash,
+            items,
+            name_hash,
+        }
+    }
+
+    /// The hash of the DestinyLoadoutColorDefinition of the loadout.
+    pub fn color_hash(&self) -> Hash<DestinyLoadoutColorDefinition> {
+        self.color_hash
+    }
+
+    /// The hash of the DestinyLoadoutIconDefinition of the loadout.
+    pub fn icon_hash(&self) -> Hash<DestinyLoadoutIconDefinition> {
+        self.icon_hash
+    }
+
+    pub fn items(&self) -> &[DestinyLoadoutItemComponent] {
+        self.items.as_ref()
+    }
+
+    /// The hash of the DestinyLoadoutNameDefinition of the loadout.
+    pub fn name_hash(&self) -> Hash<DestinyLoadoutNameDefinition> {
+        self.name_hash
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Components.Loadouts.DestinyLoadoutItemComponent
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyLoadoutItemComponent {
+    #[serde(deserialize_with = "deserialize_int64")]
+    item_instance_id: i64,
+    plug_item_hashes: Vec<Hash<DestinyInventoryItemDefinition>>,
+}
+
+impl DestinyLoadoutItemComponent {
+    pub fn new(
+        item_instance_id: i64,
+        plug_item_hashes: Vec<Hash<DestinyInventoryItemDefinition>>,
+    ) -> Self {
+        Self {
+            item_instance_id,
+            plug_item_hashes,
+        }
+    }
+
+    pub fn item_instance_id(&self) -> i64 {
+        self.item_instance_id
+    }
+
+    /// The plug to insert in each of the item's sockets, by socket index.
+    /// Sockets the loadout leaves alone hold 2166136261.
+    pub fn plug_item_hashes(&self) -> &[Hash<DestinyInventoryItemDefinition>] {
+        self.plug_item_hashes.as_ref()
+    }
+}
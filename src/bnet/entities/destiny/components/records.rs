@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::destiny::{quests::DestinyObjectiveProgress, DestinyRecordState};
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Components.Records.DestinyRecordComponent
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyRecordComponent {
+    completed_count: Option<i32>,
+    #[serde(default)]
+    interval_objectives: Vec<DestinyObjectiveProgress>,
+    #[serde(default)]
+    intervals_redeemed_count: i32,
+    #[serde(default)]
+    objectives: Vec<DestinyObjectiveProgress>,
+    #[serde(default)]
+    reward_visibilty: Vec<bool>,
+    state: DestinyRecordState,
+}
+
+impl DestinyRecordComponent {
+    /// If available, this is the number of times this record has been
+    /// completed. For example, the number of times a seal title has been gilded.
+    pub fn completed_count(&self) -> Option<i32> {
+        self.completed_count
+    }
+
+    pub fn interval_objectives(&self) -> &[DestinyObjectiveProgress] {
+        self.interval_objectives.as_ref()
+    }
+
+    pub fn intervals_redeemed_count(&self) -> i32 {
+        self.intervals_redeemed_count
+    }
+
+    pub fn objectives(&self) -> &[DestinyObjectiveProgress] {
+        self.objectives.as_ref()
+    }
+
+    /// If available, a list that describes which reward rewards should be
+    /// shown (true) or hidden (false). This property is for regular record
+    /// rewards, and not for interval objective rewards.
+    pub fn reward_visibilty(&self) -> &[bool] {
+        self.reward_visibilty.as_ref()
+    }
+
+    pub fn state(&self) -> DestinyRecordState {
+        self.state
+    }
+}
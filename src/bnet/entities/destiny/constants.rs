@@ -1,14 +1,22 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::{
+        DestinyActivityDefinition, DestinyInventoryItemDefinition, DestinyLocationDefinition,
+        DestinyObjectiveDefinition,
+    },
+    hashes::Hash,
+};
+
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Constants.DestinyEnvironmentLocationMapping
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyEnvironmentLocationMapping {
     activation_source: Option<String>,
-    activity_hash: Option<u32>,
-    item_hash: Option<u32>,
-    location_hash: Option<u32>,
-    objective_hash: Option<u32>,
+    activity_hash: Option<Hash<DestinyActivityDefinition>>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    location_hash: Option<Hash<DestinyLocationDefinition>>,
+    objective_hash: Option<Hash<DestinyObjectiveDefinition>>,
 }
 
 impl DestinyEnvironmentLocationMapping {
@@ -16,19 +24,19 @@ impl DestinyEnvironmentLocationMapping {
         self.activation_source.as_ref()
     }
 
-    pub fn activity_hash(&self) -> Option<u32> {
+    pub fn activity_hash(&self) -> Option<Hash<DestinyActivityDefinition>> {
         self.activity_hash
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
-    pub fn location_hash(&self) -> Option<u32> {
+    pub fn location_hash(&self) -> Option<Hash<DestinyLocationDefinition>> {
         self.location_hash
     }
 
-    pub fn objective_hash(&self) -> Option<u32> {
+    pub fn objective_hash(&self) -> Option<Hash<DestinyObjectiveDefinition>> {
         self.objective_hash
     }
 }
@@ -3,20 +3,41 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::bnet::entities::{
-    dates::DateRange, interpolation::InterpolationPoint, links::HyperlinkReference,
+    dates::DateRange,
+    interpolation::InterpolationPoint,
+    links::HyperlinkReference,
+    wire::{deserialize_optional_int64, serialize_keys_by_name},
 };
 
 use self::{
+    activity_modifiers::DestinyActivityModifierDefinition,
     animations::DestinyAnimationReference,
+    artifacts::DestinyArtifactDefinition,
+    breaker_types::DestinyBreakerTypeDefinition,
+    collectibles::DestinyCollectibleDefinition,
     common::DestinyDisplayPropertiesDefinition,
-    items::{DestinyDerivedItemCategoryDefinition, DestinyItemPlugDefinition},
+    director::DestinyActivityGraphDefinition,
+    items::{
+        DestinyDerivedItemCategoryDefinition, DestinyItemPlugDefinition,
+        DestinyItemTierTypeDefinition,
+    },
+    lore::DestinyLoreDefinition,
+    power_caps::DestinyPowerCapDefinition,
+    presentation::DestinyPresentationNodeDefinition,
+    seasons::DestinySeasonDefinition,
+    sockets::{
+        DestinyPlugSetDefinition, DestinySocketCategoryDefinition, DestinySocketTypeDefinition,
+    },
     sources::DestinyItemSourceDefinition,
+    traits::DestinyTraitDefinition,
+    undocumented::DestinyArtDyeChannelDefinition,
     vendors::DestinyVendorLocationDefinition,
 };
 
 use super::{
-    constants::DestinyEnvironmentLocationMapping, misc::DestinyColor, DestinyGender,
-    DestinyItemQuantity, DyeReference,
+    constants::DestinyEnvironmentLocationMapping, hashes::Hash, misc::DestinyColor,
+    DestinyAmmunitionType, DestinyBreakerType, DestinyClass, DestinyGender, DestinyItemQuantity,
+    DestinyItemSubType, DestinyItemType, DestinyRace, DyeReference, SpecialItemType,
 };
 
 pub mod activity_modifiers;
@@ -29,6 +50,7 @@ pub mod common;
 pub mod director;
 pub mod energy_types;
 pub mod items;
+pub mod loadouts;
 pub mod lore;
 pub mod metrics;
 pub mod milestones;
@@ -41,30 +63,9 @@ pub mod seasons;
 pub mod sockets;
 pub mod sources;
 pub mod traits;
+pub mod undocumented;
 pub mod vendors;
 
-/* Definitions with no known documentation:
- *     DestinyAchievementDefinition
- *     DestinyArtDyeChannelDefinition
- *     DestinyActivityInteractableDefinition
- *     DestinyBondDefinition
- *     DestinyCharacterCustomizationCategoryDefinition
- *     DestinyCharacterCustomizationOptionDefinition
- *     DestinyEntitlementOfferDefinition
- *     DestinyInventoryItemLiteDefinition
- *     DestinyNodeStepSummaryDefinition
- *     DestinyPlatformBucketMappingDefinition
- *     DestinyRewardAdjusterPointerDefinition
- *     DestinyRewardAdjusterProgressionMapDefinition
- *     DestinyRewardItemListDefinition
- *     DestinyRewardMappingDefinition
- *     DestinyRewardSheetDefinition
- *     DestinySackRewardItemListDefinition
- *     DestinyUnlockCountMappingDefinition
- *     DestinyUnlockEventDefinition
- *     DestinyUnlockExpressionMappingDefinition
- */
-
 /// The static data about Activities in Destiny 2.
 ///
 /// Note that an Activity must be combined with an ActivityMode to know - from
@@ -96,12 +97,12 @@ pub struct DestinyActivityDefinition {
     activity_graph_list: Option<Vec<DestinyActivityGraphListEntryDefinition>>,
     activity_light_level: Option<i32>,
     activity_location_mappings: Option<Vec<DestinyEnvironmentLocationMapping>>,
-    activity_mode_hashes: Option<Vec<u32>>,
+    activity_mode_hashes: Option<Vec<Hash<DestinyActivityModeDefinition>>>,
     activity_mode_types: Option<Vec<i32>>,
-    activity_type_hash: Option<u32>,
+    activity_type_hash: Option<Hash<DestinyActivityTypeDefinition>>,
     challenges: Option<Vec<DestinyActivityChallengeDefinition>>,
-    destination_hash: Option<u32>,
-    direct_activity_mode_hash: Option<u32>,
+    destination_hash: Option<Hash<DestinyDestinationDefinition>>,
+    direct_activity_mode_hash: Option<Hash<DestinyActivityModeDefinition>>,
     direct_activity_mode_type: Option<i32>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     guided_game: Option<DestinyActivityGuidedBlockDefinition>,
@@ -116,7 +117,7 @@ pub struct DestinyActivityDefinition {
     optional_unlock_strings: Option<Vec<DestinyActivityUnlockStringDefinition>>,
     original_display_properties: Option<DestinyDisplayPropertiesDefinition>,
     pgcr_image: Option<String>,
-    place_hash: Option<u32>,
+    place_hash: Option<Hash<DestinyPlaceDefinition>>,
     playlist_items: Option<Vec<DestinyActivityPlaylistItemDefinition>>,
     redacted: Option<bool>,
     release_icon: Option<String>,
@@ -149,7 +150,7 @@ impl DestinyActivityDefinition {
     ///
     /// Note that if this is a playlist, the specific playlist entry chosen
     /// will determine the actual activity modes that end up being relevant.
-    pub fn activity_mode_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn activity_mode_hashes(&self) -> Option<&Vec<Hash<DestinyActivityModeDefinition>>> {
         self.activity_mode_hashes.as_ref()
     }
 
@@ -166,7 +167,7 @@ impl DestinyActivityDefinition {
     /// will map to generic Activity Types. You'll have to use your knowledge
     /// of the Activity Mode being played to get more specific information about
     /// what the user is playing
-    pub fn activity_type_hash(&self) -> Option<u32> {
+    pub fn activity_type_hash(&self) -> Option<Hash<DestinyActivityTypeDefinition>> {
         self.activity_type_hash
     }
 
@@ -189,13 +190,13 @@ impl DestinyActivityDefinition {
     /// info about the destination. A Destination can be thought of as a more
     /// specific location than a "Place". For instance, if the "Place" is Earth,
     /// the "Destination" would be a specific city or region on Earth.
-    pub fn destination_hash(&self) -> Option<u32> {
+    pub fn destination_hash(&self) -> Option<Hash<DestinyDestinationDefinition>> {
         self.destination_hash
     }
 
     /// If this activity had an activity mode directly defined on it, this will
     /// be the hash of that mode.
-    pub fn direct_activity_mode_hash(&self) -> Option<u32> {
+    pub fn direct_activity_mode_hash(&self) -> Option<Hash<DestinyActivityModeDefinition>> {
         self.direct_activity_mode_hash
     }
 
@@ -305,7 +306,7 @@ impl DestinyActivityDefinition {
     /// about the Place. A Place is the largest-scoped concept for location
     /// information. For instance, if the "Place" is Earth, the "Destination"
     /// would be a specific city or region on Earth.
-    pub fn place_hash(&self) -> Option<u32> {
+    pub fn place_hash(&self) -> Option<Hash<DestinyPlaceDefinition>> {
         self.place_hash
     }
 
@@ -372,7 +373,7 @@ impl DestinyActivityDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyActivityChallengeDefinition {
     dummy_rewards: Option<Vec<DestinyItemQuantity>>,
-    objective_hash: Option<u32>,
+    objective_hash: Option<Hash<DestinyObjectiveDefinition>>,
 }
 
 impl DestinyActivityChallengeDefinition {
@@ -388,7 +389,7 @@ impl DestinyActivityChallengeDefinition {
 
     /// The hash for the Objective that matches this challenge. Use it to look
     /// up the DestinyObjectiveDefinition.
-    pub fn objective_hash(&self) -> Option<u32> {
+    pub fn objective_hash(&self) -> Option<Hash<DestinyObjectiveDefinition>> {
         self.objective_hash
     }
 }
@@ -402,13 +403,13 @@ impl DestinyActivityChallengeDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyActivityGraphListEntryDefinition {
-    activity_graph_hash: Option<u32>,
+    activity_graph_hash: Option<Hash<DestinyActivityGraphDefinition>>,
 }
 
 impl DestinyActivityGraphListEntryDefinition {
     /// The hash identifier of the DestinyActivityGraphDefinition that should
     /// be shown when opening the director.
-    pub fn activity_graph_hash(&self) -> Option<u32> {
+    pub fn activity_graph_hash(&self) -> Option<Hash<DestinyActivityGraphDefinition>> {
         self.activity_graph_hash
     }
 }
@@ -474,13 +475,15 @@ impl DestinyActivityInsertionPointDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyActivityLoadoutRequirement {
-    allowed_equipped_item_hashes: Option<Vec<u32>>,
+    allowed_equipped_item_hashes: Option<Vec<Hash<DestinyInventoryItemDefinition>>>,
     allowed_weapon_sub_types: Option<Vec<i32>>,
-    equipment_slot_hash: Option<u32>,
+    equipment_slot_hash: Option<Hash<DestinyEquipmentSlotDefinition>>,
 }
 
 impl DestinyActivityLoadoutRequirement {
-    pub fn allowed_equipped_item_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn allowed_equipped_item_hashes(
+        &self,
+    ) -> Option<&Vec<Hash<DestinyInventoryItemDefinition>>> {
         self.allowed_equipped_item_hashes.as_ref()
     }
 
@@ -488,7 +491,7 @@ impl DestinyActivityLoadoutRequirement {
         self.allowed_weapon_sub_types.as_ref()
     }
 
-    pub fn equipment_slot_hash(&self) -> Option<u32> {
+    pub fn equipment_slot_hash(&self) -> Option<Hash<DestinyEquipmentSlotDefinition>> {
         self.equipment_slot_hash
     }
 }
@@ -564,7 +567,7 @@ impl DestinyActivityMatchmakingBlockDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyActivityModeDefinition {
     activity_mode_category: Option<i32>,
-    activity_mode_mappings: Option<HashMap<u32, i32>>,
+    activity_mode_mappings: Option<HashMap<Hash<DestinyActivityDefinition>, i32>>,
     display: Option<bool>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     friendly_name: Option<String>,
@@ -574,7 +577,7 @@ pub struct DestinyActivityModeDefinition {
     is_team_based: Option<bool>,
     mode_type: Option<i32>,
     order: Option<i32>,
-    parent_hashes: Option<Vec<u32>>,
+    parent_hashes: Option<Vec<Hash<DestinyActivityModeDefinition>>>,
     pgcr_image: Option<String>,
     redacted: Option<bool>,
 }
@@ -590,7 +593,7 @@ impl DestinyActivityModeDefinition {
     /// played. This was useful in D1 for Private Matches, where we wanted to
     /// have Private Matches as an activity mode while still referring to the
     /// specific mode being played.
-    pub fn activity_mode_mappings(&self) -> Option<&HashMap<u32, i32>> {
+    pub fn activity_mode_mappings(&self) -> Option<&HashMap<Hash<DestinyActivityDefinition>, i32>> {
         self.activity_mode_mappings.as_ref()
     }
 
@@ -663,7 +666,7 @@ impl DestinyActivityModeDefinition {
     /// The hash identifiers of the DestinyActivityModeDefinitions that
     /// represent all of the "parent" modes for this mode. For instance, the
     /// Nightfall Mode is also a member of AllStrikes and AllPvE.
-    pub fn parent_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn parent_hashes(&self) -> Option<&Vec<Hash<DestinyActivityModeDefinition>>> {
         self.parent_hashes.as_ref()
     }
 
@@ -684,11 +687,11 @@ impl DestinyActivityModeDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyActivityModifierReferenceDefinition {
-    activity_modifier_hash: Option<u32>,
+    activity_modifier_hash: Option<Hash<DestinyActivityModifierDefinition>>,
 }
 
 impl DestinyActivityModifierReferenceDefinition {
-    pub fn activity_modifier_hash(&self) -> Option<u32> {
+    pub fn activity_modifier_hash(&self) -> Option<Hash<DestinyActivityModifierDefinition>> {
         self.activity_modifier_hash
     }
 }
@@ -697,19 +700,19 @@ impl DestinyActivityModifierReferenceDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyActivityPlaylistItemDefinition {
-    activity_hash: Option<u32>,
-    activity_mode_hashes: Option<Vec<u32>>,
+    activity_hash: Option<Hash<DestinyActivityDefinition>>,
+    activity_mode_hashes: Option<Vec<Hash<DestinyActivityModeDefinition>>>,
     activity_mode_types: Option<Vec<i32>>,
-    direct_activity_mode_hash: Option<u32>,
+    direct_activity_mode_hash: Option<Hash<DestinyActivityModeDefinition>>,
     direct_activity_mode_type: Option<i32>,
 }
 
 impl DestinyActivityPlaylistItemDefinition {
-    pub fn activity_hash(&self) -> Option<u32> {
+    pub fn activity_hash(&self) -> Option<Hash<DestinyActivityDefinition>> {
         self.activity_hash
     }
 
-    pub fn activity_mode_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn activity_mode_hashes(&self) -> Option<&Vec<Hash<DestinyActivityModeDefinition>>> {
         self.activity_mode_hashes.as_ref()
     }
 
@@ -717,7 +720,7 @@ impl DestinyActivityPlaylistItemDefinition {
         self.activity_mode_types.as_ref()
     }
 
-    pub fn direct_activity_mode_hash(&self) -> Option<u32> {
+    pub fn direct_activity_mode_hash(&self) -> Option<Hash<DestinyActivityModeDefinition>> {
         self.direct_activity_mode_hash
     }
 
@@ -792,7 +795,7 @@ pub struct DestinyArrangementRegionFilterDefinition {
     arrangement_index_by_stat_value: Option<HashMap<i32, i32>>,
     art_arrangement_region_hash: Option<u32>,
     art_arrangement_region_index: Option<i32>,
-    stat_hash: Option<u32>,
+    stat_hash: Option<Hash<DestinyStatDefinition>>,
 }
 
 impl DestinyArrangementRegionFilterDefinition {
@@ -808,7 +811,7 @@ impl DestinyArrangementRegionFilterDefinition {
         self.art_arrangement_region_index
     }
 
-    pub fn stat_hash(&self) -> Option<u32> {
+    pub fn stat_hash(&self) -> Option<Hash<DestinyStatDefinition>> {
         self.stat_hash
     }
 }
@@ -817,11 +820,11 @@ impl DestinyArrangementRegionFilterDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyArtDyeReference {
-    art_dye_channel_hash: Option<u32>,
+    art_dye_channel_hash: Option<Hash<DestinyArtDyeChannelDefinition>>,
 }
 
 impl DestinyArtDyeReference {
-    pub fn art_dye_channel_hash(&self) -> Option<u32> {
+    pub fn art_dye_channel_hash(&self) -> Option<Hash<DestinyArtDyeChannelDefinition>> {
         self.art_dye_channel_hash
     }
 }
@@ -848,18 +851,19 @@ impl DestinyBubbleDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyClassDefinition {
-    class_type: Option<i32>,
+    class_type: Option<DestinyClass>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    #[serde(serialize_with = "serialize_keys_by_name")]
     gendered_class_names: Option<HashMap<DestinyGender, String>>,
-    gendered_class_names_by_gender_hash: Option<HashMap<u32, String>>,
+    gendered_class_names_by_gender_hash: Option<HashMap<Hash<DestinyGenderDefinition>, String>>,
     hash: Option<u32>,
     index: Option<i32>,
-    mentor_vendor_hash: Option<u32>,
+    mentor_vendor_hash: Option<Hash<DestinyVendorDefinition>>,
     redacted: Option<bool>,
 }
 
 impl DestinyClassDefinition {
-    pub fn class_type(&self) -> Option<i32> {
+    pub fn class_type(&self) -> Option<DestinyClass> {
         self.class_type
     }
 
@@ -871,7 +875,9 @@ impl DestinyClassDefinition {
         self.gendered_class_names.as_ref()
     }
 
-    pub fn gendered_class_names_by_gender_hash(&self) -> Option<&HashMap<u32, String>> {
+    pub fn gendered_class_names_by_gender_hash(
+        &self,
+    ) -> Option<&HashMap<Hash<DestinyGenderDefinition>, String>> {
         self.gendered_class_names_by_gender_hash.as_ref()
     }
 
@@ -883,7 +889,7 @@ impl DestinyClassDefinition {
         self.index
     }
 
-    pub fn mentor_vendor_hash(&self) -> Option<u32> {
+    pub fn mentor_vendor_hash(&self) -> Option<Hash<DestinyVendorDefinition>> {
         self.mentor_vendor_hash
     }
 
@@ -955,11 +961,11 @@ pub struct DestinyDestinationDefinition {
     activity_graph_entries: Option<Vec<DestinyActivityGraphListEntryDefinition>>,
     bubble_settings: Option<Vec<DestinyDestinationBubbleSettingDefinition>>,
     bubbles: Option<Vec<DestinyBubbleDefinition>>,
-    default_freeroam_activity_hash: Option<u32>,
+    default_freeroam_activity_hash: Option<Hash<DestinyActivityDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     hash: Option<u32>,
     index: Option<i32>,
-    place_hash: Option<u32>,
+    place_hash: Option<Hash<DestinyPlaceDefinition>>,
     redacted: Option<bool>,
 }
 
@@ -976,7 +982,7 @@ impl DestinyDestinationDefinition {
         self.bubbles.as_ref()
     }
 
-    pub fn default_freeroam_activity_hash(&self) -> Option<u32> {
+    pub fn default_freeroam_activity_hash(&self) -> Option<Hash<DestinyActivityDefinition>> {
         self.default_freeroam_activity_hash
     }
 
@@ -992,7 +998,7 @@ impl DestinyDestinationDefinition {
         self.index
     }
 
-    pub fn place_hash(&self) -> Option<u32> {
+    pub fn place_hash(&self) -> Option<Hash<DestinyPlaceDefinition>> {
         self.place_hash
     }
 
@@ -1012,7 +1018,7 @@ pub struct DestinyDisplayCategoryDefinition {
     display_style_identifier: Option<String>,
     identifier: Option<String>,
     index: Option<i32>,
-    progression_hash: Option<u32>,
+    progression_hash: Option<Hash<DestinyProgressionDefinition>>,
     sort_order: Option<u32>,
 }
 
@@ -1045,7 +1051,7 @@ impl DestinyDisplayCategoryDefinition {
         self.index
     }
 
-    pub fn progression_hash(&self) -> Option<u32> {
+    pub fn progression_hash(&self) -> Option<Hash<DestinyProgressionDefinition>> {
         self.progression_hash
     }
 
@@ -1060,7 +1066,7 @@ impl DestinyDisplayCategoryDefinition {
 pub struct DestinyEquipmentSlotDefinition {
     apply_custom_art_dyes: Option<bool>,
     art_dye_channels: Option<Vec<DestinyArtDyeReference>>,
-    bucket_type_hash: Option<u32>,
+    bucket_type_hash: Option<Hash<DestinyInventoryBucketDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     equipment_category_hash: Option<u32>,
     hash: Option<u32>,
@@ -1077,7 +1083,7 @@ impl DestinyEquipmentSlotDefinition {
         self.art_dye_channels.as_ref()
     }
 
-    pub fn bucket_type_hash(&self) -> Option<u32> {
+    pub fn bucket_type_hash(&self) -> Option<Hash<DestinyInventoryBucketDefinition>> {
         self.bucket_type_hash
     }
 
@@ -1106,17 +1112,17 @@ impl DestinyEquipmentSlotDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyEquippingBlockDefinition {
-    ammo_type: Option<i32>,
+    ammo_type: Option<DestinyAmmunitionType>,
     attributes: Option<i32>,
     display_strings: Option<Vec<String>>,
-    equipment_slot_type_hash: Option<u32>,
-    gearset_item_hash: Option<u32>,
+    equipment_slot_type_hash: Option<Hash<DestinyEquipmentSlotDefinition>>,
+    gearset_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     unique_label: Option<String>,
     unique_label_hash: Option<u32>,
 }
 
 impl DestinyEquippingBlockDefinition {
-    pub fn ammo_type(&self) -> Option<i32> {
+    pub fn ammo_type(&self) -> Option<DestinyAmmunitionType> {
         self.ammo_type
     }
 
@@ -1128,11 +1134,11 @@ impl DestinyEquippingBlockDefinition {
         self.display_strings.as_ref()
     }
 
-    pub fn equipment_slot_type_hash(&self) -> Option<u32> {
+    pub fn equipment_slot_type_hash(&self) -> Option<Hash<DestinyEquipmentSlotDefinition>> {
         self.equipment_slot_type_hash
     }
 
-    pub fn gearset_item_hash(&self) -> Option<u32> {
+    pub fn gearset_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.gearset_item_hash
     }
 
@@ -1152,11 +1158,11 @@ pub struct DestinyFactionDefinition {
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     hash: Option<u32>,
     index: Option<i32>,
-    progression_hash: Option<u32>,
+    progression_hash: Option<Hash<DestinyProgressionDefinition>>,
     redacted: Option<bool>,
-    reward_item_hash: Option<u32>,
-    reward_vendor_hash: Option<u32>,
-    token_values: Option<HashMap<u32, u32>>,
+    reward_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    reward_vendor_hash: Option<Hash<DestinyVendorDefinition>>,
+    token_values: Option<HashMap<Hash<DestinyInventoryItemDefinition>, u32>>,
     vendors: Option<Vec<DestinyFactionVendorDefinition>>,
 }
 
@@ -1173,7 +1179,7 @@ impl DestinyFactionDefinition {
         self.index
     }
 
-    pub fn progression_hash(&self) -> Option<u32> {
+    pub fn progression_hash(&self) -> Option<Hash<DestinyProgressionDefinition>> {
         self.progression_hash
     }
 
@@ -1181,15 +1187,15 @@ impl DestinyFactionDefinition {
         self.redacted
     }
 
-    pub fn reward_item_hash(&self) -> Option<u32> {
+    pub fn reward_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.reward_item_hash
     }
 
-    pub fn reward_vendor_hash(&self) -> Option<u32> {
+    pub fn reward_vendor_hash(&self) -> Option<Hash<DestinyVendorDefinition>> {
         self.reward_vendor_hash
     }
 
-    pub fn token_values(&self) -> Option<&HashMap<u32, u32>> {
+    pub fn token_values(&self) -> Option<&HashMap<Hash<DestinyInventoryItemDefinition>, u32>> {
         self.token_values.as_ref()
     }
 
@@ -1203,8 +1209,8 @@ impl DestinyFactionDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyFactionVendorDefinition {
     background_image_path: Option<String>,
-    destination_hash: Option<u32>,
-    vendor_hash: Option<u32>,
+    destination_hash: Option<Hash<DestinyDestinationDefinition>>,
+    vendor_hash: Option<Hash<DestinyVendorDefinition>>,
 }
 
 impl DestinyFactionVendorDefinition {
@@ -1212,11 +1218,11 @@ impl DestinyFactionVendorDefinition {
         self.background_image_path.as_ref()
     }
 
-    pub fn destination_hash(&self) -> Option<u32> {
+    pub fn destination_hash(&self) -> Option<Hash<DestinyDestinationDefinition>> {
         self.destination_hash
     }
 
-    pub fn vendor_hash(&self) -> Option<u32> {
+    pub fn vendor_hash(&self) -> Option<Hash<DestinyVendorDefinition>> {
         self.vendor_hash
     }
 }
@@ -1226,7 +1232,7 @@ impl DestinyFactionVendorDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyGearArtArrangementReference {
     art_arrangement_hash: Option<u32>,
-    class_hash: Option<u32>,
+    class_hash: Option<Hash<DestinyClassDefinition>>,
 }
 
 impl DestinyGearArtArrangementReference {
@@ -1234,7 +1240,7 @@ impl DestinyGearArtArrangementReference {
         self.art_arrangement_hash
     }
 
-    pub fn class_hash(&self) -> Option<u32> {
+    pub fn class_hash(&self) -> Option<Hash<DestinyClassDefinition>> {
         self.class_hash
     }
 }
@@ -1244,7 +1250,7 @@ impl DestinyGearArtArrangementReference {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyGenderDefinition {
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
-    gender_type: Option<i32>,
+    gender_type: Option<DestinyGender>,
     hash: Option<u32>,
     index: Option<i32>,
     redacted: Option<bool>,
@@ -1255,7 +1261,7 @@ impl DestinyGenderDefinition {
         self.display_properties.as_ref()
     }
 
-    pub fn gender_type(&self) -> Option<i32> {
+    pub fn gender_type(&self) -> Option<DestinyGender> {
         self.gender_type
     }
 
@@ -1348,19 +1354,19 @@ pub struct DestinyInventoryItemDefinition {
     allow_actions: Option<bool>,
     animations: Option<Vec<DestinyAnimationReference>>,
     background_color: Option<DestinyColor>,
-    breaker_type: Option<i32>,
-    breaker_type_hash: Option<u32>,
-    class_type: Option<i32>,
-    collectible_hash: Option<u32>,
+    breaker_type: Option<DestinyBreakerType>,
+    breaker_type_hash: Option<Hash<DestinyBreakerTypeDefinition>>,
+    class_type: Option<DestinyClass>,
+    collectible_hash: Option<Hash<DestinyCollectibleDefinition>>,
     crafting: Option<DestinyItemCraftingBlockDefinition>,
-    damage_type_hashes: Option<Vec<u32>>,
+    damage_type_hashes: Option<Vec<Hash<DestinyDamageTypeDefinition>>>,
     damage_types: Option<Vec<i32>>,
     default_damage_type: Option<i32>,
-    default_damage_type_hash: Option<u32>,
+    default_damage_type_hash: Option<Hash<DestinyDamageTypeDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     display_source: Option<String>,
     does_postmaster_pull_have_side_effects: Option<bool>,
-    emblem_objective_hash: Option<u32>,
+    emblem_objective_hash: Option<Hash<DestinyObjectiveDefinition>>,
     equippable: Option<bool>,
     equipping_block: Option<DestinyEquippingBlockDefinition>,
     flavor_text: Option<String>,
@@ -1372,13 +1378,13 @@ pub struct DestinyInventoryItemDefinition {
     inventory: Option<DestinyItemInventoryBlockDefinition>,
     investment_stats: Option<Vec<DestinyItemInvestmentStatDefinition>>,
     is_wrapper: Option<bool>,
-    item_category_hashes: Option<Vec<u32>>,
-    item_sub_type: Option<i32>,
-    item_type: Option<i32>,
+    item_category_hashes: Option<Vec<Hash<DestinyItemCategoryDefinition>>>,
+    item_sub_type: Option<DestinyItemSubType>,
+    item_type: Option<DestinyItemType>,
     item_type_and_tier_display_name: Option<String>,
     item_type_display_name: Option<String>,
     links: Option<Vec<HyperlinkReference>>,
-    lore_hash: Option<u32>,
+    lore_hash: Option<Hash<DestinyLoreDefinition>>,
     metrics: Option<DestinyItemMetricBlockDefinition>,
     non_transferrable: Option<bool>,
     objectives: Option<DestinyItemObjectiveBlockDefinition>,
@@ -1389,21 +1395,21 @@ pub struct DestinyInventoryItemDefinition {
     redacted: Option<bool>,
     sack: Option<DestinyItemSackBlockDefinition>,
     screenshot: Option<String>,
-    season_hash: Option<u32>,
+    season_hash: Option<Hash<DestinySeasonDefinition>>,
     secondary_icon: Option<String>,
     secondary_overlay: Option<String>,
     secondary_special: Option<String>,
     set_data: Option<DestinyItemSetBlockDefinition>,
     sockets: Option<DestinyItemSocketBlockDefinition>,
     source_data: Option<DestinyItemSourceBlockDefinition>,
-    special_item_type: Option<i32>,
+    special_item_type: Option<SpecialItemType>,
     stats: Option<DestinyItemStatBlockDefinition>,
     summary: Option<DestinyItemSummaryBlockDefinition>,
-    summary_item_hash: Option<u32>,
+    summary_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     talent_grid: Option<DestinyItemTalentGridBlockDefinition>,
     tooltip_notifications: Option<Vec<DestinyItemTooltipNotification>>,
     tooltip_style: Option<String>,
-    trait_hashes: Option<Vec<u32>>,
+    trait_hashes: Option<Vec<Hash<DestinyTraitDefinition>>>,
     trait_ids: Option<Vec<String>>,
     translation_block: Option<DestinyItemTranslationBlockDefinition>,
     ui_item_display_style: Option<String>,
@@ -1427,19 +1433,19 @@ impl DestinyInventoryItemDefinition {
         self.background_color.as_ref()
     }
 
-    pub fn breaker_type(&self) -> Option<i32> {
+    pub fn breaker_type(&self) -> Option<DestinyBreakerType> {
         self.breaker_type
     }
 
-    pub fn breaker_type_hash(&self) -> Option<u32> {
+    pub fn breaker_type_hash(&self) -> Option<Hash<DestinyBreakerTypeDefinition>> {
         self.breaker_type_hash
     }
 
-    pub fn class_type(&self) -> Option<i32> {
+    pub fn class_type(&self) -> Option<DestinyClass> {
         self.class_type
     }
 
-    pub fn collectible_hash(&self) -> Option<u32> {
+    pub fn collectible_hash(&self) -> Option<Hash<DestinyCollectibleDefinition>> {
         self.collectible_hash
     }
 
@@ -1447,7 +1453,7 @@ impl DestinyInventoryItemDefinition {
         self.crafting.as_ref()
     }
 
-    pub fn damage_type_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn damage_type_hashes(&self) -> Option<&Vec<Hash<DestinyDamageTypeDefinition>>> {
         self.damage_type_hashes.as_ref()
     }
 
@@ -1459,7 +1465,7 @@ impl DestinyInventoryItemDefinition {
         self.default_damage_type
     }
 
-    pub fn default_damage_type_hash(&self) -> Option<u32> {
+    pub fn default_damage_type_hash(&self) -> Option<Hash<DestinyDamageTypeDefinition>> {
         self.default_damage_type_hash
     }
 
@@ -1475,7 +1481,7 @@ impl DestinyInventoryItemDefinition {
         self.does_postmaster_pull_have_side_effects
     }
 
-    pub fn emblem_objective_hash(&self) -> Option<u32> {
+    pub fn emblem_objective_hash(&self) -> Option<Hash<DestinyObjectiveDefinition>> {
         self.emblem_objective_hash
     }
 
@@ -1523,15 +1529,15 @@ impl DestinyInventoryItemDefinition {
         self.is_wrapper
     }
 
-    pub fn item_category_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn item_category_hashes(&self) -> Option<&Vec<Hash<DestinyItemCategoryDefinition>>> {
         self.item_category_hashes.as_ref()
     }
 
-    pub fn item_sub_type(&self) -> Option<i32> {
+    pub fn item_sub_type(&self) -> Option<DestinyItemSubType> {
         self.item_sub_type
     }
 
-    pub fn item_type(&self) -> Option<i32> {
+    pub fn item_type(&self) -> Option<DestinyItemType> {
         self.item_type
     }
 
@@ -1547,7 +1553,7 @@ impl DestinyInventoryItemDefinition {
         self.links.as_ref()
     }
 
-    pub fn lore_hash(&self) -> Option<u32> {
+    pub fn lore_hash(&self) -> Option<Hash<DestinyLoreDefinition>> {
         self.lore_hash
     }
 
@@ -1591,7 +1597,7 @@ impl DestinyInventoryItemDefinition {
         self.screenshot.as_ref()
     }
 
-    pub fn season_hash(&self) -> Option<u32> {
+    pub fn season_hash(&self) -> Option<Hash<DestinySeasonDefinition>> {
         self.season_hash
     }
 
@@ -1619,7 +1625,7 @@ impl DestinyInventoryItemDefinition {
         self.source_data.as_ref()
     }
 
-    pub fn special_item_type(&self) -> Option<i32> {
+    pub fn special_item_type(&self) -> Option<SpecialItemType> {
         self.special_item_type
     }
 
@@ -1631,7 +1637,7 @@ impl DestinyInventoryItemDefinition {
         self.summary.as_ref()
     }
 
-    pub fn summary_item_hash(&self) -> Option<u32> {
+    pub fn summary_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.summary_item_hash
     }
 
@@ -1647,7 +1653,7 @@ impl DestinyInventoryItemDefinition {
         self.tooltip_style.as_ref()
     }
 
-    pub fn trait_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn trait_hashes(&self) -> Option<&Vec<Hash<DestinyTraitDefinition>>> {
         self.trait_hashes.as_ref()
     }
 
@@ -1675,7 +1681,7 @@ pub struct DestinyInventoryItemStatDefinition {
     display_maximum: Option<i32>,
     maximum: Option<i32>,
     minimum: Option<i32>,
-    stat_hash: Option<u32>,
+    stat_hash: Option<Hash<DestinyStatDefinition>>,
     value: Option<i32>,
 }
 
@@ -1692,7 +1698,7 @@ impl DestinyInventoryItemStatDefinition {
         self.minimum
     }
 
-    pub fn stat_hash(&self) -> Option<u32> {
+    pub fn stat_hash(&self) -> Option<Hash<DestinyStatDefinition>> {
         self.stat_hash
     }
 
@@ -1785,7 +1791,7 @@ impl DestinyItemActionBlockDefinition {
 pub struct DestinyItemActionRequiredItemDefinition {
     count: Option<i32>,
     delete_on_action: Option<bool>,
-    item_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
 }
 
 impl DestinyItemActionRequiredItemDefinition {
@@ -1797,7 +1803,7 @@ impl DestinyItemActionRequiredItemDefinition {
         self.delete_on_action
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 }
@@ -1808,18 +1814,18 @@ impl DestinyItemActionRequiredItemDefinition {
 pub struct DestinyItemCategoryDefinition {
     deprecated: Option<bool>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
-    grant_destiny_breaker_type: Option<i32>,
+    grant_destiny_breaker_type: Option<DestinyBreakerType>,
     grant_destiny_class: Option<i32>,
-    grant_destiny_item_type: Option<i32>,
+    grant_destiny_item_type: Option<DestinyItemType>,
     grant_destiny_sub_type: Option<i32>,
     group_category_only: Option<bool>,
-    grouped_category_hashes: Option<Vec<u32>>,
+    grouped_category_hashes: Option<Vec<Hash<DestinyItemCategoryDefinition>>>,
     hash: Option<u32>,
     index: Option<i32>,
     item_type_regex: Option<String>,
     item_type_regex_not: Option<String>,
     origin_bucket_identifier: Option<String>,
-    parent_category_hashes: Option<Vec<u32>>,
+    parent_category_hashes: Option<Vec<Hash<DestinyItemCategoryDefinition>>>,
     plug_category_identifier: Option<String>,
     redacted: Option<bool>,
     short_title: Option<String>,
@@ -1836,7 +1842,7 @@ impl DestinyItemCategoryDefinition {
         self.display_properties.as_ref()
     }
 
-    pub fn grant_destiny_breaker_type(&self) -> Option<i32> {
+    pub fn grant_destiny_breaker_type(&self) -> Option<DestinyBreakerType> {
         self.grant_destiny_breaker_type
     }
 
@@ -1844,7 +1850,7 @@ impl DestinyItemCategoryDefinition {
         self.grant_destiny_class
     }
 
-    pub fn grant_destiny_item_type(&self) -> Option<i32> {
+    pub fn grant_destiny_item_type(&self) -> Option<DestinyItemType> {
         self.grant_destiny_item_type
     }
 
@@ -1856,7 +1862,7 @@ impl DestinyItemCategoryDefinition {
         self.group_category_only
     }
 
-    pub fn grouped_category_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn grouped_category_hashes(&self) -> Option<&Vec<Hash<DestinyItemCategoryDefinition>>> {
         self.grouped_category_hashes.as_ref()
     }
 
@@ -1880,7 +1886,7 @@ impl DestinyItemCategoryDefinition {
         self.origin_bucket_identifier.as_ref()
     }
 
-    pub fn parent_category_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn parent_category_hashes(&self) -> Option<&Vec<Hash<DestinyItemCategoryDefinition>>> {
         self.parent_category_hashes.as_ref()
     }
 
@@ -1909,16 +1915,16 @@ impl DestinyItemCategoryDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemCraftingBlockBonusPlugDefinition {
-    plug_item_hash: Option<u32>,
-    socket_type_hash: Option<u32>,
+    plug_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    socket_type_hash: Option<Hash<DestinySocketTypeDefinition>>,
 }
 
 impl DestinyItemCraftingBlockBonusPlugDefinition {
-    pub fn plug_item_hash(&self) -> Option<u32> {
+    pub fn plug_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.plug_item_hash
     }
 
-    pub fn socket_type_hash(&self) -> Option<u32> {
+    pub fn socket_type_hash(&self) -> Option<Hash<DestinySocketTypeDefinition>> {
         self.socket_type_hash
     }
 }
@@ -1930,8 +1936,8 @@ pub struct DestinyItemCraftingBlockDefinition {
     base_material_requirements: Option<u32>,
     bonus_plugs: Option<Vec<DestinyItemCraftingBlockBonusPlugDefinition>>,
     failed_requirement_strings: Option<Vec<String>>,
-    output_item_hash: Option<u32>,
-    required_socket_type_hashes: Option<Vec<u32>>,
+    output_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    required_socket_type_hashes: Option<Vec<Hash<DestinySocketTypeDefinition>>>,
 }
 
 impl DestinyItemCraftingBlockDefinition {
@@ -1947,11 +1953,11 @@ impl DestinyItemCraftingBlockDefinition {
         self.failed_requirement_strings.as_ref()
     }
 
-    pub fn output_item_hash(&self) -> Option<u32> {
+    pub fn output_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.output_item_hash
     }
 
-    pub fn required_socket_type_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn required_socket_type_hashes(&self) -> Option<&Vec<Hash<DestinySocketTypeDefinition>>> {
         self.required_socket_type_hashes.as_ref()
     }
 }
@@ -1973,12 +1979,12 @@ impl DestinyItemCreationEntryLevelDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemGearsetBlockDefinition {
-    item_list: Option<Vec<u32>>,
+    item_list: Option<Vec<Hash<DestinyInventoryItemDefinition>>>,
     tracking_value_max: Option<i32>,
 }
 
 impl DestinyItemGearsetBlockDefinition {
-    pub fn item_list(&self) -> Option<&Vec<u32>> {
+    pub fn item_list(&self) -> Option<&Vec<Hash<DestinyInventoryItemDefinition>>> {
         self.item_list.as_ref()
     }
 
@@ -1992,8 +1998,8 @@ impl DestinyItemGearsetBlockDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemIntrinsicSocketEntryDefinition {
     default_visible: Option<bool>,
-    plug_item_hash: Option<u32>,
-    socket_type_hash: Option<u32>,
+    plug_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    socket_type_hash: Option<Hash<DestinySocketTypeDefinition>>,
 }
 
 impl DestinyItemIntrinsicSocketEntryDefinition {
@@ -2001,11 +2007,11 @@ impl DestinyItemIntrinsicSocketEntryDefinition {
         self.default_visible
     }
 
-    pub fn plug_item_hash(&self) -> Option<u32> {
+    pub fn plug_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.plug_item_hash
     }
 
-    pub fn socket_type_hash(&self) -> Option<u32> {
+    pub fn socket_type_hash(&self) -> Option<Hash<DestinySocketTypeDefinition>> {
         self.socket_type_hash
     }
 }
@@ -2014,23 +2020,23 @@ impl DestinyItemIntrinsicSocketEntryDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemInventoryBlockDefinition {
-    bucket_type_hash: Option<u32>,
+    bucket_type_hash: Option<Hash<DestinyInventoryBucketDefinition>>,
     expiration_tooltip: Option<String>,
     expired_in_activity_message: Option<String>,
     expired_in_orbit_message: Option<String>,
     is_instance_item: Option<bool>,
     max_stack_size: Option<i32>,
-    recipe_item_hash: Option<u32>,
-    recovery_bucket_type_hash: Option<u32>,
+    recipe_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    recovery_bucket_type_hash: Option<Hash<DestinyInventoryBucketDefinition>>,
     stack_unique_label: Option<String>,
     suppress_expiration_when_objectives_complete: Option<bool>,
     tier_type: Option<i32>,
-    tier_type_hash: Option<u32>,
+    tier_type_hash: Option<Hash<DestinyItemTierTypeDefinition>>,
     tier_type_name: Option<String>,
 }
 
 impl DestinyItemInventoryBlockDefinition {
-    pub fn bucket_type_hash(&self) -> Option<u32> {
+    pub fn bucket_type_hash(&self) -> Option<Hash<DestinyInventoryBucketDefinition>> {
         self.bucket_type_hash
     }
 
@@ -2054,11 +2060,11 @@ impl DestinyItemInventoryBlockDefinition {
         self.max_stack_size
     }
 
-    pub fn recipe_item_hash(&self) -> Option<u32> {
+    pub fn recipe_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.recipe_item_hash
     }
 
-    pub fn recovery_bucket_type_hash(&self) -> Option<u32> {
+    pub fn recovery_bucket_type_hash(&self) -> Option<Hash<DestinyInventoryBucketDefinition>> {
         self.recovery_bucket_type_hash
     }
 
@@ -2074,7 +2080,7 @@ impl DestinyItemInventoryBlockDefinition {
         self.tier_type
     }
 
-    pub fn tier_type_hash(&self) -> Option<u32> {
+    pub fn tier_type_hash(&self) -> Option<Hash<DestinyItemTierTypeDefinition>> {
         self.tier_type_hash
     }
 
@@ -2088,7 +2094,7 @@ impl DestinyItemInventoryBlockDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemInvestmentStatDefinition {
     is_conditionally_active: Option<bool>,
-    stat_type_hash: Option<u32>,
+    stat_type_hash: Option<Hash<DestinyStatDefinition>>,
     value: Option<i32>,
 }
 
@@ -2097,7 +2103,7 @@ impl DestinyItemInvestmentStatDefinition {
         self.is_conditionally_active
     }
 
-    pub fn stat_type_hash(&self) -> Option<u32> {
+    pub fn stat_type_hash(&self) -> Option<Hash<DestinyStatDefinition>> {
         self.stat_type_hash
     }
 
@@ -2110,11 +2116,13 @@ impl DestinyItemInvestmentStatDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemMetricBlockDefinition {
-    available_metric_category_node_hashes: Option<Vec<u32>>,
+    available_metric_category_node_hashes: Option<Vec<Hash<DestinyPresentationNodeDefinition>>>,
 }
 
 impl DestinyItemMetricBlockDefinition {
-    pub fn available_metric_category_node_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn available_metric_category_node_hashes(
+        &self,
+    ) -> Option<&Vec<Hash<DestinyPresentationNodeDefinition>>> {
         self.available_metric_category_node_hashes.as_ref()
     }
 }
@@ -2123,20 +2131,20 @@ impl DestinyItemMetricBlockDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemObjectiveBlockDefinition {
-    display_activity_hashes: Option<Vec<u32>>,
+    display_activity_hashes: Option<Vec<Hash<DestinyActivityDefinition>>>,
     display_as_stat_tracker: Option<bool>,
     narrative: Option<String>,
-    objective_hashes: Option<Vec<u32>>,
+    objective_hashes: Option<Vec<Hash<DestinyObjectiveDefinition>>>,
     objective_verb_name: Option<String>,
     per_objective_display_properties: Option<Vec<DestinyObjectiveDisplayProperties>>,
     quest_type_hash: Option<u32>,
     quest_type_identifier: Option<String>,
-    questline_item_hash: Option<u32>,
+    questline_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     require_full_objective_completion: Option<bool>,
 }
 
 impl DestinyItemObjectiveBlockDefinition {
-    pub fn display_activity_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn display_activity_hashes(&self) -> Option<&Vec<Hash<DestinyActivityDefinition>>> {
         self.display_activity_hashes.as_ref()
     }
 
@@ -2148,7 +2156,7 @@ impl DestinyItemObjectiveBlockDefinition {
         self.narrative.as_ref()
     }
 
-    pub fn objective_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn objective_hashes(&self) -> Option<&Vec<Hash<DestinyObjectiveDefinition>>> {
         self.objective_hashes.as_ref()
     }
 
@@ -2170,7 +2178,7 @@ impl DestinyItemObjectiveBlockDefinition {
         self.quest_type_identifier.as_ref()
     }
 
-    pub fn questline_item_hash(&self) -> Option<u32> {
+    pub fn questline_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.questline_item_hash
     }
 
@@ -2183,13 +2191,13 @@ impl DestinyItemObjectiveBlockDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemPerkEntryDefinition {
-    perk_hash: Option<u32>,
+    perk_hash: Option<Hash<DestinySandboxPerkDefinition>>,
     perk_visibility: Option<i32>,
     requirement_display_string: Option<String>,
 }
 
 impl DestinyItemPerkEntryDefinition {
-    pub fn perk_hash(&self) -> Option<u32> {
+    pub fn perk_hash(&self) -> Option<Hash<DestinySandboxPerkDefinition>> {
         self.perk_hash
     }
 
@@ -2206,15 +2214,15 @@ impl DestinyItemPerkEntryDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemPreviewBlockDefinition {
-    artifact_hash: Option<u32>,
+    artifact_hash: Option<Hash<DestinyArtifactDefinition>>,
     derived_item_categories: Option<Vec<DestinyDerivedItemCategoryDefinition>>,
     preview_action_string: Option<String>,
-    preview_vendor_hash: Option<u32>,
+    preview_vendor_hash: Option<Hash<DestinyVendorDefinition>>,
     screen_style: Option<String>,
 }
 
 impl DestinyItemPreviewBlockDefinition {
-    pub fn artifact_hash(&self) -> Option<u32> {
+    pub fn artifact_hash(&self) -> Option<Hash<DestinyArtifactDefinition>> {
         self.artifact_hash
     }
 
@@ -2226,7 +2234,7 @@ impl DestinyItemPreviewBlockDefinition {
         self.preview_action_string.as_ref()
     }
 
-    pub fn preview_vendor_hash(&self) -> Option<u32> {
+    pub fn preview_vendor_hash(&self) -> Option<Hash<DestinyVendorDefinition>> {
         self.preview_vendor_hash
     }
 
@@ -2368,12 +2376,12 @@ impl DestinyItemSetBlockDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemSetBlockEntryDefinition {
-    item_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     tracking_value: Option<i32>,
 }
 
 impl DestinyItemSetBlockEntryDefinition {
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
@@ -2414,12 +2422,12 @@ impl DestinyItemSocketBlockDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemSocketCategoryDefinition {
-    socket_category_hash: Option<u32>,
+    socket_category_hash: Option<Hash<DestinySocketCategoryDefinition>>,
     socket_indexes: Option<Vec<i32>>,
 }
 
 impl DestinyItemSocketCategoryDefinition {
-    pub fn socket_category_hash(&self) -> Option<u32> {
+    pub fn socket_category_hash(&self) -> Option<Hash<DestinySocketCategoryDefinition>> {
         self.socket_category_hash
     }
 
@@ -2436,11 +2444,11 @@ pub struct DestinyItemSocketEntryDefinition {
     hide_perks_in_item_tooltip: Option<bool>,
     plug_sources: Option<i32>,
     prevent_initialization_on_vendor_purchase: Option<bool>,
-    randomized_plug_set_hash: Option<u32>,
+    randomized_plug_set_hash: Option<Hash<DestinyPlugSetDefinition>>,
     reusable_plug_items: Option<Vec<DestinyItemSocketEntryPlugItemDefinition>>,
-    reusable_plug_set_hash: Option<u32>,
-    single_initial_item_hash: Option<u32>,
-    socket_type_hash: Option<u32>,
+    reusable_plug_set_hash: Option<Hash<DestinyPlugSetDefinition>>,
+    single_initial_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    socket_type_hash: Option<Hash<DestinySocketTypeDefinition>>,
 }
 
 impl DestinyItemSocketEntryDefinition {
@@ -2460,7 +2468,7 @@ impl DestinyItemSocketEntryDefinition {
         self.prevent_initialization_on_vendor_purchase
     }
 
-    pub fn randomized_plug_set_hash(&self) -> Option<u32> {
+    pub fn randomized_plug_set_hash(&self) -> Option<Hash<DestinyPlugSetDefinition>> {
         self.randomized_plug_set_hash
     }
 
@@ -2468,15 +2476,15 @@ impl DestinyItemSocketEntryDefinition {
         self.reusable_plug_items.as_ref()
     }
 
-    pub fn reusable_plug_set_hash(&self) -> Option<u32> {
+    pub fn reusable_plug_set_hash(&self) -> Option<Hash<DestinyPlugSetDefinition>> {
         self.reusable_plug_set_hash
     }
 
-    pub fn single_initial_item_hash(&self) -> Option<u32> {
+    pub fn single_initial_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.single_initial_item_hash
     }
 
-    pub fn socket_type_hash(&self) -> Option<u32> {
+    pub fn socket_type_hash(&self) -> Option<Hash<DestinySocketTypeDefinition>> {
         self.socket_type_hash
     }
 }
@@ -2485,11 +2493,11 @@ impl DestinyItemSocketEntryDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemSocketEntryPlugItemDefinition {
-    plug_item_hash: Option<u32>,
+    plug_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
 }
 
 impl DestinyItemSocketEntryPlugItemDefinition {
-    pub fn plug_item_hash(&self) -> Option<u32> {
+    pub fn plug_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.plug_item_hash
     }
 }
@@ -2500,7 +2508,7 @@ impl DestinyItemSocketEntryPlugItemDefinition {
 pub struct DestinyItemSocketEntryPlugItemRandomizedDefinition {
     crafting_requirements: Option<DestinyPlugItemCraftingRequirements>,
     currently_can_roll: Option<bool>,
-    plug_item_hash: Option<u32>,
+    plug_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
 }
 
 impl DestinyItemSocketEntryPlugItemRandomizedDefinition {
@@ -2512,7 +2520,7 @@ impl DestinyItemSocketEntryPlugItemRandomizedDefinition {
         self.currently_can_roll
     }
 
-    pub fn plug_item_hash(&self) -> Option<u32> {
+    pub fn plug_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.plug_item_hash
     }
 }
@@ -2522,7 +2530,7 @@ impl DestinyItemSocketEntryPlugItemRandomizedDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemSourceBlockDefinition {
     exclusive: Option<i32>,
-    source_hashes: Option<Vec<u32>>,
+    source_hashes: Option<Vec<Hash<DestinyRewardSourceDefinition>>>,
     sources: Option<Vec<DestinyItemSourceDefinition>>,
     vendor_sources: Option<Vec<DestinyItemVendorSourceReference>>,
 }
@@ -2532,7 +2540,7 @@ impl DestinyItemSourceBlockDefinition {
         self.exclusive
     }
 
-    pub fn source_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn source_hashes(&self) -> Option<&Vec<Hash<DestinyRewardSourceDefinition>>> {
         self.source_hashes.as_ref()
     }
 
@@ -2551,9 +2559,9 @@ impl DestinyItemSourceBlockDefinition {
 pub struct DestinyItemStatBlockDefinition {
     disable_primary_stat_display: Option<bool>,
     has_displayable_stats: Option<bool>,
-    primary_base_stat_hash: Option<u32>,
-    stat_group_hash: Option<u32>,
-    stats: Option<HashMap<u32, DestinyInventoryItemStatDefinition>>,
+    primary_base_stat_hash: Option<Hash<DestinyStatDefinition>>,
+    stat_group_hash: Option<Hash<DestinyStatGroupDefinition>>,
+    stats: Option<HashMap<Hash<DestinyStatDefinition>, DestinyInventoryItemStatDefinition>>,
 }
 
 impl DestinyItemStatBlockDefinition {
@@ -2565,15 +2573,17 @@ impl DestinyItemStatBlockDefinition {
         self.has_displayable_stats
     }
 
-    pub fn primary_base_stat_hash(&self) -> Option<u32> {
+    pub fn primary_base_stat_hash(&self) -> Option<Hash<DestinyStatDefinition>> {
         self.primary_base_stat_hash
     }
 
-    pub fn stat_group_hash(&self) -> Option<u32> {
+    pub fn stat_group_hash(&self) -> Option<Hash<DestinyStatGroupDefinition>> {
         self.stat_group_hash
     }
 
-    pub fn stats(&self) -> Option<&HashMap<u32, DestinyInventoryItemStatDefinition>> {
+    pub fn stats(
+        &self,
+    ) -> Option<&HashMap<Hash<DestinyStatDefinition>, DestinyInventoryItemStatDefinition>> {
         self.stats.as_ref()
     }
 }
@@ -2599,7 +2609,7 @@ pub struct DestinyItemTalentGridBlockDefinition {
     hud_damage_type: Option<i32>,
     hud_icon: Option<String>,
     item_detail_string: Option<String>,
-    talent_grid_hash: Option<u32>,
+    talent_grid_hash: Option<Hash<DestinyTalentGridDefinition>>,
 }
 
 impl DestinyItemTalentGridBlockDefinition {
@@ -2619,7 +2629,7 @@ impl DestinyItemTalentGridBlockDefinition {
         self.item_detail_string.as_ref()
     }
 
-    pub fn talent_grid_hash(&self) -> Option<u32> {
+    pub fn talent_grid_hash(&self) -> Option<Hash<DestinyTalentGridDefinition>> {
         self.talent_grid_hash
     }
 }
@@ -2707,12 +2717,12 @@ impl DestinyItemValueBlockDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemVendorSourceReference {
-    vendor_hash: Option<u32>,
+    vendor_hash: Option<Hash<DestinyVendorDefinition>>,
     vendor_item_indexes: Option<Vec<i32>>,
 }
 
 impl DestinyItemVendorSourceReference {
-    pub fn vendor_hash(&self) -> Option<u32> {
+    pub fn vendor_hash(&self) -> Option<Hash<DestinyVendorDefinition>> {
         self.vendor_hash
     }
 
@@ -2725,11 +2735,11 @@ impl DestinyItemVendorSourceReference {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemVersionDefinition {
-    power_cap_hash: Option<u32>,
+    power_cap_hash: Option<Hash<DestinyPowerCapDefinition>>,
 }
 
 impl DestinyItemVersionDefinition {
-    pub fn power_cap_hash(&self) -> Option<u32> {
+    pub fn power_cap_hash(&self) -> Option<Hash<DestinyPowerCapDefinition>> {
         self.power_cap_hash
     }
 }
@@ -2742,7 +2752,7 @@ pub struct DestinyLocationDefinition {
     index: Option<i32>,
     location_releases: Option<Vec<DestinyLocationReleaseDefinition>>,
     redacted: Option<bool>,
-    vendor_hash: Option<u32>,
+    vendor_hash: Option<Hash<DestinyVendorDefinition>>,
 }
 
 impl DestinyLocationDefinition {
@@ -2762,7 +2772,7 @@ impl DestinyLocationDefinition {
         self.redacted
     }
 
-    pub fn vendor_hash(&self) -> Option<u32> {
+    pub fn vendor_hash(&self) -> Option<Hash<DestinyVendorDefinition>> {
         self.vendor_hash
     }
 }
@@ -2772,12 +2782,12 @@ impl DestinyLocationDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyLocationReleaseDefinition {
     activity_bubble_name: Option<u32>,
-    activity_graph_hash: Option<u32>,
+    activity_graph_hash: Option<Hash<DestinyActivityGraphDefinition>>,
     activity_graph_node_hash: Option<u32>,
-    activity_hash: Option<u32>,
+    activity_hash: Option<Hash<DestinyActivityDefinition>>,
     activity_path_bundle: Option<u32>,
     activity_path_destination: Option<u32>,
-    destination_hash: Option<u32>,
+    destination_hash: Option<Hash<DestinyDestinationDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     large_transparent_icon: Option<String>,
     map_icon: Option<String>,
@@ -2792,7 +2802,7 @@ impl DestinyLocationReleaseDefinition {
         self.activity_bubble_name
     }
 
-    pub fn activity_graph_hash(&self) -> Option<u32> {
+    pub fn activity_graph_hash(&self) -> Option<Hash<DestinyActivityGraphDefinition>> {
         self.activity_graph_hash
     }
 
@@ -2800,7 +2810,7 @@ impl DestinyLocationReleaseDefinition {
         self.activity_graph_node_hash
     }
 
-    pub fn activity_hash(&self) -> Option<u32> {
+    pub fn activity_hash(&self) -> Option<Hash<DestinyActivityDefinition>> {
         self.activity_hash
     }
 
@@ -2812,7 +2822,7 @@ impl DestinyLocationReleaseDefinition {
         self.activity_path_destination
     }
 
-    pub fn destination_hash(&self) -> Option<u32> {
+    pub fn destination_hash(&self) -> Option<Hash<DestinyDestinationDefinition>> {
         self.destination_hash
     }
 
@@ -2852,7 +2862,7 @@ pub struct DestinyMaterialRequirement {
     count: Option<i32>,
     count_is_constant: Option<bool>,
     delete_on_action: Option<bool>,
-    item_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     omit_from_requirements: Option<bool>,
 }
 
@@ -2869,7 +2879,7 @@ impl DestinyMaterialRequirement {
         self.delete_on_action
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
@@ -2944,7 +2954,7 @@ impl DestinyMedalTierDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyNodeActivationRequirement {
     grid_level: Option<i32>,
-    material_requirement_hashes: Option<Vec<u32>>,
+    material_requirement_hashes: Option<Vec<Hash<DestinyMaterialRequirementSetDefinition>>>,
 }
 
 impl DestinyNodeActivationRequirement {
@@ -2952,7 +2962,9 @@ impl DestinyNodeActivationRequirement {
         self.grid_level
     }
 
-    pub fn material_requirement_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn material_requirement_hashes(
+        &self,
+    ) -> Option<&Vec<Hash<DestinyMaterialRequirementSetDefinition>>> {
         self.material_requirement_hashes.as_ref()
     }
 }
@@ -2961,16 +2973,16 @@ impl DestinyNodeActivationRequirement {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyNodeSocketReplaceResponse {
-    plug_item_hash: Option<u32>,
-    socket_type_hash: Option<u32>,
+    plug_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    socket_type_hash: Option<Hash<DestinySocketTypeDefinition>>,
 }
 
 impl DestinyNodeSocketReplaceResponse {
-    pub fn plug_item_hash(&self) -> Option<u32> {
+    pub fn plug_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.plug_item_hash
     }
 
-    pub fn socket_type_hash(&self) -> Option<u32> {
+    pub fn socket_type_hash(&self) -> Option<Hash<DestinySocketTypeDefinition>> {
         self.socket_type_hash
     }
 }
@@ -2984,16 +2996,16 @@ pub struct DestinyNodeStepDefinition {
     affects_quality: Option<bool>,
     can_activate_next_step: Option<bool>,
     damage_type: Option<i32>,
-    damage_type_hash: Option<u32>,
+    damage_type_hash: Option<Hash<DestinyDamageTypeDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     interaction_description: Option<String>,
     is_next_step_random: Option<bool>,
     next_step_index: Option<i32>,
     node_step_hash: Option<u32>,
-    perk_hashes: Option<Vec<u32>>,
+    perk_hashes: Option<Vec<Hash<DestinySandboxPerkDefinition>>>,
     socket_replacements: Option<Vec<DestinyNodeSocketReplaceResponse>>,
     start_progression_bar_at_progress: Option<i32>,
-    stat_hashes: Option<Vec<u32>>,
+    stat_hashes: Option<Vec<Hash<DestinyStatDefinition>>>,
     step_groups: Option<DestinyTalentNodeStepGroups>,
     step_index: Option<i32>,
 }
@@ -3019,7 +3031,7 @@ impl DestinyNodeStepDefinition {
         self.damage_type
     }
 
-    pub fn damage_type_hash(&self) -> Option<u32> {
+    pub fn damage_type_hash(&self) -> Option<Hash<DestinyDamageTypeDefinition>> {
         self.damage_type_hash
     }
 
@@ -3043,7 +3055,7 @@ impl DestinyNodeStepDefinition {
         self.node_step_hash
     }
 
-    pub fn perk_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn perk_hashes(&self) -> Option<&Vec<Hash<DestinySandboxPerkDefinition>>> {
         self.perk_hashes.as_ref()
     }
 
@@ -3055,7 +3067,7 @@ impl DestinyNodeStepDefinition {
         self.start_progression_bar_at_progress
     }
 
-    pub fn stat_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn stat_hashes(&self) -> Option<&Vec<Hash<DestinyStatDefinition>>> {
         self.stat_hashes.as_ref()
     }
 
@@ -3082,7 +3094,7 @@ pub struct DestinyObjectiveDefinition {
     in_progress_value_style: Option<i32>,
     index: Option<i32>,
     is_counting_downward: Option<bool>,
-    location_hash: Option<u32>,
+    location_hash: Option<Hash<DestinyLocationDefinition>>,
     minimum_visibility_threshold: Option<i32>,
     perks: Option<DestinyObjectivePerkEntryDefinition>,
     progress_description: Option<String>,
@@ -3136,7 +3148,7 @@ impl DestinyObjectiveDefinition {
         self.is_counting_downward
     }
 
-    pub fn location_hash(&self) -> Option<u32> {
+    pub fn location_hash(&self) -> Option<Hash<DestinyLocationDefinition>> {
         self.location_hash
     }
 
@@ -3185,12 +3197,12 @@ impl DestinyObjectiveDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyObjectiveDisplayProperties {
-    activity_hash: Option<u32>,
+    activity_hash: Option<Hash<DestinyActivityDefinition>>,
     display_on_item_preview_screen: Option<bool>,
 }
 
 impl DestinyObjectiveDisplayProperties {
-    pub fn activity_hash(&self) -> Option<u32> {
+    pub fn activity_hash(&self) -> Option<Hash<DestinyActivityDefinition>> {
         self.activity_hash
     }
 
@@ -3203,12 +3215,12 @@ impl DestinyObjectiveDisplayProperties {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyObjectivePerkEntryDefinition {
-    perk_hash: Option<u32>,
+    perk_hash: Option<Hash<DestinySandboxPerkDefinition>>,
     style: Option<i32>,
 }
 
 impl DestinyObjectivePerkEntryDefinition {
-    pub fn perk_hash(&self) -> Option<u32> {
+    pub fn perk_hash(&self) -> Option<Hash<DestinySandboxPerkDefinition>> {
         self.perk_hash
     }
 
@@ -3267,13 +3279,15 @@ impl DestinyPlaceDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyPlugItemCraftingRequirements {
-    material_requirement_hashes: Option<Vec<u32>>,
+    material_requirement_hashes: Option<Vec<Hash<DestinyMaterialRequirementSetDefinition>>>,
     required_level: Option<i32>,
     unlock_requirements: Option<Vec<DestinyPlugItemCraftingUnlockRequirement>>,
 }
 
 impl DestinyPlugItemCraftingRequirements {
-    pub fn material_requirement_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn material_requirement_hashes(
+        &self,
+    ) -> Option<&Vec<Hash<DestinyMaterialRequirementSetDefinition>>> {
         self.material_requirement_hashes.as_ref()
     }
 
@@ -3305,7 +3319,7 @@ impl DestinyPlugItemCraftingUnlockRequirement {
 pub struct DestinyProgressionDefinition {
     color: Option<DestinyColor>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
-    faction_hash: Option<u32>,
+    faction_hash: Option<Hash<DestinyFactionDefinition>>,
     hash: Option<u32>,
     index: Option<i32>,
     rank_icon: Option<String>,
@@ -3327,7 +3341,7 @@ impl DestinyProgressionDefinition {
         self.display_properties.as_ref()
     }
 
-    pub fn faction_hash(&self) -> Option<u32> {
+    pub fn faction_hash(&self) -> Option<Hash<DestinyFactionDefinition>> {
         self.faction_hash
     }
 
@@ -3411,7 +3425,7 @@ impl DestinyProgressionMappingDefinition {
 pub struct DestinyProgressionRewardDefinition {
     amount: Option<i32>,
     apply_throttles: Option<bool>,
-    progression_mapping_hash: Option<u32>,
+    progression_mapping_hash: Option<Hash<DestinyProgressionMappingDefinition>>,
 }
 
 impl DestinyProgressionRewardDefinition {
@@ -3423,7 +3437,7 @@ impl DestinyProgressionRewardDefinition {
         self.apply_throttles
     }
 
-    pub fn progression_mapping_hash(&self) -> Option<u32> {
+    pub fn progression_mapping_hash(&self) -> Option<Hash<DestinyProgressionMappingDefinition>> {
         self.progression_mapping_hash
     }
 }
@@ -3435,7 +3449,8 @@ pub struct DestinyProgressionRewardItemQuantity {
     acquisition_behavior: Option<i32>,
     claim_unlock_display_strings: Option<Vec<String>>,
     has_conditional_visibility: Option<bool>,
-    item_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    #[serde(default, deserialize_with = "deserialize_optional_int64")]
     item_instance_id: Option<i64>,
     quantity: Option<i32>,
     rewarded_at_progression_level: Option<i32>,
@@ -3455,7 +3470,7 @@ impl DestinyProgressionRewardItemQuantity {
         self.has_conditional_visibility
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
@@ -3514,11 +3529,12 @@ impl DestinyProgressionStepDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyRaceDefinition {
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    #[serde(serialize_with = "serialize_keys_by_name")]
     gendered_race_names: Option<HashMap<DestinyGender, String>>,
-    gendered_race_names_by_gender_hash: Option<HashMap<u32, String>>,
+    gendered_race_names_by_gender_hash: Option<HashMap<Hash<DestinyGenderDefinition>, String>>,
     hash: Option<u32>,
     index: Option<i32>,
-    race_type: Option<i32>,
+    race_type: Option<DestinyRace>,
     redacted: Option<bool>,
 }
 
@@ -3531,7 +3547,9 @@ impl DestinyRaceDefinition {
         self.gendered_race_names.as_ref()
     }
 
-    pub fn gendered_race_names_by_gender_hash(&self) -> Option<&HashMap<u32, String>> {
+    pub fn gendered_race_names_by_gender_hash(
+        &self,
+    ) -> Option<&HashMap<Hash<DestinyGenderDefinition>, String>> {
         self.gendered_race_names_by_gender_hash.as_ref()
     }
 
@@ -3543,7 +3561,7 @@ impl DestinyRaceDefinition {
         self.index
     }
 
-    pub fn race_type(&self) -> Option<i32> {
+    pub fn race_type(&self) -> Option<DestinyRace> {
         self.race_type
     }
 
@@ -3648,7 +3666,7 @@ impl DestinySandboxPatternDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinySandboxPerkDefinition {
     damage_type: Option<i32>,
-    damage_type_hash: Option<u32>,
+    damage_type_hash: Option<Hash<DestinyDamageTypeDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     hash: Option<u32>,
     index: Option<i32>,
@@ -3663,7 +3681,7 @@ impl DestinySandboxPerkDefinition {
         self.damage_type
     }
 
-    pub fn damage_type_hash(&self) -> Option<u32> {
+    pub fn damage_type_hash(&self) -> Option<Hash<DestinyDamageTypeDefinition>> {
         self.damage_type_hash
     }
 
@@ -3696,6 +3714,17 @@ impl DestinySandboxPerkDefinition {
     }
 }
 
+/// The six armor stats, in the order they are displayed in game: Mobility,
+/// Resilience, Recovery, Discipline, Intellect and Strength.
+pub const ARMOR_STAT_HASHES: [Hash<DestinyStatDefinition>; 6] = [
+    Hash::new(2996146975),
+    Hash::new(392767087),
+    Hash::new(1943323491),
+    Hash::new(1735777505),
+    Hash::new(144602215),
+    Hash::new(4244567218),
+];
+
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.DestinyStatDefinition
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -3746,7 +3775,7 @@ pub struct DestinyStatDisplayDefinition {
     display_as_numeric: Option<bool>,
     display_interpolation: Option<Vec<InterpolationPoint>>,
     maximum_value: Option<i32>,
-    stat_hash: Option<u32>,
+    stat_hash: Option<Hash<DestinyStatDefinition>>,
 }
 
 impl DestinyStatDisplayDefinition {
@@ -3762,7 +3791,7 @@ impl DestinyStatDisplayDefinition {
         self.maximum_value
     }
 
-    pub fn stat_hash(&self) -> Option<u32> {
+    pub fn stat_hash(&self) -> Option<Hash<DestinyStatDefinition>> {
         self.stat_hash
     }
 }
@@ -3774,7 +3803,7 @@ pub struct DestinyStatGroupDefinition {
     hash: Option<u32>,
     index: Option<i32>,
     maximum_value: Option<i32>,
-    overrides: Option<HashMap<u32, DestinyStatOverrideDefinition>>,
+    overrides: Option<HashMap<Hash<DestinyStatDefinition>, DestinyStatOverrideDefinition>>,
     redacted: Option<bool>,
     scaled_stats: Option<Vec<DestinyStatDisplayDefinition>>,
     ui_position: Option<i32>,
@@ -3793,7 +3822,9 @@ impl DestinyStatGroupDefinition {
         self.maximum_value
     }
 
-    pub fn overrides(&self) -> Option<&HashMap<u32, DestinyStatOverrideDefinition>> {
+    pub fn overrides(
+        &self,
+    ) -> Option<&HashMap<Hash<DestinyStatDefinition>, DestinyStatOverrideDefinition>> {
         self.overrides.as_ref()
     }
 
@@ -3815,7 +3846,7 @@ impl DestinyStatGroupDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyStatOverrideDefinition {
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
-    stat_hash: Option<u32>,
+    stat_hash: Option<Hash<DestinyStatDefinition>>,
 }
 
 impl DestinyStatOverrideDefinition {
@@ -3823,7 +3854,7 @@ impl DestinyStatOverrideDefinition {
         self.display_properties.as_ref()
     }
 
-    pub fn stat_hash(&self) -> Option<u32> {
+    pub fn stat_hash(&self) -> Option<Hash<DestinyStatDefinition>> {
         self.stat_hash
     }
 }
@@ -3833,7 +3864,7 @@ impl DestinyStatOverrideDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyTalentExclusiveGroup {
     group_hash: Option<u32>,
-    lore_hash: Option<u32>,
+    lore_hash: Option<Hash<DestinyLoreDefinition>>,
     node_hashes: Option<Vec<u32>>,
     opposing_group_hashes: Option<Vec<u32>>,
     opposing_node_hashes: Option<Vec<u32>>,
@@ -3844,7 +3875,7 @@ impl DestinyTalentExclusiveGroup {
         self.group_hash
     }
 
-    pub fn lore_hash(&self) -> Option<u32> {
+    pub fn lore_hash(&self) -> Option<Hash<DestinyLoreDefinition>> {
         self.lore_hash
     }
 
@@ -3874,7 +3905,7 @@ pub struct DestinyTalentGridDefinition {
     max_grid_level: Option<i32>,
     node_categories: Option<Vec<DestinyTalentNodeCategory>>,
     nodes: Option<Vec<DestinyTalentNodeDefinition>>,
-    progression_hash: Option<u32>,
+    progression_hash: Option<Hash<DestinyProgressionDefinition>>,
     redacted: Option<bool>,
 }
 
@@ -3915,7 +3946,7 @@ impl DestinyTalentGridDefinition {
         self.nodes.as_ref()
     }
 
-    pub fn progression_hash(&self) -> Option<u32> {
+    pub fn progression_hash(&self) -> Option<Hash<DestinyProgressionDefinition>> {
         self.progression_hash
     }
 
@@ -3966,7 +3997,7 @@ pub struct DestinyTalentNodeDefinition {
     is_random_repurchasable: Option<bool>,
     last_step_repeats: Option<bool>,
     layout_identifier: Option<String>,
-    lore_hash: Option<u32>,
+    lore_hash: Option<Hash<DestinyLoreDefinition>>,
     node_hash: Option<u32>,
     node_index: Option<i32>,
     node_style_identifier: Option<String>,
@@ -4018,7 +4049,7 @@ impl DestinyTalentNodeDefinition {
         self.layout_identifier.as_ref()
     }
 
-    pub fn lore_hash(&self) -> Option<u32> {
+    pub fn lore_hash(&self) -> Option<Hash<DestinyLoreDefinition>> {
         self.lore_hash
     }
 
@@ -4169,16 +4200,18 @@ impl DestinyUnlockValueDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyVendorAcceptedItemDefinition {
-    accepted_inventory_bucket_hash: Option<u32>,
-    destination_inventory_bucket_hash: Option<u32>,
+    accepted_inventory_bucket_hash: Option<Hash<DestinyInventoryBucketDefinition>>,
+    destination_inventory_bucket_hash: Option<Hash<DestinyInventoryBucketDefinition>>,
 }
 
 impl DestinyVendorAcceptedItemDefinition {
-    pub fn accepted_inventory_bucket_hash(&self) -> Option<u32> {
+    pub fn accepted_inventory_bucket_hash(&self) -> Option<Hash<DestinyInventoryBucketDefinition>> {
         self.accepted_inventory_bucket_hash
     }
 
-    pub fn destination_inventory_bucket_hash(&self) -> Option<u32> {
+    pub fn destination_inventory_bucket_hash(
+        &self,
+    ) -> Option<Hash<DestinyInventoryBucketDefinition>> {
         self.destination_inventory_bucket_hash
     }
 }
@@ -4329,7 +4362,7 @@ impl DestinyVendorCategoryEntryDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyVendorCategoryOverlayDefinition {
     choice_description: Option<String>,
-    currency_item_hash: Option<u32>,
+    currency_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     description: Option<String>,
     icon: Option<String>,
     title: Option<String>,
@@ -4340,7 +4373,7 @@ impl DestinyVendorCategoryOverlayDefinition {
         self.choice_description.as_ref()
     }
 
-    pub fn currency_item_hash(&self) -> Option<u32> {
+    pub fn currency_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.currency_item_hash
     }
 
@@ -4367,14 +4400,14 @@ pub struct DestinyVendorDefinition {
     categories: Option<Vec<DestinyVendorCategoryEntryDefinition>>,
     consolidate_categories: Option<bool>,
     display_categories: Option<Vec<DestinyDisplayCategoryDefinition>>,
-    display_item_hash: Option<u32>,
+    display_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     enabled: Option<bool>,
-    faction_hash: Option<u32>,
+    faction_hash: Option<Hash<DestinyFactionDefinition>>,
     failure_strings: Option<Vec<String>>,
     groups: Option<Vec<DestinyVendorGroupReference>>,
     hash: Option<u32>,
-    ignore_sale_item_hashes: Option<Vec<u32>>,
+    ignore_sale_item_hashes: Option<Vec<Hash<DestinyInventoryItemDefinition>>>,
     index: Option<i32>,
     inhibit_buying: Option<bool>,
     inhibit_selling: Option<bool>,
@@ -4423,7 +4456,7 @@ impl DestinyVendorDefinition {
         self.display_categories.as_ref()
     }
 
-    pub fn display_item_hash(&self) -> Option<u32> {
+    pub fn display_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.display_item_hash
     }
 
@@ -4435,7 +4468,7 @@ impl DestinyVendorDefinition {
         self.enabled
     }
 
-    pub fn faction_hash(&self) -> Option<u32> {
+    pub fn faction_hash(&self) -> Option<Hash<DestinyFactionDefinition>> {
         self.faction_hash
     }
 
@@ -4451,7 +4484,7 @@ impl DestinyVendorDefinition {
         self.hash
     }
 
-    pub fn ignore_sale_item_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn ignore_sale_item_hashes(&self) -> Option<&Vec<Hash<DestinyInventoryItemDefinition>>> {
         self.ignore_sale_item_hashes.as_ref()
     }
 
@@ -4577,11 +4610,11 @@ impl DestinyVendorGroupDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyVendorGroupReference {
-    vendor_group_hash: Option<u32>,
+    vendor_group_hash: Option<Hash<DestinyVendorGroupDefinition>>,
 }
 
 impl DestinyVendorGroupReference {
-    pub fn vendor_group_hash(&self) -> Option<u32> {
+    pub fn vendor_group_hash(&self) -> Option<Hash<DestinyVendorGroupDefinition>> {
         self.vendor_group_hash
     }
 }
@@ -4596,7 +4629,7 @@ pub struct DestinyVendorInteractionDefinition {
     instructions: Option<String>,
     interaction_index: Option<i32>,
     interaction_type: Option<i32>,
-    questline_item_hash: Option<u32>,
+    questline_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     replies: Option<Vec<DestinyVendorInteractionReplyDefinition>>,
     reward_block_label: Option<String>,
     reward_vendor_category_index: Option<i32>,
@@ -4630,7 +4663,7 @@ impl DestinyVendorInteractionDefinition {
         self.interaction_type
     }
 
-    pub fn questline_item_hash(&self) -> Option<u32> {
+    pub fn questline_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.questline_item_hash
     }
 
@@ -4702,7 +4735,7 @@ impl DestinyVendorInteractionSackEntryDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyVendorInventoryFlyoutBucketDefinition {
     collapsible: Option<bool>,
-    inventory_bucket_hash: Option<u32>,
+    inventory_bucket_hash: Option<Hash<DestinyInventoryBucketDefinition>>,
     sort_items_by: Option<i32>,
 }
 
@@ -4711,7 +4744,7 @@ impl DestinyVendorInventoryFlyoutBucketDefinition {
         self.collapsible
     }
 
-    pub fn inventory_bucket_hash(&self) -> Option<u32> {
+    pub fn inventory_bucket_hash(&self) -> Option<Hash<DestinyInventoryBucketDefinition>> {
         self.inventory_bucket_hash
     }
 
@@ -4726,7 +4759,7 @@ impl DestinyVendorInventoryFlyoutBucketDefinition {
 pub struct DestinyVendorInventoryFlyoutDefinition {
     buckets: Option<Vec<DestinyVendorInventoryFlyoutBucketDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
-    equipment_slot_hash: Option<u32>,
+    equipment_slot_hash: Option<Hash<DestinyEquipmentSlotDefinition>>,
     flyout_id: Option<u32>,
     locked_description: Option<String>,
     suppress_newness: Option<bool>,
@@ -4741,7 +4774,7 @@ impl DestinyVendorInventoryFlyoutDefinition {
         self.display_properties.as_ref()
     }
 
-    pub fn equipment_slot_hash(&self) -> Option<u32> {
+    pub fn equipment_slot_hash(&self) -> Option<Hash<DestinyEquipmentSlotDefinition>> {
         self.equipment_slot_hash
     }
 
@@ -4771,10 +4804,10 @@ pub struct DestinyVendorItemDefinition {
     exclusivity: Option<i32>,
     expiration_tooltip: Option<String>,
     failure_indexes: Option<Vec<i32>>,
-    inventory_bucket_hash: Option<u32>,
+    inventory_bucket_hash: Option<Hash<DestinyInventoryBucketDefinition>>,
     is_crm: Option<bool>,
     is_offer: Option<bool>,
-    item_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     maximum_level: Option<i32>,
     minimum_level: Option<i32>,
     original_category_index: Option<i32>,
@@ -4827,7 +4860,7 @@ impl DestinyVendorItemDefinition {
         self.failure_indexes.as_ref()
     }
 
-    pub fn inventory_bucket_hash(&self) -> Option<u32> {
+    pub fn inventory_bucket_hash(&self) -> Option<Hash<DestinyInventoryBucketDefinition>> {
         self.inventory_bucket_hash
     }
 
@@ -4839,7 +4872,7 @@ impl DestinyVendorItemDefinition {
         self.is_offer
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
@@ -4901,7 +4934,8 @@ impl DestinyVendorItemDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyVendorItemQuantity {
     has_conditional_visibility: Option<bool>,
-    item_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    #[serde(default, deserialize_with = "deserialize_optional_int64")]
     item_instance_id: Option<i64>,
     quantity: Option<i32>,
 }
@@ -4911,7 +4945,7 @@ impl DestinyVendorItemQuantity {
         self.has_conditional_visibility
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
@@ -4929,8 +4963,8 @@ impl DestinyVendorItemQuantity {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyVendorItemSocketOverride {
     randomized_options_count: Option<i32>,
-    single_item_hash: Option<u32>,
-    socket_type_hash: Option<u32>,
+    single_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    socket_type_hash: Option<Hash<DestinySocketTypeDefinition>>,
 }
 
 impl DestinyVendorItemSocketOverride {
@@ -4938,11 +4972,11 @@ impl DestinyVendorItemSocketOverride {
         self.randomized_options_count
     }
 
-    pub fn single_item_hash(&self) -> Option<u32> {
+    pub fn single_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.single_item_hash
     }
 
-    pub fn socket_type_hash(&self) -> Option<u32> {
+    pub fn socket_type_hash(&self) -> Option<Hash<DestinySocketTypeDefinition>> {
         self.socket_type_hash
     }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{definitions::DestinyInventoryItemDefinition, hashes::Hash};
+
 use super::{common::DestinyDisplayPropertiesDefinition, DestinyItemTranslationBlockDefinition};
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Artifacts.DestinyArtifactDefinition
@@ -77,11 +79,11 @@ impl DestinyArtifactTierDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyArtifactTierItemDefinition {
-    item_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
 }
 
 impl DestinyArtifactTierItemDefinition {
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 }
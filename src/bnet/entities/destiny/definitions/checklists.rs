@@ -1,5 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::{
+        DestinyActivityDefinition, DestinyDestinationDefinition, DestinyInventoryItemDefinition,
+        DestinyLocationDefinition, DestinyVendorDefinition,
+    },
+    hashes::Hash,
+};
+
 use super::common::DestinyDisplayPropertiesDefinition;
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Checklists.DestinyChecklistDefinition
@@ -49,20 +57,20 @@ impl DestinyChecklistDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyChecklistEntryDefinition {
-    activity_hash: Option<u32>,
+    activity_hash: Option<Hash<DestinyActivityDefinition>>,
     bubble_hash: Option<u32>,
-    destination_hash: Option<u32>,
+    destination_hash: Option<Hash<DestinyDestinationDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     hash: Option<u32>,
-    item_hash: Option<u32>,
-    location_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    location_hash: Option<Hash<DestinyLocationDefinition>>,
     scope: Option<i32>,
-    vendor_hash: Option<u32>,
+    vendor_hash: Option<Hash<DestinyVendorDefinition>>,
     vendor_interaction_index: Option<i32>,
 }
 
 impl DestinyChecklistEntryDefinition {
-    pub fn activity_hash(&self) -> Option<u32> {
+    pub fn activity_hash(&self) -> Option<Hash<DestinyActivityDefinition>> {
         self.activity_hash
     }
 
@@ -70,7 +78,7 @@ impl DestinyChecklistEntryDefinition {
         self.bubble_hash
     }
 
-    pub fn destination_hash(&self) -> Option<u32> {
+    pub fn destination_hash(&self) -> Option<Hash<DestinyDestinationDefinition>> {
         self.destination_hash
     }
 
@@ -82,11 +90,11 @@ impl DestinyChecklistEntryDefinition {
         self.hash
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
-    pub fn location_hash(&self) -> Option<u32> {
+    pub fn location_hash(&self) -> Option<Hash<DestinyLocationDefinition>> {
         self.location_hash
     }
 
@@ -94,7 +102,7 @@ impl DestinyChecklistEntryDefinition {
         self.scope
     }
 
-    pub fn vendor_hash(&self) -> Option<u32> {
+    pub fn vendor_hash(&self) -> Option<Hash<DestinyVendorDefinition>> {
         self.vendor_hash
     }
 
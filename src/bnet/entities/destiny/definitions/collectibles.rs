@@ -1,5 +1,15 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::{
+        presentation::DestinyPresentationNodeDefinition, traits::DestinyTraitDefinition,
+        DestinyInventoryItemDefinition, DestinyMaterialRequirementSetDefinition,
+        DestinyUnlockValueDefinition,
+    },
+    hashes::Hash,
+    DestinyPresentationNodeType, DestinyScope,
+};
+
 use super::{
     common::DestinyDisplayPropertiesDefinition,
     presentation::{DestinyPresentationChildBlock, DestinyPresentationNodeRequirementsBlock},
@@ -9,16 +19,20 @@ use super::{
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyCollectibleAcquisitionBlock {
-    acquire_material_requirement_hash: Option<u32>,
-    acquire_timestamp_unlock_value_hash: Option<u32>,
+    acquire_material_requirement_hash: Option<Hash<DestinyMaterialRequirementSetDefinition>>,
+    acquire_timestamp_unlock_value_hash: Option<Hash<DestinyUnlockValueDefinition>>,
 }
 
 impl DestinyCollectibleAcquisitionBlock {
-    pub fn acquire_material_requirement_hash(&self) -> Option<u32> {
+    pub fn acquire_material_requirement_hash(
+        &self,
+    ) -> Option<Hash<DestinyMaterialRequirementSetDefinition>> {
         self.acquire_material_requirement_hash
     }
 
-    pub fn acquire_timestamp_unlock_value_hash(&self) -> Option<u32> {
+    pub fn acquire_timestamp_unlock_value_hash(
+        &self,
+    ) -> Option<Hash<DestinyUnlockValueDefinition>> {
         self.acquire_timestamp_unlock_value_hash
     }
 }
@@ -31,16 +45,16 @@ pub struct DestinyCollectibleDefinition {
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     hash: Option<u32>,
     index: Option<i32>,
-    item_hash: Option<u32>,
-    parent_node_hashes: Option<Vec<u32>>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    parent_node_hashes: Option<Vec<Hash<DestinyPresentationNodeDefinition>>>,
     presentation_info: Option<DestinyPresentationChildBlock>,
-    presentation_node_type: Option<i32>,
+    presentation_node_type: Option<DestinyPresentationNodeType>,
     redacted: Option<bool>,
-    scope: Option<i32>,
+    scope: Option<DestinyScope>,
     source_hash: Option<u32>,
     source_string: Option<String>,
     state_info: Option<DestinyCollectibleStateBlock>,
-    trait_hashes: Option<Vec<u32>>,
+    trait_hashes: Option<Vec<Hash<DestinyTraitDefinition>>>,
     trait_ids: Option<Vec<String>>,
 }
 
@@ -61,11 +75,11 @@ impl DestinyCollectibleDefinition {
         self.index
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
-    pub fn parent_node_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn parent_node_hashes(&self) -> Option<&Vec<Hash<DestinyPresentationNodeDefinition>>> {
         self.parent_node_hashes.as_ref()
     }
 
@@ -73,7 +87,7 @@ impl DestinyCollectibleDefinition {
         self.presentation_info.as_ref()
     }
 
-    pub fn presentation_node_type(&self) -> Option<i32> {
+    pub fn presentation_node_type(&self) -> Option<DestinyPresentationNodeType> {
         self.presentation_node_type
     }
 
@@ -81,7 +95,7 @@ impl DestinyCollectibleDefinition {
         self.redacted
     }
 
-    pub fn scope(&self) -> Option<i32> {
+    pub fn scope(&self) -> Option<DestinyScope> {
         self.scope
     }
 
@@ -97,7 +111,7 @@ impl DestinyCollectibleDefinition {
         self.state_info.as_ref()
     }
 
-    pub fn trait_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn trait_hashes(&self) -> Option<&Vec<Hash<DestinyTraitDefinition>>> {
         self.trait_hashes.as_ref()
     }
 
@@ -110,12 +124,12 @@ impl DestinyCollectibleDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyCollectibleStateBlock {
-    obscured_override_item_hash: Option<u32>,
+    obscured_override_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     requirements: Option<DestinyPresentationNodeRequirementsBlock>,
 }
 
 impl DestinyCollectibleStateBlock {
-    pub fn obscured_override_item_hash(&self) -> Option<u32> {
+    pub fn obscured_override_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.obscured_override_item_hash
     }
 
@@ -1,5 +1,12 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::{
+        DestinyActivityDefinition, DestinyObjectiveDefinition, DestinyProgressionDefinition,
+    },
+    hashes::Hash,
+};
+
 use super::{
     common::{DestinyDisplayPropertiesDefinition, DestinyPositionDefinition},
     DestinyUnlockExpressionDefinition,
@@ -148,7 +155,7 @@ impl DestinyActivityGraphDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyActivityGraphDisplayObjectiveDefinition {
     id: Option<u32>,
-    objective_hash: Option<u32>,
+    objective_hash: Option<Hash<DestinyObjectiveDefinition>>,
 }
 
 impl DestinyActivityGraphDisplayObjectiveDefinition {
@@ -159,7 +166,7 @@ impl DestinyActivityGraphDisplayObjectiveDefinition {
     }
 
     /// The objective being shown on the map.
-    pub fn objective_hash(&self) -> Option<u32> {
+    pub fn objective_hash(&self) -> Option<Hash<DestinyObjectiveDefinition>> {
         self.objective_hash
     }
 }
@@ -172,7 +179,7 @@ impl DestinyActivityGraphDisplayObjectiveDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyActivityGraphDisplayProgressionDefinition {
     id: Option<u32>,
-    progression_hash: Option<u32>,
+    progression_hash: Option<Hash<DestinyProgressionDefinition>>,
 }
 
 impl DestinyActivityGraphDisplayProgressionDefinition {
@@ -180,7 +187,7 @@ impl DestinyActivityGraphDisplayProgressionDefinition {
         self.id
     }
 
-    pub fn progression_hash(&self) -> Option<u32> {
+    pub fn progression_hash(&self) -> Option<Hash<DestinyProgressionDefinition>> {
         self.progression_hash
     }
 }
@@ -198,7 +205,7 @@ impl DestinyActivityGraphDisplayProgressionDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyActivityGraphNodeActivityDefinition {
-    activity_hash: Option<u32>,
+    activity_hash: Option<Hash<DestinyActivityDefinition>>,
     node_activity_id: Option<u32>,
 }
 
@@ -206,7 +213,7 @@ impl DestinyActivityGraphNodeActivityDefinition {
     /// The activity that will be activated if the user clicks on this node.
     /// Controls all activity-related information displayed on the node if it
     /// is active (the text shown in the tooltip etc)
-    pub fn activity_hash(&self) -> Option<u32> {
+    pub fn activity_hash(&self) -> Option<Hash<DestinyActivityDefinition>> {
         self.activity_hash
     }
 
@@ -360,11 +367,11 @@ impl DestinyLinkedGraphDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyLinkedGraphEntryDefinition {
-    activity_graph_hash: Option<u32>,
+    activity_graph_hash: Option<Hash<DestinyActivityGraphDefinition>>,
 }
 
 impl DestinyLinkedGraphEntryDefinition {
-    pub fn activity_graph_hash(&self) -> Option<u32> {
+    pub fn activity_graph_hash(&self) -> Option<Hash<DestinyActivityGraphDefinition>> {
         self.activity_graph_hash
     }
 }
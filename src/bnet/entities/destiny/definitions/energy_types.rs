@@ -1,15 +1,19 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::DestinyStatDefinition, hashes::Hash, DestinyEnergyType,
+};
+
 use super::common::DestinyDisplayPropertiesDefinition;
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.EnergyTypes.DestinyEnergyTypeDefinition
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyEnergyTypeDefinition {
-    capacity_stat_hash: Option<u32>,
-    cost_stat_hash: Option<u32>,
+    capacity_stat_hash: Option<Hash<DestinyStatDefinition>>,
+    cost_stat_hash: Option<Hash<DestinyStatDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
-    enum_value: Option<i32>,
+    enum_value: Option<DestinyEnergyType>,
     hash: Option<u32>,
     index: Option<i32>,
     redacted: Option<bool>,
@@ -18,11 +22,11 @@ pub struct DestinyEnergyTypeDefinition {
 }
 
 impl DestinyEnergyTypeDefinition {
-    pub fn capacity_stat_hash(&self) -> Option<u32> {
+    pub fn capacity_stat_hash(&self) -> Option<Hash<DestinyStatDefinition>> {
         self.capacity_stat_hash
     }
 
-    pub fn cost_stat_hash(&self) -> Option<u32> {
+    pub fn cost_stat_hash(&self) -> Option<Hash<DestinyStatDefinition>> {
         self.cost_stat_hash
     }
 
@@ -30,7 +34,7 @@ impl DestinyEnergyTypeDefinition {
         self.display_properties.as_ref()
     }
 
-    pub fn enum_value(&self) -> Option<i32> {
+    pub fn enum_value(&self) -> Option<DestinyEnergyType> {
         self.enum_value
     }
 
@@ -1,5 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::{
+        energy_types::DestinyEnergyTypeDefinition, DestinyInventoryItemDefinition,
+        DestinyMaterialRequirementSetDefinition,
+    },
+    hashes::Hash,
+    DestinyEnergyType,
+};
+
 use super::common::DestinyDisplayPropertiesDefinition;
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Items.DestinyDerivedItemCategoryDefinition
@@ -27,7 +36,7 @@ pub struct DestinyDerivedItemDefinition {
     icon_path: Option<String>,
     item_description: Option<String>,
     item_detail: Option<String>,
-    item_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     item_name: Option<String>,
     vendor_item_index: Option<i32>,
 }
@@ -45,7 +54,7 @@ impl DestinyDerivedItemDefinition {
         self.item_detail.as_ref()
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
@@ -63,8 +72,8 @@ impl DestinyDerivedItemDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyEnergyCapacityEntry {
     capacity_value: Option<i32>,
-    energy_type: Option<i32>,
-    energy_type_hash: Option<u32>,
+    energy_type: Option<DestinyEnergyType>,
+    energy_type_hash: Option<Hash<DestinyEnergyTypeDefinition>>,
 }
 
 impl DestinyEnergyCapacityEntry {
@@ -72,11 +81,11 @@ impl DestinyEnergyCapacityEntry {
         self.capacity_value
     }
 
-    pub fn energy_type(&self) -> Option<i32> {
+    pub fn energy_type(&self) -> Option<DestinyEnergyType> {
         self.energy_type
     }
 
-    pub fn energy_type_hash(&self) -> Option<u32> {
+    pub fn energy_type_hash(&self) -> Option<Hash<DestinyEnergyTypeDefinition>> {
         self.energy_type_hash
     }
 }
@@ -86,8 +95,8 @@ impl DestinyEnergyCapacityEntry {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyEnergyCostEntry {
     energy_cost: Option<i32>,
-    energy_type: Option<i32>,
-    energy_type_hash: Option<u32>,
+    energy_type: Option<DestinyEnergyType>,
+    energy_type_hash: Option<Hash<DestinyEnergyTypeDefinition>>,
 }
 
 impl DestinyEnergyCostEntry {
@@ -95,11 +104,11 @@ impl DestinyEnergyCostEntry {
         self.energy_cost
     }
 
-    pub fn energy_type(&self) -> Option<i32> {
+    pub fn energy_type(&self) -> Option<DestinyEnergyType> {
         self.energy_type
     }
 
-    pub fn energy_type_hash(&self) -> Option<u32> {
+    pub fn energy_type_hash(&self) -> Option<Hash<DestinyEnergyTypeDefinition>> {
         self.energy_type_hash
     }
 }
@@ -110,11 +119,11 @@ impl DestinyEnergyCostEntry {
 pub struct DestinyItemPlugDefinition {
     alternate_plug_style: Option<i32>,
     alternate_ui_plug_label: Option<String>,
-    enabled_material_requirement_hash: Option<u32>,
+    enabled_material_requirement_hash: Option<Hash<DestinyMaterialRequirementSetDefinition>>,
     enabled_rules: Option<Vec<DestinyPlugRuleDefinition>>,
     energy_capacity: Option<DestinyEnergyCapacityEntry>,
     energy_cost: Option<DestinyEnergyCostEntry>,
-    insertion_material_requirement_hash: Option<u32>,
+    insertion_material_requirement_hash: Option<Hash<DestinyMaterialRequirementSetDefinition>>,
     insertion_rules: Option<Vec<DestinyPlugRuleDefinition>>,
     is_dummy_plug: Option<bool>,
     on_action_recreate_self: Option<bool>,
@@ -123,7 +132,7 @@ pub struct DestinyItemPlugDefinition {
     plug_category_hash: Option<u32>,
     plug_category_identifier: Option<String>,
     plug_style: Option<i32>,
-    preview_item_override_hash: Option<u32>,
+    preview_item_override_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     ui_plug_label: Option<String>,
 }
 
@@ -136,7 +145,9 @@ impl DestinyItemPlugDefinition {
         self.alternate_ui_plug_label.as_ref()
     }
 
-    pub fn enabled_material_requirement_hash(&self) -> Option<u32> {
+    pub fn enabled_material_requirement_hash(
+        &self,
+    ) -> Option<Hash<DestinyMaterialRequirementSetDefinition>> {
         self.enabled_material_requirement_hash
     }
 
@@ -152,7 +163,9 @@ impl DestinyItemPlugDefinition {
         self.energy_cost.as_ref()
     }
 
-    pub fn insertion_material_requirement_hash(&self) -> Option<u32> {
+    pub fn insertion_material_requirement_hash(
+        &self,
+    ) -> Option<Hash<DestinyMaterialRequirementSetDefinition>> {
         self.insertion_material_requirement_hash
     }
 
@@ -188,7 +201,7 @@ impl DestinyItemPlugDefinition {
         self.plug_style
     }
 
-    pub fn preview_item_override_hash(&self) -> Option<u32> {
+    pub fn preview_item_override_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.preview_item_override_hash
     }
 
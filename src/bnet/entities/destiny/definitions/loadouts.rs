@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::destiny::{
+    definitions::sockets::{DestinySocketCategoryDefinition, DestinySocketTypeDefinition},
+    hashes::Hash,
+};
+
+use super::common::DestinyDisplayPropertiesDefinition;
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Loadouts.DestinyLoadoutColorDefinition
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyLoadoutColorDefinition {
+    color_image_path: Option<String>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+}
+
+impl DestinyLoadoutColorDefinition {
+    pub fn color_image_path(&self) -> Option<&String> {
+        self.color_image_path.as_ref()
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Loadouts.DestinyLoadoutConstantsDefinition
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyLoadoutConstantsDefinition {
+    black_icon_image_path: Option<String>,
+    display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    loadout_color_hashes: Option<Vec<Hash<DestinyLoadoutColorDefinition>>>,
+    loadout_count_per_character: Option<i32>,
+    loadout_icon_hashes: Option<Vec<Hash<DestinyLoadoutIconDefinition>>>,
+    loadout_name_hashes: Option<Vec<Hash<DestinyLoadoutNameDefinition>>>,
+    loadout_preview_filter_out_socket_category_hashes:
+        Option<Vec<Hash<DestinySocketCategoryDefinition>>>,
+    loadout_preview_filter_out_socket_type_hashes: Option<Vec<Hash<DestinySocketTypeDefinition>>>,
+    redacted: Option<bool>,
+    white_icon_image_path: Option<String>,
+}
+
+impl DestinyLoadoutConstantsDefinition {
+    /// This is the same icon as the one in the display properties, offered
+    /// here as well with a more descriptive name.
+    pub fn black_icon_image_path(&self) -> Option<&String> {
+        self.black_icon_image_path.as_ref()
+    }
+
+    pub fn display_properties(&self) -> Option<&DestinyDisplayPropertiesDefinition> {
+        self.display_properties.as_ref()
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    /// A list of the loadout color hashes in index order, for convenience.
+    pub fn loadout_color_hashes(&self) -> Option<&Vec<Hash<DestinyLoadoutColorDefinition>>> {
+        self.loadout_color_hashes.as_ref()
+    }
+
+    /// The maximum number of loadouts available to each character. The
+    /// loadouts component API response can return fewer loadouts than this,
+    /// as more loadouts are unlocked by reaching higher Guardian Ranks.
+    pub fn loadout_count_per_character(&self) -> Option<i32> {
+        self.loadout_count_per_character
+    }
+
+    /// A list of the loadout icon hashes in index order, for convenience.
+    pub fn loadout_icon_hashes(&self) -> Option<&Vec<Hash<DestinyLoadoutIconDefinition>>> {
+        self.loadout_icon_hashes.as_ref()
+    }
+
+    /// A list of the loadout name hashes in index order, for convenience.
+    pub fn loadout_name_hashes(&self) -> Option<&Vec<Hash<DestinyLoadoutNameDefinition>>> {
+        self.loadout_name_hashes.as_ref()
+    }
+
+    /// A list of the socket category hashes to be filtered out of loadout
+    /// item preview displays.
+    pub fn loadout_preview_filter_out_socket_category_hashes(
+        &self,
+    ) -> Option<&Vec<Hash<DestinySocketCategoryDefinition>>> {
+        self.loadout_preview_filter_out_socket_category_hashes
+            .as_ref()
+    }
+
+    /// A list of the socket type hashes to be filtered out of loadout item
+    /// preview displays.
+    pub fn loadout_preview_filter_out_socket_type_hashes(
+        &self,
+    ) -> Option<&Vec<Hash<DestinySocketTypeDefinition>>> {
+        self.loadout_preview_filter_out_socket_type_hashes.as_ref()
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// This is a color-inverted version of the display properties icon used
+    /// in some locations of the game.
+    pub fn white_icon_image_path(&self) -> Option<&String> {
+        self.white_icon_image_path.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Loadouts.DestinyLoadoutIconDefinition
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyLoadoutIconDefinition {
+    hash: Option<u32>,
+    icon_image_path: Option<String>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+}
+
+impl DestinyLoadoutIconDefinition {
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn icon_image_path(&self) -> Option<&String> {
+        self.icon_image_path.as_ref()
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Loadouts.DestinyLoadoutNameDefinition
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyLoadoutNameDefinition {
+    hash: Option<u32>,
+    index: Option<i32>,
+    name: Option<String>,
+    redacted: Option<bool>,
+}
+
+impl DestinyLoadoutNameDefinition {
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+}
@@ -1,5 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::{
+        presentation::DestinyPresentationNodeDefinition, traits::DestinyTraitDefinition,
+        DestinyObjectiveDefinition,
+    },
+    hashes::Hash,
+    DestinyPresentationNodeType,
+};
+
 use super::common::DestinyDisplayPropertiesDefinition;
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Metrics.DestinyMetricDefinition
@@ -10,11 +19,11 @@ pub struct DestinyMetricDefinition {
     hash: Option<u32>,
     index: Option<i32>,
     lower_value_is_better: Option<bool>,
-    parent_node_hashes: Option<Vec<u32>>,
-    presentation_node_type: Option<i32>,
+    parent_node_hashes: Option<Vec<Hash<DestinyPresentationNodeDefinition>>>,
+    presentation_node_type: Option<DestinyPresentationNodeType>,
     redacted: Option<bool>,
-    tracking_objective_hash: Option<u32>,
-    trait_hashes: Option<Vec<u32>>,
+    tracking_objective_hash: Option<Hash<DestinyObjectiveDefinition>>,
+    trait_hashes: Option<Vec<Hash<DestinyTraitDefinition>>>,
     trait_ids: Option<Vec<String>>,
 }
 
@@ -35,11 +44,11 @@ impl DestinyMetricDefinition {
         self.lower_value_is_better
     }
 
-    pub fn parent_node_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn parent_node_hashes(&self) -> Option<&Vec<Hash<DestinyPresentationNodeDefinition>>> {
         self.parent_node_hashes.as_ref()
     }
 
-    pub fn presentation_node_type(&self) -> Option<i32> {
+    pub fn presentation_node_type(&self) -> Option<DestinyPresentationNodeType> {
         self.presentation_node_type
     }
 
@@ -47,11 +56,11 @@ impl DestinyMetricDefinition {
         self.redacted
     }
 
-    pub fn tracking_objective_hash(&self) -> Option<u32> {
+    pub fn tracking_objective_hash(&self) -> Option<Hash<DestinyObjectiveDefinition>> {
         self.tracking_objective_hash
     }
 
-    pub fn trait_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn trait_hashes(&self) -> Option<&Vec<Hash<DestinyTraitDefinition>>> {
         self.trait_hashes.as_ref()
     }
 
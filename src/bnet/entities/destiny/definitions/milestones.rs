@@ -2,7 +2,18 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::bnet::entities::destiny::DestinyItemQuantity;
+use crate::bnet::entities::{
+    destiny::{
+        definitions::{
+            director::DestinyActivityGraphDefinition, DestinyActivityDefinition,
+            DestinyDestinationDefinition, DestinyInventoryItemDefinition,
+            DestinyObjectiveDefinition, DestinyVendorDefinition,
+        },
+        hashes::Hash,
+        DestinyItemQuantity,
+    },
+    wire::deserialize_optional_int64,
+};
 
 use super::common::DestinyDisplayPropertiesDefinition;
 
@@ -11,7 +22,8 @@ use super::common::DestinyDisplayPropertiesDefinition;
 #[serde(rename_all = "camelCase")]
 pub struct DestinyMilestoneActivityDefinition {
     conceptual_activity_hash: Option<u32>,
-    variants: Option<HashMap<u32, DestinyMilestoneActivityVariantDefinition>>,
+    variants:
+        Option<HashMap<Hash<DestinyActivityDefinition>, DestinyMilestoneActivityVariantDefinition>>,
 }
 
 impl DestinyMilestoneActivityDefinition {
@@ -19,7 +31,10 @@ impl DestinyMilestoneActivityDefinition {
         self.conceptual_activity_hash
     }
 
-    pub fn variants(&self) -> Option<&HashMap<u32, DestinyMilestoneActivityVariantDefinition>> {
+    pub fn variants(
+        &self,
+    ) -> Option<&HashMap<Hash<DestinyActivityDefinition>, DestinyMilestoneActivityVariantDefinition>>
+    {
         self.variants.as_ref()
     }
 }
@@ -28,12 +43,12 @@ impl DestinyMilestoneActivityDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyMilestoneActivityVariantDefinition {
-    activity_hash: Option<u32>,
+    activity_hash: Option<Hash<DestinyActivityDefinition>>,
     order: Option<i32>,
 }
 
 impl DestinyMilestoneActivityVariantDefinition {
-    pub fn activity_hash(&self) -> Option<u32> {
+    pub fn activity_hash(&self) -> Option<Hash<DestinyActivityDefinition>> {
         self.activity_hash
     }
 
@@ -47,17 +62,19 @@ impl DestinyMilestoneActivityVariantDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyMilestoneChallengeActivityDefinition {
     activity_graph_nodes: Option<Vec<DestinyMilestoneChallengeActivityGraphNodeEntry>>,
-    activity_hash: Option<u32>,
+    activity_hash: Option<Hash<DestinyActivityDefinition>>,
     challenges: Option<Vec<DestinyMilestoneChallengeDefinition>>,
     phases: Option<Vec<DestinyMilestoneChallengeActivityPhase>>,
 }
 
 impl DestinyMilestoneChallengeActivityDefinition {
-    pub fn activity_graph_nodes(&self) -> Option<&Vec<DestinyMilestoneChallengeActivityGraphNodeEntry>> {
+    pub fn activity_graph_nodes(
+        &self,
+    ) -> Option<&Vec<DestinyMilestoneChallengeActivityGraphNodeEntry>> {
         self.activity_graph_nodes.as_ref()
     }
 
-    pub fn activity_hash(&self) -> Option<u32> {
+    pub fn activity_hash(&self) -> Option<Hash<DestinyActivityDefinition>> {
         self.activity_hash
     }
 
@@ -74,12 +91,12 @@ impl DestinyMilestoneChallengeActivityDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyMilestoneChallengeActivityGraphNodeEntry {
-    activity_graph_hash: Option<u32>,
+    activity_graph_hash: Option<Hash<DestinyActivityGraphDefinition>>,
     activity_graph_node_hash: Option<u32>,
 }
 
 impl DestinyMilestoneChallengeActivityGraphNodeEntry {
-    pub fn activity_graph_hash(&self) -> Option<u32> {
+    pub fn activity_graph_hash(&self) -> Option<Hash<DestinyActivityGraphDefinition>> {
         self.activity_graph_hash
     }
 
@@ -105,11 +122,11 @@ impl DestinyMilestoneChallengeActivityPhase {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyMilestoneChallengeDefinition {
-    challenge_objective_hash: Option<u32>,
+    challenge_objective_hash: Option<Hash<DestinyObjectiveDefinition>>,
 }
 
 impl DestinyMilestoneChallengeDefinition {
-    pub fn challenge_objective_hash(&self) -> Option<u32> {
+    pub fn challenge_objective_hash(&self) -> Option<Hash<DestinyObjectiveDefinition>> {
         self.challenge_objective_hash
     }
 }
@@ -130,7 +147,7 @@ pub struct DestinyMilestoneDefinition {
     index: Option<i32>,
     is_in_game_milestone: Option<bool>,
     milestone_type: Option<i32>,
-    quests: Option<HashMap<u32, DestinyMilestoneQuestDefinition>>,
+    quests: Option<HashMap<Hash<DestinyInventoryItemDefinition>, DestinyMilestoneQuestDefinition>>,
     recruitable: Option<bool>,
     redacted: Option<bool>,
     rewards: Option<HashMap<u32, DestinyMilestoneRewardCategoryDefinition>>,
@@ -190,7 +207,10 @@ impl DestinyMilestoneDefinition {
         self.milestone_type
     }
 
-    pub fn quests(&self) -> Option<&HashMap<u32, DestinyMilestoneQuestDefinition>> {
+    pub fn quests(
+        &self,
+    ) -> Option<&HashMap<Hash<DestinyInventoryItemDefinition>, DestinyMilestoneQuestDefinition>>
+    {
         self.quests.as_ref()
     }
 
@@ -231,20 +251,23 @@ impl DestinyMilestoneDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyMilestoneQuestDefinition {
-    activities: Option<HashMap<u32, DestinyMilestoneActivityDefinition>>,
-    destination_hash: Option<u32>,
+    activities:
+        Option<HashMap<Hash<DestinyActivityDefinition>, DestinyMilestoneActivityDefinition>>,
+    destination_hash: Option<Hash<DestinyDestinationDefinition>>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     override_image: Option<String>,
-    quest_item_hash: Option<u32>,
+    quest_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     quest_rewards: Option<DestinyMilestoneQuestRewardsDefinition>,
 }
 
 impl DestinyMilestoneQuestDefinition {
-    pub fn activities(&self) -> Option<&HashMap<u32, DestinyMilestoneActivityDefinition>> {
+    pub fn activities(
+        &self,
+    ) -> Option<&HashMap<Hash<DestinyActivityDefinition>, DestinyMilestoneActivityDefinition>> {
         self.activities.as_ref()
     }
 
-    pub fn destination_hash(&self) -> Option<u32> {
+    pub fn destination_hash(&self) -> Option<Hash<DestinyDestinationDefinition>> {
         self.destination_hash
     }
 
@@ -256,7 +279,7 @@ impl DestinyMilestoneQuestDefinition {
         self.override_image.as_ref()
     }
 
-    pub fn quest_item_hash(&self) -> Option<u32> {
+    pub fn quest_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.quest_item_hash
     }
 
@@ -283,10 +306,11 @@ impl DestinyMilestoneQuestRewardsDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyMilestoneQuestRewardItem {
     has_conditional_visibility: Option<bool>,
-    item_hash: Option<u32>,
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    #[serde(default, deserialize_with = "deserialize_optional_int64")]
     item_instance_id: Option<i64>,
     quantity: Option<i32>,
-    vendor_hash: Option<u32>,
+    vendor_hash: Option<Hash<DestinyVendorDefinition>>,
     vendor_item_index: Option<i32>,
 }
 
@@ -295,7 +319,7 @@ impl DestinyMilestoneQuestRewardItem {
         self.has_conditional_visibility
     }
 
-    pub fn item_hash(&self) -> Option<u32> {
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.item_hash
     }
 
@@ -307,7 +331,7 @@ impl DestinyMilestoneQuestRewardItem {
         self.quantity
     }
 
-    pub fn vendor_hash(&self) -> Option<u32> {
+    pub fn vendor_hash(&self) -> Option<Hash<DestinyVendorDefinition>> {
         self.vendor_hash
     }
 
@@ -358,7 +382,7 @@ pub struct DestinyMilestoneRewardEntryDefinition {
     order: Option<i32>,
     reward_entry_hash: Option<u32>,
     reward_entry_identifier: Option<String>,
-    vendor_hash: Option<u32>,
+    vendor_hash: Option<Hash<DestinyVendorDefinition>>,
 }
 
 impl DestinyMilestoneRewardEntryDefinition {
@@ -382,7 +406,7 @@ impl DestinyMilestoneRewardEntryDefinition {
         self.reward_entry_identifier.as_ref()
     }
 
-    pub fn vendor_hash(&self) -> Option<u32> {
+    pub fn vendor_hash(&self) -> Option<Hash<DestinyVendorDefinition>> {
         self.vendor_hash
     }
 }
@@ -409,11 +433,11 @@ impl DestinyMilestoneValueDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyMilestoneVendorDefinition {
-    vendor_hash: Option<u32>,
+    vendor_hash: Option<Hash<DestinyVendorDefinition>>,
 }
 
 impl DestinyMilestoneVendorDefinition {
-    pub fn vendor_hash(&self) -> Option<u32> {
+    pub fn vendor_hash(&self) -> Option<Hash<DestinyVendorDefinition>> {
         self.vendor_hash
     }
 }
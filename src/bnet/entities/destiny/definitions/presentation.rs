@@ -1,5 +1,15 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::{
+        collectibles::DestinyCollectibleDefinition, metrics::DestinyMetricDefinition,
+        records::DestinyRecordDefinition, traits::DestinyTraitDefinition,
+        DestinyInventoryItemDefinition, DestinyObjectiveDefinition,
+    },
+    hashes::Hash,
+    DestinyPresentationNodeType, DestinyScope,
+};
+
 use super::common::DestinyDisplayPropertiesDefinition;
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Presentation.DestinyPresentationChildBlock
@@ -7,8 +17,8 @@ use super::common::DestinyDisplayPropertiesDefinition;
 #[serde(rename_all = "camelCase")]
 pub struct DestinyPresentationChildBlock {
     display_style: Option<i32>,
-    parent_presentation_node_hashes: Option<Vec<u32>>,
-    presentation_node_type: Option<i32>,
+    parent_presentation_node_hashes: Option<Vec<Hash<DestinyPresentationNodeDefinition>>>,
+    presentation_node_type: Option<DestinyPresentationNodeType>,
 }
 
 impl DestinyPresentationChildBlock {
@@ -16,11 +26,13 @@ impl DestinyPresentationChildBlock {
         self.display_style
     }
 
-    pub fn parent_presentation_node_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn parent_presentation_node_hashes(
+        &self,
+    ) -> Option<&Vec<Hash<DestinyPresentationNodeDefinition>>> {
         self.parent_presentation_node_hashes.as_ref()
     }
 
-    pub fn presentation_node_type(&self) -> Option<i32> {
+    pub fn presentation_node_type(&self) -> Option<DestinyPresentationNodeType> {
         self.presentation_node_type
     }
 }
@@ -63,7 +75,7 @@ impl DestinyPresentationNodeChildrenBlock {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyPresentationNodeChildEntry {
     node_display_priority: Option<u32>,
-    presentation_node_hash: Option<u32>,
+    presentation_node_hash: Option<Hash<DestinyPresentationNodeDefinition>>,
 }
 
 impl DestinyPresentationNodeChildEntry {
@@ -71,7 +83,7 @@ impl DestinyPresentationNodeChildEntry {
         self.node_display_priority
     }
 
-    pub fn presentation_node_hash(&self) -> Option<u32> {
+    pub fn presentation_node_hash(&self) -> Option<Hash<DestinyPresentationNodeDefinition>> {
         self.presentation_node_hash
     }
 }
@@ -80,12 +92,12 @@ impl DestinyPresentationNodeChildEntry {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyPresentationNodeCollectibleChildEntry {
-    collectible_hash: Option<u32>,
+    collectible_hash: Option<Hash<DestinyCollectibleDefinition>>,
     node_display_priority: Option<u32>,
 }
 
 impl DestinyPresentationNodeCollectibleChildEntry {
-    pub fn collectible_hash(&self) -> Option<u32> {
+    pub fn collectible_hash(&self) -> Option<Hash<DestinyCollectibleDefinition>> {
         self.collectible_hash
     }
 
@@ -98,12 +110,12 @@ impl DestinyPresentationNodeCollectibleChildEntry {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyPresentationNodeCraftableChildEntry {
-    craftable_item_hash: Option<u32>,
+    craftable_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     node_display_priority: Option<u32>,
 }
 
 impl DestinyPresentationNodeCraftableChildEntry {
-    pub fn craftable_item_hash(&self) -> Option<u32> {
+    pub fn craftable_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.craftable_item_hash
     }
 
@@ -116,12 +128,12 @@ impl DestinyPresentationNodeCraftableChildEntry {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyPresentationNodeMetricChildEntry {
-    metric_hash: Option<u32>,
+    metric_hash: Option<Hash<DestinyMetricDefinition>>,
     node_display_priority: Option<u32>,
 }
 
 impl DestinyPresentationNodeMetricChildEntry {
-    pub fn metric_hash(&self) -> Option<u32> {
+    pub fn metric_hash(&self) -> Option<Hash<DestinyMetricDefinition>> {
         self.metric_hash
     }
 
@@ -135,7 +147,7 @@ impl DestinyPresentationNodeMetricChildEntry {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyPresentationNodeRecordChildEntry {
     node_display_priority: Option<u32>,
-    record_hash: Option<u32>,
+    record_hash: Option<Hash<DestinyRecordDefinition>>,
 }
 
 impl DestinyPresentationNodeRecordChildEntry {
@@ -143,7 +155,7 @@ impl DestinyPresentationNodeRecordChildEntry {
         self.node_display_priority
     }
 
-    pub fn record_hash(&self) -> Option<u32> {
+    pub fn record_hash(&self) -> Option<Hash<DestinyRecordDefinition>> {
         self.record_hash
     }
 }
@@ -153,7 +165,7 @@ impl DestinyPresentationNodeRecordChildEntry {
 #[serde(rename_all = "camelCase")]
 pub struct DestinyPresentationNodeDefinition {
     children: Option<DestinyPresentationNodeChildrenBlock>,
-    completion_record_hash: Option<u32>,
+    completion_record_hash: Option<Hash<DestinyRecordDefinition>>,
     disable_child_subscreen_navigation: Option<bool>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     display_style: Option<i32>,
@@ -161,16 +173,16 @@ pub struct DestinyPresentationNodeDefinition {
     index: Option<i32>,
     max_category_record_score: Option<i32>,
     node_type: Option<i32>,
-    objective_hash: Option<u32>,
+    objective_hash: Option<Hash<DestinyObjectiveDefinition>>,
     original_icon: Option<String>,
-    parent_node_hashes: Option<Vec<u32>>,
-    presentation_node_type: Option<i32>,
+    parent_node_hashes: Option<Vec<Hash<DestinyPresentationNodeDefinition>>>,
+    presentation_node_type: Option<DestinyPresentationNodeType>,
     redacted: Option<bool>,
     requirements: Option<DestinyPresentationNodeRequirementsBlock>,
     root_view_icon: Option<String>,
-    scope: Option<i32>,
+    scope: Option<DestinyScope>,
     screen_style: Option<i32>,
-    trait_hashes: Option<Vec<u32>>,
+    trait_hashes: Option<Vec<Hash<DestinyTraitDefinition>>>,
     trait_ids: Option<Vec<String>>,
 }
 
@@ -179,7 +191,7 @@ impl DestinyPresentationNodeDefinition {
         self.children.as_ref()
     }
 
-    pub fn completion_record_hash(&self) -> Option<u32> {
+    pub fn completion_record_hash(&self) -> Option<Hash<DestinyRecordDefinition>> {
         self.completion_record_hash
     }
 
@@ -211,7 +223,7 @@ impl DestinyPresentationNodeDefinition {
         self.node_type
     }
 
-    pub fn objective_hash(&self) -> Option<u32> {
+    pub fn objective_hash(&self) -> Option<Hash<DestinyObjectiveDefinition>> {
         self.objective_hash
     }
 
@@ -219,11 +231,11 @@ impl DestinyPresentationNodeDefinition {
         self.original_icon.as_ref()
     }
 
-    pub fn parent_node_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn parent_node_hashes(&self) -> Option<&Vec<Hash<DestinyPresentationNodeDefinition>>> {
         self.parent_node_hashes.as_ref()
     }
 
-    pub fn presentation_node_type(&self) -> Option<i32> {
+    pub fn presentation_node_type(&self) -> Option<DestinyPresentationNodeType> {
         self.presentation_node_type
     }
 
@@ -239,7 +251,7 @@ impl DestinyPresentationNodeDefinition {
         self.root_view_icon.as_ref()
     }
 
-    pub fn scope(&self) -> Option<i32> {
+    pub fn scope(&self) -> Option<DestinyScope> {
         self.scope
     }
 
@@ -247,7 +259,7 @@ impl DestinyPresentationNodeDefinition {
         self.screen_style
     }
 
-    pub fn trait_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn trait_hashes(&self) -> Option<&Vec<Hash<DestinyTraitDefinition>>> {
         self.trait_hashes.as_ref()
     }
 
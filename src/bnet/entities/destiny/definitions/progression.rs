@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::bnet::entities::interpolation::InterpolationPointFloat;
+use crate::bnet::entities::{
+    destiny::{definitions::DestinyProgressionDefinition, hashes::Hash},
+    interpolation::InterpolationPointFloat,
+};
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Progression.DestinyProgressionLevelRequirementDefinition
 #[derive(Debug, Deserialize, Serialize)]
@@ -8,7 +11,7 @@ use crate::bnet::entities::interpolation::InterpolationPointFloat;
 pub struct DestinyProgressionLevelRequirementDefinition {
     hash: Option<u32>,
     index: Option<i32>,
-    progression_hash: Option<u32>,
+    progression_hash: Option<Hash<DestinyProgressionDefinition>>,
     redacted: Option<bool>,
     requirement_curve: Option<Vec<InterpolationPointFloat>>,
 }
@@ -22,7 +25,7 @@ impl DestinyProgressionLevelRequirementDefinition {
         self.index
     }
 
-    pub fn progression_hash(&self) -> Option<u32> {
+    pub fn progression_hash(&self) -> Option<Hash<DestinyProgressionDefinition>> {
         self.progression_hash
     }
 
@@ -2,7 +2,17 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::bnet::entities::destiny::{DestinyGender, DestinyItemQuantity};
+use crate::bnet::entities::{
+    destiny::{
+        definitions::{
+            lore::DestinyLoreDefinition, presentation::DestinyPresentationNodeDefinition,
+            traits::DestinyTraitDefinition, DestinyGenderDefinition, DestinyObjectiveDefinition,
+        },
+        hashes::Hash,
+        DestinyGender, DestinyItemQuantity, DestinyPresentationNodeType, DestinyScope,
+    },
+    wire::serialize_keys_by_name,
+};
 
 use super::{
     common::DestinyDisplayPropertiesDefinition,
@@ -20,20 +30,20 @@ pub struct DestinyRecordDefinition {
     hash: Option<u32>,
     index: Option<i32>,
     interval_info: Option<DestinyRecordIntervalBlock>,
-    lore_hash: Option<u32>,
-    objective_hashes: Option<Vec<u32>>,
-    parent_node_hashes: Option<Vec<u32>>,
+    lore_hash: Option<Hash<DestinyLoreDefinition>>,
+    objective_hashes: Option<Vec<Hash<DestinyObjectiveDefinition>>>,
+    parent_node_hashes: Option<Vec<Hash<DestinyPresentationNodeDefinition>>>,
     presentation_info: Option<DestinyPresentationChildBlock>,
-    presentation_node_type: Option<i32>,
+    presentation_node_type: Option<DestinyPresentationNodeType>,
     record_value_style: Option<i32>,
     redacted: Option<bool>,
     requirements: Option<DestinyPresentationNodeRequirementsBlock>,
     reward_items: Option<Vec<DestinyItemQuantity>>,
-    scope: Option<i32>,
+    scope: Option<DestinyScope>,
     should_show_large_icons: Option<bool>,
     state_info: Option<SchemaRecordStateBlock>,
     title_info: Option<DestinyRecordTitleBlock>,
-    trait_hashes: Option<Vec<u32>>,
+    trait_hashes: Option<Vec<Hash<DestinyTraitDefinition>>>,
     trait_ids: Option<Vec<String>>,
 }
 
@@ -66,15 +76,15 @@ impl DestinyRecordDefinition {
         self.interval_info.as_ref()
     }
 
-    pub fn lore_hash(&self) -> Option<u32> {
+    pub fn lore_hash(&self) -> Option<Hash<DestinyLoreDefinition>> {
         self.lore_hash
     }
 
-    pub fn objective_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn objective_hashes(&self) -> Option<&Vec<Hash<DestinyObjectiveDefinition>>> {
         self.objective_hashes.as_ref()
     }
 
-    pub fn parent_node_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn parent_node_hashes(&self) -> Option<&Vec<Hash<DestinyPresentationNodeDefinition>>> {
         self.parent_node_hashes.as_ref()
     }
 
@@ -82,7 +92,7 @@ impl DestinyRecordDefinition {
         self.presentation_info.as_ref()
     }
 
-    pub fn presentation_node_type(&self) -> Option<i32> {
+    pub fn presentation_node_type(&self) -> Option<DestinyPresentationNodeType> {
         self.presentation_node_type
     }
 
@@ -102,7 +112,7 @@ impl DestinyRecordDefinition {
         self.reward_items.as_ref()
     }
 
-    pub fn scope(&self) -> Option<i32> {
+    pub fn scope(&self) -> Option<DestinyScope> {
         self.scope
     }
 
@@ -118,7 +128,7 @@ impl DestinyRecordDefinition {
         self.title_info.as_ref()
     }
 
-    pub fn trait_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn trait_hashes(&self) -> Option<&Vec<Hash<DestinyTraitDefinition>>> {
         self.trait_hashes.as_ref()
     }
 
@@ -205,12 +215,12 @@ impl DestinyRecordIntervalBlock {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyRecordIntervalObjective {
-    interval_objective_hash: Option<u32>,
+    interval_objective_hash: Option<Hash<DestinyObjectiveDefinition>>,
     interval_score_value: Option<i32>,
 }
 
 impl DestinyRecordIntervalObjective {
-    pub fn interval_objective_hash(&self) -> Option<u32> {
+    pub fn interval_objective_hash(&self) -> Option<Hash<DestinyObjectiveDefinition>> {
         self.interval_objective_hash
     }
 
@@ -236,14 +246,15 @@ impl DestinyRecordIntervalRewards {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyRecordTitleBlock {
-    gilding_tracking_record_hash: Option<u32>,
+    gilding_tracking_record_hash: Option<Hash<DestinyRecordDefinition>>,
     has_title: Option<bool>,
+    #[serde(serialize_with = "serialize_keys_by_name")]
     titles_by_gender: Option<HashMap<DestinyGender, String>>,
-    titles_by_gender_hash: Option<HashMap<u32, String>>,
+    titles_by_gender_hash: Option<HashMap<Hash<DestinyGenderDefinition>, String>>,
 }
 
 impl DestinyRecordTitleBlock {
-    pub fn gilding_tracking_record_hash(&self) -> Option<u32> {
+    pub fn gilding_tracking_record_hash(&self) -> Option<Hash<DestinyRecordDefinition>> {
         self.gilding_tracking_record_hash
     }
 
@@ -255,7 +266,7 @@ impl DestinyRecordTitleBlock {
         self.titles_by_gender.as_ref()
     }
 
-    pub fn titles_by_gender_hash(&self) -> Option<&HashMap<u32, String>> {
+    pub fn titles_by_gender_hash(&self) -> Option<&HashMap<Hash<DestinyGenderDefinition>, String>> {
         self.titles_by_gender_hash.as_ref()
     }
 }
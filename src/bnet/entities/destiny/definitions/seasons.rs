@@ -1,13 +1,21 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::{
+        presentation::DestinyPresentationNodeDefinition, DestinyInventoryItemDefinition,
+        DestinyProgressionDefinition,
+    },
+    hashes::Hash,
+};
+
 use super::common::DestinyDisplayPropertiesDefinition;
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Seasons.DestinySeasonDefinition
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinySeasonDefinition {
-    artifact_item_hash: Option<u32>,
+    artifact_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     background_image_path: Option<String>,
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     end_date: Option<DateTime<Utc>>,
@@ -15,16 +23,16 @@ pub struct DestinySeasonDefinition {
     index: Option<i32>,
     preview: Option<DestinySeasonPreviewDefinition>,
     redacted: Option<bool>,
-    seal_presentation_node_hash: Option<u32>,
+    seal_presentation_node_hash: Option<Hash<DestinyPresentationNodeDefinition>>,
     season_number: Option<i32>,
-    season_pass_hash: Option<u32>,
-    season_pass_progression_hash: Option<u32>,
-    seasonal_challenges_presentation_node_hash: Option<u32>,
+    season_pass_hash: Option<Hash<DestinySeasonPassDefinition>>,
+    season_pass_progression_hash: Option<Hash<DestinyProgressionDefinition>>,
+    seasonal_challenges_presentation_node_hash: Option<Hash<DestinyPresentationNodeDefinition>>,
     start_date: Option<DateTime<Utc>>,
 }
 
 impl DestinySeasonDefinition {
-    pub fn artifact_item_hash(&self) -> Option<u32> {
+    pub fn artifact_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.artifact_item_hash
     }
 
@@ -56,7 +64,7 @@ impl DestinySeasonDefinition {
         self.redacted
     }
 
-    pub fn seal_presentation_node_hash(&self) -> Option<u32> {
+    pub fn seal_presentation_node_hash(&self) -> Option<Hash<DestinyPresentationNodeDefinition>> {
         self.seal_presentation_node_hash
     }
 
@@ -64,15 +72,17 @@ impl DestinySeasonDefinition {
         self.season_number
     }
 
-    pub fn season_pass_hash(&self) -> Option<u32> {
+    pub fn season_pass_hash(&self) -> Option<Hash<DestinySeasonPassDefinition>> {
         self.season_pass_hash
     }
 
-    pub fn season_pass_progression_hash(&self) -> Option<u32> {
+    pub fn season_pass_progression_hash(&self) -> Option<Hash<DestinyProgressionDefinition>> {
         self.season_pass_progression_hash
     }
 
-    pub fn seasonal_challenges_presentation_node_hash(&self) -> Option<u32> {
+    pub fn seasonal_challenges_presentation_node_hash(
+        &self,
+    ) -> Option<Hash<DestinyPresentationNodeDefinition>> {
         self.seasonal_challenges_presentation_node_hash
     }
 
@@ -88,9 +98,9 @@ pub struct DestinySeasonPassDefinition {
     display_properties: Option<DestinyDisplayPropertiesDefinition>,
     hash: Option<u32>,
     index: Option<i32>,
-    prestige_progression_hash: Option<u32>,
+    prestige_progression_hash: Option<Hash<DestinyProgressionDefinition>>,
     redacted: Option<bool>,
-    reward_progression_hash: Option<u32>,
+    reward_progression_hash: Option<Hash<DestinyProgressionDefinition>>,
 }
 
 impl DestinySeasonPassDefinition {
@@ -106,7 +116,7 @@ impl DestinySeasonPassDefinition {
         self.index
     }
 
-    pub fn prestige_progression_hash(&self) -> Option<u32> {
+    pub fn prestige_progression_hash(&self) -> Option<Hash<DestinyProgressionDefinition>> {
         self.prestige_progression_hash
     }
 
@@ -114,7 +124,7 @@ impl DestinySeasonPassDefinition {
         self.redacted
     }
 
-    pub fn reward_progression_hash(&self) -> Option<u32> {
+    pub fn reward_progression_hash(&self) -> Option<Hash<DestinyProgressionDefinition>> {
         self.reward_progression_hash
     }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{definitions::DestinyInventoryItemDefinition, hashes::Hash};
+
 use super::{
     common::DestinyDisplayPropertiesDefinition, DestinyItemSocketEntryPlugItemRandomizedDefinition,
 };
@@ -68,7 +70,7 @@ impl DestinyPlugSetDefinition {
 pub struct DestinyPlugWhitelistEntryDefinition {
     category_hash: Option<u32>,
     category_identifier: Option<String>,
-    reinitialization_possible_plug_hashes: Option<Vec<u32>>,
+    reinitialization_possible_plug_hashes: Option<Vec<Hash<DestinyInventoryItemDefinition>>>,
 }
 
 impl DestinyPlugWhitelistEntryDefinition {
@@ -80,7 +82,9 @@ impl DestinyPlugWhitelistEntryDefinition {
         self.category_identifier.as_ref()
     }
 
-    pub fn reinitialization_possible_plug_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn reinitialization_possible_plug_hashes(
+        &self,
+    ) -> Option<&Vec<Hash<DestinyInventoryItemDefinition>>> {
         self.reinitialization_possible_plug_hashes.as_ref()
     }
 }
@@ -139,7 +143,7 @@ pub struct DestinySocketTypeDefinition {
     overrides_ui_appearance: Option<bool>,
     plug_whitelist: Option<Vec<DestinyPlugWhitelistEntryDefinition>>,
     redacted: Option<bool>,
-    socket_category_hash: Option<u32>,
+    socket_category_hash: Option<Hash<DestinySocketCategoryDefinition>>,
     visibility: Option<i32>,
 }
 
@@ -194,7 +198,7 @@ impl DestinySocketTypeDefinition {
         self.redacted
     }
 
-    pub fn socket_category_hash(&self) -> Option<u32> {
+    pub fn socket_category_hash(&self) -> Option<Hash<DestinySocketCategoryDefinition>> {
         self.socket_category_hash
     }
 
@@ -207,12 +211,12 @@ impl DestinySocketTypeDefinition {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinySocketTypeScalarMaterialRequirementEntry {
-    currency_item_hash: Option<u32>,
+    currency_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
     scalar_value: Option<i32>,
 }
 
 impl DestinySocketTypeScalarMaterialRequirementEntry {
-    pub fn currency_item_hash(&self) -> Option<u32> {
+    pub fn currency_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
         self.currency_item_hash
     }
 
@@ -2,23 +2,31 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::{DestinyRewardSourceDefinition, DestinyStatDefinition},
+    hashes::Hash,
+};
+
 use super::DestinyInventoryItemStatDefinition;
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Sources.DestinyItemSourceDefinition
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemSourceDefinition {
-    computed_stats: Option<HashMap<u32, DestinyInventoryItemStatDefinition>>,
+    computed_stats:
+        Option<HashMap<Hash<DestinyStatDefinition>, DestinyInventoryItemStatDefinition>>,
     level: Option<i32>,
     max_level_required: Option<i32>,
     max_quality: Option<i32>,
     min_level_required: Option<i32>,
     min_quality: Option<i32>,
-    source_hashes: Option<Vec<u32>>,
+    source_hashes: Option<Vec<Hash<DestinyRewardSourceDefinition>>>,
 }
 
 impl DestinyItemSourceDefinition {
-    pub fn computed_stats(&self) -> Option<&HashMap<u32, DestinyInventoryItemStatDefinition>> {
+    pub fn computed_stats(
+        &self,
+    ) -> Option<&HashMap<Hash<DestinyStatDefinition>, DestinyInventoryItemStatDefinition>> {
         self.computed_stats.as_ref()
     }
 
@@ -42,7 +50,7 @@ impl DestinyItemSourceDefinition {
         self.min_quality
     }
 
-    pub fn source_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn source_hashes(&self) -> Option<&Vec<Hash<DestinyRewardSourceDefinition>>> {
         self.source_hashes.as_ref()
     }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::hashes::Hash;
+
 use super::common::DestinyDisplayPropertiesDefinition;
 
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Traits.DestinyTraitDefinition
@@ -11,7 +13,7 @@ pub struct DestinyTraitDefinition {
     hash: Option<u32>,
     index: Option<i32>,
     redacted: Option<bool>,
-    trait_category_hash: Option<u32>,
+    trait_category_hash: Option<Hash<DestinyTraitCategoryDefinition>>,
     trait_category_id: Option<String>,
 }
 
@@ -36,7 +38,7 @@ impl DestinyTraitDefinition {
         self.redacted
     }
 
-    pub fn trait_category_hash(&self) -> Option<u32> {
+    pub fn trait_category_hash(&self) -> Option<Hash<DestinyTraitCategoryDefinition>> {
         self.trait_category_hash
     }
 
@@ -53,7 +55,7 @@ pub struct DestinyTraitCategoryDefinition {
     index: Option<i32>,
     redacted: Option<bool>,
     trait_category_id: Option<String>,
-    trait_hashes: Option<Vec<u32>>,
+    trait_hashes: Option<Vec<Hash<DestinyTraitDefinition>>>,
     trait_ids: Option<Vec<String>>,
 }
 
@@ -74,7 +76,7 @@ impl DestinyTraitCategoryDefinition {
         self.trait_category_id.as_ref()
     }
 
-    pub fn trait_hashes(&self) -> Option<&Vec<u32>> {
+    pub fn trait_hashes(&self) -> Option<&Vec<Hash<DestinyTraitDefinition>>> {
         self.trait_hashes.as_ref()
     }
 
@@ -0,0 +1,872 @@
+//! Definitions in the manifest that Bungie doesn't document. Their fields are
+//! worked out from the manifest itself, and only those whose meaning is clear
+//! are modeled. Everything else a definition holds is kept in `extra`, so
+//! loading the manifest doesn't drop any of it.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::bnet::entities::destiny::{
+    definitions::{
+        DestinyActivityDefinition, DestinyGenderDefinition, DestinyInventoryBucketDefinition,
+        DestinyInventoryItemDefinition, DestinyItemCategoryDefinition, DestinyRaceDefinition,
+        DestinyUnlockDefinition, DestinyUnlockValueDefinition,
+    },
+    hashes::Hash,
+};
+
+use super::{common::DestinyDisplayPropertiesDefinition, DestinyItemInventoryBlockDefinition};
+
+/// An achievement on one of the console platforms, unlocked through play.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyAchievementDefinition {
+    #[serde(rename = "acccumulatorThreshold")]
+    acccumulator_threshold: Option<i32>,
+    blacklisted: Option<bool>,
+    display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    platform_index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyAchievementDefinition {
+    /// How many times the achievement's counter has to go up before it unlocks.
+    /// The misspelling is the manifest's own.
+    pub fn acccumulator_threshold(&self) -> Option<i32> {
+        self.acccumulator_threshold
+    }
+
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn display_properties(&self) -> Option<&DestinyDisplayPropertiesDefinition> {
+        self.display_properties.as_ref()
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    /// The achievement's index on its platform.
+    pub fn platform_index(&self) -> Option<i32> {
+        self.platform_index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// The activities an interactable in the world, such as a node on the director
+/// or a door, launches.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyActivityInteractableDefinition {
+    blacklisted: Option<bool>,
+    entries: Option<Vec<DestinyActivityInteractableEntryDefinition>>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyActivityInteractableDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn entries(&self) -> Option<&Vec<DestinyActivityInteractableEntryDefinition>> {
+        self.entries.as_ref()
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// An activity an interactable launches.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyActivityInteractableEntryDefinition {
+    activity_hash: Option<Hash<DestinyActivityDefinition>>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyActivityInteractableEntryDefinition {
+    pub fn activity_hash(&self) -> Option<Hash<DestinyActivityDefinition>> {
+        self.activity_hash
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// A channel shaders can dye, such as the primary cloth of an armor piece.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyArtDyeChannelDefinition {
+    blacklisted: Option<bool>,
+    channel_hash: Option<u32>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyArtDyeChannelDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn channel_hash(&self) -> Option<u32> {
+        self.channel_hash
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// A bond, which in Destiny 2 only survives as an unlock.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyBondDefinition {
+    blacklisted: Option<bool>,
+    display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    provided_unlock_hash: Option<Hash<DestinyUnlockDefinition>>,
+    provided_unlock_value_hash: Option<Hash<DestinyUnlockValueDefinition>>,
+    redacted: Option<bool>,
+    show_in_advisor: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyBondDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn display_properties(&self) -> Option<&DestinyDisplayPropertiesDefinition> {
+        self.display_properties.as_ref()
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn provided_unlock_hash(&self) -> Option<Hash<DestinyUnlockDefinition>> {
+        self.provided_unlock_hash
+    }
+
+    pub fn provided_unlock_value_hash(&self) -> Option<Hash<DestinyUnlockValueDefinition>> {
+        self.provided_unlock_value_hash
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    pub fn show_in_advisor(&self) -> Option<bool> {
+        self.show_in_advisor
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// A category of the options on the character creation screen.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyCharacterCustomizationCategoryDefinition {
+    blacklisted: Option<bool>,
+    display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyCharacterCustomizationCategoryDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn display_properties(&self) -> Option<&DestinyDisplayPropertiesDefinition> {
+        self.display_properties.as_ref()
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// The customization options on the character creation screen for a race and
+/// gender.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyCharacterCustomizationOptionDefinition {
+    blacklisted: Option<bool>,
+    display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    gender_hash: Option<Hash<DestinyGenderDefinition>>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    race_hash: Option<Hash<DestinyRaceDefinition>>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyCharacterCustomizationOptionDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn display_properties(&self) -> Option<&DestinyDisplayPropertiesDefinition> {
+        self.display_properties.as_ref()
+    }
+
+    pub fn gender_hash(&self) -> Option<Hash<DestinyGenderDefinition>> {
+        self.gender_hash
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn race_hash(&self) -> Option<Hash<DestinyRaceDefinition>> {
+        self.race_hash
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// An entitlement, such as a preorder bonus, that grants items when redeemed.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyEntitlementOfferDefinition {
+    blacklisted: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    offer_key: Option<String>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyEntitlementOfferDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn offer_key(&self) -> Option<&String> {
+        self.offer_key.as_ref()
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// A trimmed down DestinyInventoryItemDefinition, with what the mobile app
+/// needs to list an item.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyInventoryItemLiteDefinition {
+    blacklisted: Option<bool>,
+    class_type: Option<i32>,
+    display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    equippable: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    inventory: Option<DestinyItemInventoryBlockDefinition>,
+    item_category_hashes: Option<Vec<Hash<DestinyItemCategoryDefinition>>>,
+    item_sub_type: Option<i32>,
+    item_type: Option<i32>,
+    item_type_and_tier_display_name: Option<String>,
+    item_type_display_name: Option<String>,
+    redacted: Option<bool>,
+    summary_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyInventoryItemLiteDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn class_type(&self) -> Option<i32> {
+        self.class_type
+    }
+
+    pub fn display_properties(&self) -> Option<&DestinyDisplayPropertiesDefinition> {
+        self.display_properties.as_ref()
+    }
+
+    pub fn equippable(&self) -> Option<bool> {
+        self.equippable
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn inventory(&self) -> Option<&DestinyItemInventoryBlockDefinition> {
+        self.inventory.as_ref()
+    }
+
+    pub fn item_category_hashes(&self) -> Option<&Vec<Hash<DestinyItemCategoryDefinition>>> {
+        self.item_category_hashes.as_ref()
+    }
+
+    pub fn item_sub_type(&self) -> Option<i32> {
+        self.item_sub_type
+    }
+
+    pub fn item_type(&self) -> Option<i32> {
+        self.item_type
+    }
+
+    pub fn item_type_and_tier_display_name(&self) -> Option<&String> {
+        self.item_type_and_tier_display_name.as_ref()
+    }
+
+    pub fn item_type_display_name(&self) -> Option<&String> {
+        self.item_type_display_name.as_ref()
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    pub fn summary_item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
+        self.summary_item_hash
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// A summary of a talent grid node step, a leftover of Destiny 1.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyNodeStepSummaryDefinition {
+    blacklisted: Option<bool>,
+    display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyNodeStepSummaryDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn display_properties(&self) -> Option<&DestinyDisplayPropertiesDefinition> {
+        self.display_properties.as_ref()
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// Which inventory bucket holds a platform's exclusive content.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyPlatformBucketMappingDefinition {
+    blacklisted: Option<bool>,
+    bucket_hash: Option<Hash<DestinyInventoryBucketDefinition>>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    membership_type: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyPlatformBucketMappingDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn bucket_hash(&self) -> Option<Hash<DestinyInventoryBucketDefinition>> {
+        self.bucket_hash
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn membership_type(&self) -> Option<i32> {
+        self.membership_type
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// Points at the adjustments made to a reward.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyRewardAdjusterPointerDefinition {
+    adjuster_type: Option<i32>,
+    blacklisted: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyRewardAdjusterPointerDefinition {
+    pub fn adjuster_type(&self) -> Option<i32> {
+        self.adjuster_type
+    }
+
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// Maps a progression to the adjustments it makes to rewards.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyRewardAdjusterProgressionMapDefinition {
+    blacklisted: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    is_additive: Option<bool>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyRewardAdjusterProgressionMapDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn is_additive(&self) -> Option<bool> {
+        self.is_additive
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// A list of the items a reward can grant.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyRewardItemListDefinition {
+    blacklisted: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyRewardItemListDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// Maps an activity or vendor's rewards to the list of items they grant.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyRewardMappingDefinition {
+    blacklisted: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyRewardMappingDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// A sheet of reward lists, such as the rewards for an activity's completions.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyRewardSheetDefinition {
+    blacklisted: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyRewardSheetDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// The items a sack, such as an engram or a package, can be opened into.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinySackRewardItemListDefinition {
+    blacklisted: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinySackRewardItemListDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// Maps an unlock value to the count it displays.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyUnlockCountMappingDefinition {
+    blacklisted: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyUnlockCountMappingDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// An event that updates a sequence of unlock values.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyUnlockEventDefinition {
+    blacklisted: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    new_sequence_reward_site_hash: Option<u32>,
+    redacted: Option<bool>,
+    sequence_last_updated_unlock_value_hash: Option<Hash<DestinyUnlockValueDefinition>>,
+    sequence_unlock_value_hash: Option<Hash<DestinyUnlockValueDefinition>>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyUnlockEventDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn new_sequence_reward_site_hash(&self) -> Option<u32> {
+        self.new_sequence_reward_site_hash
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    pub fn sequence_last_updated_unlock_value_hash(
+        &self,
+    ) -> Option<Hash<DestinyUnlockValueDefinition>> {
+        self.sequence_last_updated_unlock_value_hash
+    }
+
+    pub fn sequence_unlock_value_hash(&self) -> Option<Hash<DestinyUnlockValueDefinition>> {
+        self.sequence_unlock_value_hash
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
+
+/// Maps an unlock expression to the unlock values it evaluates.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyUnlockExpressionMappingDefinition {
+    blacklisted: Option<bool>,
+    hash: Option<u32>,
+    index: Option<i32>,
+    redacted: Option<bool>,
+    #[serde(flatten)]
+    extra: Value,
+}
+
+impl DestinyUnlockExpressionMappingDefinition {
+    pub fn blacklisted(&self) -> Option<bool> {
+        self.blacklisted
+    }
+
+    pub fn hash(&self) -> Option<u32> {
+        self.hash
+    }
+
+    pub fn index(&self) -> Option<i32> {
+        self.index
+    }
+
+    pub fn redacted(&self) -> Option<bool> {
+        self.redacted
+    }
+
+    /// The fields that aren't modeled, as they are in the manifest.
+    pub fn extra(&self) -> &Value {
+        &self.extra
+    }
+}
@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{definitions::DestinyDestinationDefinition, hashes::Hash};
+
 /// https://bungie-net.github.io/#/components/schemas/Destiny.Definitions.Vendors.DestinyVendorLocationDefinition
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyVendorLocationDefinition {
     background_image_path: Option<String>,
-    destination_hash: Option<u32>,
+    destination_hash: Option<Hash<DestinyDestinationDefinition>>,
 }
 
 impl DestinyVendorLocationDefinition {
@@ -13,7 +15,7 @@ impl DestinyVendorLocationDefinition {
         self.background_image_path.as_ref()
     }
 
-    pub fn destination_hash(&self) -> Option<u32> {
+    pub fn destination_hash(&self) -> Option<Hash<DestinyDestinationDefinition>> {
         self.destination_hash
     }
 }
@@ -1,7 +1,20 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::bnet::entities::destiny::quests::DestinyObjectiveProgress;
+use crate::bnet::entities::{
+    destiny::{
+        definitions::{
+            metrics::DestinyMetricDefinition, DestinyInventoryBucketDefinition,
+            DestinyInventoryItemDefinition, DestinyStatDefinition,
+        },
+        hashes::Hash,
+        quests::DestinyObjectiveProgress,
+        DestinyStat, ItemBindStatus, ItemLocation, ItemState, TransferStatuses,
+    },
+    wire::deserialize_int64,
+};
 
 /// The base item component, filled with properties that are generally useful
 /// to know in any item request or that don't feel worthwhile to put in their
@@ -11,35 +24,43 @@ use crate::bnet::entities::destiny::quests::DestinyObjectiveProgress;
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyItemComponent {
-    bind_status: i32,
-    bucket_hash: u32,
-    expiration_date: DateTime<Utc>,
+    bind_status: ItemBindStatus,
+    bucket_hash: Hash<DestinyInventoryBucketDefinition>,
+    expiration_date: Option<DateTime<Utc>>,
+    #[serde(default)]
     is_wrapper: bool,
-    item_hash: u32,
+    item_hash: Hash<DestinyInventoryItemDefinition>,
+    #[serde(default, deserialize_with = "deserialize_int64")]
     item_instance_id: i64,
+    #[serde(default)]
     item_value_visibility: Vec<bool>,
-    location: i32,
+    location: ItemLocation,
     lockable: bool,
-    metric_hash: u32,
-    metric_objective: DestinyObjectiveProgress,
-    override_style_item_hash: u32,
+    #[serde(default)]
+    metric_hash: Hash<DestinyMetricDefinition>,
+    metric_objective: Option<DestinyObjectiveProgress>,
+    #[serde(default)]
+    override_style_item_hash: Hash<DestinyInventoryItemDefinition>,
     quantity: i32,
-    state: i32,
+    state: ItemState,
+    #[serde(default)]
     tooltip_notification_indexes: Vec<i32>,
-    transfer_status: i32,
+    transfer_status: TransferStatuses,
+    #[serde(default)]
     version_number: i32,
 }
 
 impl DestinyItemComponent {
-    pub fn bind_status(&self) -> i32 {
+    pub fn bind_status(&self) -> ItemBindStatus {
         self.bind_status
     }
 
-    pub fn bucket_hash(&self) -> u32 {
+    pub fn bucket_hash(&self) -> Hash<DestinyInventoryBucketDefinition> {
         self.bucket_hash
     }
 
-    pub fn expiration_date(&self) -> DateTime<Utc> {
+    /// If the item can expire, this is the date at which it will/did expire.
+    pub fn expiration_date(&self) -> Option<DateTime<Utc>> {
         self.expiration_date
     }
 
@@ -47,10 +68,13 @@ impl DestinyItemComponent {
         self.is_wrapper
     }
 
-    pub fn item_hash(&self) -> u32 {
+    pub fn item_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
         self.item_hash
     }
 
+    /// If the item is instanced, it will have an instance ID. Lack of an
+    /// instance ID implies that the item has no distinct local qualities
+    /// aside from stack size. Items that aren't instanced have 0.
     pub fn item_instance_id(&self) -> i64 {
         self.item_instance_id
     }
@@ -59,7 +83,7 @@ impl DestinyItemComponent {
         self.item_value_visibility.as_ref()
     }
 
-    pub fn location(&self) -> i32 {
+    pub fn location(&self) -> ItemLocation {
         self.location
     }
 
@@ -67,15 +91,16 @@ impl DestinyItemComponent {
         self.lockable
     }
 
-    pub fn metric_hash(&self) -> u32 {
+    pub fn metric_hash(&self) -> Hash<DestinyMetricDefinition> {
         self.metric_hash
     }
 
-    pub fn metric_objective(&self) -> &DestinyObjectiveProgress {
-        &self.metric_objective
+    /// The objective progress for the currently-selected metric.
+    pub fn metric_objective(&self) -> Option<&DestinyObjectiveProgress> {
+        self.metric_objective.as_ref()
     }
 
-    pub fn override_style_item_hash(&self) -> u32 {
+    pub fn override_style_item_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
         self.override_style_item_hash
     }
 
@@ -83,7 +108,7 @@ impl DestinyItemComponent {
         self.quantity
     }
 
-    pub fn state(&self) -> i32 {
+    pub fn state(&self) -> ItemState {
         self.state
     }
 
@@ -91,7 +116,7 @@ impl DestinyItemComponent {
         self.tooltip_notification_indexes.as_ref()
     }
 
-    pub fn transfer_status(&self) -> i32 {
+    pub fn transfer_status(&self) -> TransferStatuses {
         self.transfer_status
     }
 
@@ -99,3 +124,79 @@ impl DestinyItemComponent {
         self.version_number
     }
 }
+
+/// Items can have sockets, where plugs can be inserted.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Entities.Items.DestinyItemSocketsComponent
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemSocketsComponent {
+    sockets: Vec<DestinyItemSocketState>,
+}
+
+impl DestinyItemSocketsComponent {
+    /// The list of all sockets on the item, and their status information.
+    pub fn sockets(&self) -> &[DestinyItemSocketState] {
+        self.sockets.as_ref()
+    }
+}
+
+/// The status of a given item's socket. (which plug is inserted, if any:
+/// whether it is enabled, what "reusable" plugs can be inserted, etc...)
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Entities.Items.DestinyItemSocketState
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemSocketState {
+    enable_fail_indexes: Option<Vec<i32>>,
+    is_enabled: bool,
+    is_visible: bool,
+    plug_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+}
+
+impl DestinyItemSocketState {
+    /// If a plug is inserted but not enabled, this will be populated with
+    /// indexes into the plug item definition's plug.enabledRules property, so
+    /// that you can show the reasons why it is not enabled.
+    pub fn enable_fail_indexes(&self) -> Option<&Vec<i32>> {
+        self.enable_fail_indexes.as_ref()
+    }
+
+    /// Even if a plug is inserted, it doesn't mean it's enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    /// A plug may theoretically provide benefits but not be visible - for
+    /// instance, some older items use a plug's damage type perk to modify their
+    /// own damage type. These, though they are not visible, still affect the
+    /// item. This field indicates that state.
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// The currently active plug, if any.
+    ///
+    /// Note that, because all plugs are statically defined, its effect on stats
+    /// and perks can be statically determined using the plug item's definition.
+    pub fn plug_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
+        self.plug_hash
+    }
+}
+
+/// If you want the stats on an item's instanced data, get this component.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Entities.Items.DestinyItemStatsComponent
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemStatsComponent {
+    stats: HashMap<Hash<DestinyStatDefinition>, DestinyStat>,
+}
+
+impl DestinyItemStatsComponent {
+    /// If the item has stats that it provides (damage, defense, etc...), it
+    /// will be given here.
+    pub fn stats(&self) -> &HashMap<Hash<DestinyStatDefinition>, DestinyStat> {
+        &self.stats
+    }
+}
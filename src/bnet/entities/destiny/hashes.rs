@@ -0,0 +1,814 @@
+//! Typed definition hashes.
+//!
+//! Components and definitions refer to other definitions by their hash. A
+//! [`Hash`] carries the type of definition it points at, so it can only be
+//! looked up in the right table and [`Hash::resolve`] hands back that type.
+
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt,
+    hash::{self, Hasher},
+    marker::PhantomData,
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    definitions::{
+        activity_modifiers::DestinyActivityModifierDefinition,
+        artifacts::DestinyArtifactDefinition,
+        breaker_types::DestinyBreakerTypeDefinition,
+        checklists::DestinyChecklistDefinition,
+        collectibles::DestinyCollectibleDefinition,
+        director::DestinyActivityGraphDefinition,
+        energy_types::DestinyEnergyTypeDefinition,
+        items::DestinyItemTierTypeDefinition,
+        loadouts::{
+            DestinyLoadoutColorDefinition, DestinyLoadoutConstantsDefinition,
+            DestinyLoadoutIconDefinition, DestinyLoadoutNameDefinition,
+        },
+        lore::DestinyLoreDefinition,
+        metrics::DestinyMetricDefinition,
+        milestones::DestinyMilestoneDefinition,
+        power_caps::DestinyPowerCapDefinition,
+        presentation::DestinyPresentationNodeDefinition,
+        progression::DestinyProgressionLevelRequirementDefinition,
+        records::DestinyRecordDefinition,
+        reporting::DestinyReportReasonCategoryDefinition,
+        seasons::{DestinySeasonDefinition, DestinySeasonPassDefinition},
+        sockets::{
+            DestinyPlugSetDefinition, DestinySocketCategoryDefinition, DestinySocketTypeDefinition,
+        },
+        traits::{DestinyTraitCategoryDefinition, DestinyTraitDefinition},
+        undocumented::{
+            DestinyAchievementDefinition, DestinyActivityInteractableDefinition,
+            DestinyArtDyeChannelDefinition, DestinyBondDefinition,
+            DestinyCharacterCustomizationCategoryDefinition,
+            DestinyCharacterCustomizationOptionDefinition, DestinyEntitlementOfferDefinition,
+            DestinyInventoryItemLiteDefinition, DestinyNodeStepSummaryDefinition,
+            DestinyPlatformBucketMappingDefinition, DestinyRewardAdjusterPointerDefinition,
+            DestinyRewardAdjusterProgressionMapDefinition, DestinyRewardItemListDefinition,
+            DestinyRewardMappingDefinition, DestinyRewardSheetDefinition,
+            DestinySackRewardItemListDefinition, DestinyUnlockCountMappingDefinition,
+            DestinyUnlockEventDefinition, DestinyUnlockExpressionMappingDefinition,
+        },
+        DestinyActivityDefinition, DestinyActivityModeDefinition, DestinyActivityTypeDefinition,
+        DestinyArtDyeReference, DestinyClassDefinition, DestinyDamageTypeDefinition,
+        DestinyDestinationDefinition, DestinyEquipmentSlotDefinition, DestinyFactionDefinition,
+        DestinyGenderDefinition, DestinyInventoryBucketDefinition, DestinyInventoryItemDefinition,
+        DestinyItemCategoryDefinition, DestinyLocationDefinition,
+        DestinyMaterialRequirementSetDefinition, DestinyMedalTierDefinition,
+        DestinyObjectiveDefinition, DestinyPlaceDefinition, DestinyProgressionDefinition,
+        DestinyProgressionMappingDefinition, DestinyRaceDefinition, DestinyRewardSourceDefinition,
+        DestinySandboxPatternDefinition, DestinySandboxPerkDefinition, DestinyStatDefinition,
+        DestinyStatGroupDefinition, DestinyTalentGridDefinition, DestinyUnlockDefinition,
+        DestinyUnlockValueDefinition, DestinyVendorDefinition, DestinyVendorGroupDefinition,
+    },
+    DestinyWorldContent,
+};
+
+/// The hash of a definition of type `T`, serialized as the plain `u32` the
+/// API sends.
+pub struct Hash<T> {
+    value: u32,
+    definition: PhantomData<fn() -> T>,
+}
+
+impl<T> Hash<T> {
+    pub const fn new(value: u32) -> Self {
+        Self {
+            value,
+            definition: PhantomData,
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl<T: Definition> Hash<T> {
+    /// Looks the definition up in the store, None when it has no definition
+    /// with this hash.
+    pub fn resolve<'a, S: DefinitionStore>(&self, store: &'a S) -> Option<&'a T> {
+        store.get(*self)
+    }
+}
+
+// The impls are written out rather than derived, as deriving them would
+// require `T` to implement the traits too, and definitions don't.
+impl<T> Clone for Hash<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Hash<T> {}
+
+impl<T> Default for Hash<T> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T> PartialEq for Hash<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> Eq for Hash<T> {}
+
+impl<T> PartialOrd for Hash<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Hash<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T> hash::Hash for Hash<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for Hash<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Hash({})", self.value)
+    }
+}
+
+impl<T> fmt::Display for Hash<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T> From<Hash<T>> for u32 {
+    fn from(hash: Hash<T>) -> Self {
+        hash.value
+    }
+}
+
+impl<T> Serialize for Hash<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.value)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Hash<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// A definition type with a table of its own in the world content.
+pub trait Definition: DeserializeOwned + Send + Sync + 'static {
+    /// The name of the definition's table in the world content JSON.
+    const TABLE: &'static str;
+
+    /// The definition's table, keyed by the string form of the hashes.
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self>;
+}
+
+/// Anything definitions can be looked up in by their hash.
+pub trait DefinitionStore {
+    fn get<T: Definition>(&self, hash: Hash<T>) -> Option<&T>;
+
+    /// Every definition in `T`'s table, in no particular order.
+    fn definitions<T: Definition>(&self) -> impl Iterator<Item = (Hash<T>, &T)> + '_;
+}
+
+impl DefinitionStore for DestinyWorldContent {
+    fn get<T: Definition>(&self, hash: Hash<T>) -> Option<&T> {
+        T::table(self).get(&hash.value.to_string())
+    }
+
+    fn definitions<T: Definition>(&self) -> impl Iterator<Item = (Hash<T>, &T)> + '_ {
+        T::table(self)
+            .iter()
+            .filter_map(|(hash, definition)| Some((Hash::new(hash.parse().ok()?), definition)))
+    }
+}
+
+impl Definition for DestinyAchievementDefinition {
+    const TABLE: &'static str = "DestinyAchievementDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_achievement_definition()
+    }
+}
+
+impl Definition for DestinyActivityDefinition {
+    const TABLE: &'static str = "DestinyActivityDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_activity_definition()
+    }
+}
+
+impl Definition for DestinyActivityGraphDefinition {
+    const TABLE: &'static str = "DestinyActivityGraphDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_activity_graph_definition()
+    }
+}
+
+impl Definition for DestinyActivityInteractableDefinition {
+    const TABLE: &'static str = "DestinyActivityInteractableDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_activity_interactable_definition()
+    }
+}
+
+impl Definition for DestinyActivityModeDefinition {
+    const TABLE: &'static str = "DestinyActivityModeDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_activity_mode_definition()
+    }
+}
+
+impl Definition for DestinyActivityModifierDefinition {
+    const TABLE: &'static str = "DestinyActivityModifierDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_activity_modifier_definition()
+    }
+}
+
+impl Definition for DestinyActivityTypeDefinition {
+    const TABLE: &'static str = "DestinyActivityTypeDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_activity_type_definition()
+    }
+}
+
+impl Definition for DestinyArtDyeChannelDefinition {
+    const TABLE: &'static str = "DestinyArtDyeChannelDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_art_dye_channel_definition()
+    }
+}
+
+impl Definition for DestinyArtDyeReference {
+    const TABLE: &'static str = "DestinyArtDyeReferenceDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_art_dye_reference_definition()
+    }
+}
+
+impl Definition for DestinyArtifactDefinition {
+    const TABLE: &'static str = "DestinyArtifactDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_artifact_definition()
+    }
+}
+
+impl Definition for DestinyBondDefinition {
+    const TABLE: &'static str = "DestinyBondDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_bond_definition()
+    }
+}
+
+impl Definition for DestinyBreakerTypeDefinition {
+    const TABLE: &'static str = "DestinyBreakerTypeDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_breaker_type_definition()
+    }
+}
+
+impl Definition for DestinyCharacterCustomizationCategoryDefinition {
+    const TABLE: &'static str = "DestinyCharacterCustomizationCategoryDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_character_customization_category_definition()
+    }
+}
+
+impl Definition for DestinyCharacterCustomizationOptionDefinition {
+    const TABLE: &'static str = "DestinyCharacterCustomizationOptionDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_character_customization_option_definition()
+    }
+}
+
+impl Definition for DestinyChecklistDefinition {
+    const TABLE: &'static str = "DestinyChecklistDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_checklist_definition()
+    }
+}
+
+impl Definition for DestinyClassDefinition {
+    const TABLE: &'static str = "DestinyClassDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_class_definition()
+    }
+}
+
+impl Definition for DestinyCollectibleDefinition {
+    const TABLE: &'static str = "DestinyCollectibleDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_collectible_definition()
+    }
+}
+
+impl Definition for DestinyDamageTypeDefinition {
+    const TABLE: &'static str = "DestinyDamageTypeDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_damage_type_definition()
+    }
+}
+
+impl Definition for DestinyDestinationDefinition {
+    const TABLE: &'static str = "DestinyDestinationDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_destination_definition()
+    }
+}
+
+impl Definition for DestinyEnergyTypeDefinition {
+    const TABLE: &'static str = "DestinyEnergyTypeDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_energy_type_definition()
+    }
+}
+
+impl Definition for DestinyEntitlementOfferDefinition {
+    const TABLE: &'static str = "DestinyEntitlementOfferDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_entitlement_offer_definition()
+    }
+}
+
+impl Definition for DestinyEquipmentSlotDefinition {
+    const TABLE: &'static str = "DestinyEquipmentSlotDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_equipment_slot_definition()
+    }
+}
+
+impl Definition for DestinyFactionDefinition {
+    const TABLE: &'static str = "DestinyFactionDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_faction_definition()
+    }
+}
+
+impl Definition for DestinyGenderDefinition {
+    const TABLE: &'static str = "DestinyGenderDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_gender_definition()
+    }
+}
+
+impl Definition for DestinyInventoryBucketDefinition {
+    const TABLE: &'static str = "DestinyInventoryBucketDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_inventory_bucket_definition()
+    }
+}
+
+impl Definition for DestinyInventoryItemDefinition {
+    const TABLE: &'static str = "DestinyInventoryItemDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_inventory_item_definition()
+    }
+}
+
+impl Definition for DestinyInventoryItemLiteDefinition {
+    const TABLE: &'static str = "DestinyInventoryItemLiteDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_inventory_item_lite_definition()
+    }
+}
+
+impl Definition for DestinyItemCategoryDefinition {
+    const TABLE: &'static str = "DestinyItemCategoryDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_item_category_definition()
+    }
+}
+
+impl Definition for DestinyItemTierTypeDefinition {
+    const TABLE: &'static str = "DestinyItemTierTypeDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_item_tier_type_definition()
+    }
+}
+
+impl Definition for DestinyLoadoutColorDefinition {
+    const TABLE: &'static str = "DestinyLoadoutColorDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_loadout_color_definition()
+    }
+}
+
+impl Definition for DestinyLoadoutConstantsDefinition {
+    const TABLE: &'static str = "DestinyLoadoutConstantsDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_loadout_constants_definition()
+    }
+}
+
+impl Definition for DestinyLoadoutIconDefinition {
+    const TABLE: &'static str = "DestinyLoadoutIconDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_loadout_icon_definition()
+    }
+}
+
+impl Definition for DestinyLoadoutNameDefinition {
+    const TABLE: &'static str = "DestinyLoadoutNameDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_loadout_name_definition()
+    }
+}
+
+impl Definition for DestinyLocationDefinition {
+    const TABLE: &'static str = "DestinyLocationDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_location_definition()
+    }
+}
+
+impl Definition for DestinyLoreDefinition {
+    const TABLE: &'static str = "DestinyLoreDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_lore_definition()
+    }
+}
+
+impl Definition for DestinyMaterialRequirementSetDefinition {
+    const TABLE: &'static str = "DestinyMaterialRequirementSetDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_material_requirement_set_definition()
+    }
+}
+
+impl Definition for DestinyMedalTierDefinition {
+    const TABLE: &'static str = "DestinyMedalTierDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_medal_tier_definition()
+    }
+}
+
+impl Definition for DestinyMetricDefinition {
+    const TABLE: &'static str = "DestinyMetricDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_metric_definition()
+    }
+}
+
+impl Definition for DestinyMilestoneDefinition {
+    const TABLE: &'static str = "DestinyMilestoneDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_milestone_definition()
+    }
+}
+
+impl Definition for DestinyNodeStepSummaryDefinition {
+    const TABLE: &'static str = "DestinyNodeStepSummaryDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_node_step_summary_definition()
+    }
+}
+
+impl Definition for DestinyObjectiveDefinition {
+    const TABLE: &'static str = "DestinyObjectiveDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_objective_definition()
+    }
+}
+
+impl Definition for DestinyPlaceDefinition {
+    const TABLE: &'static str = "DestinyPlaceDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_place_definition()
+    }
+}
+
+impl Definition for DestinyPlatformBucketMappingDefinition {
+    const TABLE: &'static str = "DestinyPlatformBucketMappingDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_platform_bucket_mapping_definition()
+    }
+}
+
+impl Definition for DestinyPlugSetDefinition {
+    const TABLE: &'static str = "DestinyPlugSetDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_plug_set_definition()
+    }
+}
+
+impl Definition for DestinyPowerCapDefinition {
+    const TABLE: &'static str = "DestinyPowerCapDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_power_cap_definition()
+    }
+}
+
+impl Definition for DestinyPresentationNodeDefinition {
+    const TABLE: &'static str = "DestinyPresentationNodeDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_presentation_node_definition()
+    }
+}
+
+impl Definition for DestinyProgressionDefinition {
+    const TABLE: &'static str = "DestinyProgressionDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_progression_definition()
+    }
+}
+
+impl Definition for DestinyProgressionLevelRequirementDefinition {
+    const TABLE: &'static str = "DestinyProgressionLevelRequirementDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_progression_level_requirement_definition()
+    }
+}
+
+impl Definition for DestinyProgressionMappingDefinition {
+    const TABLE: &'static str = "DestinyProgressionMappingDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_progression_mapping_definition()
+    }
+}
+
+impl Definition for DestinyRaceDefinition {
+    const TABLE: &'static str = "DestinyRaceDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_race_definition()
+    }
+}
+
+impl Definition for DestinyRecordDefinition {
+    const TABLE: &'static str = "DestinyRecordDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_record_definition()
+    }
+}
+
+impl Definition for DestinyReportReasonCategoryDefinition {
+    const TABLE: &'static str = "DestinyReportReasonCategoryDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_report_reason_category_definition()
+    }
+}
+
+impl Definition for DestinyRewardAdjusterPointerDefinition {
+    const TABLE: &'static str = "DestinyRewardAdjusterPointerDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_reward_adjuster_pointer_definition()
+    }
+}
+
+impl Definition for DestinyRewardAdjusterProgressionMapDefinition {
+    const TABLE: &'static str = "DestinyRewardAdjusterProgressionMapDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_reward_adjuster_progression_map_definition()
+    }
+}
+
+impl Definition for DestinyRewardItemListDefinition {
+    const TABLE: &'static str = "DestinyRewardItemListDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_reward_item_list_definition()
+    }
+}
+
+impl Definition for DestinyRewardMappingDefinition {
+    const TABLE: &'static str = "DestinyRewardMappingDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_reward_mapping_definition()
+    }
+}
+
+impl Definition for DestinyRewardSheetDefinition {
+    const TABLE: &'static str = "DestinyRewardSheetDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_reward_sheet_definition()
+    }
+}
+
+impl Definition for DestinyRewardSourceDefinition {
+    const TABLE: &'static str = "DestinyRewardSourceDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_reward_source_definition()
+    }
+}
+
+impl Definition for DestinySackRewardItemListDefinition {
+    const TABLE: &'static str = "DestinySackRewardItemListDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_sack_reward_item_list_definition()
+    }
+}
+
+impl Definition for DestinySandboxPatternDefinition {
+    const TABLE: &'static str = "DestinySandboxPatternDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_sandbox_pattern_definition()
+    }
+}
+
+impl Definition for DestinySandboxPerkDefinition {
+    const TABLE: &'static str = "DestinySandboxPerkDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_sandbox_perk_definition()
+    }
+}
+
+impl Definition for DestinySeasonDefinition {
+    const TABLE: &'static str = "DestinySeasonDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_season_definition()
+    }
+}
+
+impl Definition for DestinySeasonPassDefinition {
+    const TABLE: &'static str = "DestinySeasonPassDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_season_pass_definition()
+    }
+}
+
+impl Definition for DestinySocketCategoryDefinition {
+    const TABLE: &'static str = "DestinySocketCategoryDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_socket_category_definition()
+    }
+}
+
+impl Definition for DestinySocketTypeDefinition {
+    const TABLE: &'static str = "DestinySocketTypeDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_socket_type_definition()
+    }
+}
+
+impl Definition for DestinyStatDefinition {
+    const TABLE: &'static str = "DestinyStatDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_stat_definition()
+    }
+}
+
+impl Definition for DestinyStatGroupDefinition {
+    const TABLE: &'static str = "DestinyStatGroupDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_stat_group_definition()
+    }
+}
+
+impl Definition for DestinyTalentGridDefinition {
+    const TABLE: &'static str = "DestinyTalentGridDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_talent_grid_definition()
+    }
+}
+
+impl Definition for DestinyTraitCategoryDefinition {
+    const TABLE: &'static str = "DestinyTraitCategoryDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_trait_category_definition()
+    }
+}
+
+impl Definition for DestinyTraitDefinition {
+    const TABLE: &'static str = "DestinyTraitDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_trait_definition()
+    }
+}
+
+impl Definition for DestinyUnlockCountMappingDefinition {
+    const TABLE: &'static str = "DestinyUnlockCountMappingDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_unlock_count_mapping_definition()
+    }
+}
+
+impl Definition for DestinyUnlockDefinition {
+    const TABLE: &'static str = "DestinyUnlockDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_unlock_definition()
+    }
+}
+
+impl Definition for DestinyUnlockEventDefinition {
+    const TABLE: &'static str = "DestinyUnlockEventDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_unlock_event_definition()
+    }
+}
+
+impl Definition for DestinyUnlockExpressionMappingDefinition {
+    const TABLE: &'static str = "DestinyUnlockExpressionMappingDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_unlock_expression_mapping_definition()
+    }
+}
+
+impl Definition for DestinyUnlockValueDefinition {
+    const TABLE: &'static str = "DestinyUnlockValueDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_unlock_value_definition()
+    }
+}
+
+impl Definition for DestinyVendorDefinition {
+    const TABLE: &'static str = "DestinyVendorDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_vendor_definition()
+    }
+}
+
+impl Definition for DestinyVendorGroupDefinition {
+    const TABLE: &'static str = "DestinyVendorGroupDefinition";
+
+    fn table(content: &DestinyWorldContent) -> &HashMap<String, Self> {
+        content.destiny_vendor_group_definition()
+    }
+}
@@ -0,0 +1,744 @@
+use std::{collections::HashMap, fmt};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::{
+    destiny::{
+        definitions::{
+            DestinyActivityDefinition, DestinyClassDefinition, DestinyGenderDefinition,
+            DestinyInventoryItemDefinition, DestinyRaceDefinition,
+        },
+        hashes::Hash,
+    },
+    user::UserInfoCard,
+    wire::{deserialize_int64, deserialize_optional_int64},
+};
+
+pub mod definitions;
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyActivityHistoryResults
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyActivityHistoryResults {
+    #[serde(default)]
+    activities: Vec<DestinyHistoricalStatsPeriodGroup>,
+}
+
+impl DestinyActivityHistoryResults {
+    /// List of activities, the most recent activity first. Left out by the
+    /// API once there are no more activities to page through.
+    pub fn activities(&self) -> &[DestinyHistoricalStatsPeriodGroup] {
+        self.activities.as_ref()
+    }
+
+    pub fn into_activities(self) -> Vec<DestinyHistoricalStatsPeriodGroup> {
+        self.activities
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyAggregateActivityResults
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyAggregateActivityResults {
+    #[serde(default)]
+    activities: Vec<DestinyAggregateActivityStats>,
+}
+
+impl DestinyAggregateActivityResults {
+    /// List of all activities the player has participated in.
+    pub fn activities(&self) -> &[DestinyAggregateActivityStats] {
+        self.activities.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyAggregateActivityStats
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyAggregateActivityStats {
+    activity_hash: Hash<DestinyActivityDefinition>,
+    values: HashMap<StatId, DestinyHistoricalStatsValue>,
+}
+
+impl DestinyAggregateActivityStats {
+    /// Hash ID that can be looked up in the DestinyActivityTable.
+    pub fn activity_hash(&self) -> Hash<DestinyActivityDefinition> {
+        self.activity_hash
+    }
+
+    /// Collection of stats for the player in this activity.
+    pub fn values(&self) -> &HashMap<StatId, DestinyHistoricalStatsValue> {
+        &self.values
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyHistoricalStatsAccountResult
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalStatsAccountResult {
+    #[serde(default)]
+    characters: Vec<DestinyHistoricalStatsPerCharacter>,
+    merged_all_characters: DestinyHistoricalStatsWithMerged,
+    merged_deleted_characters: DestinyHistoricalStatsWithMerged,
+}
+
+impl DestinyHistoricalStatsAccountResult {
+    pub fn characters(&self) -> &[DestinyHistoricalStatsPerCharacter] {
+        self.characters.as_ref()
+    }
+
+    pub fn merged_all_characters(&self) -> &DestinyHistoricalStatsWithMerged {
+        &self.merged_all_characters
+    }
+
+    pub fn merged_deleted_characters(&self) -> &DestinyHistoricalStatsWithMerged {
+        &self.merged_deleted_characters
+    }
+}
+
+/// Summary information about the activity that was played.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyHistoricalStatsActivity
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalStatsActivity {
+    director_activity_hash: Hash<DestinyActivityDefinition>,
+    #[serde(deserialize_with = "deserialize_int64")]
+    instance_id: i64,
+    #[serde(default)]
+    is_private: bool,
+    membership_type: i32,
+    mode: i32,
+    #[serde(default)]
+    modes: Vec<i32>,
+    reference_id: Hash<DestinyActivityDefinition>,
+}
+
+impl DestinyHistoricalStatsActivity {
+    /// The unique hash identifier of the DestinyActivityDefinition that was
+    /// played. If I had this to do over, it'd be named activityHash. Too late
+    /// now.
+    pub fn director_activity_hash(&self) -> Hash<DestinyActivityDefinition> {
+        self.director_activity_hash
+    }
+
+    /// The unique identifier for this *specific* match that was played.
+    ///
+    /// This value can be used to get additional data about this activity such
+    /// as who else was playing via the GetPostGameCarnageReport endpoint.
+    pub fn instance_id(&self) -> i64 {
+        self.instance_id
+    }
+
+    /// Whether or not the match was a private match.
+    pub fn is_private(&self) -> bool {
+        self.is_private
+    }
+
+    /// The Membership Type indicating the platform on which this match was
+    /// played.
+    pub fn membership_type(&self) -> i32 {
+        self.membership_type
+    }
+
+    /// Indicates the most specific game mode of the activity that we could
+    /// find, as a DestinyActivityModeType.
+    pub fn mode(&self) -> i32 {
+        self.mode
+    }
+
+    /// The list of all DestinyActivityModeTypes that apply to this activity.
+    pub fn modes(&self) -> &[i32] {
+        self.modes.as_ref()
+    }
+
+    /// The unique hash identifier of the DestinyActivityDefinition that was
+    /// played.
+    pub fn reference_id(&self) -> Hash<DestinyActivityDefinition> {
+        self.reference_id
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyHistoricalStatsByPeriod
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalStatsByPeriod {
+    #[serde(default)]
+    all_time: HashMap<StatId, DestinyHistoricalStatsValue>,
+    #[serde(default)]
+    all_time_tier1: HashMap<StatId, DestinyHistoricalStatsValue>,
+    #[serde(default)]
+    all_time_tier2: HashMap<StatId, DestinyHistoricalStatsValue>,
+    #[serde(default)]
+    all_time_tier3: HashMap<StatId, DestinyHistoricalStatsValue>,
+    #[serde(default)]
+    daily: Vec<DestinyHistoricalStatsPeriodGroup>,
+    #[serde(default)]
+    monthly: Vec<DestinyHistoricalStatsPeriodGroup>,
+}
+
+impl DestinyHistoricalStatsByPeriod {
+    /// Stats covering every activity played, keyed by stat id. Empty unless
+    /// all-time stats were asked for.
+    pub fn all_time(&self) -> &HashMap<StatId, DestinyHistoricalStatsValue> {
+        &self.all_time
+    }
+
+    pub fn all_time_tier1(&self) -> &HashMap<StatId, DestinyHistoricalStatsValue> {
+        &self.all_time_tier1
+    }
+
+    pub fn all_time_tier2(&self) -> &HashMap<StatId, DestinyHistoricalStatsValue> {
+        &self.all_time_tier2
+    }
+
+    pub fn all_time_tier3(&self) -> &HashMap<StatId, DestinyHistoricalStatsValue> {
+        &self.all_time_tier3
+    }
+
+    /// One group of stats per day played. Empty unless daily stats were
+    /// asked for.
+    pub fn daily(&self) -> &[DestinyHistoricalStatsPeriodGroup] {
+        self.daily.as_ref()
+    }
+
+    /// One group of stats per month played, each dated the first day of its
+    /// month.
+    pub fn monthly(&self) -> &[DestinyHistoricalStatsPeriodGroup] {
+        self.monthly.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyHistoricalStatsPerCharacter
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalStatsPerCharacter {
+    #[serde(deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    deleted: bool,
+    merged: DestinyHistoricalStatsByPeriod,
+    #[serde(default)]
+    results: HashMap<String, DestinyHistoricalStatsByPeriod>,
+}
+
+impl DestinyHistoricalStatsPerCharacter {
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    pub fn deleted(&self) -> bool {
+        self.deleted
+    }
+
+    pub fn merged(&self) -> &DestinyHistoricalStatsByPeriod {
+        &self.merged
+    }
+
+    /// The character's stats, keyed by the name of the mode they cover.
+    pub fn results(&self) -> &HashMap<String, DestinyHistoricalStatsByPeriod> {
+        &self.results
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyHistoricalStatsPeriodGroup
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalStatsPeriodGroup {
+    activity_details: Option<DestinyHistoricalStatsActivity>,
+    period: DateTime<Utc>,
+    values: HashMap<StatId, DestinyHistoricalStatsValue>,
+}
+
+impl DestinyHistoricalStatsPeriodGroup {
+    /// If the period group is for a specific activity, this property will be
+    /// set.
+    pub fn activity_details(&self) -> Option<&DestinyHistoricalStatsActivity> {
+        self.activity_details.as_ref()
+    }
+
+    /// Period for the group. If the stat periodType is day, then this will
+    /// have a specific day. If the type is monthly, then this value will be
+    /// the first day of the applicable month. This value is not set when the
+    /// periodType is 'all time'.
+    pub fn period(&self) -> DateTime<Utc> {
+        self.period
+    }
+
+    /// Collection of stats for the period.
+    pub fn values(&self) -> &HashMap<StatId, DestinyHistoricalStatsValue> {
+        &self.values
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyHistoricalStatsValue
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalStatsValue {
+    #[serde(default, deserialize_with = "deserialize_optional_int64")]
+    activity_id: Option<i64>,
+    basic: DestinyHistoricalStatsValuePair,
+    pga: Option<DestinyHistoricalStatsValuePair>,
+    stat_id: Option<StatId>,
+    weighted: Option<DestinyHistoricalStatsValuePair>,
+}
+
+impl DestinyHistoricalStatsValue {
+    /// When a stat represents the best, most, longest, fastest or some other
+    /// personal best, the actual activity ID where that personal best was
+    /// established is available on this property.
+    pub fn activity_id(&self) -> Option<i64> {
+        self.activity_id
+    }
+
+    /// Basic stat value.
+    pub fn basic(&self) -> &DestinyHistoricalStatsValuePair {
+        &self.basic
+    }
+
+    /// Per game average for the statistic, if applicable
+    pub fn pga(&self) -> Option<&DestinyHistoricalStatsValuePair> {
+        self.pga.as_ref()
+    }
+
+    /// Unique ID for this stat. None for the score and standing of a post
+    /// game carnage report entry or team, which Bungie sends without one.
+    pub fn stat_id(&self) -> Option<&StatId> {
+        self.stat_id.as_ref()
+    }
+
+    /// Weighted value of the stat if a weight greater than 1 has been assigned.
+    pub fn weighted(&self) -> Option<&DestinyHistoricalStatsValuePair> {
+        self.weighted.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyHistoricalStatsValuePair
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalStatsValuePair {
+    display_value: String,
+    value: f64,
+}
+
+impl DestinyHistoricalStatsValuePair {
+    /// Localized formated version of the value.
+    pub fn display_value(&self) -> &str {
+        self.display_value.as_ref()
+    }
+
+    /// Raw value of the statistic
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyHistoricalStatsWithMerged
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalStatsWithMerged {
+    merged: DestinyHistoricalStatsByPeriod,
+    #[serde(default)]
+    results: HashMap<String, DestinyHistoricalStatsByPeriod>,
+}
+
+impl DestinyHistoricalStatsWithMerged {
+    pub fn merged(&self) -> &DestinyHistoricalStatsByPeriod {
+        &self.merged
+    }
+
+    /// The stats, keyed by the name of the mode they cover.
+    pub fn results(&self) -> &HashMap<String, DestinyHistoricalStatsByPeriod> {
+        &self.results
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyHistoricalWeaponStats
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalWeaponStats {
+    reference_id: Hash<DestinyInventoryItemDefinition>,
+    values: HashMap<StatId, DestinyHistoricalStatsValue>,
+}
+
+impl DestinyHistoricalWeaponStats {
+    /// The hash ID of the item definition that describes the weapon.
+    pub fn reference_id(&self) -> Hash<DestinyInventoryItemDefinition> {
+        self.reference_id
+    }
+
+    /// Collection of stats for the period.
+    pub fn values(&self) -> &HashMap<StatId, DestinyHistoricalStatsValue> {
+        &self.values
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyHistoricalWeaponStatsData
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalWeaponStatsData {
+    #[serde(default)]
+    weapons: Vec<DestinyHistoricalWeaponStats>,
+}
+
+impl DestinyHistoricalWeaponStatsData {
+    /// List of weapons and their perspective values.
+    pub fn weapons(&self) -> &[DestinyHistoricalWeaponStats] {
+        self.weapons.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyPlayer
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyPlayer {
+    bungie_net_user_info: Option<UserInfoCard>,
+    character_class: Option<String>,
+    character_level: i32,
+    class_hash: Hash<DestinyClassDefinition>,
+    clan_name: Option<String>,
+    clan_tag: Option<String>,
+    destiny_user_info: UserInfoCard,
+    emblem_hash: Hash<DestinyInventoryItemDefinition>,
+    gender_hash: Hash<DestinyGenderDefinition>,
+    light_level: i32,
+    race_hash: Hash<DestinyRaceDefinition>,
+}
+
+impl DestinyPlayer {
+    /// Details about the player as they are known on BungieNet. This will be
+    /// undefined if the player has marked their credential private, or does
+    /// not have a BungieNet account.
+    pub fn bungie_net_user_info(&self) -> Option<&UserInfoCard> {
+        self.bungie_net_user_info.as_ref()
+    }
+
+    /// Class of the character if applicable and available.
+    pub fn character_class(&self) -> Option<&String> {
+        self.character_class.as_ref()
+    }
+
+    /// Level of the character if available. Zero if it is not available.
+    pub fn character_level(&self) -> i32 {
+        self.character_level
+    }
+
+    pub fn class_hash(&self) -> Hash<DestinyClassDefinition> {
+        self.class_hash
+    }
+
+    /// Current clan name for the player. This value may be null or an empty
+    /// string if the user does not have a clan.
+    pub fn clan_name(&self) -> Option<&String> {
+        self.clan_name.as_ref()
+    }
+
+    /// Current clan tag for the player. This value may be null or an empty
+    /// string if the user does not have a clan.
+    pub fn clan_tag(&self) -> Option<&String> {
+        self.clan_tag.as_ref()
+    }
+
+    /// Details about the player as they are known in game (platform display
+    /// name, Destiny emblem)
+    pub fn destiny_user_info(&self) -> &UserInfoCard {
+        &self.destiny_user_info
+    }
+
+    /// If we know the emblem's hash, this can be used to look up the player's
+    /// emblem at the time of a match when receiving PGCR data, or otherwise
+    /// their currently equipped emblem (if we are able to obtain it).
+    pub fn emblem_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
+        self.emblem_hash
+    }
+
+    pub fn gender_hash(&self) -> Hash<DestinyGenderDefinition> {
+        self.gender_hash
+    }
+
+    /// Light Level of the character if available. Zero if it is not
+    /// available.
+    pub fn light_level(&self) -> i32 {
+        self.light_level
+    }
+
+    pub fn race_hash(&self) -> Hash<DestinyRaceDefinition> {
+        self.race_hash
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyPostGameCarnageReportData
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyPostGameCarnageReportData {
+    activity_details: DestinyHistoricalStatsActivity,
+    activity_was_started_from_beginning: Option<bool>,
+    entries: Vec<DestinyPostGameCarnageReportEntry>,
+    period: DateTime<Utc>,
+    starting_phase_index: Option<i32>,
+    #[serde(default)]
+    teams: Vec<DestinyPostGameCarnageReportTeamEntry>,
+}
+
+impl DestinyPostGameCarnageReportData {
+    /// Details about the activity.
+    pub fn activity_details(&self) -> &DestinyHistoricalStatsActivity {
+        &self.activity_details
+    }
+
+    /// True if the activity was started from the beginning, if that
+    /// information is available and the activity was played post Witch Queen
+    /// release.
+    pub fn activity_was_started_from_beginning(&self) -> Option<bool> {
+        self.activity_was_started_from_beginning
+    }
+
+    /// Collection of players and their data for this activity.
+    pub fn entries(&self) -> &[DestinyPostGameCarnageReportEntry] {
+        self.entries.as_ref()
+    }
+
+    /// Date and time for the activity.
+    pub fn period(&self) -> DateTime<Utc> {
+        self.period
+    }
+
+    /// If this activity has "phases", this is the phase at which the activity
+    /// was started. This value is only valid for activities before the
+    /// Beyond Light expansion shipped. Subsequent activities will not have a
+    /// valid value here.
+    pub fn starting_phase_index(&self) -> Option<i32> {
+        self.starting_phase_index
+    }
+
+    /// Collection of stats for the player in this activity.
+    pub fn teams(&self) -> &[DestinyPostGameCarnageReportTeamEntry] {
+        self.teams.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyPostGameCarnageReportEntry
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyPostGameCarnageReportEntry {
+    #[serde(deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    extended: Option<DestinyPostGameCarnageReportExtendedData>,
+    player: DestinyPlayer,
+    score: DestinyHistoricalStatsValue,
+    standing: i32,
+    values: HashMap<StatId, DestinyHistoricalStatsValue>,
+}
+
+impl DestinyPostGameCarnageReportEntry {
+    /// ID of the player's character used in the activity.
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    /// Extended data extracted from the activity blob.
+    pub fn extended(&self) -> Option<&DestinyPostGameCarnageReportExtendedData> {
+        self.extended.as_ref()
+    }
+
+    /// Identity details of the player
+    pub fn player(&self) -> &DestinyPlayer {
+        &self.player
+    }
+
+    /// Score of the player if available
+    pub fn score(&self) -> &DestinyHistoricalStatsValue {
+        &self.score
+    }
+
+    /// Standing of the player
+    pub fn standing(&self) -> i32 {
+        self.standing
+    }
+
+    /// Collection of stats for the player in this activity.
+    pub fn values(&self) -> &HashMap<StatId, DestinyHistoricalStatsValue> {
+        &self.values
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyPostGameCarnageReportExtendedData
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyPostGameCarnageReportExtendedData {
+    values: HashMap<StatId, DestinyHistoricalStatsValue>,
+    weapons: Option<Vec<DestinyHistoricalWeaponStats>>,
+}
+
+impl DestinyPostGameCarnageReportExtendedData {
+    /// Collection of stats for the player in this activity, medals included.
+    pub fn values(&self) -> &HashMap<StatId, DestinyHistoricalStatsValue> {
+        &self.values
+    }
+
+    /// List of weapons and their perspective values.
+    pub fn weapons(&self) -> Option<&Vec<DestinyHistoricalWeaponStats>> {
+        self.weapons.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.DestinyPostGameCarnageReportTeamEntry
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyPostGameCarnageReportTeamEntry {
+    score: DestinyHistoricalStatsValue,
+    standing: DestinyHistoricalStatsValue,
+    team_id: i32,
+    team_name: Option<String>,
+}
+
+impl DestinyPostGameCarnageReportTeamEntry {
+    /// Score earned by the team
+    pub fn score(&self) -> &DestinyHistoricalStatsValue {
+        &self.score
+    }
+
+    /// Team's standing relative to other teams.
+    pub fn standing(&self) -> &DestinyHistoricalStatsValue {
+        &self.standing
+    }
+
+    /// Integer ID for the team.
+    pub fn team_id(&self) -> i32 {
+        self.team_id
+    }
+
+    /// Alpha or Bravo
+    pub fn team_name(&self) -> Option<&String> {
+        self.team_name.as_ref()
+    }
+}
+
+/// The id of a historical stat, as in the keys of a set of stat values and
+/// in DestinyHistoricalStatsDefinition. Only the stats this crate reads are
+/// named, every other one, medals included, is kept as it was sent.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum StatId {
+    ActivityDurationSeconds,
+    Assists,
+    AverageScorePerKill,
+    AverageScorePerLife,
+    Completed,
+    /// As a completion reason, 0 being that the objective was completed.
+    CompletionReason,
+    Deaths,
+    Efficiency,
+    FireteamId,
+    Kills,
+    KillsDeathsAssists,
+    KillsDeathsRatio,
+    OpponentsDefeated,
+    PlayerCount,
+    PrecisionKills,
+    Score,
+    Standing,
+    StartSeconds,
+    Team,
+    TeamScore,
+    TimePlayedSeconds,
+    UniqueWeaponKills,
+    UniqueWeaponKillsPrecisionKills,
+    UniqueWeaponPrecisionKills,
+    /// A stat not named above. Build it with `From` so that a named stat
+    /// never ends up here.
+    Unknown(String),
+}
+
+impl StatId {
+    pub fn as_str(&self) -> &str {
+        match self {
+            StatId::ActivityDurationSeconds => "activityDurationSeconds",
+            StatId::Assists => "assists",
+            StatId::AverageScorePerKill => "averageScorePerKill",
+            StatId::AverageScorePerLife => "averageScorePerLife",
+            StatId::Completed => "completed",
+            StatId::CompletionReason => "completionReason",
+            StatId::Deaths => "deaths",
+            StatId::Efficiency => "efficiency",
+            StatId::FireteamId => "fireteamId",
+            StatId::Kills => "kills",
+            StatId::KillsDeathsAssists => "killsDeathsAssists",
+            StatId::KillsDeathsRatio => "killsDeathsRatio",
+            StatId::OpponentsDefeated => "opponentsDefeated",
+            StatId::PlayerCount => "playerCount",
+            StatId::PrecisionKills => "precisionKills",
+            StatId::Score => "score",
+            StatId::Standing => "standing",
+            StatId::StartSeconds => "startSeconds",
+            StatId::Team => "team",
+            StatId::TeamScore => "teamScore",
+            StatId::TimePlayedSeconds => "timePlayedSeconds",
+            StatId::UniqueWeaponKills => "uniqueWeaponKills",
+            StatId::UniqueWeaponKillsPrecisionKills => "uniqueWeaponKillsPrecisionKills",
+            StatId::UniqueWeaponPrecisionKills => "uniqueWeaponPrecisionKills",
+            StatId::Unknown(stat_id) => stat_id.as_str(),
+        }
+    }
+
+    /// Whether the stat counts a medal, which all have ids starting with
+    /// "medal".
+    pub fn is_medal(&self) -> bool {
+        self.as_str().starts_with("medal")
+    }
+}
+
+impl fmt::Display for StatId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<StatId> for String {
+    fn from(value: StatId) -> Self {
+        match value {
+            StatId::Unknown(stat_id) => stat_id,
+            _ => value.as_str().to_string(),
+        }
+    }
+}
+
+impl From<&str> for StatId {
+    fn from(value: &str) -> Self {
+        match value {
+            "activityDurationSeconds" => StatId::ActivityDurationSeconds,
+            "assists" => StatId::Assists,
+            "averageScorePerKill" => StatId::AverageScorePerKill,
+            "averageScorePerLife" => StatId::AverageScorePerLife,
+            "completed" => StatId::Completed,
+            "completionReason" => StatId::CompletionReason,
+            "deaths" => StatId::Deaths,
+            "efficiency" => StatId::Efficiency,
+            "fireteamId" => StatId::FireteamId,
+            "kills" => StatId::Kills,
+            "killsDeathsAssists" => StatId::KillsDeathsAssists,
+            "killsDeathsRatio" => StatId::KillsDeathsRatio,
+            "opponentsDefeated" => StatId::OpponentsDefeated,
+            "playerCount" => StatId::PlayerCount,
+            "precisionKills" => StatId::PrecisionKills,
+            "score" => StatId::Score,
+            "standing" => StatId::Standing,
+            "startSeconds" => StatId::StartSeconds,
+            "team" => StatId::Team,
+            "teamScore" => StatId::TeamScore,
+            "timePlayedSeconds" => StatId::TimePlayedSeconds,
+            "uniqueWeaponKills" => StatId::UniqueWeaponKills,
+            "uniqueWeaponKillsPrecisionKills" => StatId::UniqueWeaponKillsPrecisionKills,
+            "uniqueWeaponPrecisionKills" => StatId::UniqueWeaponPrecisionKills,
+            _ => StatId::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for StatId {
+    fn from(value: String) -> Self {
+        match StatId::from(value.as_str()) {
+            StatId::Unknown(_) => StatId::Unknown(value),
+            stat_id => stat_id,
+        }
+    }
+}
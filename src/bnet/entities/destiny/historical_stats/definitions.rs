@@ -0,0 +1,487 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::destiny::{
+    definitions::DestinyMedalTierDefinition,
+    hashes::Hash,
+    historical_stats::{DestinyHistoricalStatsValue, StatId},
+};
+
+/// For historical reasons, this list will have both D1 and D2-relevant
+/// Activity Modes in it. Please don't take this to mean that some D1-only
+/// feature is coming back!
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.Definitions.DestinyActivityModeType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "i32", try_from = "i32")]
+pub enum DestinyActivityModeType {
+    None = 0,
+    Story = 2,
+    Strike = 3,
+    Raid = 4,
+    AllPvP = 5,
+    Patrol = 6,
+    AllPvE = 7,
+    Reserved9 = 9,
+    Control = 10,
+    Reserved11 = 11,
+    Clash = 12,
+    Reserved13 = 13,
+    CrimsonDoubles = 15,
+    Nightfall = 16,
+    HeroicNightfall = 17,
+    AllStrikes = 18,
+    IronBanner = 19,
+    Reserved20 = 20,
+    Reserved21 = 21,
+    Reserved22 = 22,
+    Reserved24 = 24,
+    AllMayhem = 25,
+    Reserved26 = 26,
+    Reserved27 = 27,
+    Reserved28 = 28,
+    Reserved29 = 29,
+    Reserved30 = 30,
+    Supremacy = 31,
+    PrivateMatchesAll = 32,
+    Survival = 37,
+    Countdown = 38,
+    TrialsOfTheNine = 39,
+    Social = 40,
+    TrialsCountdown = 41,
+    TrialsSurvival = 42,
+    IronBannerControl = 43,
+    IronBannerClash = 44,
+    IronBannerSupremacy = 45,
+    ScoredNightfall = 46,
+    ScoredHeroicNightfall = 47,
+    Rumble = 48,
+    AllDoubles = 49,
+    Doubles = 50,
+    PrivateMatchesClash = 51,
+    PrivateMatchesControl = 52,
+    PrivateMatchesSupremacy = 53,
+    PrivateMatchesCountdown = 54,
+    PrivateMatchesSurvival = 55,
+    PrivateMatchesMayhem = 56,
+    PrivateMatchesRumble = 57,
+    HeroicAdventure = 58,
+    Showdown = 59,
+    Lockdown = 60,
+    Scorched = 61,
+    ScorchedTeam = 62,
+    Gambit = 63,
+    AllPvECompetitive = 64,
+    Breakthrough = 65,
+    BlackArmoryRun = 66,
+    Salvage = 67,
+    IronBannerSalvage = 68,
+    PvPCompetitive = 69,
+    PvPQuickplay = 70,
+    ClashQuickplay = 71,
+    ClashCompetitive = 72,
+    ControlQuickplay = 73,
+    ControlCompetitive = 74,
+    GambitPrime = 75,
+    Reckoning = 76,
+    Menagerie = 77,
+    VexOffensive = 78,
+    NightmareHunt = 79,
+    Elimination = 80,
+    Momentum = 81,
+    Dungeon = 82,
+    Sundial = 83,
+    TrialsOfOsiris = 84,
+    Dares = 85,
+    Offensive = 86,
+    LostSector = 87,
+    Rift = 88,
+    ZoneControl = 89,
+    IronBannerRift = 90,
+    IronBannerZoneControl = 91,
+    Relic = 92,
+}
+
+impl From<DestinyActivityModeType> for i32 {
+    fn from(mode: DestinyActivityModeType) -> Self {
+        mode as i32
+    }
+}
+
+impl TryFrom<i32> for DestinyActivityModeType {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(DestinyActivityModeType::None),
+            2 => Ok(DestinyActivityModeType::Story),
+            3 => Ok(DestinyActivityModeType::Strike),
+            4 => Ok(DestinyActivityModeType::Raid),
+            5 => Ok(DestinyActivityModeType::AllPvP),
+            6 => Ok(DestinyActivityModeType::Patrol),
+            7 => Ok(DestinyActivityModeType::AllPvE),
+            9 => Ok(DestinyActivityModeType::Reserved9),
+            10 => Ok(DestinyActivityModeType::Control),
+            11 => Ok(DestinyActivityModeType::Reserved11),
+            12 => Ok(DestinyActivityModeType::Clash),
+            13 => Ok(DestinyActivityModeType::Reserved13),
+            15 => Ok(DestinyActivityModeType::CrimsonDoubles),
+            16 => Ok(DestinyActivityModeType::Nightfall),
+            17 => Ok(DestinyActivityModeType::HeroicNightfall),
+            18 => Ok(DestinyActivityModeType::AllStrikes),
+            19 => Ok(DestinyActivityModeType::IronBanner),
+            20 => Ok(DestinyActivityModeType::Reserved20),
+            21 => Ok(DestinyActivityModeType::Reserved21),
+            22 => Ok(DestinyActivityModeType::Reserved22),
+            24 => Ok(DestinyActivityModeType::Reserved24),
+            25 => Ok(DestinyActivityModeType::AllMayhem),
+            26 => Ok(DestinyActivityModeType::Reserved26),
+            27 => Ok(DestinyActivityModeType::Reserved27),
+            28 => Ok(DestinyActivityModeType::Reserved28),
+            29 => Ok(DestinyActivityModeType::Reserved29),
+            30 => Ok(DestinyActivityModeType::Reserved30),
+            31 => Ok(DestinyActivityModeType::Supremacy),
+            32 => Ok(DestinyActivityModeType::PrivateMatchesAll),
+            37 => Ok(DestinyActivityModeType::Survival),
+            38 => Ok(DestinyActivityModeType::Countdown),
+            39 => Ok(DestinyActivityModeType::TrialsOfTheNine),
+            40 => Ok(DestinyActivityModeType::Social),
+            41 => Ok(DestinyActivityModeType::TrialsCountdown),
+            42 => Ok(DestinyActivityModeType::TrialsSurvival),
+            43 => Ok(DestinyActivityModeType::IronBannerControl),
+            44 => Ok(DestinyActivityModeType::IronBannerClash),
+            45 => Ok(DestinyActivityModeType::IronBannerSupremacy),
+            46 => Ok(DestinyActivityModeType::ScoredNightfall),
+            47 => Ok(DestinyActivityModeType::ScoredHeroicNightfall),
+            48 => Ok(DestinyActivityModeType::Rumble),
+            49 => Ok(DestinyActivityModeType::AllDoubles),
+            50 => Ok(DestinyActivityModeType::Doubles),
+            51 => Ok(DestinyActivityModeType::PrivateMatchesClash),
+            52 => Ok(DestinyActivityModeType::PrivateMatchesControl),
+            53 => Ok(DestinyActivityModeType::PrivateMatchesSupremacy),
+            54 => Ok(DestinyActivityModeType::PrivateMatchesCountdown),
+            55 => Ok(DestinyActivityModeType::PrivateMatchesSurvival),
+            56 => Ok(DestinyActivityModeType::PrivateMatchesMayhem),
+            57 => Ok(DestinyActivityModeType::PrivateMatchesRumble),
+            58 => Ok(DestinyActivityModeType::HeroicAdventure),
+            59 => Ok(DestinyActivityModeType::Showdown),
+            60 => Ok(DestinyActivityModeType::Lockdown),
+            61 => Ok(DestinyActivityModeType::Scorched),
+            62 => Ok(DestinyActivityModeType::ScorchedTeam),
+            63 => Ok(DestinyActivityModeType::Gambit),
+            64 => Ok(DestinyActivityModeType::AllPvECompetitive),
+            65 => Ok(DestinyActivityModeType::Breakthrough),
+            66 => Ok(DestinyActivityModeType::BlackArmoryRun),
+            67 => Ok(DestinyActivityModeType::Salvage),
+            68 => Ok(DestinyActivityModeType::IronBannerSalvage),
+            69 => Ok(DestinyActivityModeType::PvPCompetitive),
+            70 => Ok(DestinyActivityModeType::PvPQuickplay),
+            71 => Ok(DestinyActivityModeType::ClashQuickplay),
+            72 => Ok(DestinyActivityModeType::ClashCompetitive),
+            73 => Ok(DestinyActivityModeType::ControlQuickplay),
+            74 => Ok(DestinyActivityModeType::ControlCompetitive),
+            75 => Ok(DestinyActivityModeType::GambitPrime),
+            76 => Ok(DestinyActivityModeType::Reckoning),
+            77 => Ok(DestinyActivityModeType::Menagerie),
+            78 => Ok(DestinyActivityModeType::VexOffensive),
+            79 => Ok(DestinyActivityModeType::NightmareHunt),
+            80 => Ok(DestinyActivityModeType::Elimination),
+            81 => Ok(DestinyActivityModeType::Momentum),
+            82 => Ok(DestinyActivityModeType::Dungeon),
+            83 => Ok(DestinyActivityModeType::Sundial),
+            84 => Ok(DestinyActivityModeType::TrialsOfOsiris),
+            85 => Ok(DestinyActivityModeType::Dares),
+            86 => Ok(DestinyActivityModeType::Offensive),
+            87 => Ok(DestinyActivityModeType::LostSector),
+            88 => Ok(DestinyActivityModeType::Rift),
+            89 => Ok(DestinyActivityModeType::ZoneControl),
+            90 => Ok(DestinyActivityModeType::IronBannerRift),
+            91 => Ok(DestinyActivityModeType::IronBannerZoneControl),
+            92 => Ok(DestinyActivityModeType::Relic),
+            _ => Err(format!("invalid DestinyActivityModeType {}", value)),
+        }
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.Definitions.DestinyHistoricalStatsDefinition
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyHistoricalStatsDefinition {
+    category: DestinyStatsCategoryType,
+    group: DestinyStatsGroupType,
+    icon_image: Option<String>,
+    medal_tier_hash: Option<Hash<DestinyMedalTierDefinition>>,
+    merge_method: Option<i32>,
+    #[serde(default)]
+    modes: Vec<i32>,
+    #[serde(default)]
+    period_types: Vec<i32>,
+    stat_description: Option<String>,
+    stat_id: StatId,
+    stat_name: Option<String>,
+    stat_name_abbr: Option<String>,
+    unit_label: Option<String>,
+    unit_type: UnitType,
+    weight: i32,
+}
+
+impl DestinyHistoricalStatsDefinition {
+    /// Category for the stat.
+    pub fn category(&self) -> DestinyStatsCategoryType {
+        self.category
+    }
+
+    /// Statistic group
+    pub fn group(&self) -> DestinyStatsGroupType {
+        self.group
+    }
+
+    /// Optional URI to an icon for the statistic
+    pub fn icon_image(&self) -> Option<&String> {
+        self.icon_image.as_ref()
+    }
+
+    /// The tier associated with this medal - be it implicitly or explicitly.
+    pub fn medal_tier_hash(&self) -> Option<Hash<DestinyMedalTierDefinition>> {
+        self.medal_tier_hash
+    }
+
+    /// How the stat is merged across characters, as a DestinyStatsMergeMethods
+    /// value.
+    pub fn merge_method(&self) -> Option<i32> {
+        self.merge_method
+    }
+
+    /// Game modes where this statistic can be reported, as
+    /// DestinyActivityModeType values. Unknown modes are kept as they are.
+    pub fn modes(&self) -> &[i32] {
+        self.modes.as_ref()
+    }
+
+    /// Time periods the statistic covers, as PeriodType values.
+    pub fn period_types(&self) -> &[i32] {
+        self.period_types.as_ref()
+    }
+
+    /// Description of a stat if applicable.
+    pub fn stat_description(&self) -> Option<&String> {
+        self.stat_description.as_ref()
+    }
+
+    /// Unique programmer friendly ID for this stat
+    pub fn stat_id(&self) -> &StatId {
+        &self.stat_id
+    }
+
+    /// Display name
+    pub fn stat_name(&self) -> Option<&String> {
+        self.stat_name.as_ref()
+    }
+
+    /// Display name abbreviated
+    pub fn stat_name_abbr(&self) -> Option<&String> {
+        self.stat_name_abbr.as_ref()
+    }
+
+    /// Localized Unit Name for the stat.
+    pub fn unit_label(&self) -> Option<&String> {
+        self.unit_label.as_ref()
+    }
+
+    /// Unit, if any, for the statistic
+    pub fn unit_type(&self) -> UnitType {
+        self.unit_type
+    }
+
+    /// Weight assigned to this stat indicating its relative impressiveness.
+    pub fn weight(&self) -> i32 {
+        self.weight
+    }
+
+    /// Looks the stat up in a set of values, such as those of a period group.
+    pub fn value_in<'v>(
+        &self,
+        values: &'v HashMap<StatId, DestinyHistoricalStatsValue>,
+    ) -> Option<&'v DestinyHistoricalStatsValue> {
+        values.get(&self.stat_id)
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.Definitions.DestinyStatsCategoryType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "i32", try_from = "i32")]
+pub enum DestinyStatsCategoryType {
+    None = 0,
+    Kills = 1,
+    Assists = 2,
+    Deaths = 3,
+    Criticals = 4,
+    KDa = 5,
+    KD = 6,
+    Score = 7,
+    Entered = 8,
+    TimePlayed = 9,
+    MedalWins = 10,
+    MedalGame = 11,
+    MedalSpecialKills = 12,
+    MedalSprees = 13,
+    MedalMultiKills = 14,
+    MedalAbilities = 15,
+}
+
+impl From<DestinyStatsCategoryType> for i32 {
+    fn from(value: DestinyStatsCategoryType) -> Self {
+        value as i32
+    }
+}
+
+impl TryFrom<i32> for DestinyStatsCategoryType {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(DestinyStatsCategoryType::None),
+            1 => Ok(DestinyStatsCategoryType::Kills),
+            2 => Ok(DestinyStatsCategoryType::Assists),
+            3 => Ok(DestinyStatsCategoryType::Deaths),
+            4 => Ok(DestinyStatsCategoryType::Criticals),
+            5 => Ok(DestinyStatsCategoryType::KDa),
+            6 => Ok(DestinyStatsCategoryType::KD),
+            7 => Ok(DestinyStatsCategoryType::Score),
+            8 => Ok(DestinyStatsCategoryType::Entered),
+            9 => Ok(DestinyStatsCategoryType::TimePlayed),
+            10 => Ok(DestinyStatsCategoryType::MedalWins),
+            11 => Ok(DestinyStatsCategoryType::MedalGame),
+            12 => Ok(DestinyStatsCategoryType::MedalSpecialKills),
+            13 => Ok(DestinyStatsCategoryType::MedalSprees),
+            14 => Ok(DestinyStatsCategoryType::MedalMultiKills),
+            15 => Ok(DestinyStatsCategoryType::MedalAbilities),
+            _ => Err(format!("invalid DestinyStatsCategoryType {}", value)),
+        }
+    }
+}
+
+/// If the enum value is > 100, it is a "special" group that cannot be
+/// queried for directly (special cases apply to when they are returned, and
+/// are not relevant in general cases)
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.Definitions.DestinyStatsGroupType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "i32", try_from = "i32")]
+pub enum DestinyStatsGroupType {
+    None = 0,
+    General = 1,
+    Weapons = 2,
+    Medals = 3,
+    ReservedGroups = 100,
+    Leaderboard = 101,
+    Activity = 102,
+    UniqueWeapon = 103,
+    Internal = 104,
+}
+
+impl From<DestinyStatsGroupType> for i32 {
+    fn from(value: DestinyStatsGroupType) -> Self {
+        value as i32
+    }
+}
+
+impl TryFrom<i32> for DestinyStatsGroupType {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(DestinyStatsGroupType::None),
+            1 => Ok(DestinyStatsGroupType::General),
+            2 => Ok(DestinyStatsGroupType::Weapons),
+            3 => Ok(DestinyStatsGroupType::Medals),
+            100 => Ok(DestinyStatsGroupType::ReservedGroups),
+            101 => Ok(DestinyStatsGroupType::Leaderboard),
+            102 => Ok(DestinyStatsGroupType::Activity),
+            103 => Ok(DestinyStatsGroupType::UniqueWeapon),
+            104 => Ok(DestinyStatsGroupType::Internal),
+            _ => Err(format!("invalid DestinyStatsGroupType {}", value)),
+        }
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.Definitions.PeriodType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "i32", try_from = "i32")]
+pub enum PeriodType {
+    None = 0,
+    Daily = 1,
+    AllTime = 2,
+    Activity = 3,
+}
+
+impl From<PeriodType> for i32 {
+    fn from(value: PeriodType) -> Self {
+        value as i32
+    }
+}
+
+impl TryFrom<i32> for PeriodType {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PeriodType::None),
+            1 => Ok(PeriodType::Daily),
+            2 => Ok(PeriodType::AllTime),
+            3 => Ok(PeriodType::Activity),
+            _ => Err(format!("invalid PeriodType {}", value)),
+        }
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.HistoricalStats.Definitions.UnitType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "i32", try_from = "i32")]
+pub enum UnitType {
+    None = 0,
+    Count = 1,
+    PerGame = 2,
+    Seconds = 3,
+    Points = 4,
+    Team = 5,
+    Distance = 6,
+    Percent = 7,
+    Ratio = 8,
+    Boolean = 9,
+    WeaponType = 10,
+    Standing = 11,
+    Milliseconds = 12,
+    CompletionReason = 13,
+}
+
+impl From<UnitType> for i32 {
+    fn from(value: UnitType) -> Self {
+        value as i32
+    }
+}
+
+impl TryFrom<i32> for UnitType {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(UnitType::None),
+            1 => Ok(UnitType::Count),
+            2 => Ok(UnitType::PerGame),
+            3 => Ok(UnitType::Seconds),
+            4 => Ok(UnitType::Points),
+            5 => Ok(UnitType::Team),
+            6 => Ok(UnitType::Distance),
+            7 => Ok(UnitType::Percent),
+            8 => Ok(UnitType::Ratio),
+            9 => Ok(UnitType::Boolean),
+            10 => Ok(UnitType::WeaponType),
+            11 => Ok(UnitType::Standing),
+            12 => Ok(UnitType::Milliseconds),
+            13 => Ok(UnitType::CompletionReason),
+            _ => Err(format!("invalid UnitType {}", value)),
+        }
+    }
+}
@@ -1,5 +1,12 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bnet::entities::destiny::{
+    definitions::{
+        DestinyActivityDefinition, DestinyDestinationDefinition, DestinyObjectiveDefinition,
+    },
+    hashes::Hash,
+};
+
 /// Returns data about a character's status with a given Objective. Combine
 /// with DestinyObjectiveDefinition static data for display purposes.
 ///
@@ -7,11 +14,14 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyObjectiveProgress {
-    activity_hash: u32,
+    #[serde(default)]
+    activity_hash: Hash<DestinyActivityDefinition>,
     complete: bool,
     completion_value: i32,
-    destination_hash: u32,
-    objective_hash: u32,
+    #[serde(default)]
+    destination_hash: Hash<DestinyDestinationDefinition>,
+    objective_hash: Hash<DestinyObjectiveDefinition>,
+    #[serde(default)]
     progress: i32,
     visible: bool,
 }
@@ -21,7 +31,7 @@ impl DestinyObjectiveProgress {
     /// identifier of the Activity being referred to. Use to look up the
     /// DestinyActivityDefinition in static data. This will give localized data
     /// about *what* you should be playing for the objective to be achieved.
-    pub fn activity_hash(&self) -> u32 {
+    pub fn activity_hash(&self) -> Hash<DestinyActivityDefinition> {
         self.activity_hash
     }
 
@@ -47,13 +57,13 @@ impl DestinyObjectiveProgress {
     /// the DestinyDestinationDefinition in static data. This will give
     /// localized data about *where* in the universe the objective should be
     /// achieved.
-    pub fn destination_hash(&self) -> u32 {
+    pub fn destination_hash(&self) -> Hash<DestinyDestinationDefinition> {
         self.destination_hash
     }
 
     /// The unique identifier of the Objective being referred to. Use to look
     /// up the DestinyObjectiveDefinition in static data.
-    pub fn objective_hash(&self) -> u32 {
+    pub fn objective_hash(&self) -> Hash<DestinyObjectiveDefinition> {
         self.objective_hash
     }
 
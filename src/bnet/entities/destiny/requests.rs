@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::{
+    destiny::{definitions::DestinyInventoryItemDefinition, hashes::Hash},
+    wire::deserialize_int64,
+    BungieMembershipType,
+};
+
+pub mod actions;
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Requests.DestinyItemTransferRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemTransferRequest {
+    #[serde(deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    #[serde(deserialize_with = "deserialize_int64")]
+    item_id: i64,
+    item_reference_hash: Hash<DestinyInventoryItemDefinition>,
+    membership_type: BungieMembershipType,
+    stack_size: i32,
+    transfer_to_vault: bool,
+}
+
+impl DestinyItemTransferRequest {
+    pub fn new(
+        item_reference_hash: Hash<DestinyInventoryItemDefinition>,
+        stack_size: i32,
+        transfer_to_vault: bool,
+        item_id: i64,
+        character_id: i64,
+        membership_type: BungieMembershipType,
+    ) -> Self {
+        Self {
+            character_id,
+            item_id,
+            item_reference_hash,
+            membership_type,
+            stack_size,
+            transfer_to_vault,
+        }
+    }
+
+    /// The character the item is moved from when transferring to the vault,
+    /// or to when transferring from it.
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    /// The instance id of the item, if it's an instanced item.
+    pub fn item_id(&self) -> i64 {
+        self.item_id
+    }
+
+    pub fn item_reference_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
+        self.item_reference_hash
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+
+    pub fn stack_size(&self) -> i32 {
+        self.stack_size
+    }
+
+    pub fn transfer_to_vault(&self) -> bool {
+        self.transfer_to_vault
+    }
+}
@@ -0,0 +1,393 @@
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::{
+    destiny::{
+        definitions::{
+            loadouts::{
+                DestinyLoadoutColorDefinition, DestinyLoadoutIconDefinition,
+                DestinyLoadoutNameDefinition,
+            },
+            DestinyInventoryItemDefinition,
+        },
+        hashes::Hash,
+        DestinySocketArrayType,
+    },
+    wire::{deserialize_int64, deserialize_int64s},
+    BungieMembershipType,
+};
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Requests.Actions.DestinyItemActionRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemActionRequest {
+    #[serde(deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    #[serde(deserialize_with = "deserialize_int64")]
+    item_id: i64,
+    membership_type: BungieMembershipType,
+}
+
+impl DestinyItemActionRequest {
+    pub fn new(item_id: i64, character_id: i64, membership_type: BungieMembershipType) -> Self {
+        Self {
+            character_id,
+            item_id,
+            membership_type,
+        }
+    }
+
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    pub fn item_id(&self) -> i64 {
+        self.item_id
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Requests.Actions.DestinyItemSetActionRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemSetActionRequest {
+    #[serde(deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    #[serde(deserialize_with = "deserialize_int64s")]
+    item_ids: Vec<i64>,
+    membership_type: BungieMembershipType,
+}
+
+impl DestinyItemSetActionRequest {
+    pub fn new(
+        item_ids: Vec<i64>,
+        character_id: i64,
+        membership_type: BungieMembershipType,
+    ) -> Self {
+        Self {
+            character_id,
+            item_ids,
+            membership_type,
+        }
+    }
+
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    pub fn item_ids(&self) -> &[i64] {
+        self.item_ids.as_ref()
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Requests.Actions.DestinyItemStateRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemStateRequest {
+    #[serde(deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    #[serde(deserialize_with = "deserialize_int64")]
+    item_id: i64,
+    membership_type: BungieMembershipType,
+    state: bool,
+}
+
+impl DestinyItemStateRequest {
+    pub fn new(
+        state: bool,
+        item_id: i64,
+        character_id: i64,
+        membership_type: BungieMembershipType,
+    ) -> Self {
+        Self {
+            character_id,
+            item_id,
+            membership_type,
+            state,
+        }
+    }
+
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    pub fn item_id(&self) -> i64 {
+        self.item_id
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+
+    /// Whether the item should be locked (or tracked), or not.
+    pub fn state(&self) -> bool {
+        self.state
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Requests.Actions.DestinyPostmasterTransferRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyPostmasterTransferRequest {
+    #[serde(deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    #[serde(deserialize_with = "deserialize_int64")]
+    item_id: i64,
+    item_reference_hash: Hash<DestinyInventoryItemDefinition>,
+    membership_type: BungieMembershipType,
+    stack_size: i32,
+}
+
+impl DestinyPostmasterTransferRequest {
+    pub fn new(
+        item_reference_hash: Hash<DestinyInventoryItemDefinition>,
+        stack_size: i32,
+        item_id: i64,
+        character_id: i64,
+        membership_type: BungieMembershipType,
+    ) -> Self {
+        Self {
+            character_id,
+            item_id,
+            item_reference_hash,
+            membership_type,
+            stack_size,
+        }
+    }
+
+    /// The character whose postmaster the item is pulled from.
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    pub fn item_id(&self) -> i64 {
+        self.item_id
+    }
+
+    pub fn item_reference_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
+        self.item_reference_hash
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+
+    pub fn stack_size(&self) -> i32 {
+        self.stack_size
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Requests.Actions.DestinyLoadoutActionRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyLoadoutActionRequest {
+    #[serde(deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    loadout_index: i32,
+    membership_type: BungieMembershipType,
+}
+
+impl DestinyLoadoutActionRequest {
+    pub fn new(
+        loadout_index: i32,
+        character_id: i64,
+        membership_type: BungieMembershipType,
+    ) -> Self {
+        Self {
+            character_id,
+            loadout_index,
+            membership_type,
+        }
+    }
+
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    /// The index of the loadout for this action request.
+    pub fn loadout_index(&self) -> i32 {
+        self.loadout_index
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Requests.Actions.DestinyLoadoutUpdateActionRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyLoadoutUpdateActionRequest {
+    #[serde(deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color_hash: Option<Hash<DestinyLoadoutColorDefinition>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon_hash: Option<Hash<DestinyLoadoutIconDefinition>>,
+    loadout_index: i32,
+    membership_type: BungieMembershipType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name_hash: Option<Hash<DestinyLoadoutNameDefinition>>,
+}
+
+impl DestinyLoadoutUpdateActionRequest {
+    pub fn new(
+        loadout_index: i32,
+        character_id: i64,
+        membership_type: BungieMembershipType,
+    ) -> Self {
+        Self {
+            character_id,
+            color_hash: None,
+            icon_hash: None,
+            loadout_index,
+            membership_type,
+            name_hash: None,
+        }
+    }
+
+    pub fn with_color_hash(mut self, color_hash: Hash<DestinyLoadoutColorDefinition>) -> Self {
+        self.color_hash = Some(color_hash);
+        self
+    }
+
+    pub fn with_icon_hash(mut self, icon_hash: Hash<DestinyLoadoutIconDefinition>) -> Self {
+        self.icon_hash = Some(icon_hash);
+        self
+    }
+
+    pub fn with_name_hash(mut self, name_hash: Hash<DestinyLoadoutNameDefinition>) -> Self {
+        self.name_hash = Some(name_hash);
+        self
+    }
+
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    pub fn color_hash(&self) -> Option<Hash<DestinyLoadoutColorDefinition>> {
+        self.color_hash
+    }
+
+    pub fn icon_hash(&self) -> Option<Hash<DestinyLoadoutIconDefinition>> {
+        self.icon_hash
+    }
+
+    /// The index of the loadout for this action request.
+    pub fn loadout_index(&self) -> i32 {
+        self.loadout_index
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+
+    pub fn name_hash(&self) -> Option<Hash<DestinyLoadoutNameDefinition>> {
+        self.name_hash
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Requests.Actions.DestinyInsertPlugsFreeActionRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyInsertPlugsFreeActionRequest {
+    #[serde(deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    #[serde(deserialize_with = "deserialize_int64")]
+    item_id: i64,
+    membership_type: BungieMembershipType,
+    plug: DestinyInsertPlugsRequestEntry,
+}
+
+impl DestinyInsertPlugsFreeActionRequest {
+    pub fn new(
+        plug: DestinyInsertPlugsRequestEntry,
+        item_id: i64,
+        character_id: i64,
+        membership_type: BungieMembershipType,
+    ) -> Self {
+        Self {
+            character_id,
+            item_id,
+            membership_type,
+            plug,
+        }
+    }
+
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    /// The instance ID of the item receiving the plug.
+    pub fn item_id(&self) -> i64 {
+        self.item_id
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+
+    /// The plugs being inserted.
+    pub fn plug(&self) -> &DestinyInsertPlugsRequestEntry {
+        &self.plug
+    }
+}
+
+/// Represents all of the data related to a single plug to be inserted.
+///
+/// Note that, while you *can* point to a socket that represents infusion, you
+/// will receive an error if you attempt to do so. Come on guys, let's play
+/// nice.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Requests.Actions.DestinyInsertPlugsRequestEntry
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyInsertPlugsRequestEntry {
+    plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+    socket_array_type: DestinySocketArrayType,
+    socket_index: i32,
+}
+
+impl DestinyInsertPlugsRequestEntry {
+    pub fn new(
+        socket_index: i32,
+        socket_array_type: DestinySocketArrayType,
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+    ) -> Self {
+        Self {
+            plug_item_hash,
+            socket_array_type,
+            socket_index,
+        }
+    }
+
+    /// Plugs are never instanced (except in infusion). So with the hash alone,
+    /// we should be able to: 1) Infer whether the player actually needs to
+    /// have the item, or if it's a reusable plug 2) Perform any operation
+    /// needed to use the Plug, including removing the plug item and running
+    /// reward sheets.
+    pub fn plug_item_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
+        self.plug_item_hash
+    }
+
+    /// This property, combined with the socketIndex, tells us which socket we
+    /// are referring to (since operations can be performed on both Intrinsic
+    /// and "default" sockets, and they occupy different arrays in the
+    /// Inventory Item Definition).
+    pub fn socket_array_type(&self) -> DestinySocketArrayType {
+        self.socket_array_type
+    }
+
+    /// The index into the socket array, which identifies the specific socket
+    /// being operated on. We also need to know the socketArrayType in order to
+    /// uniquely identify the socket.
+    pub fn socket_index(&self) -> i32 {
+        self.socket_index
+    }
+}
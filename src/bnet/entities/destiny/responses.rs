@@ -1,9 +1,20 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::bnet::entities::user::UserInfoCard;
+use crate::bnet::entities::{
+    components::ComponentResponse,
+    user::UserInfoCard,
+    wire::{deserialize_int64, deserialize_optional_int64},
+};
 
-use super::components::inventory::DestinyPlatformSilverComponent;
+use super::{
+    components::{
+        inventory::DestinyPlatformSilverComponent, items::DestinyItemReusablePlugsComponent,
+    },
+    entities::items::{
+        DestinyItemComponent, DestinyItemSocketsComponent, DestinyItemStatsComponent,
+    },
+};
 
 /// If a Destiny Profile can't be returned, but we're pretty certain it's a
 /// valid Destiny account, this will contain as much info as we can get about
@@ -34,6 +45,92 @@ impl DestinyErrorProfile {
     }
 }
 
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Responses.DestinyItemChangeResponse
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemChangeResponse {
+    added_inventory_items: Vec<DestinyItemComponent>,
+    item: DestinyItemResponse,
+    removed_inventory_items: Vec<DestinyItemComponent>,
+}
+
+impl DestinyItemChangeResponse {
+    /// Items that appeared in the inventory possibly as a result of an action.
+    pub fn added_inventory_items(&self) -> &[DestinyItemComponent] {
+        self.added_inventory_items.as_ref()
+    }
+
+    pub fn item(&self) -> &DestinyItemResponse {
+        &self.item
+    }
+
+    /// Items that disappeared from the inventory possibly as a result of an
+    /// action.
+    pub fn removed_inventory_items(&self) -> &[DestinyItemComponent] {
+        self.removed_inventory_items.as_ref()
+    }
+}
+
+/// The response object for retrieving an individual instanced item. None of
+/// these components are relevant for an item that doesn't have an
+/// "itemInstanceId": for those, get your information from the
+/// DestinyInventoryDefinition.
+///
+/// Only the components the crate models are kept.
+///
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Responses.DestinyItemResponse
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemResponse {
+    #[serde(default, deserialize_with = "deserialize_optional_int64")]
+    character_id: Option<i64>,
+    item: Option<ComponentResponse<DestinyItemComponent>>,
+    reusable_plugs: Option<ComponentResponse<DestinyItemReusablePlugsComponent>>,
+    sockets: Option<ComponentResponse<DestinyItemSocketsComponent>>,
+    stats: Option<ComponentResponse<DestinyItemStatsComponent>>,
+}
+
+impl DestinyItemResponse {
+    /// If the item is on a character, this will return the ID of the character
+    /// that is holding the item.
+    pub fn character_id(&self) -> Option<i64> {
+        self.character_id
+    }
+
+    /// Common data for the item relevant to its non-instanced properties.
+    ///
+    /// COMPONENT TYPE: ItemCommonData
+    pub fn item(&self) -> Option<&ComponentResponse<DestinyItemComponent>> {
+        self.item.as_ref()
+    }
+
+    /// Information about the Reusable Plugs for sockets on an item. These are
+    /// plugs that you can insert into the given socket regardless of if you
+    /// actually own an instance of that plug: they are logic-driven plugs
+    /// rather than inventory-driven.
+    ///
+    /// COMPONENT TYPE: ItemReusablePlugs
+    pub fn reusable_plugs(&self) -> Option<&ComponentResponse<DestinyItemReusablePlugsComponent>> {
+        self.reusable_plugs.as_ref()
+    }
+
+    /// Information about the sockets of the item: which are currently active,
+    /// what potential sockets you could have and the stats/abilities/perks you
+    /// can gain from them.
+    ///
+    /// COMPONENT TYPE: ItemSockets
+    pub fn sockets(&self) -> Option<&ComponentResponse<DestinyItemSocketsComponent>> {
+        self.sockets.as_ref()
+    }
+
+    /// Information about the computed stats of the item: power, defense, etc...
+    ///
+    /// COMPONENT TYPE: ItemStats
+    pub fn stats(&self) -> Option<&ComponentResponse<DestinyItemStatsComponent>> {
+        self.stats.as_ref()
+    }
+}
+
 /// I know what you seek. You seek linked accounts. Found them, you have.
 ///
 /// This contract returns a minimal amount of data about Destiny Accounts that
@@ -43,9 +140,11 @@ impl DestinyErrorProfile {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyLinkedProfilesResponse {
-    bnet_membership: UserInfoCard,
+    bnet_membership: Option<UserInfoCard>,
+    #[serde(default)]
     profiles: Vec<DestinyProfileUserInfoCard>,
-    profiles_with_errors: DestinyErrorProfile,
+    #[serde(default)]
+    profiles_with_errors: Vec<DestinyErrorProfile>,
 }
 
 impl DestinyLinkedProfilesResponse {
@@ -54,23 +153,23 @@ impl DestinyLinkedProfilesResponse {
     ///
     /// I know, Tetron; I know this is mixing UserServices concerns with
     /// DestinyServices concerns. But it's so damn convenient!
-    pub fn bnet_membership(&self) -> &UserInfoCard {
-        &self.bnet_membership
+    pub fn bnet_membership(&self) -> Option<&UserInfoCard> {
+        self.bnet_membership.as_ref()
     }
 
     /// Any Destiny account for whom we could successfully pull characters will
     /// be returned here, as the Platform-level summary of user data. (no
     /// character data, no Destiny account data other than the Membership ID
     /// and Type so you can make further queries)
-    pub fn profiles(&self) -> &Vec<DestinyProfileUserInfoCard> {
-        &self.profiles
+    pub fn profiles(&self) -> &[DestinyProfileUserInfoCard] {
+        self.profiles.as_ref()
     }
 
     /// This is brief summary info for profiles that we believe have valid
     /// Destiny info, but who failed to return data for some other reason and
     /// thus we know that subsequent calls for their info will also fail.
-    pub fn profiles_with_errors(&self) -> &DestinyErrorProfile {
-        &self.profiles_with_errors
+    pub fn profiles_with_errors(&self) -> &[DestinyErrorProfile] {
+        self.profiles_with_errors.as_ref()
     }
 }
 
@@ -109,19 +208,28 @@ impl DestinyLinkedProfilesResponse {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DestinyProfileUserInfoCard {
+    #[serde(default)]
     applicable_membership_types: Vec<i32>,
+    #[serde(default)]
     bungie_global_display_name: String,
+    #[serde(default)]
     bungie_global_display_name_code: i16,
     cross_save_override: i32,
     date_last_played: DateTime<Utc>,
+    #[serde(default)]
     display_name: String,
+    #[serde(default)]
     icon_path: String,
     is_cross_save_primary: bool,
     is_overridden: bool,
     is_public: bool,
-    membership_type: i64,
-    platform_silver: DestinyPlatformSilverComponent,
+    #[serde(deserialize_with = "deserialize_int64")]
+    membership_id: i64,
+    membership_type: i32,
+    platform_silver: Option<DestinyPlatformSilverComponent>,
+    #[serde(default)]
     supplemental_display_name: String,
+    #[serde(default)]
     unpaired_game_versions: i32,
 }
 
@@ -166,12 +274,18 @@ impl DestinyProfileUserInfoCard {
         self.is_public
     }
 
-    pub fn membership_type(&self) -> i64 {
+    pub fn membership_id(&self) -> i64 {
+        self.membership_id
+    }
+
+    pub fn membership_type(&self) -> i32 {
         self.membership_type
     }
 
-    pub fn platform_silver(&self) -> &DestinyPlatformSilverComponent {
-        &self.platform_silver
+    /// This is only populated on the cross save primary, and only when
+    /// requested.
+    pub fn platform_silver(&self) -> Option<&DestinyPlatformSilverComponent> {
+        self.platform_silver.as_ref()
     }
 
     pub fn supplemental_display_name(&self) -> &str {
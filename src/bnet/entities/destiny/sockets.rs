@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::destiny::{definitions::DestinyInventoryItemDefinition, hashes::Hash};
+
+/// https://bungie-net.github.io/#/components/schemas/Destiny.Sockets.DestinyItemPlugBase
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyItemPlugBase {
+    can_insert: bool,
+    enable_fail_indexes: Option<Vec<i32>>,
+    enabled: bool,
+    insert_fail_indexes: Option<Vec<i32>>,
+    plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+}
+
+impl DestinyItemPlugBase {
+    /// If true, this plug has met all of its insertion requirements. Big if
+    /// true.
+    pub fn can_insert(&self) -> bool {
+        self.can_insert
+    }
+
+    /// If a plug is not enabled, this will be populated with indexes into the
+    /// plug item definition's plug.enabledRules property, so that you can show
+    /// the reasons why it is not enabled.
+    pub fn enable_fail_indexes(&self) -> Option<&Vec<i32>> {
+        self.enable_fail_indexes.as_ref()
+    }
+
+    /// If true, this plug will provide its benefits while inserted.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// If the plug cannot be inserted for some reason, this will have the
+    /// indexes into the plug item definition's plug.insertionRules property,
+    /// so you can show the reasons why it can't be inserted.
+    pub fn insert_fail_indexes(&self) -> Option<&Vec<i32>> {
+        self.insert_fail_indexes.as_ref()
+    }
+
+    /// The hash identifier of the DestinyInventoryItemDefinition that
+    /// represents this plug.
+    pub fn plug_item_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
+        self.plug_item_hash
+    }
+}
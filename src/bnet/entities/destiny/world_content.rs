@@ -0,0 +1,194 @@
+pub mod cache;
+
+use std::{
+    any::Any,
+    borrow::Cow,
+    collections::HashMap,
+    fs::{self, File},
+    ops::{Deref, Range},
+    path::Path,
+    sync::OnceLock,
+};
+
+use memmap2::Mmap;
+use serde::Deserialize;
+use serde_json::value::RawValue;
+
+use crate::Result;
+
+use super::hashes::{Definition, DefinitionStore, Hash};
+
+/// The world content JSON, either read into memory or mapped from its file.
+enum Source {
+    Owned(Vec<u8>),
+    Mapped(Mmap),
+}
+
+impl Deref for Source {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Source::Owned(bytes) => bytes,
+            Source::Mapped(map) => map,
+        }
+    }
+}
+
+/// How the definitions in the source are encoded.
+#[derive(Clone, Copy)]
+enum Encoding {
+    /// The world content JSON.
+    Json,
+    /// A cache, which holds each definition as MessagePack.
+    MessagePack,
+}
+
+/// A table name or hash out of the JSON, borrowed unless it has escapes.
+#[derive(Deserialize, Eq, Hash, PartialEq)]
+struct Key<'a>(#[serde(borrow)] Cow<'a, str>);
+
+/// Where a definition is in the JSON, and the definition once it's been
+/// deserialized, or why it couldn't be.
+struct Entry {
+    range: Range<usize>,
+    definition: OnceLock<std::result::Result<Box<dyn Any + Send + Sync>, String>>,
+}
+
+/// The world content, deserializing definitions only when they're looked up.
+///
+/// Opening it only indexes where every definition is in the JSON, which is a
+/// fraction of the time and memory [`DestinyWorldContent`] takes to build all
+/// of its tables. A definition is deserialized out of the JSON the first time
+/// it's asked for, then kept for later lookups.
+///
+/// Indexing borrows the table names and hashes out of the JSON where it can,
+/// but definitions own their strings, so each one that gets looked up is
+/// copied out of the JSON once.
+///
+/// Every tool takes a [`DefinitionStore`], so this can stand in for
+/// [`DestinyWorldContent`] anywhere.
+///
+/// [`DestinyWorldContent`]: super::DestinyWorldContent
+pub struct LazyWorldContent {
+    source: Source,
+    encoding: Encoding,
+    tables: HashMap<String, HashMap<u32, Entry>>,
+}
+
+impl LazyWorldContent {
+    /// Indexes world content JSON, as downloaded from one of the paths of
+    /// `DestinyManifest::json_world_content_paths`.
+    pub fn from_json(json: Vec<u8>) -> Result<Self> {
+        Self::index(Source::Owned(json))
+    }
+
+    /// Reads and indexes the world content file at the path.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_json(fs::read(path)?)
+    }
+
+    /// Maps the world content file at the path into memory and indexes it,
+    /// leaving it to the OS to page in the parts that get used.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the content is
+    /// alive, as definitions are deserialized out of the map on demand.
+    pub unsafe fn map(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::open(path)?;
+        Self::index(Source::Mapped(Mmap::map(&file)?))
+    }
+
+    fn index(source: Source) -> Result<Self> {
+        let start = source.as_ptr() as usize;
+        let tables: HashMap<Key, HashMap<Key, &RawValue>> = serde_json::from_slice(&source)?;
+        let tables = tables
+            .into_iter()
+            .map(|(name, definitions)| {
+                let entries = definitions
+                    .into_iter()
+                    .filter_map(|(hash, json)| {
+                        let offset = json.get().as_ptr() as usize - start;
+                        let entry = Entry {
+                            range: offset..offset + json.get().len(),
+                            definition: OnceLock::new(),
+                        };
+                        Some((hash.0.parse().ok()?, entry))
+                    })
+                    .collect();
+                (name.0.into_owned(), entries)
+            })
+            .collect();
+
+        Ok(Self {
+            source,
+            encoding: Encoding::Json,
+            tables,
+        })
+    }
+
+    /// Looks up a definition, deserializing it if it's the first time it's
+    /// asked for. None when there's no definition with the hash, and an error
+    /// when there is one but it doesn't deserialize.
+    pub fn try_get<T: Definition>(&self, hash: Hash<T>) -> Result<Option<&T>> {
+        let Some(entry) = self.entry(hash) else {
+            return Ok(None);
+        };
+        let definition = entry.definition.get_or_init(|| {
+            let bytes = &self.source[entry.range.clone()];
+            let definition = match self.encoding {
+                Encoding::Json => serde_json::from_slice::<T>(bytes).map_err(|e| e.to_string()),
+                Encoding::MessagePack => {
+                    rmp_serde::from_slice::<T>(bytes).map_err(|e| e.to_string())
+                }
+            };
+            definition.map(|definition| Box::new(definition) as Box<dyn Any + Send + Sync>)
+        });
+        match definition {
+            Ok(definition) => Ok(definition.downcast_ref()),
+            Err(e) => Err(format!("{} {}: {}", T::TABLE, hash, e).into()),
+        }
+    }
+
+    pub fn contains<T: Definition>(&self, hash: Hash<T>) -> bool {
+        self.entry(hash).is_some()
+    }
+
+    /// The hashes of every definition in `T`'s table, without deserializing
+    /// any of them.
+    pub fn hashes<T: Definition>(&self) -> impl Iterator<Item = Hash<T>> + '_ {
+        self.tables
+            .get(T::TABLE)
+            .into_iter()
+            .flat_map(|table| table.keys().map(|hash| Hash::new(*hash)))
+    }
+
+    /// The JSON of a definition, as it is in the world content. None when
+    /// the content was loaded out of a cache, which doesn't keep the JSON.
+    pub fn json<T: Definition>(&self, hash: Hash<T>) -> Option<&[u8]> {
+        match self.encoding {
+            Encoding::Json => self
+                .entry(hash)
+                .map(|entry| &self.source[entry.range.clone()]),
+            Encoding::MessagePack => None,
+        }
+    }
+
+    fn entry<T: Definition>(&self, hash: Hash<T>) -> Option<&Entry> {
+        self.tables.get(T::TABLE)?.get(&hash.value())
+    }
+}
+
+impl DefinitionStore for LazyWorldContent {
+    fn get<T: Definition>(&self, hash: Hash<T>) -> Option<&T> {
+        self.try_get(hash).ok().flatten()
+    }
+
+    /// Every definition in `T`'s table, deserializing the ones that haven't
+    /// been yet. Definitions that don't deserialize are left out.
+    fn definitions<T: Definition>(&self) -> impl Iterator<Item = (Hash<T>, &T)> + '_ {
+        self.hashes()
+            .filter_map(move |hash| Some((hash, self.try_get(hash).ok()??)))
+    }
+}
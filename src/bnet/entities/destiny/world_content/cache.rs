@@ -0,0 +1,495 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::OnceLock,
+};
+
+use crc32fast::Hasher;
+use memmap2::Mmap;
+use serde::Serialize;
+
+use super::super::{
+    definitions::{
+        activity_modifiers::DestinyActivityModifierDefinition,
+        artifacts::DestinyArtifactDefinition,
+        breaker_types::DestinyBreakerTypeDefinition,
+        checklists::DestinyChecklistDefinition,
+        collectibles::DestinyCollectibleDefinition,
+        director::DestinyActivityGraphDefinition,
+        energy_types::DestinyEnergyTypeDefinition,
+        items::DestinyItemTierTypeDefinition,
+        loadouts::{
+            DestinyLoadoutColorDefinition, DestinyLoadoutConstantsDefinition,
+            DestinyLoadoutIconDefinition, DestinyLoadoutNameDefinition,
+        },
+        lore::DestinyLoreDefinition,
+        metrics::DestinyMetricDefinition,
+        milestones::DestinyMilestoneDefinition,
+        power_caps::DestinyPowerCapDefinition,
+        presentation::DestinyPresentationNodeDefinition,
+        progression::DestinyProgressionLevelRequirementDefinition,
+        records::DestinyRecordDefinition,
+        reporting::DestinyReportReasonCategoryDefinition,
+        seasons::{DestinySeasonDefinition, DestinySeasonPassDefinition},
+        sockets::{
+            DestinyPlugSetDefinition, DestinySocketCategoryDefinition, DestinySocketTypeDefinition,
+        },
+        traits::{DestinyTraitCategoryDefinition, DestinyTraitDefinition},
+        undocumented::{
+            DestinyAchievementDefinition, DestinyActivityInteractableDefinition,
+            DestinyArtDyeChannelDefinition, DestinyBondDefinition,
+            DestinyCharacterCustomizationCategoryDefinition,
+            DestinyCharacterCustomizationOptionDefinition, DestinyEntitlementOfferDefinition,
+            DestinyInventoryItemLiteDefinition, DestinyNodeStepSummaryDefinition,
+            DestinyPlatformBucketMappingDefinition, DestinyRewardAdjusterPointerDefinition,
+            DestinyRewardAdjusterProgressionMapDefinition, DestinyRewardItemListDefinition,
+            DestinyRewardMappingDefinition, DestinyRewardSheetDefinition,
+            DestinySackRewardItemListDefinition, DestinyUnlockCountMappingDefinition,
+            DestinyUnlockEventDefinition, DestinyUnlockExpressionMappingDefinition,
+        },
+        DestinyActivityDefinition, DestinyActivityModeDefinition, DestinyActivityTypeDefinition,
+        DestinyArtDyeReference, DestinyClassDefinition, DestinyDamageTypeDefinition,
+        DestinyDestinationDefinition, DestinyEquipmentSlotDefinition, DestinyFactionDefinition,
+        DestinyGenderDefinition, DestinyInventoryBucketDefinition, DestinyInventoryItemDefinition,
+        DestinyItemCategoryDefinition, DestinyLocationDefinition,
+        DestinyMaterialRequirementSetDefinition, DestinyMedalTierDefinition,
+        DestinyObjectiveDefinition, DestinyPlaceDefinition, DestinyProgressionDefinition,
+        DestinyProgressionMappingDefinition, DestinyRaceDefinition, DestinyRewardSourceDefinition,
+        DestinySandboxPatternDefinition, DestinySandboxPerkDefinition, DestinyStatDefinition,
+        DestinyStatGroupDefinition, DestinyTalentGridDefinition, DestinyUnlockDefinition,
+        DestinyUnlockValueDefinition, DestinyVendorDefinition, DestinyVendorGroupDefinition,
+    },
+    hashes::Definition,
+};
+use super::{Encoding, Entry, LazyWorldContent, Source};
+
+/// Marks the start of a cache file.
+const MAGIC: &[u8; 8] = b"GMTWCACH";
+
+/// The version of the cache layout and of the definitions it holds. Bumped
+/// whenever either changes in a way older caches can't be read with, so they
+/// are rebuilt instead.
+pub const CACHE_SCHEMA_VERSION: u32 = 2;
+
+/// Why a cache couldn't be loaded.
+#[derive(Debug)]
+pub enum CacheError {
+    Io(io::Error),
+    /// The file isn't a world content cache at all.
+    NotACache,
+    /// The cache was written by another version of the crate, or for another
+    /// version of the manifest.
+    Stale {
+        schema_version: u32,
+        manifest_version: String,
+    },
+    /// The cache is truncated, or its contents don't match its checksum.
+    Corrupt {
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheError::Io(e) => write!(f, "couldn't read the cache: {}", e),
+            CacheError::NotACache => write!(f, "not a world content cache"),
+            CacheError::Stale {
+                schema_version,
+                manifest_version,
+            } => write!(
+                f,
+                "the cache is for manifest {} with schema {}",
+                manifest_version, schema_version
+            ),
+            CacheError::Corrupt { reason } => write!(f, "the cache is corrupt: {}", reason),
+        }
+    }
+}
+
+impl Error for CacheError {}
+
+impl From<io::Error> for CacheError {
+    fn from(e: io::Error) -> Self {
+        CacheError::Io(e)
+    }
+}
+
+impl LazyWorldContent {
+    /// Writes the content to a binary cache, stamped with the version of the
+    /// manifest it came from.
+    ///
+    /// The cache holds the index of every table along with each definition
+    /// encoded as MessagePack, so loading it parses nothing until
+    /// definitions are looked up, and those decode faster than their JSON.
+    /// Only tables with a [`Definition`] type are cached. It's laid out as:
+    ///
+    /// - the magic bytes, [`CACHE_SCHEMA_VERSION`] and the manifest version
+    /// - the table count, then for each table its name, its entry count and
+    ///   the hash and length of each entry
+    /// - the MessagePack of every entry, in the same order
+    /// - a CRC-32 of everything before it
+    ///
+    /// Integers are little endian `u32`s, and strings are prefixed with their
+    /// length. Fails with [`io::ErrorKind::InvalidData`] when a definition
+    /// doesn't deserialize.
+    pub fn write_cache(&self, writer: impl Write, manifest_version: &str) -> io::Result<()> {
+        let tables = self.encode_tables()?;
+
+        let mut writer = ChecksumWriter::new(writer);
+        writer.write_all(MAGIC)?;
+        writer.write_u32(CACHE_SCHEMA_VERSION)?;
+        writer.write_str(manifest_version)?;
+
+        writer.write_u32(length(tables.len())?)?;
+        for (name, entries) in &tables {
+            writer.write_str(name)?;
+            writer.write_u32(length(entries.len())?)?;
+            for (hash, bytes) in entries {
+                writer.write_u32(*hash)?;
+                writer.write_u32(length(bytes.len())?)?;
+            }
+        }
+        for (_, entries) in &tables {
+            for (_, bytes) in entries {
+                writer.write_all(bytes)?;
+            }
+        }
+
+        writer.finish()
+    }
+
+    /// The MessagePack of every cached definition, by table, with tables
+    /// sorted by name and entries by hash.
+    fn encode_tables(&self) -> io::Result<Vec<EncodedTable<'_>>> {
+        let mut tables = Vec::new();
+        for (name, entries) in &self.tables {
+            let Some(transcode) = transcoder(name) else {
+                continue;
+            };
+            let mut encoded = Vec::with_capacity(entries.len());
+            for (hash, entry) in entries {
+                let bytes = &self.source[entry.range.clone()];
+                let bytes = match self.encoding {
+                    Encoding::Json => Cow::Owned(transcode(bytes).map_err(|e| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("{} {}: {}", name, hash, e),
+                        )
+                    })?),
+                    Encoding::MessagePack => Cow::Borrowed(bytes),
+                };
+                encoded.push((*hash, bytes));
+            }
+            encoded.sort_unstable_by_key(|(hash, _)| *hash);
+            tables.push((name.as_str(), encoded));
+        }
+        tables.sort_unstable_by_key(|(name, _)| *name);
+        Ok(tables)
+    }
+
+    /// Reads the cache at the path, checking it's intact and was written for
+    /// this manifest version by this version of the crate.
+    pub fn open_cache(
+        path: impl AsRef<Path>,
+        manifest_version: &str,
+    ) -> std::result::Result<Self, CacheError> {
+        Self::load_cache(Source::Owned(fs::read(path)?), manifest_version)
+    }
+
+    /// Maps the cache at the path into memory, with the same checks as
+    /// [`LazyWorldContent::open_cache`].
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the content is
+    /// alive, as definitions are deserialized out of the map on demand.
+    pub unsafe fn map_cache(
+        path: impl AsRef<Path>,
+        manifest_version: &str,
+    ) -> std::result::Result<Self, CacheError> {
+        let file = File::open(path)?;
+        Self::load_cache(Source::Mapped(Mmap::map(&file)?), manifest_version)
+    }
+
+    /// Loads the content out of the cache when it's usable, otherwise out of
+    /// the world content JSON, rewriting the cache from it for the next time.
+    ///
+    /// The new cache is written next to the old one and renamed over it, so
+    /// an interrupted write never leaves a half-written cache behind. The
+    /// cache is only a speed-up: failing to write it doesn't fail the load.
+    pub fn open_cached(
+        json_path: impl AsRef<Path>,
+        cache_path: impl AsRef<Path>,
+        manifest_version: &str,
+    ) -> crate::Result<Self> {
+        let cache_path = cache_path.as_ref();
+        if let Ok(content) = Self::open_cache(cache_path, manifest_version) {
+            return Ok(content);
+        }
+
+        let content = Self::open(json_path)?;
+        let mut partial = cache_path.as_os_str().to_owned();
+        partial.push(".partial");
+        let written = File::create(&partial)
+            .and_then(|file| {
+                let mut writer = BufWriter::new(file);
+                content.write_cache(&mut writer, manifest_version)?;
+                writer.flush()
+            })
+            .and_then(|()| fs::rename(&partial, cache_path));
+        if written.is_err() {
+            let _ = fs::remove_file(&partial);
+        }
+        Ok(content)
+    }
+
+    fn load_cache(source: Source, manifest_version: &str) -> std::result::Result<Self, CacheError> {
+        let mut reader = Reader::new(&source);
+        if reader.take(MAGIC.len()).ok() != Some(&MAGIC[..]) {
+            return Err(CacheError::NotACache);
+        }
+        let schema_version = reader.read_u32()?;
+        let cached_version = reader.read_str()?;
+        if schema_version != CACHE_SCHEMA_VERSION || cached_version != manifest_version {
+            return Err(CacheError::Stale {
+                schema_version,
+                manifest_version: cached_version.to_string(),
+            });
+        }
+
+        let (body, checksum) = source.split_at(source.len().checked_sub(4).ok_or(TRUNCATED)?);
+        if crc32fast::hash(body).to_le_bytes() != checksum {
+            return Err(CacheError::Corrupt {
+                reason: "the checksum doesn't match",
+            });
+        }
+
+        let mut directory = Vec::new();
+        for _ in 0..reader.read_u32()? {
+            let name = reader.read_str()?.to_string();
+            let count = reader.read_u32()?;
+            let mut entries = Vec::new();
+            for _ in 0..count {
+                entries.push((reader.read_u32()?, reader.read_u32()? as usize));
+            }
+            directory.push((name, entries));
+        }
+
+        let mut offset = reader.position;
+        let mut tables = HashMap::new();
+        for (name, entries) in directory {
+            let mut table = HashMap::with_capacity(entries.len());
+            for (hash, len) in entries {
+                let entry = Entry {
+                    range: offset..offset + len,
+                    definition: OnceLock::new(),
+                };
+                offset += len;
+                table.insert(hash, entry);
+            }
+            tables.insert(name, table);
+        }
+        if offset != body.len() {
+            return Err(CacheError::Corrupt {
+                reason: "the entries don't add up to the data",
+            });
+        }
+
+        Ok(Self {
+            source,
+            encoding: Encoding::MessagePack,
+            tables,
+        })
+    }
+}
+
+/// A table's name, and the hash and MessagePack of each of its definitions.
+type EncodedTable<'a> = (&'a str, Vec<(u32, Cow<'a, [u8]>)>);
+
+/// Re-encodes a definition from its JSON to MessagePack.
+type Transcoder = fn(&[u8]) -> std::result::Result<Vec<u8>, String>;
+
+/// Goes through the definition's type rather than transcoding the JSON as is,
+/// so hashes used as map keys are written as the integers they deserialize
+/// from instead of the strings JSON has to use. Structs are written as maps,
+/// as definitions with flattened fields need their field names.
+fn transcode<T: Definition + Serialize>(json: &[u8]) -> std::result::Result<Vec<u8>, String> {
+    let definition: T = serde_json::from_slice(json).map_err(|e| e.to_string())?;
+    rmp_serde::to_vec_named(&definition).map_err(|e| e.to_string())
+}
+
+macro_rules! transcoders {
+    ($($definition:ty),* $(,)?) => {
+        /// The transcoder for the table's definition type, if it has one.
+        fn transcoder(table: &str) -> Option<Transcoder> {
+            $(
+                if table == <$definition as Definition>::TABLE {
+                    return Some(transcode::<$definition>);
+                }
+            )*
+            None
+        }
+    };
+}
+
+transcoders!(
+    DestinyAchievementDefinition,
+    DestinyActivityDefinition,
+    DestinyActivityGraphDefinition,
+    DestinyActivityInteractableDefinition,
+    DestinyActivityModeDefinition,
+    DestinyActivityModifierDefinition,
+    DestinyActivityTypeDefinition,
+    DestinyArtDyeChannelDefinition,
+    DestinyArtDyeReference,
+    DestinyArtifactDefinition,
+    DestinyBondDefinition,
+    DestinyBreakerTypeDefinition,
+    DestinyCharacterCustomizationCategoryDefinition,
+    DestinyCharacterCustomizationOptionDefinition,
+    DestinyChecklistDefinition,
+    DestinyClassDefinition,
+    DestinyCollectibleDefinition,
+    DestinyDamageTypeDefinition,
+    DestinyDestinationDefinition,
+    DestinyEnergyTypeDefinition,
+    DestinyEntitlementOfferDefinition,
+    DestinyEquipmentSlotDefinition,
+    DestinyFactionDefinition,
+    DestinyGenderDefinition,
+    DestinyInventoryBucketDefinition,
+    DestinyInventoryItemDefinition,
+    DestinyInventoryItemLiteDefinition,
+    DestinyItemCategoryDefinition,
+    DestinyItemTierTypeDefinition,
+    DestinyLoadoutColorDefinition,
+    DestinyLoadoutConstantsDefinition,
+    DestinyLoadoutIconDefinition,
+    DestinyLoadoutNameDefinition,
+    DestinyLocationDefinition,
+    DestinyLoreDefinition,
+    DestinyMaterialRequirementSetDefinition,
+    DestinyMedalTierDefinition,
+    DestinyMetricDefinition,
+    DestinyMilestoneDefinition,
+    DestinyNodeStepSummaryDefinition,
+    DestinyObjectiveDefinition,
+    DestinyPlaceDefinition,
+    DestinyPlatformBucketMappingDefinition,
+    DestinyPlugSetDefinition,
+    DestinyPowerCapDefinition,
+    DestinyPresentationNodeDefinition,
+    DestinyProgressionDefinition,
+    DestinyProgressionLevelRequirementDefinition,
+    DestinyProgressionMappingDefinition,
+    DestinyRaceDefinition,
+    DestinyRecordDefinition,
+    DestinyReportReasonCategoryDefinition,
+    DestinyRewardAdjusterPointerDefinition,
+    DestinyRewardAdjusterProgressionMapDefinition,
+    DestinyRewardItemListDefinition,
+    DestinyRewardMappingDefinition,
+    DestinyRewardSheetDefinition,
+    DestinyRewardSourceDefinition,
+    DestinySackRewardItemListDefinition,
+    DestinySandboxPatternDefinition,
+    DestinySandboxPerkDefinition,
+    DestinySeasonDefinition,
+    DestinySeasonPassDefinition,
+    DestinySocketCategoryDefinition,
+    DestinySocketTypeDefinition,
+    DestinyStatDefinition,
+    DestinyStatGroupDefinition,
+    DestinyTalentGridDefinition,
+    DestinyTraitCategoryDefinition,
+    DestinyTraitDefinition,
+    DestinyUnlockCountMappingDefinition,
+    DestinyUnlockDefinition,
+    DestinyUnlockEventDefinition,
+    DestinyUnlockExpressionMappingDefinition,
+    DestinyUnlockValueDefinition,
+    DestinyVendorDefinition,
+    DestinyVendorGroupDefinition,
+);
+
+const TRUNCATED: CacheError = CacheError::Corrupt {
+    reason: "the cache is truncated",
+};
+
+/// A length as it's written to the cache.
+fn length(len: usize) -> io::Result<u32> {
+    u32::try_from(len)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too large to cache"))
+}
+
+/// Reads the cache's integers and strings, failing on truncation.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> std::result::Result<&'a [u8], CacheError> {
+        let end = self.position.checked_add(len).ok_or(TRUNCATED)?;
+        let bytes = self.bytes.get(self.position..end).ok_or(TRUNCATED)?;
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self) -> std::result::Result<u32, CacheError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_str(&mut self) -> std::result::Result<&'a str, CacheError> {
+        let len = self.read_u32()? as usize;
+        std::str::from_utf8(self.take(len)?).map_err(|_| CacheError::Corrupt {
+            reason: "a name isn't UTF-8",
+        })
+    }
+}
+
+/// Writes the cache while keeping the checksum of everything written.
+struct ChecksumWriter<W> {
+    writer: W,
+    hasher: Hasher,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            hasher: Hasher::new(),
+        }
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.hasher.update(bytes);
+        self.writer.write_all(bytes)
+    }
+
+    fn write_u32(&mut self, value: u32) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_str(&mut self, value: &str) -> io::Result<()> {
+        self.write_u32(length(value.len())?)?;
+        self.write_all(value.as_bytes())
+    }
+
+    /// Writes the checksum at the end.
+    fn finish(mut self) -> io::Result<()> {
+        let checksum = self.hasher.finalize();
+        self.writer.write_all(&checksum.to_le_bytes())
+    }
+}
@@ -0,0 +1,361 @@
+// @generated by `cargo xtask codegen` from xtask/openapi.json. Do not edit.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::bnet::entities::{user::UserInfoCard, wire::deserialize_int64, BungieMembershipType};
+
+/// https://bungie-net.github.io/#/components/schemas/Fireteam.FireteamMember
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FireteamMember {
+    bungie_net_user_info: UserInfoCard,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    character_id: i64,
+    date_joined: DateTime<Utc>,
+    destiny_user_info: FireteamUserInfoCard,
+    #[serde(default)]
+    has_microphone: bool,
+    last_platform_invite_attempt_date: DateTime<Utc>,
+    last_platform_invite_attempt_result: FireteamPlatformInviteResult,
+}
+
+impl FireteamMember {
+    pub fn bungie_net_user_info(&self) -> &UserInfoCard {
+        &self.bungie_net_user_info
+    }
+
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    pub fn date_joined(&self) -> DateTime<Utc> {
+        self.date_joined
+    }
+
+    pub fn destiny_user_info(&self) -> &FireteamUserInfoCard {
+        &self.destiny_user_info
+    }
+
+    pub fn has_microphone(&self) -> bool {
+        self.has_microphone
+    }
+
+    pub fn last_platform_invite_attempt_date(&self) -> DateTime<Utc> {
+        self.last_platform_invite_attempt_date
+    }
+
+    pub fn last_platform_invite_attempt_result(&self) -> FireteamPlatformInviteResult {
+        self.last_platform_invite_attempt_result
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Fireteam.FireteamPlatform
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum FireteamPlatform {
+    Playstation4,
+    XboxOne,
+    Blizzard,
+    Steam,
+    Stadia,
+    Egs,
+    /// Any other value, including the API's own Unknown (0).
+    Unknown(u8),
+}
+
+impl From<FireteamPlatform> for u8 {
+    fn from(value: FireteamPlatform) -> Self {
+        match value {
+            FireteamPlatform::Playstation4 => 1,
+            FireteamPlatform::XboxOne => 2,
+            FireteamPlatform::Blizzard => 3,
+            FireteamPlatform::Steam => 4,
+            FireteamPlatform::Stadia => 5,
+            FireteamPlatform::Egs => 6,
+            FireteamPlatform::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<u8> for FireteamPlatform {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => FireteamPlatform::Playstation4,
+            2 => FireteamPlatform::XboxOne,
+            3 => FireteamPlatform::Blizzard,
+            4 => FireteamPlatform::Steam,
+            5 => FireteamPlatform::Stadia,
+            6 => FireteamPlatform::Egs,
+            _ => FireteamPlatform::Unknown(value),
+        }
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Fireteam.FireteamPlatformInviteResult
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum FireteamPlatformInviteResult {
+    None,
+    Success,
+    AlreadyInFireteam,
+    Throttled,
+    ServiceError,
+    /// A value not known of when this was written, kept as it was sent.
+    Unknown(u8),
+}
+
+impl From<FireteamPlatformInviteResult> for u8 {
+    fn from(value: FireteamPlatformInviteResult) -> Self {
+        match value {
+            FireteamPlatformInviteResult::None => 0,
+            FireteamPlatformInviteResult::Success => 1,
+            FireteamPlatformInviteResult::AlreadyInFireteam => 2,
+            FireteamPlatformInviteResult::Throttled => 3,
+            FireteamPlatformInviteResult::ServiceError => 4,
+            FireteamPlatformInviteResult::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<u8> for FireteamPlatformInviteResult {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => FireteamPlatformInviteResult::None,
+            1 => FireteamPlatformInviteResult::Success,
+            2 => FireteamPlatformInviteResult::AlreadyInFireteam,
+            3 => FireteamPlatformInviteResult::Throttled,
+            4 => FireteamPlatformInviteResult::ServiceError,
+            _ => FireteamPlatformInviteResult::Unknown(value),
+        }
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Fireteam.FireteamResponse
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FireteamResponse {
+    #[serde(rename = "Alternates", default)]
+    alternates: Vec<FireteamMember>,
+    #[serde(rename = "Members", default)]
+    members: Vec<FireteamMember>,
+    #[serde(rename = "Summary")]
+    summary: FireteamSummary,
+}
+
+impl FireteamResponse {
+    pub fn alternates(&self) -> &[FireteamMember] {
+        self.alternates.as_ref()
+    }
+
+    pub fn members(&self) -> &[FireteamMember] {
+        self.members.as_ref()
+    }
+
+    pub fn summary(&self) -> &FireteamSummary {
+        &self.summary
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Fireteam.FireteamSummary
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FireteamSummary {
+    #[serde(default)]
+    activity_type: i32,
+    alternate_slot_count: Option<i32>,
+    #[serde(default)]
+    available_alternate_slot_count: i32,
+    #[serde(default)]
+    available_player_slot_count: i32,
+    date_created: DateTime<Utc>,
+    date_modified: Option<DateTime<Utc>>,
+    date_player_modified: DateTime<Utc>,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    fireteam_id: i64,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    group_id: i64,
+    #[serde(default)]
+    is_immediate: bool,
+    #[serde(default)]
+    is_public: bool,
+    #[serde(default)]
+    is_valid: bool,
+    #[serde(default)]
+    locale: String,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    owner_membership_id: i64,
+    platform: FireteamPlatform,
+    #[serde(default)]
+    player_slot_count: i32,
+    scheduled_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    title: String,
+}
+
+impl FireteamSummary {
+    pub fn activity_type(&self) -> i32 {
+        self.activity_type
+    }
+
+    pub fn alternate_slot_count(&self) -> Option<i32> {
+        self.alternate_slot_count
+    }
+
+    pub fn available_alternate_slot_count(&self) -> i32 {
+        self.available_alternate_slot_count
+    }
+
+    pub fn available_player_slot_count(&self) -> i32 {
+        self.available_player_slot_count
+    }
+
+    pub fn date_created(&self) -> DateTime<Utc> {
+        self.date_created
+    }
+
+    pub fn date_modified(&self) -> Option<DateTime<Utc>> {
+        self.date_modified
+    }
+
+    pub fn date_player_modified(&self) -> DateTime<Utc> {
+        self.date_player_modified
+    }
+
+    pub fn fireteam_id(&self) -> i64 {
+        self.fireteam_id
+    }
+
+    pub fn group_id(&self) -> i64 {
+        self.group_id
+    }
+
+    pub fn is_immediate(&self) -> bool {
+        self.is_immediate
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.is_public
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    pub fn locale(&self) -> &str {
+        self.locale.as_ref()
+    }
+
+    pub fn owner_membership_id(&self) -> i64 {
+        self.owner_membership_id
+    }
+
+    pub fn platform(&self) -> FireteamPlatform {
+        self.platform
+    }
+
+    pub fn player_slot_count(&self) -> i32 {
+        self.player_slot_count
+    }
+
+    pub fn scheduled_time(&self) -> Option<DateTime<Utc>> {
+        self.scheduled_time
+    }
+
+    pub fn title(&self) -> &str {
+        self.title.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/Fireteam.FireteamUserInfoCard
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FireteamUserInfoCard {
+    #[serde(default)]
+    applicable_membership_types: Vec<BungieMembershipType>,
+    #[serde(default)]
+    bungie_global_display_name: String,
+    bungie_global_display_name_code: Option<i16>,
+    cross_save_override: BungieMembershipType,
+    #[serde(default)]
+    display_name: String,
+    #[serde(rename = "FireteamDisplayName", default)]
+    fireteam_display_name: String,
+    #[serde(rename = "FireteamMembershipType")]
+    fireteam_membership_type: BungieMembershipType,
+    #[serde(default)]
+    icon_path: String,
+    #[serde(default)]
+    is_public: bool,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    membership_id: i64,
+    membership_type: BungieMembershipType,
+    #[serde(default)]
+    supplemental_display_name: String,
+}
+
+impl FireteamUserInfoCard {
+    /// The list of Membership Types indicating the platforms on which this
+    /// Membership can be used. Not in Cross Save = its original membership
+    /// type. Cross Save Primary = Any membership types it is overridding, and
+    /// its original membership type Cross Save Overridden = Empty list
+    pub fn applicable_membership_types(&self) -> &[BungieMembershipType] {
+        self.applicable_membership_types.as_ref()
+    }
+
+    /// The bungie global display name, if set.
+    pub fn bungie_global_display_name(&self) -> &str {
+        self.bungie_global_display_name.as_ref()
+    }
+
+    /// The bungie global display name code, if set.
+    pub fn bungie_global_display_name_code(&self) -> Option<i16> {
+        self.bungie_global_display_name_code
+    }
+
+    /// If there is a cross save override in effect, this value will tell you
+    /// the type that is overridding this one.
+    pub fn cross_save_override(&self) -> BungieMembershipType {
+        self.cross_save_override
+    }
+
+    /// Display Name the player has chosen for themselves. The display name is
+    /// optional when the data type is used as input to a platform API.
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_ref()
+    }
+
+    pub fn fireteam_display_name(&self) -> &str {
+        self.fireteam_display_name.as_ref()
+    }
+
+    pub fn fireteam_membership_type(&self) -> BungieMembershipType {
+        self.fireteam_membership_type
+    }
+
+    /// URL the Icon if available.
+    pub fn icon_path(&self) -> &str {
+        self.icon_path.as_ref()
+    }
+
+    /// If True, this is a public user membership.
+    pub fn is_public(&self) -> bool {
+        self.is_public
+    }
+
+    /// Membership ID as they user is known in the Accounts service
+    pub fn membership_id(&self) -> i64 {
+        self.membership_id
+    }
+
+    /// Type of the membership. Not necessarily the native type.
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+
+    /// A platform specific additional display name - ex: psn Real Name, bnet
+    /// Unique Name, etc.
+    pub fn supplemental_display_name(&self) -> &str {
+        self.supplemental_display_name.as_ref()
+    }
+}
@@ -0,0 +1,675 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    queries::SearchResult,
+    user::{UserInfoCard, UserMembership},
+    wire::{deserialize_int64, deserialize_int64s, deserialize_optional_int64},
+    BungieMembershipType,
+};
+
+/// The clan's banner, as the ids of the pieces it's made of.
+///
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.ClanBanner
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClanBanner {
+    decal_background_color_id: u32,
+    decal_color_id: u32,
+    decal_id: u32,
+    gonfalon_color_id: u32,
+    gonfalon_detail_color_id: u32,
+    gonfalon_detail_id: u32,
+    gonfalon_id: u32,
+}
+
+impl ClanBanner {
+    pub fn decal_background_color_id(&self) -> u32 {
+        self.decal_background_color_id
+    }
+
+    pub fn decal_color_id(&self) -> u32 {
+        self.decal_color_id
+    }
+
+    pub fn decal_id(&self) -> u32 {
+        self.decal_id
+    }
+
+    pub fn gonfalon_color_id(&self) -> u32 {
+        self.gonfalon_color_id
+    }
+
+    pub fn gonfalon_detail_color_id(&self) -> u32 {
+        self.gonfalon_detail_color_id
+    }
+
+    pub fn gonfalon_detail_id(&self) -> u32 {
+        self.gonfalon_detail_id
+    }
+
+    pub fn gonfalon_id(&self) -> u32 {
+        self.gonfalon_id
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GetGroupsForMemberResponse
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetGroupsForMemberResponse {
+    are_all_memberships_inactive: HashMap<String, bool>,
+    #[serde(flatten)]
+    search: SearchResult<GroupMembership>,
+}
+
+impl GetGroupsForMemberResponse {
+    /// A convenience property that indicates if every membership this user
+    /// has that is a part of this group are part of an account that is
+    /// considered inactive - for example, overridden accounts in Cross Save.
+    ///
+    /// The key is the Group ID for the group being checked, and the value is
+    /// true if the users' memberships for that group are all inactive.
+    pub fn are_all_memberships_inactive(&self) -> &HashMap<String, bool> {
+        &self.are_all_memberships_inactive
+    }
+
+    /// The groups the member belongs to, with its membership of each.
+    pub fn search(&self) -> &SearchResult<GroupMembership> {
+        &self.search
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupApplicationListRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupApplicationListRequest {
+    memberships: Vec<UserMembership>,
+    message: String,
+}
+
+impl GroupApplicationListRequest {
+    pub fn new(memberships: Vec<UserMembership>, message: impl Into<String>) -> Self {
+        Self {
+            memberships,
+            message: message.into(),
+        }
+    }
+
+    pub fn memberships(&self) -> &[UserMembership] {
+        self.memberships.as_ref()
+    }
+
+    pub fn message(&self) -> &str {
+        self.message.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupApplicationRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupApplicationRequest {
+    message: String,
+}
+
+impl GroupApplicationRequest {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        self.message.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupBanRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupBanRequest {
+    comment: String,
+    length: i32,
+}
+
+impl GroupBanRequest {
+    /// Bans for a length of time, as an IgnoreLength value.
+    pub fn new(comment: impl Into<String>, length: i32) -> Self {
+        Self {
+            comment: comment.into(),
+            length,
+        }
+    }
+
+    pub fn comment(&self) -> &str {
+        self.comment.as_ref()
+    }
+
+    pub fn length(&self) -> i32 {
+        self.length
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupFeatures
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupFeatures {
+    capabilities: i32,
+    host_guided_game_permission_override: i32,
+    invite_permission_override: bool,
+    join_level: RuntimeGroupMemberType,
+    maximum_members: i32,
+    maximum_memberships_of_group_type: i32,
+    membership_types: Vec<BungieMembershipType>,
+    update_banner_permission_override: bool,
+    update_culture_permission_override: bool,
+}
+
+impl GroupFeatures {
+    /// The Capabilities flags enabled for the group.
+    pub fn capabilities(&self) -> i32 {
+        self.capabilities
+    }
+
+    /// Minimum Member Level allowed to host guided games, as a
+    /// HostGuidedGamesPermissionLevel value.
+    pub fn host_guided_game_permission_override(&self) -> i32 {
+        self.host_guided_game_permission_override
+    }
+
+    /// When true, admins can invite members. When false, only the founder can
+    /// invite.
+    pub fn invite_permission_override(&self) -> bool {
+        self.invite_permission_override
+    }
+
+    /// Level to join a member at when accepting an invite, application, or
+    /// joining an open clan.
+    pub fn join_level(&self) -> RuntimeGroupMemberType {
+        self.join_level
+    }
+
+    pub fn maximum_members(&self) -> i32 {
+        self.maximum_members
+    }
+
+    /// Maximum number of groups of this type a typical membership may join.
+    pub fn maximum_memberships_of_group_type(&self) -> i32 {
+        self.maximum_memberships_of_group_type
+    }
+
+    pub fn membership_types(&self) -> &[BungieMembershipType] {
+        self.membership_types.as_ref()
+    }
+
+    /// When true, admins can update the banner. When false, only the founder
+    /// can.
+    pub fn update_banner_permission_override(&self) -> bool {
+        self.update_banner_permission_override
+    }
+
+    /// When true, admins can update the group's culture. When false, only the
+    /// founder can.
+    pub fn update_culture_permission_override(&self) -> bool {
+        self.update_culture_permission_override
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupMember
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMember {
+    bungie_net_user_info: Option<UserInfoCard>,
+    destiny_user_info: GroupUserInfoCard,
+    #[serde(deserialize_with = "deserialize_int64")]
+    group_id: i64,
+    is_online: bool,
+    join_date: DateTime<Utc>,
+    #[serde(deserialize_with = "deserialize_int64")]
+    last_online_status_change: i64,
+    member_type: RuntimeGroupMemberType,
+}
+
+impl GroupMember {
+    pub fn bungie_net_user_info(&self) -> Option<&UserInfoCard> {
+        self.bungie_net_user_info.as_ref()
+    }
+
+    pub fn destiny_user_info(&self) -> &GroupUserInfoCard {
+        &self.destiny_user_info
+    }
+
+    pub fn group_id(&self) -> i64 {
+        self.group_id
+    }
+
+    pub fn is_online(&self) -> bool {
+        self.is_online
+    }
+
+    pub fn join_date(&self) -> DateTime<Utc> {
+        self.join_date
+    }
+
+    /// When the member last came online or went offline, in seconds since
+    /// the Unix epoch.
+    pub fn last_online_status_change(&self) -> i64 {
+        self.last_online_status_change
+    }
+
+    /// The member's rank.
+    pub fn member_type(&self) -> RuntimeGroupMemberType {
+        self.member_type
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupMemberApplication
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMemberApplication {
+    bungie_net_user_info: Option<UserInfoCard>,
+    creation_date: DateTime<Utc>,
+    destiny_user_info: GroupUserInfoCard,
+    #[serde(deserialize_with = "deserialize_int64")]
+    group_id: i64,
+    request_message: Option<String>,
+    resolve_date: Option<DateTime<Utc>>,
+    resolve_message: Option<String>,
+    resolve_state: i32,
+    #[serde(default, deserialize_with = "deserialize_optional_int64")]
+    resolved_by_membership_id: Option<i64>,
+}
+
+impl GroupMemberApplication {
+    pub fn bungie_net_user_info(&self) -> Option<&UserInfoCard> {
+        self.bungie_net_user_info.as_ref()
+    }
+
+    pub fn creation_date(&self) -> DateTime<Utc> {
+        self.creation_date
+    }
+
+    pub fn destiny_user_info(&self) -> &GroupUserInfoCard {
+        &self.destiny_user_info
+    }
+
+    pub fn group_id(&self) -> i64 {
+        self.group_id
+    }
+
+    pub fn request_message(&self) -> Option<&String> {
+        self.request_message.as_ref()
+    }
+
+    pub fn resolve_date(&self) -> Option<DateTime<Utc>> {
+        self.resolve_date
+    }
+
+    pub fn resolve_message(&self) -> Option<&String> {
+        self.resolve_message.as_ref()
+    }
+
+    /// As a GroupApplicationResolveState value.
+    pub fn resolve_state(&self) -> i32 {
+        self.resolve_state
+    }
+
+    pub fn resolved_by_membership_id(&self) -> Option<i64> {
+        self.resolved_by_membership_id
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupMemberLeaveResult
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMemberLeaveResult {
+    group: GroupV2,
+    group_deleted: bool,
+}
+
+impl GroupMemberLeaveResult {
+    pub fn group(&self) -> &GroupV2 {
+        &self.group
+    }
+
+    pub fn group_deleted(&self) -> bool {
+        self.group_deleted
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupMembership
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMembership {
+    group: GroupV2,
+    member: GroupMember,
+}
+
+impl GroupMembership {
+    pub fn group(&self) -> &GroupV2 {
+        &self.group
+    }
+
+    pub fn member(&self) -> &GroupMember {
+        &self.member
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupResponse
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupResponse {
+    alliance_status: i32,
+    #[serde(default, deserialize_with = "deserialize_int64s")]
+    allied_ids: Vec<i64>,
+    current_user_member_map: Option<HashMap<String, GroupMember>>,
+    current_user_memberships_inactive_for_destiny: bool,
+    detail: GroupV2,
+    founder: GroupMember,
+    group_join_invite_count: i32,
+    parent_group: Option<GroupV2>,
+}
+
+impl GroupResponse {
+    /// As a GroupAllianceStatus value.
+    pub fn alliance_status(&self) -> i32 {
+        self.alliance_status
+    }
+
+    pub fn allied_ids(&self) -> &[i64] {
+        self.allied_ids.as_ref()
+    }
+
+    /// This property will be populated if the authenticated user is a member
+    /// of the group. Note that because of account linking, a user can
+    /// sometimes be part of a clan more than once. As such, this returns the
+    /// highest member type available.
+    pub fn current_user_member_map(&self) -> Option<&HashMap<String, GroupMember>> {
+        self.current_user_member_map.as_ref()
+    }
+
+    /// A convenience property that indicates if every membership you (the
+    /// current user) have that is a part of this group are part of an account
+    /// that is considered inactive - for example, overridden accounts in Cross
+    /// Save.
+    pub fn current_user_memberships_inactive_for_destiny(&self) -> bool {
+        self.current_user_memberships_inactive_for_destiny
+    }
+
+    pub fn detail(&self) -> &GroupV2 {
+        &self.detail
+    }
+
+    pub fn founder(&self) -> &GroupMember {
+        &self.founder
+    }
+
+    pub fn group_join_invite_count(&self) -> i32 {
+        self.group_join_invite_count
+    }
+
+    pub fn parent_group(&self) -> Option<&GroupV2> {
+        self.parent_group.as_ref()
+    }
+}
+
+/// A UserInfoCard, along with the last display name the group saw.
+///
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupUserInfoCard
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupUserInfoCard {
+    #[serde(rename = "LastSeenDisplayName")]
+    last_seen_display_name: Option<String>,
+    #[serde(rename = "LastSeenDisplayNameType")]
+    last_seen_display_name_type: Option<i32>,
+    #[serde(flatten)]
+    user_info: UserInfoCard,
+}
+
+impl GroupUserInfoCard {
+    /// This will be the display name the clan server last saw the user as. If
+    /// the account is an active cross save override, this will be the display
+    /// name to use. Otherwise, this will match the displayName property.
+    pub fn last_seen_display_name(&self) -> Option<&String> {
+        self.last_seen_display_name.as_ref()
+    }
+
+    /// The platform of the LastSeenDisplayName
+    pub fn last_seen_display_name_type(&self) -> Option<i32> {
+        self.last_seen_display_name_type
+    }
+
+    pub fn user_info(&self) -> &UserInfoCard {
+        &self.user_info
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupV2
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupV2 {
+    about: String,
+    allow_chat: bool,
+    avatar_image_index: i32,
+    avatar_path: Option<String>,
+    ban_expire_date: Option<DateTime<Utc>>,
+    banner_path: Option<String>,
+    chat_security: i32,
+    clan_info: Option<GroupV2ClanInfo>,
+    #[serde(deserialize_with = "deserialize_int64")]
+    conversation_id: i64,
+    creation_date: DateTime<Utc>,
+    default_publicity: i32,
+    enable_invitation_messaging_for_admins: bool,
+    features: GroupFeatures,
+    #[serde(deserialize_with = "deserialize_int64")]
+    group_id: i64,
+    group_type: i32,
+    homepage: i32,
+    is_default_post_public: bool,
+    is_public: bool,
+    is_public_topic_admin_only: bool,
+    locale: String,
+    member_count: i32,
+    #[serde(deserialize_with = "deserialize_int64")]
+    membership_id_created: i64,
+    membership_option: i32,
+    modification_date: DateTime<Utc>,
+    motto: String,
+    name: String,
+    #[serde(default, deserialize_with = "deserialize_optional_int64")]
+    remote_group_id: Option<i64>,
+    #[serde(default)]
+    tags: Vec<String>,
+    theme: String,
+}
+
+impl GroupV2 {
+    pub fn about(&self) -> &str {
+        self.about.as_ref()
+    }
+
+    pub fn allow_chat(&self) -> bool {
+        self.allow_chat
+    }
+
+    pub fn avatar_image_index(&self) -> i32 {
+        self.avatar_image_index
+    }
+
+    pub fn avatar_path(&self) -> Option<&String> {
+        self.avatar_path.as_ref()
+    }
+
+    pub fn ban_expire_date(&self) -> Option<DateTime<Utc>> {
+        self.ban_expire_date
+    }
+
+    pub fn banner_path(&self) -> Option<&String> {
+        self.banner_path.as_ref()
+    }
+
+    /// As a ChatSecuritySetting value.
+    pub fn chat_security(&self) -> i32 {
+        self.chat_security
+    }
+
+    /// The clan's callsign and banner, for groups that are clans.
+    pub fn clan_info(&self) -> Option<&GroupV2ClanInfo> {
+        self.clan_info.as_ref()
+    }
+
+    pub fn conversation_id(&self) -> i64 {
+        self.conversation_id
+    }
+
+    pub fn creation_date(&self) -> DateTime<Utc> {
+        self.creation_date
+    }
+
+    pub fn default_publicity(&self) -> i32 {
+        self.default_publicity
+    }
+
+    pub fn enable_invitation_messaging_for_admins(&self) -> bool {
+        self.enable_invitation_messaging_for_admins
+    }
+
+    pub fn features(&self) -> &GroupFeatures {
+        &self.features
+    }
+
+    pub fn group_id(&self) -> i64 {
+        self.group_id
+    }
+
+    /// As a GroupType value.
+    pub fn group_type(&self) -> i32 {
+        self.group_type
+    }
+
+    /// As a GroupHomepage value.
+    pub fn homepage(&self) -> i32 {
+        self.homepage
+    }
+
+    pub fn is_default_post_public(&self) -> bool {
+        self.is_default_post_public
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.is_public
+    }
+
+    pub fn is_public_topic_admin_only(&self) -> bool {
+        self.is_public_topic_admin_only
+    }
+
+    pub fn locale(&self) -> &str {
+        self.locale.as_ref()
+    }
+
+    pub fn member_count(&self) -> i32 {
+        self.member_count
+    }
+
+    pub fn membership_id_created(&self) -> i64 {
+        self.membership_id_created
+    }
+
+    /// As a MembershipOption value.
+    pub fn membership_option(&self) -> i32 {
+        self.membership_option
+    }
+
+    pub fn modification_date(&self) -> DateTime<Utc> {
+        self.modification_date
+    }
+
+    pub fn motto(&self) -> &str {
+        self.motto.as_ref()
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn remote_group_id(&self) -> Option<i64> {
+        self.remote_group_id
+    }
+
+    pub fn tags(&self) -> &[String] {
+        self.tags.as_ref()
+    }
+
+    pub fn theme(&self) -> &str {
+        self.theme.as_ref()
+    }
+}
+
+/// This contract contains clan-specific group information. It does not
+/// include any investment data.
+///
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.GroupV2ClanInfo
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupV2ClanInfo {
+    clan_banner_data: Option<ClanBanner>,
+    clan_callsign: String,
+}
+
+impl GroupV2ClanInfo {
+    pub fn clan_banner_data(&self) -> Option<&ClanBanner> {
+        self.clan_banner_data.as_ref()
+    }
+
+    pub fn clan_callsign(&self) -> &str {
+        self.clan_callsign.as_ref()
+    }
+}
+
+/// A member's rank in a group.
+///
+/// https://bungie-net.github.io/#/components/schemas/GroupsV2.RuntimeGroupMemberType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum RuntimeGroupMemberType {
+    None,
+    Beginner,
+    Member,
+    Admin,
+    ActingFounder,
+    Founder,
+    /// A rank added after this was written, kept as it was sent.
+    Unknown(i32),
+}
+
+impl From<RuntimeGroupMemberType> for i32 {
+    fn from(value: RuntimeGroupMemberType) -> Self {
+        match value {
+            RuntimeGroupMemberType::None => 0,
+            RuntimeGroupMemberType::Beginner => 1,
+            RuntimeGroupMemberType::Member => 2,
+            RuntimeGroupMemberType::Admin => 3,
+            RuntimeGroupMemberType::ActingFounder => 4,
+            RuntimeGroupMemberType::Founder => 5,
+            RuntimeGroupMemberType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for RuntimeGroupMemberType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => RuntimeGroupMemberType::None,
+            1 => RuntimeGroupMemberType::Beginner,
+            2 => RuntimeGroupMemberType::Member,
+            3 => RuntimeGroupMemberType::Admin,
+            4 => RuntimeGroupMemberType::ActingFounder,
+            5 => RuntimeGroupMemberType::Founder,
+            _ => RuntimeGroupMemberType::Unknown(value),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+/// https://bungie-net.github.io/#/components/schemas/Queries.PagedQuery
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PagedQuery {
+    current_page: i32,
+    items_per_page: i32,
+    request_continuation_token: Option<String>,
+}
+
+impl PagedQuery {
+    pub fn current_page(&self) -> i32 {
+        self.current_page
+    }
+
+    pub fn items_per_page(&self) -> i32 {
+        self.items_per_page
+    }
+
+    pub fn request_continuation_token(&self) -> Option<&String> {
+        self.request_continuation_token.as_ref()
+    }
+}
+
+/// The shape shared by the API's SearchResultOf* contracts, one page of
+/// results out of a larger set.
+///
+/// https://bungie-net.github.io/#/components/schemas/SearchResult
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult<T> {
+    has_more: bool,
+    query: PagedQuery,
+    replacement_continuation_token: Option<String>,
+    #[serde(default = "Vec::new")]
+    results: Vec<T>,
+    total_results: i32,
+    use_total_results: bool,
+}
+
+impl<T> SearchResult<T> {
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    pub fn query(&self) -> &PagedQuery {
+        &self.query
+    }
+
+    pub fn replacement_continuation_token(&self) -> Option<&String> {
+        self.replacement_continuation_token.as_ref()
+    }
+
+    pub fn results(&self) -> &[T] {
+        self.results.as_ref()
+    }
+
+    pub fn into_results(self) -> Vec<T> {
+        self.results
+    }
+
+    pub fn total_results(&self) -> i32 {
+        self.total_results
+    }
+
+    /// If useTotalResults is true, then totalResults represents an accurate
+    /// count. If False, it does not, and may be estimated/only the size of the
+    /// current page. Either way, you should probably always only trust
+    /// hasMore. This is a long-held historical throwback to when we used to do
+    /// paging with known total results. Those queries toasted our database,
+    /// and we were left to hastily alter our endpoints and create backward-
+    /// compatible shims, of which useTotalResults is one.
+    pub fn use_total_results(&self) -> bool {
+        self.use_total_results
+    }
+}
@@ -1,5 +1,110 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::{
+    groups_v2::GroupUserInfoCard,
+    wire::{deserialize_int64, deserialize_optional_int64},
+};
+
+/// https://bungie-net.github.io/#/components/schemas/User.ExactSearchRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExactSearchRequest {
+    display_name: String,
+    display_name_code: i16,
+}
+
+impl ExactSearchRequest {
+    pub fn new(display_name: impl Into<String>, display_name_code: i16) -> Self {
+        Self {
+            display_name: display_name.into(),
+            display_name_code,
+        }
+    }
+
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_ref()
+    }
+
+    pub fn display_name_code(&self) -> i16 {
+        self.display_name_code
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/User.GeneralUser
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneralUser {
+    about: Option<String>,
+    cached_bungie_global_display_name: Option<String>,
+    cached_bungie_global_display_name_code: Option<i16>,
+    display_name: Option<String>,
+    first_access: Option<DateTime<Utc>>,
+    is_deleted: bool,
+    last_update: Option<DateTime<Utc>>,
+    locale: Option<String>,
+    #[serde(deserialize_with = "deserialize_int64")]
+    membership_id: i64,
+    profile_picture_path: Option<String>,
+    show_activity: Option<bool>,
+    status_text: Option<String>,
+    unique_name: Option<String>,
+}
+
+impl GeneralUser {
+    pub fn about(&self) -> Option<&String> {
+        self.about.as_ref()
+    }
+
+    pub fn cached_bungie_global_display_name(&self) -> Option<&String> {
+        self.cached_bungie_global_display_name.as_ref()
+    }
+
+    pub fn cached_bungie_global_display_name_code(&self) -> Option<i16> {
+        self.cached_bungie_global_display_name_code
+    }
+
+    pub fn display_name(&self) -> Option<&String> {
+        self.display_name.as_ref()
+    }
+
+    pub fn first_access(&self) -> Option<DateTime<Utc>> {
+        self.first_access
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.is_deleted
+    }
+
+    pub fn last_update(&self) -> Option<DateTime<Utc>> {
+        self.last_update
+    }
+
+    pub fn locale(&self) -> Option<&String> {
+        self.locale.as_ref()
+    }
+
+    pub fn membership_id(&self) -> i64 {
+        self.membership_id
+    }
+
+    pub fn profile_picture_path(&self) -> Option<&String> {
+        self.profile_picture_path.as_ref()
+    }
+
+    pub fn show_activity(&self) -> Option<bool> {
+        self.show_activity
+    }
+
+    pub fn status_text(&self) -> Option<&String> {
+        self.status_text.as_ref()
+    }
+
+    pub fn unique_name(&self) -> Option<&String> {
+        self.unique_name.as_ref()
+    }
+}
+
 /// This contract supplies basic information commonly used to display a minimal
 /// amount of information about a user. Take care to not add more properties
 /// here unless the property applies in all (or at least the majority) of the
@@ -11,15 +116,22 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserInfoCard {
+    #[serde(default)]
     applicable_membership_types: Vec<i32>,
+    #[serde(default)]
     bungie_global_display_name: String,
+    #[serde(default)]
     bungie_global_display_name_code: i16,
     cross_save_override: i32,
+    #[serde(default)]
     display_name: String,
+    #[serde(default)]
     icon_path: String,
     is_public: bool,
+    #[serde(deserialize_with = "deserialize_int64")]
     membership_id: i64,
     membership_type: i32,
+    #[serde(default)]
     supplemental_display_name: String,
 }
 
@@ -78,3 +190,157 @@ impl UserInfoCard {
         self.supplemental_display_name.as_ref()
     }
 }
+
+/// https://bungie-net.github.io/#/components/schemas/User.UserMembership
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserMembership {
+    bungie_global_display_name: Option<String>,
+    bungie_global_display_name_code: Option<i16>,
+    display_name: String,
+    #[serde(deserialize_with = "deserialize_int64")]
+    membership_id: i64,
+    membership_type: i32,
+}
+
+impl UserMembership {
+    pub fn new(membership_type: i32, membership_id: i64) -> Self {
+        Self {
+            bungie_global_display_name: None,
+            bungie_global_display_name_code: None,
+            display_name: String::new(),
+            membership_id,
+            membership_type,
+        }
+    }
+
+    pub fn bungie_global_display_name(&self) -> Option<&String> {
+        self.bungie_global_display_name.as_ref()
+    }
+
+    pub fn bungie_global_display_name_code(&self) -> Option<i16> {
+        self.bungie_global_display_name_code
+    }
+
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_ref()
+    }
+
+    pub fn membership_id(&self) -> i64 {
+        self.membership_id
+    }
+
+    pub fn membership_type(&self) -> i32 {
+        self.membership_type
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/User.UserMembershipData
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserMembershipData {
+    bungie_net_user: GeneralUser,
+    #[serde(default)]
+    destiny_memberships: Vec<GroupUserInfoCard>,
+    #[serde(default, deserialize_with = "deserialize_optional_int64")]
+    primary_membership_id: Option<i64>,
+}
+
+impl UserMembershipData {
+    pub fn bungie_net_user(&self) -> &GeneralUser {
+        &self.bungie_net_user
+    }
+
+    /// this allows you to see destiny memberships that are visible and linked
+    /// to this account (regardless of whether or not they have characters on
+    /// the world server)
+    pub fn destiny_memberships(&self) -> &[GroupUserInfoCard] {
+        self.destiny_memberships.as_ref()
+    }
+
+    /// If this property is populated, it will have the membership ID of the
+    /// account considered to be "primary" in this user's cross save
+    /// relationship.
+    ///
+    /// If null, this user has no cross save relationship, nor primary account.
+    pub fn primary_membership_id(&self) -> Option<i64> {
+        self.primary_membership_id
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/User.UserSearchPrefixRequest
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSearchPrefixRequest {
+    display_name_prefix: String,
+}
+
+impl UserSearchPrefixRequest {
+    pub fn new(display_name_prefix: impl Into<String>) -> Self {
+        Self {
+            display_name_prefix: display_name_prefix.into(),
+        }
+    }
+
+    pub fn display_name_prefix(&self) -> &str {
+        self.display_name_prefix.as_ref()
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/User.UserSearchResponse
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSearchResponse {
+    has_more: bool,
+    page: i32,
+    #[serde(default)]
+    search_results: Vec<UserSearchResponseDetail>,
+}
+
+impl UserSearchResponse {
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    pub fn page(&self) -> i32 {
+        self.page
+    }
+
+    pub fn search_results(&self) -> &[UserSearchResponseDetail] {
+        self.search_results.as_ref()
+    }
+
+    pub fn into_search_results(self) -> Vec<UserSearchResponseDetail> {
+        self.search_results
+    }
+}
+
+/// https://bungie-net.github.io/#/components/schemas/User.UserSearchResponseDetail
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSearchResponseDetail {
+    bungie_global_display_name: String,
+    bungie_global_display_name_code: Option<i16>,
+    #[serde(default, deserialize_with = "deserialize_optional_int64")]
+    bungie_net_membership_id: Option<i64>,
+    #[serde(default)]
+    destiny_memberships: Vec<UserInfoCard>,
+}
+
+impl UserSearchResponseDetail {
+    pub fn bungie_global_display_name(&self) -> &str {
+        self.bungie_global_display_name.as_ref()
+    }
+
+    pub fn bungie_global_display_name_code(&self) -> Option<i16> {
+        self.bungie_global_display_name_code
+    }
+
+    pub fn bungie_net_membership_id(&self) -> Option<i64> {
+        self.bungie_net_membership_id
+    }
+
+    pub fn destiny_memberships(&self) -> &[UserInfoCard] {
+        self.destiny_memberships.as_ref()
+    }
+}
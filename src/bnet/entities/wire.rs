@@ -0,0 +1,124 @@
+//! Serde support for the enums the API sends as their integer value, which
+//! the manifest also uses by name as the keys of some maps, such as a record
+//! title's `titlesByGender`, and for the int64 ids it sends as strings.
+
+use std::{collections::HashMap, fmt, marker::PhantomData, str::FromStr};
+
+use serde::{
+    de::{self, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Deserializes an enum from its integer value, or from its name or value as
+/// a string, which is what map keys are.
+pub(crate) fn deserialize_enum<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<i32> + FromStr,
+{
+    deserializer.deserialize_any(EnumVisitor(PhantomData))
+}
+
+/// Serializes a map keyed by an enum with the names of the keys, as the
+/// manifest has them, rather than their integer values.
+pub(crate) fn serialize_keys_by_name<S, K, V>(
+    map: &Option<HashMap<K, V>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: fmt::Display,
+    V: Serialize,
+{
+    match map {
+        Some(map) => serializer.collect_map(map.iter().map(|(k, v)| (k.to_string(), v))),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes an int64, which the API sends as a string so JavaScript
+/// doesn't lose precision, from a string or a number.
+pub(crate) fn deserialize_int64<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<i64, D::Error> {
+    Int64::deserialize(deserializer).map(|Int64(value)| value)
+}
+
+/// Deserializes an optional int64 from a string, a number or null. Fields
+/// using it need `#[serde(default)]` too, to stay optional.
+pub(crate) fn deserialize_optional_int64<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<i64>, D::Error> {
+    Option::<Int64>::deserialize(deserializer).map(|value| value.map(|Int64(value)| value))
+}
+
+/// Deserializes a list of int64s, each a string or a number.
+pub(crate) fn deserialize_int64s<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<i64>, D::Error> {
+    Vec::<Int64>::deserialize(deserializer)
+        .map(|values| values.into_iter().map(|Int64(value)| value).collect())
+}
+
+struct Int64(i64);
+
+impl<'de> Deserialize<'de> for Int64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(Int64Visitor).map(Int64)
+    }
+}
+
+struct Int64Visitor;
+
+impl<'de> Visitor<'de> for Int64Visitor {
+    type Value = i64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an int64, as a string or a number")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<i64, E> {
+        Ok(value)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<i64, E> {
+        i64::try_from(value).map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<i64, E> {
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+    }
+}
+
+struct EnumVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for EnumVisitor<T>
+where
+    T: From<i32> + FromStr,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an enum value, as an integer or by name")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        i32::try_from(value)
+            .map(T::from)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        i32::try_from(value)
+            .map(T::from)
+            .map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+    }
+}
@@ -1,4 +1,5 @@
 pub mod bnet;
+pub mod tools;
 
 /// Result type shared across all modules
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
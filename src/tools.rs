@@ -0,0 +1,16 @@
+pub mod banner;
+pub mod clans;
+pub mod fitting;
+pub mod history;
+pub mod loadouts;
+pub mod lore;
+pub mod memberships;
+pub mod names;
+pub mod optimizer;
+pub mod pgcr;
+pub mod plugs;
+pub mod raids;
+pub mod search;
+pub mod stats;
+pub mod transfer;
+pub mod wishlist;
@@ -0,0 +1,436 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+};
+
+use png::{BitDepth, ColorType, Decoder, Encoder, Transformations};
+use rusqlite::{Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
+
+use crate::{bnet::entities::groups_v2::ClanBanner, Result};
+
+/// The tables of the clan banner database, one per kind of banner piece, each
+/// row holding an id and the JSON of the piece.
+const DECALS_TABLE: &str = "Decals";
+const DECAL_PRIMARY_COLORS_TABLE: &str = "DecalPrimaryColors";
+const DECAL_SECONDARY_COLORS_TABLE: &str = "DecalSecondaryColors";
+const GONFALONS_TABLE: &str = "Gonfalons";
+const GONFALON_COLORS_TABLE: &str = "GonfalonColors";
+const GONFALON_DETAILS_TABLE: &str = "GonfalonDetails";
+const GONFALON_DETAIL_COLORS_TABLE: &str = "GonfalonDetailColors";
+
+/// A piece of a banner drawn out of images: the gonfalon, its detail or the
+/// decal. Gonfalons and their details only have a foreground.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BannerImage {
+    background_image_path: Option<String>,
+    foreground_image_path: Option<String>,
+}
+
+impl BannerImage {
+    pub fn background_image_path(&self) -> Option<&String> {
+        self.background_image_path.as_ref()
+    }
+
+    pub fn foreground_image_path(&self) -> Option<&String> {
+        self.foreground_image_path.as_ref()
+    }
+}
+
+/// A color a banner piece is tinted with.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct BannerColor {
+    alpha: u8,
+    blue: u8,
+    green: u8,
+    red: u8,
+}
+
+impl BannerColor {
+    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            alpha,
+            blue,
+            green,
+            red,
+        }
+    }
+
+    pub fn alpha(&self) -> u8 {
+        self.alpha
+    }
+
+    pub fn blue(&self) -> u8 {
+        self.blue
+    }
+
+    pub fn green(&self) -> u8 {
+        self.green
+    }
+
+    pub fn red(&self) -> u8 {
+        self.red
+    }
+}
+
+/// Why a banner couldn't be rendered.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BannerError {
+    /// One of the banner's ids isn't in the database.
+    UnknownPiece { table: &'static str, id: u32 },
+    /// A banner piece has no image to draw.
+    MissingImage { table: &'static str, id: u32 },
+    /// An image isn't in the cache directory.
+    NotCached { path: PathBuf },
+}
+
+impl fmt::Display for BannerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BannerError::UnknownPiece { table, id } => {
+                write!(f, "{} has no entry {}", table, id)
+            }
+            BannerError::MissingImage { table, id } => {
+                write!(f, "entry {} of {} has no image", id, table)
+            }
+            BannerError::NotCached { path } => {
+                write!(f, "{} is not in the image cache", path.display())
+            }
+        }
+    }
+}
+
+impl Error for BannerError {}
+
+/// The pieces clan banners are put together from, as read out of the
+/// database at `DestinyManifest::mobile_clan_banner_database_path`, once it's
+/// been unzipped.
+#[derive(Debug, Default)]
+pub struct ClanBannerDatabase {
+    decal_primary_colors: HashMap<u32, BannerColor>,
+    decal_secondary_colors: HashMap<u32, BannerColor>,
+    decals: HashMap<u32, BannerImage>,
+    gonfalon_colors: HashMap<u32, BannerColor>,
+    gonfalon_detail_colors: HashMap<u32, BannerColor>,
+    gonfalon_details: HashMap<u32, BannerImage>,
+    gonfalons: HashMap<u32, BannerImage>,
+}
+
+impl ClanBannerDatabase {
+    /// Reads every table of the database at the path.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let connection = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        Ok(Self {
+            decal_primary_colors: read_table(&connection, DECAL_PRIMARY_COLORS_TABLE)?,
+            decal_secondary_colors: read_table(&connection, DECAL_SECONDARY_COLORS_TABLE)?,
+            decals: read_table(&connection, DECALS_TABLE)?,
+            gonfalon_colors: read_table(&connection, GONFALON_COLORS_TABLE)?,
+            gonfalon_detail_colors: read_table(&connection, GONFALON_DETAIL_COLORS_TABLE)?,
+            gonfalon_details: read_table(&connection, GONFALON_DETAILS_TABLE)?,
+            gonfalons: read_table(&connection, GONFALONS_TABLE)?,
+        })
+    }
+
+    /// The colors of decal foregrounds, matched by `ClanBanner::decal_color_id`.
+    pub fn decal_primary_colors(&self) -> &HashMap<u32, BannerColor> {
+        &self.decal_primary_colors
+    }
+
+    /// The colors of decal backgrounds, matched by
+    /// `ClanBanner::decal_background_color_id`.
+    pub fn decal_secondary_colors(&self) -> &HashMap<u32, BannerColor> {
+        &self.decal_secondary_colors
+    }
+
+    pub fn decals(&self) -> &HashMap<u32, BannerImage> {
+        &self.decals
+    }
+
+    pub fn gonfalon_colors(&self) -> &HashMap<u32, BannerColor> {
+        &self.gonfalon_colors
+    }
+
+    pub fn gonfalon_detail_colors(&self) -> &HashMap<u32, BannerColor> {
+        &self.gonfalon_detail_colors
+    }
+
+    pub fn gonfalon_details(&self) -> &HashMap<u32, BannerImage> {
+        &self.gonfalon_details
+    }
+
+    pub fn gonfalons(&self) -> &HashMap<u32, BannerImage> {
+        &self.gonfalons
+    }
+
+    /// The image layers of a banner, bottom first, each with the color it's
+    /// tinted with.
+    pub fn layers(
+        &self,
+        banner: &ClanBanner,
+    ) -> std::result::Result<Vec<(&str, BannerColor)>, BannerError> {
+        let gonfalon = piece(&self.gonfalons, GONFALONS_TABLE, banner.gonfalon_id())?;
+        let detail = piece(
+            &self.gonfalon_details,
+            GONFALON_DETAILS_TABLE,
+            banner.gonfalon_detail_id(),
+        )?;
+        let decal = piece(&self.decals, DECALS_TABLE, banner.decal_id())?;
+
+        let mut layers = vec![(
+            gonfalon
+                .foreground_image_path
+                .as_deref()
+                .ok_or(BannerError::MissingImage {
+                    table: GONFALONS_TABLE,
+                    id: banner.gonfalon_id(),
+                })?,
+            *piece(
+                &self.gonfalon_colors,
+                GONFALON_COLORS_TABLE,
+                banner.gonfalon_color_id(),
+            )?,
+        )];
+        if let Some(path) = detail.foreground_image_path.as_deref() {
+            layers.push((
+                path,
+                *piece(
+                    &self.gonfalon_detail_colors,
+                    GONFALON_DETAIL_COLORS_TABLE,
+                    banner.gonfalon_detail_color_id(),
+                )?,
+            ));
+        }
+        if let Some(path) = decal.background_image_path.as_deref() {
+            layers.push((
+                path,
+                *piece(
+                    &self.decal_secondary_colors,
+                    DECAL_SECONDARY_COLORS_TABLE,
+                    banner.decal_background_color_id(),
+                )?,
+            ));
+        }
+        if let Some(path) = decal.foreground_image_path.as_deref() {
+            layers.push((
+                path,
+                *piece(
+                    &self.decal_primary_colors,
+                    DECAL_PRIMARY_COLORS_TABLE,
+                    banner.decal_color_id(),
+                )?,
+            ));
+        }
+        Ok(layers)
+    }
+}
+
+/// An 8-bit RGBA image.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BannerCanvas {
+    height: u32,
+    pixels: Vec<u8>,
+    width: u32,
+}
+
+impl BannerCanvas {
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The pixels, row by row, four bytes each.
+    pub fn pixels(&self) -> &[u8] {
+        self.pixels.as_ref()
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Reads a PNG of any color type, converting it to 8-bit RGBA.
+    pub fn read_png(path: impl AsRef<Path>) -> Result<Self> {
+        let mut decoder = Decoder::new(BufReader::new(File::open(path)?));
+        decoder.set_transformations(Transformations::EXPAND | Transformations::STRIP_16);
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+        buffer.truncate(info.buffer_size());
+
+        let pixels = match info.color_type {
+            ColorType::Rgba => buffer,
+            ColorType::Rgb => buffer
+                .chunks_exact(3)
+                .flat_map(|p| [p[0], p[1], p[2], u8::MAX])
+                .collect(),
+            ColorType::GrayscaleAlpha => buffer
+                .chunks_exact(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            // Indexed images are expanded to RGB or RGBA by the decoder.
+            ColorType::Grayscale | ColorType::Indexed => {
+                buffer.iter().flat_map(|p| [*p, *p, *p, u8::MAX]).collect()
+            }
+        };
+        Ok(Self {
+            height: info.height,
+            pixels,
+            width: info.width,
+        })
+    }
+
+    pub fn write_png(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut encoder =
+            Encoder::new(BufWriter::new(File::create(path)?), self.width, self.height);
+        encoder.set_color(ColorType::Rgba);
+        encoder.set_depth(BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    fn blank(width: u32, height: u32) -> Self {
+        Self {
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+            width,
+        }
+    }
+
+    /// Multiplies the layer by the color, then draws it over the canvas,
+    /// centered.
+    fn draw(&mut self, layer: &BannerCanvas, color: BannerColor) {
+        let tint = [color.red, color.green, color.blue, color.alpha];
+        let x_offset = (i64::from(self.width) - i64::from(layer.width)) / 2;
+        let y_offset = (i64::from(self.height) - i64::from(layer.height)) / 2;
+
+        for y in 0..layer.height {
+            let canvas_y = i64::from(y) + y_offset;
+            if canvas_y < 0 || canvas_y >= i64::from(self.height) {
+                continue;
+            }
+            for x in 0..layer.width {
+                let canvas_x = i64::from(x) + x_offset;
+                if canvas_x < 0 || canvas_x >= i64::from(self.width) {
+                    continue;
+                }
+                let source = (y as usize * layer.width as usize + x as usize) * 4;
+                let target = (canvas_y as usize * self.width as usize + canvas_x as usize) * 4;
+
+                let mut pixel = [0.0; 4];
+                for (channel, value) in pixel.iter_mut().enumerate() {
+                    *value = f32::from(layer.pixels[source + channel]) / 255.0
+                        * f32::from(tint[channel])
+                        / 255.0;
+                }
+                let below: Vec<f32> = self.pixels[target..target + 4]
+                    .iter()
+                    .map(|value| f32::from(*value) / 255.0)
+                    .collect();
+
+                let alpha = pixel[3] + below[3] * (1.0 - pixel[3]);
+                for channel in 0..3 {
+                    let value = if alpha > 0.0 {
+                        (pixel[channel] * pixel[3] + below[channel] * below[3] * (1.0 - pixel[3]))
+                            / alpha
+                    } else {
+                        0.0
+                    };
+                    self.pixels[target + channel] = (value * 255.0).round() as u8;
+                }
+                self.pixels[target + 3] = (alpha * 255.0).round() as u8;
+            }
+        }
+    }
+}
+
+/// Puts clan banners together out of images kept in a local directory, laid
+/// out like their paths on bungie.net.
+#[derive(Debug)]
+pub struct BannerRenderer<'a> {
+    cache_dir: PathBuf,
+    database: &'a ClanBannerDatabase,
+}
+
+impl<'a> BannerRenderer<'a> {
+    pub fn new(database: &'a ClanBannerDatabase, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            database,
+        }
+    }
+
+    /// Where an image of the database is expected in the cache directory.
+    pub fn cached_path(&self, image_path: &str) -> PathBuf {
+        self.cache_dir.join(image_path.trim_start_matches('/'))
+    }
+
+    /// Every image the banner is drawn from that isn't cached yet, as paths
+    /// on bungie.net.
+    pub fn missing_images(
+        &self,
+        banner: &ClanBanner,
+    ) -> std::result::Result<Vec<&'a str>, BannerError> {
+        Ok(self
+            .database
+            .layers(banner)?
+            .into_iter()
+            .map(|(path, _)| path)
+            .filter(|path| !self.cached_path(path).is_file())
+            .collect())
+    }
+
+    /// Composites the banner, sized after its gonfalon.
+    pub fn render(&self, banner: &ClanBanner) -> Result<BannerCanvas> {
+        let mut canvas: Option<BannerCanvas> = None;
+        for (image_path, color) in self.database.layers(banner)? {
+            let path = self.cached_path(image_path);
+            if !path.is_file() {
+                return Err(Box::new(BannerError::NotCached { path }));
+            }
+            let layer = BannerCanvas::read_png(&path)?;
+            canvas
+                .get_or_insert_with(|| BannerCanvas::blank(layer.width, layer.height))
+                .draw(&layer, color);
+        }
+        Ok(canvas.unwrap_or_else(|| BannerCanvas::blank(0, 0)))
+    }
+
+    /// Composites the banner and writes it out as a PNG.
+    pub fn render_png(&self, banner: &ClanBanner, path: impl AsRef<Path>) -> Result<()> {
+        self.render(banner)?.write_png(path)
+    }
+}
+
+fn read_table<T: for<'de> Deserialize<'de>>(
+    connection: &Connection,
+    table: &str,
+) -> Result<HashMap<u32, T>> {
+    let mut statement = connection.prepare(&format!("SELECT id, json FROM {}", table))?;
+    let rows = statement.query_map([], |row| {
+        Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+    })?;
+
+    let mut entries = HashMap::new();
+    for row in rows {
+        let (id, json) = row?;
+        // Ids are stored as signed 32-bit integers, like world content hashes
+        // in the mobile world content database.
+        entries.insert(id as u32, serde_json::from_str(&json)?);
+    }
+    Ok(entries)
+}
+
+fn piece<'t, T>(
+    table: &'t HashMap<u32, T>,
+    name: &'static str,
+    id: u32,
+) -> std::result::Result<&'t T, BannerError> {
+    table
+        .get(&id)
+        .ok_or(BannerError::UnknownPiece { table: name, id })
+}
@@ -0,0 +1,82 @@
+use crate::{
+    bnet::{
+        endpoints::groups::Groups,
+        entities::groups_v2::{GroupMember, RuntimeGroupMemberType},
+    },
+    Result,
+};
+
+/// Pages through a group's members, one GetMembersOfGroup call at a time.
+#[derive(Clone, Debug)]
+pub struct RosterPages {
+    done: bool,
+    group_id: i64,
+    member_type: Option<RuntimeGroupMemberType>,
+    name_search: Option<String>,
+    page: i32,
+}
+
+impl RosterPages {
+    pub fn new(group_id: i64) -> Self {
+        Self {
+            done: false,
+            group_id,
+            member_type: None,
+            name_search: None,
+            page: 1,
+        }
+    }
+
+    /// Only lists members of this rank.
+    pub fn with_member_type(mut self, member_type: RuntimeGroupMemberType) -> Self {
+        self.member_type = Some(member_type);
+        self
+    }
+
+    /// Only lists members whose name contains this.
+    pub fn with_name_search(mut self, name_search: impl Into<String>) -> Self {
+        self.name_search = Some(name_search.into());
+        self
+    }
+
+    pub fn group_id(&self) -> i64 {
+        self.group_id
+    }
+
+    /// The page the next call fetches, starting at 1.
+    pub fn page(&self) -> i32 {
+        self.page
+    }
+
+    /// Fetches the next page of members, or None once every page has been
+    /// fetched. Fails when the API does, such as when it's throttled, rather
+    /// than taking the failure for the last page. A failed call can be
+    /// retried, as it doesn't move on a page.
+    pub async fn next_page(&mut self, groups: &Groups<'_>) -> Result<Option<Vec<GroupMember>>> {
+        if self.done {
+            return Ok(None);
+        }
+        let results = groups
+            .get_members_of_group(
+                self.group_id,
+                self.page,
+                self.member_type,
+                self.name_search.as_deref(),
+            )
+            .await?
+            .into_result()?;
+        self.done = !results.has_more() || results.results().is_empty();
+        self.page += 1;
+        Ok(Some(results.into_results()))
+    }
+}
+
+/// Fetches every member of a group.
+pub async fn roster(groups: &Groups<'_>, group_id: i64) -> Result<Vec<GroupMember>> {
+    let mut pages = RosterPages::new(group_id);
+    let mut members = Vec::new();
+    while let Some(page) = pages.next_page(groups).await? {
+        members.extend(page);
+    }
+    Ok(members)
+}
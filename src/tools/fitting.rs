@@ -0,0 +1,556 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use crate::bnet::entities::destiny::{
+    definitions::{
+        energy_types::DestinyEnergyTypeDefinition, items::DestinyPlugRuleDefinition,
+        DestinyInventoryItemDefinition, DestinyStatDefinition,
+    },
+    entities::items::DestinyItemSocketState,
+    hashes::{DefinitionStore, Hash},
+    sockets::DestinyItemPlugBase,
+    DestinyEnergyType,
+};
+
+/// A mod the player wants to socket into an armor piece.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ProposedMod {
+    socket_index: i32,
+    plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+}
+
+impl ProposedMod {
+    pub fn new(socket_index: i32, plug_item_hash: Hash<DestinyInventoryItemDefinition>) -> Self {
+        Self {
+            socket_index,
+            plug_item_hash,
+        }
+    }
+
+    /// The index into the armor's socket entries.
+    pub fn socket_index(&self) -> i32 {
+        self.socket_index
+    }
+
+    /// The hash of the DestinyInventoryItemDefinition of the mod.
+    pub fn plug_item_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
+        self.plug_item_hash
+    }
+}
+
+/// The energy an armor piece has available for its mods.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ArmorEnergy {
+    capacity: i32,
+    energy_type_hash: Option<Hash<DestinyEnergyTypeDefinition>>,
+}
+
+impl ArmorEnergy {
+    pub fn new(capacity: i32, energy_type_hash: Option<Hash<DestinyEnergyTypeDefinition>>) -> Self {
+        Self {
+            capacity,
+            energy_type_hash,
+        }
+    }
+
+    pub fn capacity(&self) -> i32 {
+        self.capacity
+    }
+
+    /// The DestinyEnergyTypeDefinition of the armor, if it is restricted to
+    /// one. Mods of any other energy type than "Any" won't fit.
+    pub fn energy_type_hash(&self) -> Option<Hash<DestinyEnergyTypeDefinition>> {
+        self.energy_type_hash
+    }
+}
+
+/// A reason why a proposed set of mods can't be applied to an armor piece.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FitProblem {
+    /// The plug item isn't in the world content.
+    UnknownPlug {
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+    },
+    /// The item exists, but can't be inserted into a socket.
+    NotAPlug {
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+    },
+    /// The armor has no socket at this index.
+    UnknownSocket { socket_index: i32 },
+    /// More than one mod was proposed for the same socket.
+    SocketAssignedTwice { socket_index: i32 },
+    /// The socket's plug whitelist doesn't accept the plug's category.
+    NotWhitelisted {
+        socket_index: i32,
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+        plug_category_identifier: Option<String>,
+    },
+    /// The mod costs a kind of energy the armor doesn't have.
+    EnergyTypeMismatch {
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+        required_energy_type_hash: Hash<DestinyEnergyTypeDefinition>,
+        armor_energy_type_hash: Hash<DestinyEnergyTypeDefinition>,
+    },
+    /// The mods cost more energy than the armor has.
+    InsufficientEnergy { required: i32, capacity: i32 },
+    /// Several mods share a unique label, of which only one can be equipped.
+    DuplicateUniqueMod {
+        unique_label: String,
+        plug_item_hashes: Vec<Hash<DestinyInventoryItemDefinition>>,
+    },
+    /// One of the plug's insertion rules isn't met, so it can't be inserted.
+    /// The message is None when the API doesn't say which rule failed.
+    InsertionRuleFailed {
+        socket_index: i32,
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+        failure_message: Option<String>,
+    },
+    /// One of the plug's enabled rules isn't met, so it can be inserted but
+    /// won't provide its benefits. The message is None when the API doesn't
+    /// say which rule failed.
+    EnabledRuleFailed {
+        socket_index: i32,
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+        failure_message: Option<String>,
+    },
+}
+
+impl fmt::Display for FitProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FitProblem::UnknownPlug { plug_item_hash } => {
+                write!(f, "plug {} is not in the world content", plug_item_hash)
+            }
+            FitProblem::NotAPlug { plug_item_hash } => {
+                write!(f, "item {} can't be inserted into a socket", plug_item_hash)
+            }
+            FitProblem::UnknownSocket { socket_index } => {
+                write!(f, "the armor has no socket at index {}", socket_index)
+            }
+            FitProblem::SocketAssignedTwice { socket_index } => {
+                write!(
+                    f,
+                    "more than one mod was proposed for socket {}",
+                    socket_index
+                )
+            }
+            FitProblem::NotWhitelisted {
+                socket_index,
+                plug_item_hash,
+                plug_category_identifier,
+            } => write!(
+                f,
+                "socket {} doesn't accept plug {} (category {})",
+                socket_index,
+                plug_item_hash,
+                plug_category_identifier.as_deref().unwrap_or("unknown")
+            ),
+            FitProblem::EnergyTypeMismatch {
+                plug_item_hash,
+                required_energy_type_hash,
+                armor_energy_type_hash,
+            } => write!(
+                f,
+                "plug {} requires energy type {}, but the armor has energy type {}",
+                plug_item_hash, required_energy_type_hash, armor_energy_type_hash
+            ),
+            FitProblem::InsufficientEnergy { required, capacity } => write!(
+                f,
+                "the mods cost {} energy, but the armor only has {}",
+                required, capacity
+            ),
+            FitProblem::DuplicateUniqueMod {
+                unique_label,
+                plug_item_hashes,
+            } => write!(
+                f,
+                "only one mod labeled \"{}\" can be equipped, but {} were proposed",
+                unique_label,
+                plug_item_hashes.len()
+            ),
+            FitProblem::InsertionRuleFailed {
+                socket_index,
+                plug_item_hash,
+                failure_message,
+            } => write!(
+                f,
+                "plug {} can't be inserted into socket {}: {}",
+                plug_item_hash,
+                socket_index,
+                failure_message
+                    .as_deref()
+                    .unwrap_or("an insertion rule isn't met")
+            ),
+            FitProblem::EnabledRuleFailed {
+                socket_index,
+                plug_item_hash,
+                failure_message,
+            } => write!(
+                f,
+                "plug {} wouldn't be enabled in socket {}: {}",
+                plug_item_hash,
+                socket_index,
+                failure_message
+                    .as_deref()
+                    .unwrap_or("an enabled rule isn't met")
+            ),
+        }
+    }
+}
+
+impl Error for FitProblem {}
+
+/// The outcome of validating a set of mods against an armor piece.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FitReport {
+    energy_capacity: i32,
+    energy_used: i32,
+    problems: Vec<FitProblem>,
+}
+
+impl FitReport {
+    /// Whether every proposed mod can be applied at once.
+    pub fn fits(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    pub fn energy_capacity(&self) -> i32 {
+        self.energy_capacity
+    }
+
+    /// The total energy cost of the proposed mods.
+    pub fn energy_used(&self) -> i32 {
+        self.energy_used
+    }
+
+    /// The energy left over once every proposed mod is applied. Negative when
+    /// the mods don't fit.
+    pub fn energy_remaining(&self) -> i32 {
+        self.energy_capacity - self.energy_used
+    }
+
+    pub fn problems(&self) -> &[FitProblem] {
+        self.problems.as_ref()
+    }
+}
+
+/// The live state of an armor piece's sockets, used to evaluate the plugs'
+/// insertion and enabled rules.
+#[derive(Clone, Copy, Debug)]
+struct PlugStates<'a> {
+    reusable_plugs: &'a HashMap<i32, Vec<DestinyItemPlugBase>>,
+    sockets: &'a [DestinyItemSocketState],
+}
+
+/// Validates armor mod loadouts against the socket and energy rules found in
+/// the world content.
+#[derive(Debug)]
+pub struct ModFitter<'a, S> {
+    content: &'a S,
+    plug_states: Option<PlugStates<'a>>,
+}
+
+impl<'a, S: DefinitionStore> ModFitter<'a, S> {
+    pub fn new(content: &'a S) -> Self {
+        Self {
+            content,
+            plug_states: None,
+        }
+    }
+
+    /// Checks the plugs' insertion and enabled rules against the armor's
+    /// socket states, in socket order, and its reusable plugs keyed by socket
+    /// index. The rules can only be evaluated by the API, which reports the
+    /// ones that fail: without this, they aren't checked.
+    pub fn with_plug_states(
+        mut self,
+        sockets: &'a [DestinyItemSocketState],
+        reusable_plugs: &'a HashMap<i32, Vec<DestinyItemPlugBase>>,
+    ) -> Self {
+        self.plug_states = Some(PlugStates {
+            reusable_plugs,
+            sockets,
+        });
+        self
+    }
+
+    /// Works out the energy of an armor piece from the plugs currently in its
+    /// sockets, typically its masterwork plug.
+    ///
+    /// The plug's energy capacity entry is used when present, otherwise its
+    /// investment stats are checked against the capacity stat of every energy
+    /// type. Returns None if no plug grants any energy.
+    pub fn armor_energy(
+        &self,
+        socketed_plug_hashes: &[Hash<DestinyInventoryItemDefinition>],
+    ) -> Option<ArmorEnergy> {
+        socketed_plug_hashes
+            .iter()
+            .filter_map(|hash| hash.resolve(self.content))
+            .filter_map(|item| self.energy_capacity(item))
+            .max_by_key(|energy| energy.capacity)
+    }
+
+    /// The energy cost of a plug, along with the energy type it consumes.
+    ///
+    /// The plug's energy cost entry is used when present, otherwise its
+    /// investment stats are checked against the cost stat of every energy type.
+    pub fn energy_cost(
+        &self,
+        plug_item: &DestinyInventoryItemDefinition,
+    ) -> Option<(i32, Option<Hash<DestinyEnergyTypeDefinition>>)> {
+        if let Some(cost) = plug_item.plug().and_then(|plug| plug.energy_cost()) {
+            if let Some(energy_cost) = cost.energy_cost() {
+                return Some((energy_cost, cost.energy_type_hash()));
+            }
+        }
+
+        self.stat_for_energy_type(plug_item, |energy_type| energy_type.cost_stat_hash())
+            .map(|(value, energy_type_hash)| (value, Some(energy_type_hash)))
+    }
+
+    /// Checks a proposed set of mods against an armor piece: every mod must
+    /// target an existing socket whose whitelist accepts it, the mods' total
+    /// cost must fit in the armor's energy, and no two mods may share a unique
+    /// label. When the fitter has plug states, every mod must also meet its
+    /// insertion and enabled rules. Every problem found is reported, not just
+    /// the first one.
+    pub fn validate(
+        &self,
+        armor: &DestinyInventoryItemDefinition,
+        energy: ArmorEnergy,
+        mods: &[ProposedMod],
+    ) -> FitReport {
+        let mut problems = Vec::new();
+        let mut energy_used = 0;
+        let mut sockets_used = HashMap::new();
+        let mut unique_labels: Vec<(&String, Vec<Hash<DestinyInventoryItemDefinition>>)> =
+            Vec::new();
+        let socket_entries = armor.sockets().and_then(|sockets| sockets.socket_entries());
+
+        for proposed in mods {
+            let count = sockets_used.entry(proposed.socket_index).or_insert(0);
+            *count += 1;
+            if *count == 2 {
+                problems.push(FitProblem::SocketAssignedTwice {
+                    socket_index: proposed.socket_index,
+                });
+            }
+
+            let plug_item = match proposed.plug_item_hash.resolve(self.content) {
+                Some(item) => item,
+                None => {
+                    problems.push(FitProblem::UnknownPlug {
+                        plug_item_hash: proposed.plug_item_hash,
+                    });
+                    continue;
+                }
+            };
+            let plug = match plug_item.plug() {
+                Some(plug) => plug,
+                None => {
+                    problems.push(FitProblem::NotAPlug {
+                        plug_item_hash: proposed.plug_item_hash,
+                    });
+                    continue;
+                }
+            };
+
+            let socket = usize::try_from(proposed.socket_index)
+                .ok()
+                .and_then(|index| socket_entries.and_then(|entries| entries.get(index)));
+            match socket {
+                Some(socket) => {
+                    let accepted = socket
+                        .socket_type_hash()
+                        .and_then(|hash| hash.resolve(self.content))
+                        .and_then(|socket_type| socket_type.plug_whitelist())
+                        .is_some_and(|whitelist| {
+                            whitelist.iter().any(|entry| {
+                                entry.category_hash().is_some()
+                                    && entry.category_hash() == plug.plug_category_hash()
+                            })
+                        });
+                    if !accepted {
+                        problems.push(FitProblem::NotWhitelisted {
+                            socket_index: proposed.socket_index,
+                            plug_item_hash: proposed.plug_item_hash,
+                            plug_category_identifier: plug.plug_category_identifier().cloned(),
+                        });
+                    }
+                }
+                None => problems.push(FitProblem::UnknownSocket {
+                    socket_index: proposed.socket_index,
+                }),
+            }
+
+            if let Some(plug_states) = self.plug_states {
+                let (insert_failures, enable_failures) = plug_states.failures(proposed);
+                let insert_failures = insert_failures
+                    .iter()
+                    .flat_map(|failures| failure_messages(plug.insertion_rules(), failures));
+                for failure_message in insert_failures {
+                    problems.push(FitProblem::InsertionRuleFailed {
+                        socket_index: proposed.socket_index,
+                        plug_item_hash: proposed.plug_item_hash,
+                        failure_message,
+                    });
+                }
+                let enable_failures = enable_failures
+                    .iter()
+                    .flat_map(|failures| failure_messages(plug.enabled_rules(), failures));
+                for failure_message in enable_failures {
+                    problems.push(FitProblem::EnabledRuleFailed {
+                        socket_index: proposed.socket_index,
+                        plug_item_hash: proposed.plug_item_hash,
+                        failure_message,
+                    });
+                }
+            }
+
+            if let Some((cost, cost_type_hash)) = self.energy_cost(plug_item) {
+                energy_used += cost;
+                if let (Some(required), Some(available)) = (cost_type_hash, energy.energy_type_hash)
+                {
+                    if cost > 0 && required != available && !self.is_any_energy(required) {
+                        problems.push(FitProblem::EnergyTypeMismatch {
+                            plug_item_hash: proposed.plug_item_hash,
+                            required_energy_type_hash: required,
+                            armor_energy_type_hash: available,
+                        });
+                    }
+                }
+            }
+
+            let label = plug_item
+                .equipping_block()
+                .and_then(|block| block.unique_label())
+                .filter(|label| !label.is_empty());
+            if let Some(label) = label {
+                match unique_labels
+                    .iter_mut()
+                    .find(|(existing, _)| *existing == label)
+                {
+                    Some((_, hashes)) => hashes.push(proposed.plug_item_hash),
+                    None => unique_labels.push((label, vec![proposed.plug_item_hash])),
+                }
+            }
+        }
+
+        if energy_used > energy.capacity {
+            problems.push(FitProblem::InsufficientEnergy {
+                required: energy_used,
+                capacity: energy.capacity,
+            });
+        }
+
+        for (label, hashes) in unique_labels {
+            if hashes.len() > 1 {
+                problems.push(FitProblem::DuplicateUniqueMod {
+                    unique_label: label.clone(),
+                    plug_item_hashes: hashes,
+                });
+            }
+        }
+
+        FitReport {
+            energy_capacity: energy.capacity,
+            energy_used,
+            problems,
+        }
+    }
+
+    fn energy_capacity(&self, item: &DestinyInventoryItemDefinition) -> Option<ArmorEnergy> {
+        if let Some(capacity) = item.plug().and_then(|plug| plug.energy_capacity()) {
+            if let Some(value) = capacity.capacity_value() {
+                return Some(ArmorEnergy::new(value, capacity.energy_type_hash()));
+            }
+        }
+
+        self.stat_for_energy_type(item, |energy_type| energy_type.capacity_stat_hash())
+            .map(|(value, energy_type_hash)| ArmorEnergy::new(value, Some(energy_type_hash)))
+    }
+
+    /// Finds the first investment stat of the item that matches the stat
+    /// selected from an energy type, returning its value and the energy type.
+    fn stat_for_energy_type(
+        &self,
+        item: &DestinyInventoryItemDefinition,
+        stat: impl Fn(&DestinyEnergyTypeDefinition) -> Option<Hash<DestinyStatDefinition>>,
+    ) -> Option<(i32, Hash<DestinyEnergyTypeDefinition>)> {
+        let investment_stats = item.investment_stats()?;
+        self.content
+            .definitions::<DestinyEnergyTypeDefinition>()
+            .find_map(|(hash, energy_type)| {
+                let stat_hash = stat(energy_type)?;
+                let value = investment_stats
+                    .iter()
+                    .find(|s| s.stat_type_hash() == Some(stat_hash))?
+                    .value()?;
+                Some((value, hash))
+            })
+    }
+
+    fn is_any_energy(&self, energy_type_hash: Hash<DestinyEnergyTypeDefinition>) -> bool {
+        energy_type_hash
+            .resolve(self.content)
+            .and_then(|energy_type| energy_type.enum_value())
+            == Some(DestinyEnergyType::Any)
+    }
+}
+
+impl PlugStates<'_> {
+    /// The indexes of the insertion and enabled rules the proposed mod fails,
+    /// or None for either when it passes them or its state isn't known.
+    ///
+    /// A reusable plug of the socket reports both. Otherwise, when the mod is
+    /// the plug already in the socket, the socket state reports whether it's
+    /// enabled.
+    fn failures(&self, proposed: &ProposedMod) -> (Option<Vec<i32>>, Option<Vec<i32>>) {
+        let reusable_plug = self
+            .reusable_plugs
+            .get(&proposed.socket_index)
+            .and_then(|plugs| {
+                plugs
+                    .iter()
+                    .find(|plug| plug.plug_item_hash() == proposed.plug_item_hash)
+            });
+        if let Some(plug) = reusable_plug {
+            let insert_failures = (!plug.can_insert())
+                .then(|| plug.insert_fail_indexes().cloned().unwrap_or_default());
+            let enable_failures =
+                (!plug.enabled()).then(|| plug.enable_fail_indexes().cloned().unwrap_or_default());
+            return (insert_failures, enable_failures);
+        }
+
+        let socket = usize::try_from(proposed.socket_index)
+            .ok()
+            .and_then(|index| self.sockets.get(index))
+            .filter(|socket| socket.plug_hash() == Some(proposed.plug_item_hash));
+        let enable_failures = socket
+            .filter(|socket| !socket.is_enabled())
+            .map(|socket| socket.enable_fail_indexes().cloned().unwrap_or_default());
+        (None, enable_failures)
+    }
+}
+
+/// The failure message of every failed rule, by their indexes into the plug's
+/// rules. A single None when the API didn't say which rules failed, and None
+/// for an index that's out of the rules' range.
+fn failure_messages(
+    rules: Option<&Vec<DestinyPlugRuleDefinition>>,
+    failure_indexes: &[i32],
+) -> Vec<Option<String>> {
+    if failure_indexes.is_empty() {
+        return vec![None];
+    }
+
+    failure_indexes
+        .iter()
+        .map(|index| {
+            let rule = usize::try_from(*index)
+                .ok()
+                .and_then(|index| rules?.get(index));
+            rule.and_then(|rule| rule.failure_message()).cloned()
+        })
+        .collect()
+}
@@ -0,0 +1,250 @@
+use std::{collections::HashSet, future::Future, path::Path};
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::{
+    bnet::{
+        endpoints::{destiny::Destiny, Response},
+        entities::{
+            destiny::historical_stats::{
+                definitions::DestinyActivityModeType, DestinyActivityHistoryResults,
+                DestinyHistoricalStatsPeriodGroup, DestinyPostGameCarnageReportData,
+            },
+            BungieMembershipType,
+        },
+    },
+    Result,
+};
+
+/// The most activities GetActivityHistory returns per page.
+pub const ACTIVITY_PAGE_SIZE: i32 = 250;
+
+/// The table of the archive database, each row holding an instance id and
+/// the JSON of its report.
+const REPORTS_TABLE: &str = "Reports";
+
+/// The character whose history is read.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HistoryOwner {
+    character_id: i64,
+    destiny_membership_id: i64,
+    membership_type: BungieMembershipType,
+}
+
+impl HistoryOwner {
+    pub fn new(
+        membership_type: BungieMembershipType,
+        destiny_membership_id: i64,
+        character_id: i64,
+    ) -> Self {
+        Self {
+            character_id,
+            destiny_membership_id,
+            membership_type,
+        }
+    }
+
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    pub fn destiny_membership_id(&self) -> i64 {
+        self.destiny_membership_id
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+}
+
+/// Where activity history and the reports of its activities come from. The
+/// API is one, through [`Destiny`].
+pub trait HistorySource {
+    /// A page of at most [`ACTIVITY_PAGE_SIZE`] activities, most recent first.
+    fn activity_page(
+        &self,
+        owner: HistoryOwner,
+        mode: Option<DestinyActivityModeType>,
+        page: i32,
+    ) -> impl Future<Output = Result<Response<DestinyActivityHistoryResults>>>;
+
+    fn carnage_report(
+        &self,
+        instance_id: i64,
+    ) -> impl Future<Output = Result<Response<DestinyPostGameCarnageReportData>>>;
+}
+
+impl HistorySource for Destiny<'_> {
+    async fn activity_page(
+        &self,
+        owner: HistoryOwner,
+        mode: Option<DestinyActivityModeType>,
+        page: i32,
+    ) -> Result<Response<DestinyActivityHistoryResults>> {
+        self.get_activity_history(
+            owner.membership_type,
+            owner.destiny_membership_id,
+            owner.character_id,
+            ACTIVITY_PAGE_SIZE,
+            mode,
+            page,
+        )
+        .await
+    }
+
+    async fn carnage_report(
+        &self,
+        instance_id: i64,
+    ) -> Result<Response<DestinyPostGameCarnageReportData>> {
+        self.get_post_game_carnage_report(instance_id).await
+    }
+}
+
+/// Pages through a character's activity history, most recent activity first,
+/// until it runs out or `stop` returns true for a page. The page `stop` is
+/// called with is kept. Fails if the API fails for any page, rather than
+/// returning part of the history.
+pub async fn activity_history(
+    source: &impl HistorySource,
+    owner: HistoryOwner,
+    mode: Option<DestinyActivityModeType>,
+    mut stop: impl FnMut(&[DestinyHistoricalStatsPeriodGroup]) -> bool,
+) -> Result<Vec<DestinyHistoricalStatsPeriodGroup>> {
+    let mut activities = Vec::new();
+    let mut page = 0;
+    loop {
+        let response = source.activity_page(owner, mode, page).await?;
+        let results = response.into_result()?;
+        let done =
+            results.activities().len() < ACTIVITY_PAGE_SIZE as usize || stop(results.activities());
+        activities.extend(results.into_activities());
+        if done {
+            return Ok(activities);
+        }
+        page += 1;
+    }
+}
+
+/// Post game carnage reports kept in a SQLite database, so they only ever
+/// need fetching once.
+#[derive(Debug)]
+pub struct PgcrArchive {
+    connection: Connection,
+    instance_ids: HashSet<i64>,
+}
+
+impl PgcrArchive {
+    /// Opens the archive database at the path, creating it if needed.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let connection = Connection::open(path)?;
+        connection.execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} (instance_id INTEGER PRIMARY KEY, json TEXT NOT NULL)",
+                REPORTS_TABLE
+            ),
+            [],
+        )?;
+
+        let mut instance_ids = HashSet::new();
+        {
+            let mut statement =
+                connection.prepare(&format!("SELECT instance_id FROM {}", REPORTS_TABLE))?;
+            for id in statement.query_map([], |row| row.get(0))? {
+                instance_ids.insert(id?);
+            }
+        }
+
+        Ok(Self {
+            connection,
+            instance_ids,
+        })
+    }
+
+    pub fn contains(&self, instance_id: i64) -> bool {
+        self.instance_ids.contains(&instance_id)
+    }
+
+    pub fn instance_ids(&self) -> &HashSet<i64> {
+        &self.instance_ids
+    }
+
+    pub fn len(&self) -> usize {
+        self.instance_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instance_ids.is_empty()
+    }
+
+    pub fn get(&self, instance_id: i64) -> Result<Option<DestinyPostGameCarnageReportData>> {
+        let json: Option<String> = self
+            .connection
+            .query_row(
+                &format!("SELECT json FROM {} WHERE instance_id = ?1", REPORTS_TABLE),
+                [instance_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        match json {
+            Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Stores a report, replacing any previous copy.
+    pub fn insert(&mut self, report: &DestinyPostGameCarnageReportData) -> Result<()> {
+        let instance_id = report.activity_details().instance_id();
+        self.connection.execute(
+            &format!(
+                "INSERT OR REPLACE INTO {} (instance_id, json) VALUES (?1, ?2)",
+                REPORTS_TABLE
+            ),
+            params![instance_id, serde_json::to_string(report)?],
+        )?;
+        self.instance_ids.insert(instance_id);
+        Ok(())
+    }
+
+    /// Every archived report, in no particular order.
+    pub fn reports(&self) -> impl Iterator<Item = Result<DestinyPostGameCarnageReportData>> + '_ {
+        self.instance_ids
+            .iter()
+            .filter_map(|id| self.get(*id).transpose())
+    }
+
+    /// Fetches the character's new activities and archives their reports,
+    /// returning how many were added.
+    ///
+    /// Paging stops at the first page whose activities are all archived
+    /// already. Reports are fetched oldest first, so that everything older
+    /// than an archived activity is archived too, even when a previous run
+    /// was interrupted part way through.
+    pub async fn sync(
+        &mut self,
+        source: &impl HistorySource,
+        owner: HistoryOwner,
+        mode: Option<DestinyActivityModeType>,
+    ) -> Result<usize> {
+        let instance_ids = &self.instance_ids;
+        let activities = activity_history(source, owner, mode, |page| {
+            page.iter().all(|activity| {
+                activity
+                    .activity_details()
+                    .is_none_or(|details| instance_ids.contains(&details.instance_id()))
+            })
+        })
+        .await?;
+
+        let mut added = 0;
+        for details in activities.iter().rev().filter_map(|a| a.activity_details()) {
+            let instance_id = details.instance_id();
+            if self.contains(instance_id) {
+                continue;
+            }
+            let report = source.carnage_report(instance_id).await?.into_result()?;
+            self.insert(&report)?;
+            added += 1;
+        }
+        Ok(added)
+    }
+}
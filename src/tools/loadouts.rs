@@ -0,0 +1,391 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bnet::{
+        endpoints::{destiny::Destiny, Response},
+        entities::destiny::{
+            components::loadouts::{DestinyLoadoutComponent, DestinyLoadoutItemComponent},
+            definitions::{
+                loadouts::{
+                    DestinyLoadoutColorDefinition, DestinyLoadoutIconDefinition,
+                    DestinyLoadoutNameDefinition,
+                },
+                DestinyEquipmentSlotDefinition, DestinyInventoryBucketDefinition,
+                DestinyInventoryItemDefinition,
+            },
+            entities::items::{DestinyItemComponent, DestinyItemSocketsComponent},
+            hashes::{DefinitionStore, Hash},
+            requests::actions::DestinyItemSetActionRequest,
+            DestinyEquipItemResults,
+        },
+    },
+    Result,
+};
+
+use super::transfer::{self, ItemKey, Location, MoveError, MovePlanner, MoveStep};
+
+/// The bucket holding a character's subclass.
+pub const SUBCLASS_BUCKET_HASH: Hash<DestinyInventoryBucketDefinition> = Hash::new(3284755031);
+
+/// The plug hash in-game loadouts hold for sockets they leave alone.
+pub const UNSET_PLUG_HASH: Hash<DestinyInventoryItemDefinition> = Hash::new(2166136261);
+
+/// The buckets a loadout can hold an item for: one per equipment slot, in the
+/// order the game shows them.
+#[derive(Clone, Debug)]
+pub struct LoadoutSlots {
+    buckets: Vec<Hash<DestinyInventoryBucketDefinition>>,
+}
+
+impl LoadoutSlots {
+    pub fn new(content: &impl DefinitionStore) -> Self {
+        let mut buckets: Vec<(i32, Hash<DestinyInventoryBucketDefinition>)> = content
+            .definitions::<DestinyEquipmentSlotDefinition>()
+            .filter_map(|(_, slot)| slot.bucket_type_hash())
+            .filter_map(|hash| {
+                let bucket = hash.resolve(content)?;
+                match bucket.enabled() {
+                    Some(false) => None,
+                    _ => Some((bucket.bucket_order().unwrap_or(i32::MAX), hash)),
+                }
+            })
+            .collect();
+        buckets.sort_unstable();
+        buckets.dedup_by_key(|(_, hash)| *hash);
+
+        Self {
+            buckets: buckets.into_iter().map(|(_, hash)| hash).collect(),
+        }
+    }
+
+    pub fn buckets(&self) -> &[Hash<DestinyInventoryBucketDefinition>] {
+        self.buckets.as_ref()
+    }
+
+    pub fn covers(&self, bucket_hash: Hash<DestinyInventoryBucketDefinition>) -> bool {
+        self.buckets.contains(&bucket_hash)
+    }
+}
+
+/// An item in a loadout, along with the plugs it should have.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadoutItem {
+    item_hash: Hash<DestinyInventoryItemDefinition>,
+    item_instance_id: i64,
+    #[serde(default)]
+    socket_overrides: BTreeMap<i32, Hash<DestinyInventoryItemDefinition>>,
+}
+
+impl LoadoutItem {
+    pub fn new(item_hash: Hash<DestinyInventoryItemDefinition>, item_instance_id: i64) -> Self {
+        Self {
+            item_hash,
+            item_instance_id,
+            socket_overrides: BTreeMap::new(),
+        }
+    }
+
+    /// Asks for a plug to be inserted in the socket at this index.
+    pub fn with_socket_override(
+        mut self,
+        socket_index: i32,
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+    ) -> Self {
+        self.socket_overrides.insert(socket_index, plug_item_hash);
+        self
+    }
+
+    pub fn item_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
+        self.item_hash
+    }
+
+    pub fn item_instance_id(&self) -> i64 {
+        self.item_instance_id
+    }
+
+    /// The plug item hashes to insert, keyed by socket index.
+    pub fn socket_overrides(&self) -> &BTreeMap<i32, Hash<DestinyInventoryItemDefinition>> {
+        &self.socket_overrides
+    }
+}
+
+/// A named set of items, at most one per equipment slot.
+///
+/// The subclass is kept apart from the other items, its socket overrides being
+/// the abilities, aspects and fragments it's configured with.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Loadout {
+    #[serde(default)]
+    items: BTreeMap<Hash<DestinyInventoryBucketDefinition>, LoadoutItem>,
+    name: String,
+    subclass: Option<LoadoutItem>,
+}
+
+impl Loadout {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            items: BTreeMap::new(),
+            name: name.into(),
+            subclass: None,
+        }
+    }
+
+    /// Puts the item in the bucket, replacing whatever the loadout had there.
+    pub fn with_item(
+        mut self,
+        bucket_hash: Hash<DestinyInventoryBucketDefinition>,
+        item: LoadoutItem,
+    ) -> Self {
+        if bucket_hash == SUBCLASS_BUCKET_HASH {
+            self.subclass = Some(item);
+        } else {
+            self.items.insert(bucket_hash, item);
+        }
+        self
+    }
+
+    pub fn with_subclass(mut self, subclass: LoadoutItem) -> Self {
+        self.subclass = Some(subclass);
+        self
+    }
+
+    /// Takes a loadout of what a character has equipped, out of its equipment
+    /// component. Only the subclass's plugs are kept as socket overrides, out
+    /// of the item sockets component when it's known.
+    pub fn snapshot(
+        name: impl Into<String>,
+        slots: &LoadoutSlots,
+        equipment: &[DestinyItemComponent],
+        sockets: &HashMap<i64, DestinyItemSocketsComponent>,
+    ) -> Self {
+        let mut loadout = Self::new(name);
+        for item in equipment
+            .iter()
+            .filter(|item| slots.covers(item.bucket_hash()))
+        {
+            let mut loadout_item = LoadoutItem::new(item.item_hash(), item.item_instance_id());
+            if item.bucket_hash() == SUBCLASS_BUCKET_HASH {
+                let plugs = sockets
+                    .get(&item.item_instance_id())
+                    .map(|sockets| sockets.sockets())
+                    .unwrap_or_default();
+                for (index, socket) in plugs.iter().enumerate() {
+                    if let Some(plug_hash) = socket.plug_hash() {
+                        loadout_item = loadout_item.with_socket_override(index as i32, plug_hash);
+                    }
+                }
+            }
+            loadout = loadout.with_item(item.bucket_hash(), loadout_item);
+        }
+        loadout
+    }
+
+    /// Converts an in-game loadout, finding out what its items are among the
+    /// given ones. Items that can't be found are left out, and the loadout is
+    /// named after its DestinyLoadoutNameDefinition.
+    pub fn from_in_game<'i>(
+        content: &impl DefinitionStore,
+        loadout: &DestinyLoadoutComponent,
+        items: impl IntoIterator<Item = &'i DestinyItemComponent>,
+    ) -> Self {
+        let items: HashMap<i64, &DestinyItemComponent> = items
+            .into_iter()
+            .map(|item| (item.item_instance_id(), item))
+            .collect();
+        let name = loadout
+            .name_hash()
+            .resolve(content)
+            .and_then(|name| name.name())
+            .map_or("Loadout", |name| name.as_str());
+
+        let mut converted = Self::new(name);
+        for loadout_item in loadout.items() {
+            let Some(item) = items.get(&loadout_item.item_instance_id()) else {
+                continue;
+            };
+            let bucket_hash = item
+                .item_hash()
+                .resolve(content)
+                .and_then(|d| d.inventory())
+                .and_then(|i| i.bucket_type_hash())
+                .unwrap_or_else(|| item.bucket_hash());
+
+            let mut converted_item = LoadoutItem::new(item.item_hash(), item.item_instance_id());
+            for (index, plug_hash) in loadout_item.plug_item_hashes().iter().enumerate() {
+                if *plug_hash != UNSET_PLUG_HASH {
+                    converted_item = converted_item.with_socket_override(index as i32, *plug_hash);
+                }
+            }
+            converted = converted.with_item(bucket_hash, converted_item);
+        }
+        converted
+    }
+
+    /// Converts the loadout to the shape of an in-game loadout, with its items
+    /// in slot order.
+    ///
+    /// The game only ever saves a loadout out of what a character has
+    /// equipped, so getting this one in-game means applying it and then
+    /// snapshotting it with these identifiers.
+    pub fn to_in_game(
+        &self,
+        slots: &LoadoutSlots,
+        color_hash: Hash<DestinyLoadoutColorDefinition>,
+        icon_hash: Hash<DestinyLoadoutIconDefinition>,
+        name_hash: Hash<DestinyLoadoutNameDefinition>,
+    ) -> DestinyLoadoutComponent {
+        let items = slots
+            .buckets()
+            .iter()
+            .filter_map(|bucket_hash| {
+                if *bucket_hash == SUBCLASS_BUCKET_HASH {
+                    self.subclass.as_ref()
+                } else {
+                    self.items.get(bucket_hash)
+                }
+            })
+            .map(|item| {
+                let sockets = item
+                    .socket_overrides
+                    .keys()
+                    .next_back()
+                    .map_or(0, |index| *index + 1);
+                let plug_item_hashes = (0..sockets)
+                    .map(|index| {
+                        item.socket_overrides
+                            .get(&index)
+                            .copied()
+                            .unwrap_or(UNSET_PLUG_HASH)
+                    })
+                    .collect();
+                DestinyLoadoutItemComponent::new(item.item_instance_id, plug_item_hashes)
+            })
+            .collect();
+
+        DestinyLoadoutComponent::new(color_hash, icon_hash, name_hash, items)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// The items other than the subclass, keyed by bucket hash.
+    pub fn items(&self) -> &BTreeMap<Hash<DestinyInventoryBucketDefinition>, LoadoutItem> {
+        &self.items
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn subclass(&self) -> Option<&LoadoutItem> {
+        self.subclass.as_ref()
+    }
+
+    /// Plans the moves that put the loadout on a character, leaving the items
+    /// to be equipped all at once so the game can sort out exotic conflicts.
+    ///
+    /// Items that can't be moved are reported rather than failing the whole
+    /// plan. Socket overrides aren't part of the plan.
+    pub fn plan<S: DefinitionStore>(
+        &self,
+        planner: &mut MovePlanner<S>,
+        character_id: i64,
+    ) -> LoadoutPlan {
+        let mut moves = Vec::new();
+        let mut item_ids = Vec::new();
+        let mut problems = Vec::new();
+
+        for item in self.subclass.iter().chain(self.items.values()) {
+            let item_instance_id = item.item_instance_id;
+            let key = ItemKey::Instance(item_instance_id);
+            match planner.plan_move(key, Location::Character(character_id)) {
+                Ok(steps) => {
+                    moves.extend(steps);
+                    if !planner.item(key).is_some_and(|item| item.equipped()) {
+                        item_ids.push(item_instance_id);
+                    }
+                }
+                Err(e) => problems.push(e),
+            }
+        }
+
+        let equip = (!item_ids.is_empty()).then(|| {
+            DestinyItemSetActionRequest::new(item_ids, character_id, planner.membership_type())
+        });
+        LoadoutPlan {
+            equip,
+            moves,
+            problems,
+        }
+    }
+}
+
+/// Everything applying a loadout would do, which doubles as a dry run.
+#[derive(Clone, Debug)]
+pub struct LoadoutPlan {
+    equip: Option<DestinyItemSetActionRequest>,
+    moves: Vec<MoveStep>,
+    problems: Vec<MoveError>,
+}
+
+impl LoadoutPlan {
+    /// The items equipped once every move is done, if any aren't already.
+    pub fn equip(&self) -> Option<&DestinyItemSetActionRequest> {
+        self.equip.as_ref()
+    }
+
+    pub fn moves(&self) -> &[MoveStep] {
+        self.moves.as_ref()
+    }
+
+    /// The items that can't be put on the character, and why.
+    pub fn problems(&self) -> &[MoveError] {
+        self.problems.as_ref()
+    }
+
+    /// Makes the moves, then equips the items. The result of each equip is
+    /// reported individually by the API.
+    pub async fn apply(
+        &self,
+        destiny: &Destiny<'_>,
+    ) -> Result<Option<Response<DestinyEquipItemResults>>> {
+        transfer::execute(destiny, &self.moves).await?;
+        match &self.equip {
+            Some(request) => Ok(Some(destiny.equip_items(request).await?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl fmt::Display for LoadoutPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.moves {
+            writeln!(f, "{}", step)?;
+        }
+        if let Some(equip) = &self.equip {
+            let ids: Vec<String> = equip.item_ids().iter().map(|id| id.to_string()).collect();
+            writeln!(
+                f,
+                "equip items {} on character {}",
+                ids.join(", "),
+                equip.character_id()
+            )?;
+        }
+        for problem in &self.problems {
+            writeln!(f, "can't move: {}", problem)?;
+        }
+        Ok(())
+    }
+}
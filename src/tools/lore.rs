@@ -0,0 +1,499 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write as _,
+    io::{Seek, Write},
+};
+
+use chrono::Utc;
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use crate::{
+    bnet::entities::destiny::{
+        components::{collectibles::DestinyCollectibleComponent, records::DestinyRecordComponent},
+        definitions::{
+            collectibles::DestinyCollectibleDefinition, lore::DestinyLoreDefinition,
+            presentation::DestinyPresentationNodeDefinition, records::DestinyRecordDefinition,
+            DestinyInventoryItemDefinition,
+        },
+        hashes::{DefinitionStore, Hash},
+        DestinyCollectibleState, DestinyRecordState,
+    },
+    Result,
+};
+
+/// Where a lore entry can be read in game: either in the inspect screen of an
+/// item, or in the triumphs/lore screen through a record.
+#[derive(Clone, Copy, Debug)]
+pub enum LoreSource<'a> {
+    Item(&'a DestinyInventoryItemDefinition),
+    Record(&'a DestinyRecordDefinition),
+}
+
+impl<'a> LoreSource<'a> {
+    /// The hash of the item or record definition.
+    pub fn hash(&self) -> Option<u32> {
+        match self {
+            LoreSource::Item(item) => item.hash(),
+            LoreSource::Record(record) => record.hash(),
+        }
+    }
+
+    /// The display name of the item or record.
+    pub fn name(&self) -> Option<&'a String> {
+        match self {
+            LoreSource::Item(item) => item.display_properties().and_then(|d| d.name()),
+            LoreSource::Record(record) => record.display_properties().and_then(|d| d.name()),
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            LoreSource::Item(_) => "Item",
+            LoreSource::Record(_) => "Record",
+        }
+    }
+}
+
+/// A single page of a lore book, along with the item or record that unlocks it.
+#[derive(Debug)]
+pub struct LoreEntry<'a> {
+    lore: &'a DestinyLoreDefinition,
+    source: LoreSource<'a>,
+    unlocked: bool,
+}
+
+impl<'a> LoreEntry<'a> {
+    pub fn lore(&self) -> &'a DestinyLoreDefinition {
+        self.lore
+    }
+
+    pub fn source(&self) -> LoreSource<'a> {
+        self.source
+    }
+
+    /// Whether the player has unlocked this entry. Always true when the reader
+    /// was not given any unlock state.
+    pub fn unlocked(&self) -> bool {
+        self.unlocked
+    }
+
+    /// The name of the entry, falling back to the name of its source when the
+    /// lore itself is untitled.
+    pub fn title(&self) -> &'a str {
+        self.lore
+            .display_properties()
+            .and_then(|d| d.name())
+            .filter(|name| !name.is_empty())
+            .or_else(|| self.source.name())
+            .map_or("", |name| name.as_str())
+    }
+
+    pub fn subtitle(&self) -> Option<&'a str> {
+        self.lore
+            .subtitle()
+            .filter(|subtitle| !subtitle.is_empty())
+            .map(|subtitle| subtitle.as_str())
+    }
+
+    /// The body of the entry.
+    pub fn text(&self) -> &'a str {
+        self.lore
+            .display_properties()
+            .and_then(|d| d.description())
+            .map_or("", |text| text.as_str())
+    }
+}
+
+/// An ordered collection of lore entries, as presented by a single lore
+/// presentation node in game.
+#[derive(Debug)]
+pub struct LoreBook<'a> {
+    node: &'a DestinyPresentationNodeDefinition,
+    entries: Vec<LoreEntry<'a>>,
+}
+
+impl<'a> LoreBook<'a> {
+    pub fn node(&self) -> &'a DestinyPresentationNodeDefinition {
+        self.node
+    }
+
+    pub fn entries(&self) -> &[LoreEntry<'a>] {
+        self.entries.as_ref()
+    }
+
+    pub fn title(&self) -> &'a str {
+        self.node
+            .display_properties()
+            .and_then(|d| d.name())
+            .map_or("", |name| name.as_str())
+    }
+
+    pub fn description(&self) -> &'a str {
+        self.node
+            .display_properties()
+            .and_then(|d| d.description())
+            .map_or("", |description| description.as_str())
+    }
+
+    /// The number of entries the player has unlocked.
+    pub fn unlocked_count(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.unlocked).count()
+    }
+
+    /// Renders the book as Markdown. Locked entries keep their place in the
+    /// book, but their title and text are masked.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# {}\n", self.title());
+        if !self.description().is_empty() {
+            let _ = writeln!(out, "_{}_\n", self.description());
+        }
+
+        for entry in &self.entries {
+            if !entry.unlocked {
+                let _ = writeln!(out, "## ???\n\n_This entry has not been unlocked._\n");
+                continue;
+            }
+
+            let _ = writeln!(out, "## {}\n", entry.title());
+            if let Some(subtitle) = entry.subtitle() {
+                let _ = writeln!(out, "_{}_\n", subtitle);
+            }
+            let _ = writeln!(out, "{}\n", entry.text().trim_end());
+            if let Some(name) = entry.source.name() {
+                let _ = writeln!(out, "> Source: {} \"{}\"\n", entry.source.kind(), name);
+            }
+        }
+
+        out.truncate(out.trim_end().len());
+        out.push('\n');
+        out
+    }
+
+    /// Writes the book as an EPUB 3 archive, one chapter per entry. Locked
+    /// entries are masked the same way as in [`LoreBook::to_markdown`].
+    pub fn write_epub<W: Write + Seek>(&self, writer: W) -> Result<()> {
+        let stored = FileOptions::default().compression_method(CompressionMethod::Stored);
+        let mut zip = ZipWriter::new(writer);
+
+        // The mimetype must be the first, uncompressed entry of the archive.
+        zip.start_file("mimetype", stored)?;
+        zip.write_all(b"application/epub+zip")?;
+
+        zip.start_file("META-INF/container.xml", stored)?;
+        zip.write_all(EPUB_CONTAINER.as_bytes())?;
+
+        zip.start_file("OEBPS/content.opf", stored)?;
+        zip.write_all(self.epub_package().as_bytes())?;
+
+        zip.start_file("OEBPS/nav.xhtml", stored)?;
+        zip.write_all(self.epub_nav().as_bytes())?;
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            zip.start_file(format!("OEBPS/entry-{}.xhtml", index), stored)?;
+            zip.write_all(epub_chapter(entry).as_bytes())?;
+        }
+
+        zip.finish()?;
+        Ok(())
+    }
+
+    fn epub_package(&self) -> String {
+        let mut manifest = String::new();
+        let mut spine = String::new();
+        for index in 0..self.entries.len() {
+            let _ = writeln!(
+                manifest,
+                r#"    <item id="entry-{0}" href="entry-{0}.xhtml" media-type="application/xhtml+xml"/>"#,
+                index
+            );
+            let _ = writeln!(spine, r#"    <itemref idref="entry-{}"/>"#, index);
+        }
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="book-id">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:identifier id="book-id">urn:destiny:lore:{hash}</dc:identifier>
+    <dc:title>{title}</dc:title>
+    <dc:language>en</dc:language>
+    <meta property="dcterms:modified">{modified}</meta>
+  </metadata>
+  <manifest>
+    <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+{manifest}  </manifest>
+  <spine>
+{spine}  </spine>
+</package>
+"#,
+            hash = self.node.hash().unwrap_or_default(),
+            title = escape_xml(self.title()),
+            modified = Utc::now().format("%Y-%m-%dT%H:%M:%SZ"),
+            manifest = manifest,
+            spine = spine,
+        )
+    }
+
+    fn epub_nav(&self) -> String {
+        let mut items = String::new();
+        for (index, entry) in self.entries.iter().enumerate() {
+            let title = if entry.unlocked { entry.title() } else { "???" };
+            let _ = writeln!(
+                items,
+                r#"      <li><a href="entry-{}.xhtml">{}</a></li>"#,
+                index,
+                escape_xml(title)
+            );
+        }
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops">
+<head><title>{title}</title></head>
+<body>
+  <nav epub:type="toc">
+    <h1>{title}</h1>
+    <ol>
+{items}    </ol>
+  </nav>
+</body>
+</html>
+"#,
+            title = escape_xml(self.title()),
+            items = items,
+        )
+    }
+}
+
+const EPUB_CONTAINER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>
+"#;
+
+fn epub_chapter(entry: &LoreEntry) -> String {
+    let mut body = String::new();
+    if entry.unlocked {
+        let _ = writeln!(body, "<h2>{}</h2>", escape_xml(entry.title()));
+        if let Some(subtitle) = entry.subtitle() {
+            let _ = writeln!(body, "<p><em>{}</em></p>", escape_xml(subtitle));
+        }
+        for paragraph in entry.text().lines().filter(|line| !line.trim().is_empty()) {
+            let _ = writeln!(body, "<p>{}</p>", escape_xml(paragraph));
+        }
+    } else {
+        let _ = writeln!(body, "<h2>???</h2>");
+        let _ = writeln!(body, "<p><em>This entry has not been unlocked.</em></p>");
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<html xmlns="http://www.w3.org/1999/xhtml">
+<head><title>{}</title></head>
+<body>
+{}</body>
+</html>
+"#,
+        escape_xml(if entry.unlocked { entry.title() } else { "???" }),
+        body
+    )
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// The player's progress, used to mask the lore entries they haven't unlocked.
+#[derive(Clone, Copy, Debug)]
+struct LoreUnlocks<'a> {
+    collectibles: &'a HashMap<u32, DestinyCollectibleComponent>,
+    records: &'a HashMap<u32, DestinyRecordComponent>,
+}
+
+/// Assembles lore books out of the lore presentation nodes of the world content.
+///
+/// Lore in Destiny 2 is presented as a tree of presentation nodes, where the
+/// leaves are either records (the "Lore" tab of the triumphs screen) or
+/// collectibles whose item carries a lore hash. The reader walks that tree in
+/// presentation order and resolves every leaf to its DestinyLoreDefinition.
+#[derive(Debug)]
+pub struct LoreReader<'a, S> {
+    content: &'a S,
+    unlocks: Option<LoreUnlocks<'a>>,
+}
+
+impl<'a, S: DefinitionStore> LoreReader<'a, S> {
+    pub fn new(content: &'a S) -> Self {
+        Self {
+            content,
+            unlocks: None,
+        }
+    }
+
+    /// Masks the entries the player hasn't unlocked, using the profile's
+    /// record and collectible components keyed by their definition hash.
+    /// Entries with no matching component are considered locked.
+    pub fn with_unlocks(
+        mut self,
+        records: &'a HashMap<u32, DestinyRecordComponent>,
+        collectibles: &'a HashMap<u32, DestinyCollectibleComponent>,
+    ) -> Self {
+        self.unlocks = Some(LoreUnlocks {
+            collectibles,
+            records,
+        });
+        self
+    }
+
+    /// Returns the book presented by a single node, made of the node's direct
+    /// record and collectible children that carry lore.
+    ///
+    /// Records and collectibles are listed separately by the node, so they
+    /// are merged back into the order the node presents them in: by display
+    /// priority, then by the index of their definitions.
+    pub fn book(&self, node_hash: Hash<DestinyPresentationNodeDefinition>) -> Option<LoreBook<'a>> {
+        let node = node_hash.resolve(self.content)?;
+        let mut entries = Vec::new();
+
+        if let Some(children) = node.children() {
+            for child in children.records().into_iter().flatten() {
+                let Some(hash) = child.record_hash() else {
+                    continue;
+                };
+                if let Some(entry) = self.record_entry(hash) {
+                    let index = hash.resolve(self.content).and_then(|record| record.index());
+                    entries.push(((child.node_display_priority(), index), entry));
+                }
+            }
+
+            for child in children.collectibles().into_iter().flatten() {
+                let Some(hash) = child.collectible_hash() else {
+                    continue;
+                };
+                if let Some(entry) = self.collectible_entry(hash) {
+                    let index = hash
+                        .resolve(self.content)
+                        .and_then(|collectible| collectible.index());
+                    entries.push(((child.node_display_priority(), index), entry));
+                }
+            }
+        }
+
+        entries.sort_by_key(|((priority, index), _)| {
+            (priority.unwrap_or_default(), index.unwrap_or(i32::MAX))
+        });
+        let entries = entries.into_iter().map(|(_, entry)| entry).collect();
+        Some(LoreBook { node, entries })
+    }
+
+    /// Walks the presentation node tree below `root_node_hash` depth first, in
+    /// presentation order, and returns every node that holds lore as a book.
+    /// A node reached again, through a cycle or from another parent, is only
+    /// read the first time.
+    pub fn books(
+        &self,
+        root_node_hash: Hash<DestinyPresentationNodeDefinition>,
+    ) -> Vec<LoreBook<'a>> {
+        let mut books = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![root_node_hash];
+
+        while let Some(hash) = stack.pop() {
+            if !visited.insert(hash) {
+                continue;
+            }
+            let node = match hash.resolve(self.content) {
+                Some(node) => node,
+                None => continue,
+            };
+
+            if let Some(book) = self.book(hash).filter(|book| !book.entries.is_empty()) {
+                books.push(book);
+            }
+
+            let children = node.children().and_then(|c| c.presentation_nodes());
+            for child in children.into_iter().flatten().rev() {
+                if let Some(child_hash) = child.presentation_node_hash() {
+                    stack.push(child_hash);
+                }
+            }
+        }
+
+        books
+    }
+
+    /// Every item and record the given lore can be read from.
+    pub fn sources(&self, lore_hash: Hash<DestinyLoreDefinition>) -> Vec<LoreSource<'a>> {
+        let items = self
+            .content
+            .definitions::<DestinyInventoryItemDefinition>()
+            .filter(|(_, item)| item.lore_hash() == Some(lore_hash))
+            .map(|(_, item)| LoreSource::Item(item));
+        let records = self
+            .content
+            .definitions::<DestinyRecordDefinition>()
+            .filter(|(_, record)| record.lore_hash() == Some(lore_hash))
+            .map(|(_, record)| LoreSource::Record(record));
+
+        items.chain(records).collect()
+    }
+
+    fn record_entry(&self, record_hash: Hash<DestinyRecordDefinition>) -> Option<LoreEntry<'a>> {
+        let record = record_hash.resolve(self.content)?;
+        let lore = record.lore_hash()?.resolve(self.content)?;
+        let unlocked = self.unlocks.is_none_or(|unlocks| {
+            unlocks
+                .records
+                .get(&record_hash.value())
+                .is_some_and(|component| {
+                    !component.state().intersects(
+                        DestinyRecordState::OBJECTIVE_NOT_COMPLETED | DestinyRecordState::OBSCURED,
+                    )
+                })
+        });
+
+        Some(LoreEntry {
+            lore,
+            source: LoreSource::Record(record),
+            unlocked,
+        })
+    }
+
+    fn collectible_entry(
+        &self,
+        collectible_hash: Hash<DestinyCollectibleDefinition>,
+    ) -> Option<LoreEntry<'a>> {
+        let collectible = collectible_hash.resolve(self.content)?;
+        let item = collectible.item_hash()?.resolve(self.content)?;
+        let lore = item.lore_hash()?.resolve(self.content)?;
+        let unlocked = self.unlocks.is_none_or(|unlocks| {
+            unlocks
+                .collectibles
+                .get(&collectible_hash.value())
+                .is_some_and(|component| {
+                    !component
+                        .state()
+                        .contains(DestinyCollectibleState::NOT_ACQUIRED)
+                })
+        });
+
+        Some(LoreEntry {
+            lore,
+            source: LoreSource::Item(item),
+            unlocked,
+        })
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt;
+
+use crate::{
+    bnet::{
+        endpoints::destiny::Destiny,
+        entities::{
+            destiny::responses::{DestinyLinkedProfilesResponse, DestinyProfileUserInfoCard},
+            user::UserInfoCard,
+            BungieMembershipType,
+        },
+    },
+    Result,
+};
+
+/// A Destiny membership, as a membership type and id.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MembershipRef {
+    membership_id: i64,
+    membership_type: BungieMembershipType,
+}
+
+impl MembershipRef {
+    pub fn new(membership_type: BungieMembershipType, membership_id: i64) -> Self {
+        Self {
+            membership_id,
+            membership_type,
+        }
+    }
+
+    pub fn membership_id(&self) -> i64 {
+        self.membership_id
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+
+    fn of(profile: &DestinyProfileUserInfoCard) -> Self {
+        Self::new(profile.membership_type().into(), profile.membership_id())
+    }
+}
+
+impl fmt::Display for MembershipRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.membership_type, self.membership_id)
+    }
+}
+
+/// Why a membership is the one to query.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MembershipReason {
+    /// The account isn't cross saved, so the membership asked about is the
+    /// one its data lives on. `other_platforms` are the account's separate
+    /// memberships on other platforms, each with data of its own.
+    NotCrossSaved { other_platforms: Vec<MembershipRef> },
+    /// The membership asked about is the account's cross save primary.
+    CrossSavePrimary { overridden: Vec<MembershipRef> },
+    /// The membership asked about is overridden by cross save, its data
+    /// living on the primary membership instead.
+    Overridden { requested: MembershipRef },
+    /// The membership asked about isn't a Destiny membership, such as a
+    /// Bungie.net account, so the cross save primary of its Destiny
+    /// memberships was picked.
+    AccountPrimary { requested: MembershipRef },
+    /// The membership asked about isn't a Destiny membership, such as a
+    /// Bungie.net account, and isn't cross saved, so the most recently played
+    /// of its Destiny memberships was picked.
+    MostRecentlyPlayed {
+        requested: MembershipRef,
+        candidates: Vec<MembershipRef>,
+    },
+}
+
+/// The membership to query for an account, and why.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CanonicalMembership {
+    membership: MembershipRef,
+    reason: MembershipReason,
+}
+
+impl CanonicalMembership {
+    pub fn membership(&self) -> MembershipRef {
+        self.membership
+    }
+
+    pub fn membership_id(&self) -> i64 {
+        self.membership.membership_id
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership.membership_type
+    }
+
+    pub fn reason(&self) -> &MembershipReason {
+        &self.reason
+    }
+
+    /// Whether the membership differs from the one asked about.
+    pub fn is_redirected(&self) -> bool {
+        matches!(
+            self.reason,
+            MembershipReason::Overridden { .. }
+                | MembershipReason::AccountPrimary { .. }
+                | MembershipReason::MostRecentlyPlayed { .. }
+        )
+    }
+}
+
+impl fmt::Display for CanonicalMembership {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.reason {
+            MembershipReason::NotCrossSaved { other_platforms } if other_platforms.is_empty() => {
+                write!(f, "{} is not cross saved", self.membership)
+            }
+            MembershipReason::NotCrossSaved { other_platforms } => write!(
+                f,
+                "{} is not cross saved; {} separate membership(s) on other platforms are not \
+                 included",
+                self.membership,
+                other_platforms.len()
+            ),
+            MembershipReason::CrossSavePrimary { overridden } => write!(
+                f,
+                "{} is the cross save primary, overriding {}",
+                self.membership,
+                join(overridden)
+            ),
+            MembershipReason::Overridden { requested } => write!(
+                f,
+                "{} is overridden by cross save; its data lives on the primary {}",
+                requested, self.membership
+            ),
+            MembershipReason::AccountPrimary { requested } => write!(
+                f,
+                "{} is not a Destiny membership; {} is its cross save primary",
+                requested, self.membership
+            ),
+            MembershipReason::MostRecentlyPlayed {
+                requested,
+                candidates,
+            } => write!(
+                f,
+                "{} is not a Destiny membership; {} is the most recently played of {}",
+                requested,
+                self.membership,
+                join(candidates)
+            ),
+        }
+    }
+}
+
+/// Whether a membership is overridden by another through cross save. A
+/// membership's cross save override is the type of its primary membership,
+/// which is the primary's own type on the primary itself.
+pub fn is_overridden(card: &UserInfoCard) -> bool {
+    card.cross_save_override() != 0 && card.cross_save_override() != card.membership_type()
+}
+
+/// Works out the membership to query for the one asked about, out of its
+/// linked profiles. These must have been fetched with `get_all_memberships`,
+/// for overridden memberships to be among them.
+///
+/// None when the account has no Destiny memberships at all.
+pub fn canonical_membership(
+    linked: &DestinyLinkedProfilesResponse,
+    requested: MembershipRef,
+) -> Option<CanonicalMembership> {
+    let profiles = linked.profiles();
+    let refs = |filter: &dyn Fn(&DestinyProfileUserInfoCard) -> bool| -> Vec<MembershipRef> {
+        profiles
+            .iter()
+            .filter(|p| filter(p))
+            .map(MembershipRef::of)
+            .collect()
+    };
+
+    let requested_profile = profiles
+        .iter()
+        .find(|p| p.membership_id() == requested.membership_id);
+
+    // The primary is flagged as such, and failing that found through the type
+    // the overridden memberships point at.
+    let primary = profiles
+        .iter()
+        .find(|p| p.is_cross_save_primary())
+        .or_else(|| {
+            let primary_type = profiles
+                .iter()
+                .map(|p| p.cross_save_override())
+                .find(|override_type| *override_type != 0)?;
+            profiles
+                .iter()
+                .find(|p| p.membership_type() == primary_type && !p.is_overridden())
+        });
+
+    if let Some(primary) = primary {
+        let membership = MembershipRef::of(primary);
+        let reason = if membership.membership_id == requested.membership_id {
+            MembershipReason::CrossSavePrimary {
+                overridden: refs(&|p| p.membership_id() != membership.membership_id),
+            }
+        } else if requested_profile.is_some() {
+            MembershipReason::Overridden { requested }
+        } else {
+            MembershipReason::AccountPrimary { requested }
+        };
+        return Some(CanonicalMembership { membership, reason });
+    }
+
+    if let Some(profile) = requested_profile {
+        let membership = MembershipRef::of(profile);
+        return Some(CanonicalMembership {
+            membership,
+            reason: MembershipReason::NotCrossSaved {
+                other_platforms: refs(&|p| p.membership_id() != membership.membership_id),
+            },
+        });
+    }
+
+    let candidates = refs(&|p| !p.is_overridden());
+    let latest = profiles
+        .iter()
+        .filter(|p| !p.is_overridden())
+        .max_by_key(|p| p.date_last_played())?;
+    Some(CanonicalMembership {
+        membership: MembershipRef::of(latest),
+        reason: MembershipReason::MostRecentlyPlayed {
+            requested,
+            candidates,
+        },
+    })
+}
+
+/// Fetches a membership's linked profiles, and works out the membership to
+/// query out of them. Any membership can be passed, including a Bungie.net
+/// one.
+pub async fn resolve_canonical(
+    destiny: &Destiny<'_>,
+    requested: MembershipRef,
+) -> Result<Option<CanonicalMembership>> {
+    let linked = destiny
+        .get_linked_profiles(requested.membership_type, requested.membership_id, true)
+        .await?
+        .into_result()?;
+    Ok(canonical_membership(&linked, requested))
+}
+
+fn join(memberships: &[MembershipRef]) -> String {
+    if memberships.is_empty() {
+        return "nothing".to_string();
+    }
+    memberships
+        .iter()
+        .map(|m| m.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
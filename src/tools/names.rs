@@ -0,0 +1,160 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::{
+    bnet::{
+        endpoints::{destiny::Destiny, user::User},
+        entities::{
+            user::{
+                ExactSearchRequest, UserInfoCard, UserSearchPrefixRequest, UserSearchResponseDetail,
+            },
+            BungieMembershipType,
+        },
+    },
+    Result,
+};
+
+use super::memberships::{resolve_canonical, CanonicalMembership, MembershipRef};
+
+/// Why a string isn't a Bungie name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NameError {
+    /// There's nothing before the `#`.
+    EmptyName,
+    /// There's no `#` followed by the code.
+    MissingCode,
+    /// The code isn't a number from 1 to 9999.
+    InvalidCode(String),
+}
+
+impl fmt::Display for NameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NameError::EmptyName => write!(f, "the name before the # is empty"),
+            NameError::MissingCode => write!(f, "the name has no #code"),
+            NameError::InvalidCode(code) => {
+                write!(f, "{} is not a code from 0001 to 9999", code)
+            }
+        }
+    }
+}
+
+impl Error for NameError {}
+
+/// A Bungie name, such as `Guardian#0042`: the global display name followed
+/// by the code telling apart the players sharing it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BungieName {
+    code: i16,
+    name: String,
+}
+
+impl BungieName {
+    pub fn new(name: impl Into<String>, code: i16) -> Self {
+        Self {
+            code,
+            name: name.into(),
+        }
+    }
+
+    /// The name a membership goes by, if it has a Bungie name.
+    pub fn of(card: &UserInfoCard) -> Option<Self> {
+        (!card.bungie_global_display_name().is_empty()).then(|| {
+            Self::new(
+                card.bungie_global_display_name(),
+                card.bungie_global_display_name_code(),
+            )
+        })
+    }
+
+    pub fn code(&self) -> i16 {
+        self.code
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// Whether the membership goes by this name. Names are compared without
+    /// regard to case, as the API does.
+    pub fn matches(&self, card: &UserInfoCard) -> bool {
+        card.bungie_global_display_name_code() == self.code
+            && card
+                .bungie_global_display_name()
+                .to_lowercase()
+                .eq(&self.name.to_lowercase())
+    }
+
+    pub fn request(&self) -> ExactSearchRequest {
+        ExactSearchRequest::new(self.name.clone(), self.code)
+    }
+}
+
+impl fmt::Display for BungieName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{:04}", self.name, self.code)
+    }
+}
+
+impl FromStr for BungieName {
+    type Err = NameError;
+
+    /// Parses `Name#1234`. Names may hold a `#` of their own, so the code is
+    /// whatever follows the last one.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (name, code) = s.trim().rsplit_once('#').ok_or(NameError::MissingCode)?;
+        if name.trim().is_empty() {
+            return Err(NameError::EmptyName);
+        }
+        let invalid = || NameError::InvalidCode(code.to_string());
+        if code.is_empty() || code.len() > 4 || !code.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        match code.parse::<i16>() {
+            Ok(parsed) if parsed > 0 => Ok(Self::new(name.trim(), parsed)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Looks a Bungie name up on every platform, resolving it through the
+/// account's linked profiles to the membership its data lives on. None when
+/// nobody goes by the name.
+pub async fn resolve(
+    destiny: &Destiny<'_>,
+    name: &BungieName,
+) -> Result<Option<CanonicalMembership>> {
+    let cards = destiny
+        .search_destiny_player_by_bungie_name(BungieMembershipType::All, &name.request())
+        .await?
+        .into_result()?;
+    // Every membership of the account links to the others, so any of them
+    // leads to the same one.
+    let Some(card) = cards.iter().find(|card| name.matches(card)) else {
+        return Ok(None);
+    };
+    let requested = MembershipRef::new(card.membership_type().into(), card.membership_id());
+    resolve_canonical(destiny, requested).await
+}
+
+/// Pages through every user whose global display name starts with the
+/// prefix.
+pub async fn search_global_name(
+    user: &User<'_>,
+    prefix: &str,
+) -> Result<Vec<UserSearchResponseDetail>> {
+    let request = UserSearchPrefixRequest::new(prefix);
+    let mut results = Vec::new();
+    let mut page = 0;
+    loop {
+        let response = user
+            .search_by_global_name_post(page, &request)
+            .await?
+            .into_result()?;
+        let has_more = response.has_more() && !response.search_results().is_empty();
+        results.extend(response.into_search_results());
+        if !has_more {
+            return Ok(results);
+        }
+        page += 1;
+    }
+}
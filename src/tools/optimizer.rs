@@ -0,0 +1,572 @@
+use std::collections::HashMap;
+
+use crate::bnet::entities::destiny::{
+    definitions::{
+        DestinyInventoryBucketDefinition, DestinyInventoryItemDefinition, ARMOR_STAT_HASHES,
+    },
+    entities::items::{
+        DestinyItemComponent, DestinyItemSocketsComponent, DestinyItemStatsComponent,
+    },
+    hashes::{DefinitionStore, Hash},
+    DestinyClass, ItemState,
+};
+
+/// The inventory buckets of the five armor slots: Helmet, Gauntlets, Chest
+/// Armor, Leg Armor and Class Armor.
+pub const ARMOR_BUCKET_HASHES: [Hash<DestinyInventoryBucketDefinition>; 5] = [
+    Hash::new(3448274439),
+    Hash::new(3551918588),
+    Hash::new(14239492),
+    Hash::new(20886954),
+    Hash::new(1585787867),
+];
+
+/// Bonus granted to every stat by a masterworked armor piece.
+const MASTERWORK_BONUS: i32 = 2;
+
+/// Bonus granted by a major stat mod.
+const STAT_MOD_BONUS: i32 = 10;
+
+/// A stat stops granting tiers past this value.
+const STAT_MAX: i32 = 100;
+
+/// TierType.Exotic
+const TIER_TYPE_EXOTIC: i32 = 6;
+
+/// DestinyClass.Unknown, the class of the armor any class can equip.
+const CLASS_ANY: i32 = 3;
+
+/// An armor piece, reduced to what the optimizer needs to know about it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArmorPiece {
+    class: DestinyClass,
+    exotic: bool,
+    item_hash: Hash<DestinyInventoryItemDefinition>,
+    item_instance_id: i64,
+    masterworked: bool,
+    slot: usize,
+    stats: [i32; 6],
+}
+
+impl ArmorPiece {
+    /// `slot` is an index into [`ARMOR_BUCKET_HASHES`] and `stats` are the base
+    /// stats of the piece, without mods or masterwork bonus, in the order of
+    /// [`ARMOR_STAT_HASHES`].
+    pub fn new(
+        item_instance_id: i64,
+        item_hash: Hash<DestinyInventoryItemDefinition>,
+        slot: usize,
+        class: DestinyClass,
+        exotic: bool,
+        masterworked: bool,
+        stats: [i32; 6],
+    ) -> Self {
+        Self {
+            class,
+            exotic,
+            item_hash,
+            item_instance_id,
+            masterworked,
+            slot,
+            stats,
+        }
+    }
+
+    /// Builds an armor piece out of the live item components.
+    ///
+    /// The stats component reports stats with every mod and the masterwork
+    /// already applied, so the investment stats of every socketed plug that
+    /// costs or grants energy are taken back out to recover the base stats.
+    /// Returns None if the item isn't armor.
+    pub fn from_components(
+        content: &impl DefinitionStore,
+        item: &DestinyItemComponent,
+        stats: &DestinyItemStatsComponent,
+        sockets: Option<&DestinyItemSocketsComponent>,
+    ) -> Option<Self> {
+        let item_definition = item.item_hash().resolve(content)?;
+        let inventory = item_definition.inventory()?;
+        let slot = ARMOR_BUCKET_HASHES
+            .iter()
+            .position(|hash| Some(*hash) == inventory.bucket_type_hash())?;
+        let class = item_definition
+            .class_type()
+            .unwrap_or(DestinyClass::Unknown(CLASS_ANY));
+
+        let mut base = [0; 6];
+        for (value, stat_hash) in base.iter_mut().zip(ARMOR_STAT_HASHES) {
+            *value = stats.stats().get(&stat_hash).map_or(0, |stat| stat.value());
+        }
+
+        let plugs = sockets
+            .into_iter()
+            .flat_map(|sockets| sockets.sockets())
+            .filter_map(|socket| socket.plug_hash())
+            .filter_map(|hash| hash.resolve(content));
+        for plug_item in plugs {
+            let adds_energy = plug_item.plug().is_some_and(|plug| {
+                plug.energy_cost().is_some() || plug.energy_capacity().is_some()
+            });
+            if !adds_energy {
+                continue;
+            }
+
+            for stat in plug_item.investment_stats().into_iter().flatten() {
+                let index = ARMOR_STAT_HASHES
+                    .iter()
+                    .position(|hash| Some(*hash) == stat.stat_type_hash());
+                if let (Some(index), Some(value)) = (index, stat.value()) {
+                    base[index] -= value;
+                }
+            }
+        }
+
+        Some(Self {
+            class,
+            exotic: inventory.tier_type() == Some(TIER_TYPE_EXOTIC),
+            item_hash: item.item_hash(),
+            item_instance_id: item.item_instance_id(),
+            masterworked: item.state().contains(ItemState::MASTERWORK),
+            slot,
+            stats: base,
+        })
+    }
+
+    /// The class that can equip this piece. Unknown means any class can.
+    pub fn class(&self) -> DestinyClass {
+        self.class
+    }
+
+    pub fn exotic(&self) -> bool {
+        self.exotic
+    }
+
+    pub fn item_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
+        self.item_hash
+    }
+
+    pub fn item_instance_id(&self) -> i64 {
+        self.item_instance_id
+    }
+
+    pub fn masterworked(&self) -> bool {
+        self.masterworked
+    }
+
+    /// The index of the piece's slot in [`ARMOR_BUCKET_HASHES`].
+    pub fn slot(&self) -> usize {
+        self.slot
+    }
+
+    pub fn bucket_hash(&self) -> Hash<DestinyInventoryBucketDefinition> {
+        ARMOR_BUCKET_HASHES[self.slot]
+    }
+
+    /// The base stats of the piece, in the order of [`ARMOR_STAT_HASHES`].
+    pub fn stats(&self) -> [i32; 6] {
+        self.stats
+    }
+}
+
+/// What the optimizer should look for.
+#[derive(Clone, Debug)]
+pub struct OptimizerQuery {
+    assume_masterworked: bool,
+    class: DestinyClass,
+    exotic_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    limit: usize,
+    min_tiers: [u8; 6],
+    stat_mods: u8,
+}
+
+impl OptimizerQuery {
+    /// Looks for sets for the given class, built around the given exotic. If
+    /// no exotic is given, sets may contain any one exotic, or none at all.
+    ///
+    /// By default every set is returned, no stat mods are used, and only the
+    /// pieces that are already masterworked get the masterwork bonus.
+    pub fn new(
+        class: DestinyClass,
+        exotic_item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    ) -> Self {
+        Self {
+            assume_masterworked: false,
+            class,
+            exotic_item_hash,
+            limit: usize::MAX,
+            min_tiers: [0; 6],
+            stat_mods: 0,
+        }
+    }
+
+    /// The minimum tier required for each stat, in the order of
+    /// [`ARMOR_STAT_HASHES`]. Tiers are capped at 10.
+    pub fn with_min_tiers(mut self, min_tiers: [u8; 6]) -> Self {
+        self.min_tiers = min_tiers.map(|tier| tier.min(10));
+        self
+    }
+
+    /// How many major (+10) stat mods can be slotted, one per piece.
+    pub fn with_stat_mods(mut self, stat_mods: u8) -> Self {
+        self.stat_mods = stat_mods.min(ARMOR_BUCKET_HASHES.len() as u8);
+        self
+    }
+
+    /// Treats every piece as masterworked, to plan for upgrades.
+    pub fn assume_masterworked(mut self, assume_masterworked: bool) -> Self {
+        self.assume_masterworked = assume_masterworked;
+        self
+    }
+
+    /// The maximum number of sets returned.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+/// A full set of armor that meets the query, along with the stat mods it needs.
+#[derive(Clone, Debug)]
+pub struct ArmorSet<'a> {
+    pieces: [&'a ArmorPiece; 5],
+    stat_mods: [u8; 6],
+    stats: [i32; 6],
+}
+
+impl<'a> ArmorSet<'a> {
+    /// One piece per slot, in the order of [`ARMOR_BUCKET_HASHES`].
+    pub fn pieces(&self) -> [&'a ArmorPiece; 5] {
+        self.pieces
+    }
+
+    /// The number of major stat mods to slot into each stat.
+    pub fn stat_mods(&self) -> [u8; 6] {
+        self.stat_mods
+    }
+
+    /// The stats of the set with masterwork bonuses and stat mods applied.
+    pub fn stats(&self) -> [i32; 6] {
+        self.stats
+    }
+
+    pub fn tiers(&self) -> [u8; 6] {
+        self.stats.map(tier)
+    }
+
+    pub fn total_tier(&self) -> u32 {
+        self.tiers().iter().map(|tier| u32::from(*tier)).sum()
+    }
+}
+
+/// Searches every combination of armor pieces, and every way of spending the
+/// stat mods on it, for the sets that meet the query, and returns the
+/// Pareto-optimal ones ranked by total tier.
+///
+/// A set is Pareto-optimal when no other set reaches a tier at least as high
+/// in every stat and a higher tier in one. Only one set is kept per distinct
+/// combination of tiers: the one with the highest total of raw stats.
+///
+/// Pieces that are beaten or matched in every stat by another piece of the
+/// same slot are pruned before the search. During it, a partial set is
+/// dropped as soon as the best stats the remaining slots could add, with
+/// every stat mod, can't reach the minimum tiers anymore. Together they keep
+/// the search fast even on vaults with several hundred pieces.
+pub fn optimize<'a>(pieces: &'a [ArmorPiece], query: &OptimizerQuery) -> Vec<ArmorSet<'a>> {
+    let exotic_slot = query.exotic_item_hash.and_then(|hash| {
+        pieces
+            .iter()
+            .find(|piece| piece.item_hash == hash)
+            .map(|piece| piece.slot)
+    });
+    if query.exotic_item_hash.is_some() && exotic_slot.is_none() {
+        return Vec::new();
+    }
+
+    let mut slots: Vec<Vec<Candidate>> = vec![Vec::new(); ARMOR_BUCKET_HASHES.len()];
+    for (index, piece) in pieces.iter().enumerate() {
+        if piece.class != query.class && !matches!(piece.class, DestinyClass::Unknown(_)) {
+            continue;
+        }
+        if let Some(slot) = exotic_slot {
+            let is_fixed_exotic = Some(piece.item_hash) == query.exotic_item_hash;
+            if (piece.slot == slot) != is_fixed_exotic || (piece.exotic && !is_fixed_exotic) {
+                continue;
+            }
+        }
+
+        let bonus = if piece.masterworked || query.assume_masterworked {
+            MASTERWORK_BONUS
+        } else {
+            0
+        };
+        if let Some(candidates) = slots.get_mut(piece.slot) {
+            candidates.push(Candidate {
+                exotic: piece.exotic,
+                index,
+                stats: piece.stats.map(|stat| stat + bonus),
+            });
+        }
+    }
+
+    for candidates in &mut slots {
+        prune(candidates);
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+    }
+
+    let mut search = Search::new(slots, query);
+    search.run(0, [0; 6], 0);
+
+    let mut ranked: Vec<_> = search.best.into_values().collect();
+    ranked.sort_by(|a, b| {
+        total(&b.tiers)
+            .cmp(&total(&a.tiers))
+            .then(b.raw_total.cmp(&a.raw_total))
+    });
+
+    let mut front: Vec<BestSet> = Vec::new();
+    for set in ranked {
+        if front
+            .iter()
+            .any(|other| dominates(&other.tiers, &set.tiers))
+        {
+            continue;
+        }
+        front.push(set);
+        if front.len() == query.limit {
+            break;
+        }
+    }
+
+    front
+        .into_iter()
+        .map(|set| {
+            let mut stats = set.stats;
+            for (stat, mods) in stats.iter_mut().zip(set.stat_mods) {
+                *stat += i32::from(mods) * STAT_MOD_BONUS;
+            }
+
+            ArmorSet {
+                pieces: set.indexes.map(|index| &pieces[index]),
+                stat_mods: set.stat_mods,
+                stats,
+            }
+        })
+        .collect()
+}
+
+/// The best combination of pieces found so far for a given set of tiers.
+#[derive(Clone, Copy, Debug)]
+struct BestSet {
+    indexes: [usize; 5],
+    raw_total: i32,
+    stat_mods: [u8; 6],
+    stats: [i32; 6],
+    tiers: [u8; 6],
+}
+
+#[derive(Clone, Debug)]
+struct Candidate {
+    exotic: bool,
+    index: usize,
+    stats: [i32; 6],
+}
+
+/// Removes the candidates that another candidate of the same slot matches or
+/// beats in every stat. Exotics are only compared with exotics, since picking
+/// one changes what the other slots can hold.
+fn prune(candidates: &mut Vec<Candidate>) {
+    let mut kept: Vec<Candidate> = Vec::with_capacity(candidates.len());
+    candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.stats.iter().sum::<i32>()));
+    for candidate in candidates.drain(..) {
+        let covered = kept.iter().any(|other| {
+            other.exotic == candidate.exotic
+                && other
+                    .stats
+                    .iter()
+                    .zip(candidate.stats)
+                    .all(|(a, b)| *a >= b)
+        });
+        if !covered {
+            kept.push(candidate);
+        }
+    }
+    *candidates = kept;
+}
+
+/// The state of the depth-first search through the slots' candidates.
+struct Search<'q> {
+    best: HashMap<[u8; 6], BestSet>,
+    /// For each slot, the highest value of each stat the slots from it onwards
+    /// can add, candidate by candidate. The last entry is all zeroes.
+    bounds: Vec<[i32; 6]>,
+    chosen: [usize; 5],
+    /// Every way of spending stat mods over the six stats, by the number of
+    /// mods spent.
+    distributions: Vec<Vec<[u8; 6]>>,
+    /// The highest raw total of the complete sets seen so far, by their tiers
+    /// before stat mods. A set whose tiers were seen with a higher total can
+    /// only lose to that one, whatever the mods.
+    seen: HashMap<[u8; 6], i32>,
+    query: &'q OptimizerQuery,
+    slots: Vec<Vec<Candidate>>,
+}
+
+impl<'q> Search<'q> {
+    fn new(slots: Vec<Vec<Candidate>>, query: &'q OptimizerQuery) -> Self {
+        let mut bounds = vec![[0; 6]; slots.len() + 1];
+        for (slot, candidates) in slots.iter().enumerate().rev() {
+            let mut bound = bounds[slot + 1];
+            for (index, value) in bound.iter_mut().enumerate() {
+                *value += candidates
+                    .iter()
+                    .map(|candidate| candidate.stats[index])
+                    .max()
+                    .unwrap_or(0);
+            }
+            bounds[slot] = bound;
+        }
+
+        let mut distributions = vec![vec![[0; 6]]];
+        for mods in 1..=usize::from(query.stat_mods) {
+            let mut next: Vec<[u8; 6]> = distributions[mods - 1]
+                .iter()
+                .flat_map(|distribution| {
+                    (0..6).map(move |stat| {
+                        let mut more = *distribution;
+                        more[stat] += 1;
+                        more
+                    })
+                })
+                .collect();
+            next.sort_unstable();
+            next.dedup();
+            distributions.push(next);
+        }
+
+        Self {
+            best: HashMap::new(),
+            bounds,
+            chosen: [0; 5],
+            distributions,
+            query,
+            seen: HashMap::new(),
+            slots,
+        }
+    }
+
+    fn run(&mut self, slot: usize, stats: [i32; 6], exotics: u8) {
+        if !self.can_reach_min_tiers(slot, stats) {
+            return;
+        }
+
+        if slot == self.slots.len() {
+            self.spend_mods(stats);
+            return;
+        }
+
+        for index in 0..self.slots[slot].len() {
+            let candidate = &self.slots[slot][index];
+            let exotics = exotics + u8::from(candidate.exotic);
+            if exotics > 1 {
+                continue;
+            }
+
+            let mut next = stats;
+            for (stat, value) in next.iter_mut().zip(candidate.stats) {
+                *stat += value;
+            }
+            self.chosen[slot] = candidate.index;
+            self.run(slot + 1, next, exotics);
+        }
+    }
+
+    /// Records the complete set with every way of spending the stat mods on
+    /// it that meets the minimum tiers and isn't beaten by another.
+    ///
+    /// A major mod always adds a tier, unless the stat is already at its
+    /// highest. So the ways that aren't beaten spend as many mods as the stats
+    /// have room for, none of them on a stat past its highest tier, and
+    /// differ only in which stats they go to.
+    fn spend_mods(&mut self, stats: [i32; 6]) {
+        let base = stats.map(tier);
+        let raw_total = stats.iter().sum();
+        match self.seen.get(&base) {
+            Some(seen) if *seen >= raw_total => return,
+            _ => {
+                self.seen.insert(base, raw_total);
+            }
+        }
+
+        let room = base.map(|tier| 10 - tier);
+        let mods =
+            usize::from(self.query.stat_mods).min(room.iter().map(|room| usize::from(*room)).sum());
+
+        for distribution in &self.distributions[mods] {
+            if distribution
+                .iter()
+                .zip(room)
+                .any(|(mods, room)| *mods > room)
+            {
+                continue;
+            }
+            let mut tiers = base;
+            for (tier, mods) in tiers.iter_mut().zip(distribution) {
+                *tier += mods;
+            }
+            if tiers
+                .iter()
+                .zip(self.query.min_tiers)
+                .any(|(tier, min)| *tier < min)
+            {
+                continue;
+            }
+
+            match self.best.get(&tiers) {
+                Some(existing) if existing.raw_total >= raw_total => {}
+                _ => {
+                    self.best.insert(
+                        tiers,
+                        BestSet {
+                            indexes: self.chosen,
+                            raw_total,
+                            stat_mods: *distribution,
+                            stats,
+                            tiers,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Whether the best the slots from `slot` onwards could add to the stats
+    /// leaves few enough missing tiers for the stat mods to make up.
+    fn can_reach_min_tiers(&self, slot: usize, stats: [i32; 6]) -> bool {
+        let mut mods_needed = 0;
+        for ((stat, bound), min_tier) in stats
+            .iter()
+            .zip(self.bounds[slot])
+            .zip(self.query.min_tiers)
+        {
+            let missing = i32::from(min_tier) * 10 - (stat + bound);
+            if missing > 0 {
+                mods_needed += (missing + STAT_MOD_BONUS - 1) / STAT_MOD_BONUS;
+            }
+        }
+        mods_needed <= i32::from(self.query.stat_mods)
+    }
+}
+
+fn tier(stat: i32) -> u8 {
+    (stat.clamp(0, STAT_MAX) / 10) as u8
+}
+
+fn total(tiers: &[u8; 6]) -> u32 {
+    tiers.iter().map(|tier| u32::from(*tier)).sum()
+}
+
+fn dominates(a: &[u8; 6], b: &[u8; 6]) -> bool {
+    a.iter().zip(b).all(|(a, b)| a >= b) && a != b
+}
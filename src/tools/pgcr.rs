@@ -0,0 +1,269 @@
+use std::{collections::HashMap, fmt::Write as _};
+
+use crate::bnet::entities::destiny::{
+    definitions::{DestinyActivityDefinition, DestinyActivityModeDefinition},
+    hashes::DefinitionStore,
+    historical_stats::{
+        DestinyHistoricalStatsValue, DestinyPostGameCarnageReportData,
+        DestinyPostGameCarnageReportEntry, StatId,
+    },
+};
+
+/// DestinyActivityModeCategory.PvP
+const MODE_CATEGORY_PVP: i32 = 2;
+
+/// Looks up the basic value of a historical stat.
+pub fn stat_value(
+    values: &HashMap<StatId, DestinyHistoricalStatsValue>,
+    stat_id: &StatId,
+) -> Option<f64> {
+    values.get(stat_id).map(|value| value.basic().value())
+}
+
+/// A post game carnage report joined with the activity and mode definitions
+/// it refers to.
+#[derive(Debug)]
+pub struct CarnageReport<'a, S> {
+    content: &'a S,
+    data: &'a DestinyPostGameCarnageReportData,
+}
+
+impl<'a, S: DefinitionStore> CarnageReport<'a, S> {
+    pub fn new(content: &'a S, data: &'a DestinyPostGameCarnageReportData) -> Self {
+        Self { content, data }
+    }
+
+    pub fn data(&self) -> &'a DestinyPostGameCarnageReportData {
+        self.data
+    }
+
+    /// The activity that was played, such as a specific playlist or strike.
+    pub fn activity(&self) -> Option<&'a DestinyActivityDefinition> {
+        self.data
+            .activity_details()
+            .director_activity_hash()
+            .resolve(self.content)
+            .or_else(|| self.map())
+    }
+
+    /// The activity describing where the activity was played, which for PvP
+    /// is the map.
+    pub fn map(&self) -> Option<&'a DestinyActivityDefinition> {
+        self.data
+            .activity_details()
+            .reference_id()
+            .resolve(self.content)
+    }
+
+    /// The most specific mode the activity was played in.
+    pub fn mode(&self) -> Option<&'a DestinyActivityModeDefinition> {
+        let mode_type = self.data.activity_details().mode();
+        self.content
+            .definitions::<DestinyActivityModeDefinition>()
+            .map(|(_, mode)| mode)
+            .find(|mode| mode.mode_type() == Some(mode_type))
+    }
+
+    pub fn activity_name(&self) -> Option<&'a str> {
+        self.activity()
+            .and_then(|activity| activity.display_properties())
+            .and_then(|d| d.name())
+            .map(|name| name.as_str())
+    }
+
+    pub fn map_name(&self) -> Option<&'a str> {
+        self.map()
+            .and_then(|map| map.display_properties())
+            .and_then(|d| d.name())
+            .map(|name| name.as_str())
+    }
+
+    pub fn mode_name(&self) -> Option<&'a str> {
+        self.mode()
+            .and_then(|mode| mode.display_properties())
+            .and_then(|d| d.name())
+            .map(|name| name.as_str())
+    }
+
+    /// The path of the report's banner image, from the map, the activity or
+    /// the mode, whichever has one first.
+    pub fn pgcr_image(&self) -> Option<&'a str> {
+        self.map()
+            .and_then(|map| map.pgcr_image())
+            .or_else(|| self.activity().and_then(|activity| activity.pgcr_image()))
+            .or_else(|| self.mode().and_then(|mode| mode.pgcr_image()))
+            .map(|image| image.as_str())
+    }
+
+    /// Every DestinyActivityModeType the activity counts as, from the report
+    /// or else from the activity definition.
+    pub fn activity_mode_types(&self) -> &'a [i32] {
+        let modes = self.data.activity_details().modes();
+        if !modes.is_empty() {
+            return modes;
+        }
+        self.activity()
+            .and_then(|activity| activity.activity_mode_types())
+            .map_or(&[], |modes| modes.as_slice())
+    }
+
+    pub fn is_pvp(&self) -> bool {
+        self.activity()
+            .and_then(|activity| activity.is_pvp())
+            .or_else(|| {
+                self.mode()
+                    .and_then(|mode| mode.activity_mode_category())
+                    .map(|category| category == MODE_CATEGORY_PVP)
+            })
+            .unwrap_or(false)
+    }
+
+    /// The entries of a team, or of everyone for activities without teams.
+    pub fn entries(&self, team_id: Option<i32>) -> Vec<&'a DestinyPostGameCarnageReportEntry> {
+        self.data
+            .entries()
+            .iter()
+            .filter(|entry| {
+                team_id.is_none_or(|team_id| {
+                    stat_value(entry.values(), &StatId::Team) == Some(f64::from(team_id))
+                })
+            })
+            .collect()
+    }
+
+    /// The medals an entry earned and how many times, by stat id.
+    pub fn medals(&self, entry: &'a DestinyPostGameCarnageReportEntry) -> Vec<(&'a StatId, f64)> {
+        let mut medals: Vec<(&StatId, f64)> = entry
+            .extended()
+            .map(|extended| extended.values())
+            .into_iter()
+            .flatten()
+            .filter(|(stat_id, _)| stat_id.is_medal())
+            .map(|(stat_id, value)| (stat_id, value.basic().value()))
+            .filter(|(_, count)| *count > 0.0)
+            .collect();
+        medals.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.as_str().cmp(b.0.as_str())));
+        medals
+    }
+
+    /// Renders the report as Markdown: the activity, the team standings, every
+    /// player's score line, and then each player's weapons and medals.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let activity = self.activity_name().unwrap_or("Unknown activity");
+        match self.mode_name() {
+            Some(mode) if mode != activity => {
+                let _ = writeln!(out, "# {} ({})\n", activity, mode);
+            }
+            _ => {
+                let _ = writeln!(out, "# {}\n", activity);
+            }
+        }
+        if let Some(image) = self.pgcr_image() {
+            let _ = writeln!(out, "![](https://www.bungie.net{})\n", image);
+        }
+
+        let mut details = Vec::new();
+        if let Some(map) = self.map_name().filter(|map| *map != activity) {
+            details.push(map.to_string());
+        }
+        details.push(self.data.period().format("%Y-%m-%d %H:%M UTC").to_string());
+        if self.is_pvp() {
+            details.push("PvP".to_string());
+        }
+        if self.data.activity_details().is_private() {
+            details.push("private".to_string());
+        }
+        let _ = writeln!(out, "{}\n", details.join(" · "));
+
+        if !self.data.teams().is_empty() {
+            let _ = writeln!(out, "## Teams\n");
+            let _ = writeln!(out, "| Team | Standing | Score |");
+            let _ = writeln!(out, "| --- | --- | --- |");
+            for team in self.data.teams() {
+                let _ = writeln!(
+                    out,
+                    "| {} | {} | {} |",
+                    team.team_name()
+                        .map_or_else(|| team.team_id().to_string(), |name| name.clone()),
+                    team.standing().basic().display_value(),
+                    team.score().basic().display_value()
+                );
+            }
+            let _ = writeln!(out);
+        }
+
+        let _ = writeln!(out, "## Players\n");
+        let _ = writeln!(out, "| Player | Class | Kills | Deaths | Assists | Score |");
+        let _ = writeln!(out, "| --- | --- | --- | --- | --- | --- |");
+        for entry in self.data.entries() {
+            let values = entry.values();
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} | {} | {} |",
+                player_name(entry),
+                entry.player().character_class().map_or("", |c| c.as_str()),
+                display_value(values, &StatId::Kills),
+                display_value(values, &StatId::Deaths),
+                display_value(values, &StatId::Assists),
+                entry.score().basic().display_value()
+            );
+        }
+
+        for entry in self.data.entries() {
+            let weapons = entry
+                .extended()
+                .and_then(|extended| extended.weapons())
+                .map_or(&[][..], |weapons| weapons.as_slice());
+            let medals = self.medals(entry);
+            if weapons.is_empty() && medals.is_empty() {
+                continue;
+            }
+
+            let _ = writeln!(out, "\n### {}\n", player_name(entry));
+            for weapon in weapons {
+                let name = weapon
+                    .reference_id()
+                    .resolve(self.content)
+                    .and_then(|item| item.display_properties())
+                    .and_then(|d| d.name())
+                    .map_or_else(|| weapon.reference_id().to_string(), |name| name.clone());
+                let _ = writeln!(
+                    out,
+                    "- {}: {} kills, {} precision",
+                    name,
+                    display_value(weapon.values(), &StatId::UniqueWeaponKills),
+                    display_value(weapon.values(), &StatId::UniqueWeaponPrecisionKills)
+                );
+            }
+            for (medal, count) in medals {
+                let _ = writeln!(out, "- {} ×{}", medal, count);
+            }
+        }
+
+        out
+    }
+}
+
+fn display_value(
+    values: &HashMap<StatId, DestinyHistoricalStatsValue>,
+    stat_id: &StatId,
+) -> String {
+    values.get(stat_id).map_or_else(
+        || "-".to_string(),
+        |value| value.basic().display_value().to_string(),
+    )
+}
+
+/// The player's Bungie name when they have one, else their platform name.
+pub(crate) fn player_name(entry: &DestinyPostGameCarnageReportEntry) -> String {
+    let info = entry.player().destiny_user_info();
+    if info.bungie_global_display_name().is_empty() {
+        return info.display_name().to_string();
+    }
+    format!(
+        "{}#{:04}",
+        info.bungie_global_display_name(),
+        info.bungie_global_display_name_code()
+    )
+}
@@ -0,0 +1,247 @@
+use std::{error::Error, fmt};
+
+use crate::bnet::entities::{
+    destiny::{
+        components::items::DestinyItemReusablePlugsComponent,
+        definitions::{DestinyInventoryItemDefinition, DestinyMaterialRequirementSetDefinition},
+        hashes::{DefinitionStore, Hash},
+        requests::actions::{DestinyInsertPlugsFreeActionRequest, DestinyInsertPlugsRequestEntry},
+        DestinySocketArrayType,
+    },
+    BungieMembershipType,
+};
+
+/// Why inserting a plug through InsertSocketPlugFree would fail, or wouldn't
+/// be free.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InsertProblem {
+    /// The item receiving the plug isn't in the world content.
+    UnknownItem {
+        item_hash: Hash<DestinyInventoryItemDefinition>,
+    },
+    /// The plug item isn't in the world content.
+    UnknownPlug {
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+    },
+    /// The item exists, but can't be inserted into a socket.
+    NotAPlug {
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+    },
+    /// The item has no socket at this index of the socket array.
+    UnknownSocket {
+        socket_array_type: DestinySocketArrayType,
+        socket_index: i32,
+    },
+    /// The socket's plug whitelist doesn't accept the plug's category.
+    NotWhitelisted {
+        socket_index: i32,
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+        plug_category_identifier: Option<String>,
+    },
+    /// Inserting the plug consumes materials.
+    CostsMaterials {
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+        material_requirement_hash: Hash<DestinyMaterialRequirementSetDefinition>,
+    },
+    /// The item's reusable plugs for the socket don't include the plug, or
+    /// don't allow inserting it.
+    NotAvailable {
+        socket_index: i32,
+        plug_item_hash: Hash<DestinyInventoryItemDefinition>,
+    },
+}
+
+impl fmt::Display for InsertProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InsertProblem::UnknownItem { item_hash } => {
+                write!(f, "item {} is not in the world content", item_hash)
+            }
+            InsertProblem::UnknownPlug { plug_item_hash } => {
+                write!(f, "plug {} is not in the world content", plug_item_hash)
+            }
+            InsertProblem::NotAPlug { plug_item_hash } => {
+                write!(f, "item {} can't be inserted into a socket", plug_item_hash)
+            }
+            InsertProblem::UnknownSocket {
+                socket_array_type,
+                socket_index,
+            } => write!(
+                f,
+                "the item has no {:?} socket at index {}",
+                socket_array_type, socket_index
+            ),
+            InsertProblem::NotWhitelisted {
+                socket_index,
+                plug_item_hash,
+                plug_category_identifier,
+            } => write!(
+                f,
+                "socket {} doesn't accept plug {} (category {})",
+                socket_index,
+                plug_item_hash,
+                plug_category_identifier.as_deref().unwrap_or("unknown")
+            ),
+            InsertProblem::CostsMaterials {
+                plug_item_hash,
+                material_requirement_hash,
+            } => write!(
+                f,
+                "inserting plug {} costs the materials of requirement set {}",
+                plug_item_hash, material_requirement_hash
+            ),
+            InsertProblem::NotAvailable {
+                socket_index,
+                plug_item_hash,
+            } => write!(
+                f,
+                "plug {} can't currently be inserted into socket {}",
+                plug_item_hash, socket_index
+            ),
+        }
+    }
+}
+
+impl Error for InsertProblem {}
+
+/// Checks plug insertions before they're sent to InsertSocketPlugFree, which
+/// only performs free and reversible socket changes.
+#[derive(Debug)]
+pub struct PlugInsertionCheck<'a, S> {
+    content: &'a S,
+}
+
+impl<'a, S: DefinitionStore> PlugInsertionCheck<'a, S> {
+    pub fn new(content: &'a S) -> Self {
+        Self { content }
+    }
+
+    /// Checks that the socket exists on the item, that its socket type's
+    /// whitelist accepts the plug's category, and that inserting the plug
+    /// doesn't cost materials.
+    ///
+    /// When the item's reusable plugs are known, a plug that isn't among the
+    /// insertable reusable plugs of a socket that has any is rejected too.
+    /// Sockets without reusable plugs are fed from the inventory or plug sets,
+    /// which aren't checked.
+    pub fn check(
+        &self,
+        item_hash: Hash<DestinyInventoryItemDefinition>,
+        entry: &DestinyInsertPlugsRequestEntry,
+        reusable_plugs: Option<&DestinyItemReusablePlugsComponent>,
+    ) -> Result<(), InsertProblem> {
+        let item = item_hash
+            .resolve(self.content)
+            .ok_or(InsertProblem::UnknownItem { item_hash })?;
+        let plug_item_hash = entry.plug_item_hash();
+        let plug = plug_item_hash
+            .resolve(self.content)
+            .ok_or(InsertProblem::UnknownPlug { plug_item_hash })?
+            .plug()
+            .ok_or(InsertProblem::NotAPlug { plug_item_hash })?;
+
+        let socket_index = entry.socket_index();
+        let index = usize::try_from(socket_index).ok();
+        let sockets = item.sockets();
+        let socket_type_hash = match entry.socket_array_type() {
+            DestinySocketArrayType::Default => sockets
+                .and_then(|sockets| sockets.socket_entries())
+                .zip(index)
+                .and_then(|(entries, index)| entries.get(index))
+                .map(|socket| socket.socket_type_hash()),
+            DestinySocketArrayType::Intrinsic => sockets
+                .and_then(|sockets| sockets.intrinsic_sockets())
+                .zip(index)
+                .and_then(|(entries, index)| entries.get(index))
+                .map(|socket| socket.socket_type_hash()),
+        }
+        .ok_or(InsertProblem::UnknownSocket {
+            socket_array_type: entry.socket_array_type(),
+            socket_index,
+        })?;
+
+        let accepted = socket_type_hash
+            .and_then(|hash| hash.resolve(self.content))
+            .and_then(|socket_type| socket_type.plug_whitelist())
+            .is_some_and(|whitelist| {
+                whitelist.iter().any(|entry| {
+                    entry.category_hash().is_some()
+                        && entry.category_hash() == plug.plug_category_hash()
+                })
+            });
+        if !accepted {
+            return Err(InsertProblem::NotWhitelisted {
+                socket_index,
+                plug_item_hash,
+                plug_category_identifier: plug.plug_category_identifier().cloned(),
+            });
+        }
+
+        if let Some(material_requirement_hash) = plug
+            .insertion_material_requirement_hash()
+            .filter(|hash| hash.value() != 0)
+        {
+            if self.costs_materials(material_requirement_hash) {
+                return Err(InsertProblem::CostsMaterials {
+                    plug_item_hash,
+                    material_requirement_hash,
+                });
+            }
+        }
+
+        if entry.socket_array_type() == DestinySocketArrayType::Default {
+            let available = reusable_plugs
+                .and_then(|reusable| reusable.plugs().get(&socket_index))
+                .filter(|plugs| !plugs.is_empty())
+                .map(|plugs| {
+                    plugs
+                        .iter()
+                        .any(|p| p.plug_item_hash() == plug_item_hash && p.can_insert())
+                });
+            if available == Some(false) {
+                return Err(InsertProblem::NotAvailable {
+                    socket_index,
+                    plug_item_hash,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the insertion, and builds the request for it when it passes.
+    pub fn request(
+        &self,
+        item_hash: Hash<DestinyInventoryItemDefinition>,
+        entry: DestinyInsertPlugsRequestEntry,
+        reusable_plugs: Option<&DestinyItemReusablePlugsComponent>,
+        item_id: i64,
+        character_id: i64,
+        membership_type: BungieMembershipType,
+    ) -> Result<DestinyInsertPlugsFreeActionRequest, InsertProblem> {
+        self.check(item_hash, &entry, reusable_plugs)?;
+        Ok(DestinyInsertPlugsFreeActionRequest::new(
+            entry,
+            item_id,
+            character_id,
+            membership_type,
+        ))
+    }
+
+    /// Whether a material requirement set asks for anything. Sets missing from
+    /// the world content are assumed to.
+    fn costs_materials(
+        &self,
+        material_requirement_hash: Hash<DestinyMaterialRequirementSetDefinition>,
+    ) -> bool {
+        material_requirement_hash
+            .resolve(self.content)
+            .is_none_or(|set| {
+                set.materials().is_some_and(|materials| {
+                    materials.iter().any(|m| {
+                        m.count().unwrap_or(0) > 0 && m.omit_from_requirements() != Some(true)
+                    })
+                })
+            })
+    }
+}
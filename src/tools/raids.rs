@@ -0,0 +1,482 @@
+use std::{collections::BTreeMap, fmt::Write as _};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::bnet::entities::destiny::{
+    definitions::DestinyActivityDefinition,
+    hashes::DefinitionStore,
+    historical_stats::{
+        definitions::DestinyActivityModeType, DestinyPostGameCarnageReportData, StatId,
+    },
+};
+
+use super::pgcr::{player_name, stat_value};
+
+/// The completion reason of activities finished by completing their
+/// objective.
+const COMPLETION_REASON_OBJECTIVE_COMPLETED: f64 = 0.0;
+
+/// The most players a raid clear counts as low-man with.
+pub const RAID_LOW_MAN_PLAYERS: usize = 3;
+
+/// The most players a dungeon clear counts as low-man with.
+pub const DUNGEON_LOW_MAN_PLAYERS: usize = 2;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum EndgameKind {
+    Raid,
+    Dungeon,
+}
+
+impl EndgameKind {
+    /// Tells raids and dungeons apart by the activity's direct mode, then
+    /// by any of its modes, then by the name of its activity type.
+    pub fn of(
+        content: &impl DefinitionStore,
+        activity: &DestinyActivityDefinition,
+    ) -> Option<Self> {
+        let kind = |mode: i32| match DestinyActivityModeType::try_from(mode) {
+            Ok(DestinyActivityModeType::Raid) => Some(EndgameKind::Raid),
+            Ok(DestinyActivityModeType::Dungeon) => Some(EndgameKind::Dungeon),
+            _ => None,
+        };
+        activity
+            .direct_activity_mode_type()
+            .and_then(kind)
+            .or_else(|| {
+                activity
+                    .activity_mode_types()
+                    .and_then(|modes| modes.iter().find_map(|mode| kind(*mode)))
+            })
+            .or_else(|| {
+                let name = activity
+                    .activity_type_hash()
+                    .and_then(|hash| hash.resolve(content))
+                    .and_then(|activity_type| activity_type.display_properties())
+                    .and_then(|d| d.name())?;
+                match name.as_str() {
+                    "Raid" => Some(EndgameKind::Raid),
+                    "Dungeon" => Some(EndgameKind::Dungeon),
+                    _ => None,
+                }
+            })
+    }
+
+    pub fn low_man_players(&self) -> usize {
+        match self {
+            EndgameKind::Raid => RAID_LOW_MAN_PLAYERS,
+            EndgameKind::Dungeon => DUNGEON_LOW_MAN_PLAYERS,
+        }
+    }
+}
+
+/// A raid or dungeon as runs are grouped: by name and tier, so the versions
+/// of an activity each difficulty gets count together.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndgameActivity {
+    kind: EndgameKind,
+    name: String,
+    tier: i32,
+}
+
+impl EndgameActivity {
+    pub fn kind(&self) -> EndgameKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn tier(&self) -> i32 {
+        self.tier
+    }
+}
+
+/// How a player took part in a run.
+#[derive(Clone, Debug)]
+pub struct RunPlayer {
+    completed: bool,
+    deaths: f64,
+    display_name: String,
+    membership_id: i64,
+}
+
+impl RunPlayer {
+    /// Whether the player was there for the final completion.
+    pub fn completed(&self) -> bool {
+        self.completed
+    }
+
+    pub fn deaths(&self) -> f64 {
+        self.deaths
+    }
+
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_ref()
+    }
+
+    pub fn membership_id(&self) -> i64 {
+        self.membership_id
+    }
+}
+
+/// A raid or dungeon run, read out of its post game carnage report.
+#[derive(Clone, Debug)]
+pub struct EndgameRun {
+    activity: EndgameActivity,
+    cleared: bool,
+    duration_seconds: f64,
+    fresh: bool,
+    instance_id: i64,
+    period: DateTime<Utc>,
+    players: Vec<RunPlayer>,
+}
+
+impl EndgameRun {
+    /// Reads the run, if the report is of a raid or dungeon. A player with
+    /// several entries, having switched characters, is counted once.
+    pub fn from_report(
+        content: &impl DefinitionStore,
+        report: &DestinyPostGameCarnageReportData,
+    ) -> Option<Self> {
+        let details = report.activity_details();
+        let activity = details
+            .director_activity_hash()
+            .resolve(content)
+            .or_else(|| details.reference_id().resolve(content))?;
+        let kind = EndgameKind::of(content, activity)?;
+        let name = activity
+            .original_display_properties()
+            .or_else(|| activity.display_properties())
+            .and_then(|d| d.name())
+            .cloned()
+            .unwrap_or_else(|| details.director_activity_hash().to_string());
+
+        let mut players: Vec<RunPlayer> = Vec::new();
+        let mut duration_seconds: f64 = 0.0;
+        for entry in report.entries() {
+            let values = entry.values();
+            let completed = stat_value(values, &StatId::Completed) == Some(1.0)
+                && stat_value(values, &StatId::CompletionReason)
+                    == Some(COMPLETION_REASON_OBJECTIVE_COMPLETED);
+            let deaths = stat_value(values, &StatId::Deaths).unwrap_or(0.0);
+            duration_seconds = duration_seconds
+                .max(stat_value(values, &StatId::ActivityDurationSeconds).unwrap_or(0.0));
+
+            let membership_id = entry.player().destiny_user_info().membership_id();
+            match players
+                .iter_mut()
+                .find(|p| p.membership_id == membership_id)
+            {
+                Some(player) => {
+                    player.completed |= completed;
+                    player.deaths += deaths;
+                }
+                None => players.push(RunPlayer {
+                    completed,
+                    deaths,
+                    display_name: player_name(entry),
+                    membership_id,
+                }),
+            }
+        }
+
+        Some(Self {
+            activity: EndgameActivity {
+                kind,
+                name,
+                tier: activity.tier().unwrap_or(0),
+            },
+            cleared: players.iter().any(|p| p.completed),
+            duration_seconds,
+            fresh: report.activity_was_started_from_beginning() == Some(true)
+                || report.starting_phase_index() == Some(0),
+            instance_id: details.instance_id(),
+            period: report.period(),
+            players,
+        })
+    }
+
+    pub fn activity(&self) -> &EndgameActivity {
+        &self.activity
+    }
+
+    /// Whether anyone was there for the final completion.
+    pub fn cleared(&self) -> bool {
+        self.cleared
+    }
+
+    pub fn duration_seconds(&self) -> f64 {
+        self.duration_seconds
+    }
+
+    /// Whether the run started from the beginning rather than a checkpoint.
+    pub fn fresh(&self) -> bool {
+        self.fresh
+    }
+
+    pub fn instance_id(&self) -> i64 {
+        self.instance_id
+    }
+
+    pub fn period(&self) -> DateTime<Utc> {
+        self.period
+    }
+
+    /// Everyone who took part, including those who left early.
+    pub fn players(&self) -> &[RunPlayer] {
+        self.players.as_ref()
+    }
+
+    /// A fresh clear without a single death.
+    pub fn flawless(&self) -> bool {
+        self.cleared && self.fresh && self.players.iter().all(|p| p.deaths == 0.0)
+    }
+
+    /// A clear by no more players than the activity's low-man limit.
+    pub fn low_man(&self) -> bool {
+        self.cleared && self.players.len() <= self.activity.kind.low_man_players()
+    }
+}
+
+/// A player's runs of one raid or dungeon.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityRecord {
+    activity: EndgameActivity,
+    attempts: u32,
+    checkpoint_clears: u32,
+    clears: u32,
+    fastest_instance_id: Option<i64>,
+    fastest_seconds: Option<f64>,
+    fewest_players: Option<usize>,
+    flawless_clears: u32,
+    fresh_clears: u32,
+    low_man_clears: u32,
+}
+
+impl ActivityRecord {
+    fn new(activity: EndgameActivity) -> Self {
+        Self {
+            activity,
+            attempts: 0,
+            checkpoint_clears: 0,
+            clears: 0,
+            fastest_instance_id: None,
+            fastest_seconds: None,
+            fewest_players: None,
+            flawless_clears: 0,
+            fresh_clears: 0,
+            low_man_clears: 0,
+        }
+    }
+
+    pub fn activity(&self) -> &EndgameActivity {
+        &self.activity
+    }
+
+    /// Every run the player took part in, cleared or not.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn checkpoint_clears(&self) -> u32 {
+        self.checkpoint_clears
+    }
+
+    pub fn clears(&self) -> u32 {
+        self.clears
+    }
+
+    /// The run of the fastest fresh clear.
+    pub fn fastest_instance_id(&self) -> Option<i64> {
+        self.fastest_instance_id
+    }
+
+    /// How long the fastest fresh clear took. Checkpoint clears don't count.
+    pub fn fastest_seconds(&self) -> Option<f64> {
+        self.fastest_seconds
+    }
+
+    /// The fewest players a clear was done with.
+    pub fn fewest_players(&self) -> Option<usize> {
+        self.fewest_players
+    }
+
+    pub fn flawless_clears(&self) -> u32 {
+        self.flawless_clears
+    }
+
+    pub fn fresh_clears(&self) -> u32 {
+        self.fresh_clears
+    }
+
+    pub fn low_man_clears(&self) -> u32 {
+        self.low_man_clears
+    }
+
+    fn add(&mut self, run: &EndgameRun, completed: bool) {
+        self.attempts += 1;
+        if !completed {
+            return;
+        }
+        self.clears += 1;
+        if run.fresh {
+            self.fresh_clears += 1;
+            if self
+                .fastest_seconds
+                .is_none_or(|fastest| run.duration_seconds < fastest)
+            {
+                self.fastest_seconds = Some(run.duration_seconds);
+                self.fastest_instance_id = Some(run.instance_id);
+            }
+        } else {
+            self.checkpoint_clears += 1;
+        }
+        if run.flawless() {
+            self.flawless_clears += 1;
+        }
+        if run.low_man() {
+            self.low_man_clears += 1;
+        }
+        let players = run.players.len();
+        if self.fewest_players.is_none_or(|fewest| players < fewest) {
+            self.fewest_players = Some(players);
+        }
+    }
+}
+
+/// A player's raid and dungeon records.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerRecord {
+    activities: Vec<ActivityRecord>,
+    display_name: String,
+    membership_id: i64,
+}
+
+impl PlayerRecord {
+    /// The player's records, one per raid or dungeon, ordered by kind, name
+    /// and tier.
+    pub fn activities(&self) -> &[ActivityRecord] {
+        self.activities.as_ref()
+    }
+
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_ref()
+    }
+
+    pub fn membership_id(&self) -> i64 {
+        self.membership_id
+    }
+}
+
+/// Raid and dungeon completions of every player met in a set of post game
+/// carnage reports, such as those of a [`super::history::PgcrArchive`].
+#[derive(Debug)]
+pub struct EndgameAnalytics<'a, S> {
+    content: &'a S,
+    players: BTreeMap<i64, PlayerRecord>,
+}
+
+impl<'a, S: DefinitionStore> EndgameAnalytics<'a, S> {
+    pub fn new(content: &'a S) -> Self {
+        Self {
+            content,
+            players: BTreeMap::new(),
+        }
+    }
+
+    /// Counts a report, returning the run it was when it's a raid or dungeon.
+    /// A report should only be counted once.
+    pub fn add(&mut self, report: &DestinyPostGameCarnageReportData) -> Option<EndgameRun> {
+        let run = EndgameRun::from_report(self.content, report)?;
+        for player in &run.players {
+            let record = self
+                .players
+                .entry(player.membership_id)
+                .or_insert_with(|| PlayerRecord {
+                    activities: Vec::new(),
+                    display_name: player.display_name.clone(),
+                    membership_id: player.membership_id,
+                });
+            let index = match record
+                .activities
+                .binary_search_by(|a| a.activity.cmp(&run.activity))
+            {
+                Ok(index) => index,
+                Err(index) => {
+                    let activity = ActivityRecord::new(run.activity.clone());
+                    record.activities.insert(index, activity);
+                    index
+                }
+            };
+            record.activities[index].add(&run, player.completed);
+        }
+        Some(run)
+    }
+
+    pub fn player(&self, membership_id: i64) -> Option<&PlayerRecord> {
+        self.players.get(&membership_id)
+    }
+
+    /// Every player, ordered by membership id.
+    pub fn players(&self) -> impl Iterator<Item = &PlayerRecord> {
+        self.players.values()
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        let players: Vec<&PlayerRecord> = self.players().collect();
+        serde_json::to_string_pretty(&players)
+    }
+
+    /// One row per player and raid or dungeon, with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "membershipId,displayName,kind,activity,tier,attempts,clears,freshClears,\
+             checkpointClears,fastestSeconds,fastestInstanceId,flawlessClears,lowManClears,\
+             fewestPlayers"
+        );
+        for player in self.players() {
+            for record in &player.activities {
+                let _ = writeln!(
+                    out,
+                    "{},{},{:?},{},{},{},{},{},{},{},{},{},{},{}",
+                    player.membership_id,
+                    csv_field(&player.display_name),
+                    record.activity.kind,
+                    csv_field(&record.activity.name),
+                    record.activity.tier,
+                    record.attempts,
+                    record.clears,
+                    record.fresh_clears,
+                    record.checkpoint_clears,
+                    optional(record.fastest_seconds),
+                    optional(record.fastest_instance_id),
+                    record.flawless_clears,
+                    record.low_man_clears,
+                    optional(record.fewest_players)
+                );
+            }
+        }
+        out
+    }
+}
+
+/// Quotes a field when it holds a comma, a quote or a line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(String::new, |value| value.to_string())
+}
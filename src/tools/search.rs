@@ -0,0 +1,555 @@
+use std::{collections::HashMap, error::Error, fmt, iter::Peekable, str::Chars};
+
+use crate::bnet::entities::destiny::{
+    definitions::{
+        DestinyDamageTypeDefinition, DestinyInventoryItemDefinition, DestinyItemCategoryDefinition,
+        DestinyStatDefinition, ARMOR_STAT_HASHES,
+    },
+    entities::items::{
+        DestinyItemComponent, DestinyItemSocketsComponent, DestinyItemStatsComponent,
+    },
+    hashes::{DefinitionStore, Hash},
+    ItemState,
+};
+
+/// `is:` keywords that map to an item category.
+const CATEGORY_KEYWORDS: &[(&str, Hash<DestinyItemCategoryDefinition>)] = &[
+    ("weapon", Hash::new(1)),
+    ("kinetic", Hash::new(2)),
+    ("energy", Hash::new(3)),
+    ("power", Hash::new(4)),
+    ("autorifle", Hash::new(5)),
+    ("handcannon", Hash::new(6)),
+    ("pulserifle", Hash::new(7)),
+    ("scoutrifle", Hash::new(8)),
+    ("fusionrifle", Hash::new(9)),
+    ("sniperrifle", Hash::new(10)),
+    ("shotgun", Hash::new(11)),
+    ("machinegun", Hash::new(12)),
+    ("rocketlauncher", Hash::new(13)),
+    ("sidearm", Hash::new(14)),
+    ("armor", Hash::new(20)),
+    ("warlock", Hash::new(21)),
+    ("titan", Hash::new(22)),
+    ("hunter", Hash::new(23)),
+    ("ghost", Hash::new(39)),
+    ("helmet", Hash::new(45)),
+    ("gauntlets", Hash::new(46)),
+    ("chest", Hash::new(47)),
+    ("leg", Hash::new(48)),
+    ("classitem", Hash::new(49)),
+    ("sword", Hash::new(54)),
+    ("grenadelauncher", Hash::new(153950757)),
+    ("linearfusionrifle", Hash::new(1504945536)),
+    ("tracerifle", Hash::new(2489664120)),
+    ("bow", Hash::new(3317538576)),
+    ("glaive", Hash::new(3871742104)),
+    ("submachinegun", Hash::new(3954685534)),
+];
+
+/// `is:` keywords that map to a damage type.
+const DAMAGE_KEYWORDS: &[(&str, Hash<DestinyDamageTypeDefinition>)] = &[
+    ("kineticdamage", Hash::new(3373582085)),
+    ("arc", Hash::new(2303181850)),
+    ("solar", Hash::new(1847026933)),
+    ("void", Hash::new(3454344768)),
+    ("stasis", Hash::new(151347233)),
+    ("strand", Hash::new(3949783978)),
+];
+
+/// `is:` keywords that map to an item tier.
+const TIER_KEYWORDS: &[(&str, i32)] = &[
+    ("common", 2),
+    ("uncommon", 3),
+    ("rare", 4),
+    ("legendary", 5),
+    ("exotic", 6),
+];
+
+/// `is:` keywords that map to a bit of ItemState.
+const STATE_KEYWORDS: &[(&str, ItemState)] = &[
+    ("locked", ItemState::LOCKED),
+    ("tracked", ItemState::TRACKED),
+    ("masterwork", ItemState::MASTERWORK),
+    ("crafted", ItemState::CRAFTED),
+];
+
+/// An item, along with whatever live data is known about it, that queries are
+/// evaluated against.
+#[derive(Clone, Copy, Debug)]
+pub struct ResolvedItem<'a> {
+    definition: &'a DestinyInventoryItemDefinition,
+    item: Option<&'a DestinyItemComponent>,
+    sockets: Option<&'a DestinyItemSocketsComponent>,
+    stats: Option<&'a DestinyItemStatsComponent>,
+    tags: &'a [String],
+}
+
+impl<'a> ResolvedItem<'a> {
+    pub fn new(definition: &'a DestinyInventoryItemDefinition) -> Self {
+        Self {
+            definition,
+            item: None,
+            sockets: None,
+            stats: None,
+            tags: &[],
+        }
+    }
+
+    pub fn with_item(mut self, item: &'a DestinyItemComponent) -> Self {
+        self.item = Some(item);
+        self
+    }
+
+    pub fn with_sockets(mut self, sockets: &'a DestinyItemSocketsComponent) -> Self {
+        self.sockets = Some(sockets);
+        self
+    }
+
+    pub fn with_stats(mut self, stats: &'a DestinyItemStatsComponent) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    /// User defined tags, matched by `tag:` terms.
+    pub fn with_tags(mut self, tags: &'a [String]) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn definition(&self) -> &'a DestinyInventoryItemDefinition {
+        self.definition
+    }
+
+    pub fn item(&self) -> Option<&'a DestinyItemComponent> {
+        self.item
+    }
+}
+
+/// How a numeric term compares the item's value to the query's.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Comparison {
+    Equal,
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+impl Comparison {
+    fn parse(value: &str) -> (Self, &str) {
+        for (prefix, comparison) in [
+            (">=", Comparison::GreaterOrEqual),
+            ("<=", Comparison::LessOrEqual),
+            (">", Comparison::Greater),
+            ("<", Comparison::Less),
+            ("=", Comparison::Equal),
+        ] {
+            if let Some(rest) = value.strip_prefix(prefix) {
+                return (comparison, rest);
+            }
+        }
+        (Comparison::Equal, value)
+    }
+
+    fn compare(&self, left: i32, right: i32) -> bool {
+        match self {
+            Comparison::Equal => left == right,
+            Comparison::Greater => left > right,
+            Comparison::GreaterOrEqual => left >= right,
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+        }
+    }
+}
+
+/// A single search term.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Filter {
+    /// `is:weapon`, `is:handcannon`, `is:solar`, `is:exotic`, `is:locked`...
+    /// Unknown keywords are matched against the last segment of the item's
+    /// trait ids.
+    Is(String),
+    /// `name:"ace of spades"`, or any bare word.
+    Name(String),
+    /// `perk:"outlaw"`, matched against the names of the item's plugs.
+    Perk(String),
+    /// `season:18`, `season:>=18`
+    Season(Comparison, i32),
+    /// `stat:recovery:>=60`, or `stat:total:>=65` for the six armor stats.
+    Stat(String, Comparison, i32),
+    /// `tag:junk`
+    Tag(String),
+    /// `trait:item.weapon.hand_cannon`
+    Trait(String),
+}
+
+/// A parsed search query.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Query {
+    And(Box<Query>, Box<Query>),
+    Filter(Filter),
+    Not(Box<Query>),
+    Or(Box<Query>, Box<Query>),
+}
+
+impl Query {
+    /// Parses a DIM-style search query.
+    ///
+    /// Terms are separated by whitespace and implicitly combined with `and`.
+    /// Terms and groups can be combined with `and`, `or` and `not` (or a
+    /// leading `-`), and grouped with parentheses. `and` binds tighter than
+    /// `or`. Values containing whitespace can be quoted.
+    pub fn parse(text: &str) -> Result<Self, QueryError> {
+        let tokens = tokenize(text)?;
+        let mut parser = Parser {
+            tokens: tokens.into_iter().peekable(),
+        };
+        let query = parser.or()?;
+        match parser.tokens.next() {
+            None => Ok(query),
+            Some(Token::Close) => Err(QueryError::new("unexpected \")\"")),
+            Some(token) => Err(QueryError::new(format!("unexpected {:?}", token))),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueryError {
+    message: String,
+}
+
+impl QueryError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid search query: {}", self.message)
+    }
+}
+
+impl Error for QueryError {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    Close,
+    Negate,
+    Open,
+    Word(String),
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.peek().copied() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '-' => {
+                chars.next();
+                tokens.push(Token::Negate);
+            }
+            _ => tokens.push(Token::Word(word(&mut chars)?)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Reads a word up to the next whitespace or parenthesis outside of quotes,
+/// dropping the quotes themselves. A word that's nothing but empty quotes is
+/// an error, as it would match everything.
+fn word(chars: &mut Peekable<Chars>) -> Result<String, QueryError> {
+    let mut word = String::new();
+    while let Some(c) = chars.peek().copied() {
+        match c {
+            '"' => {
+                chars.next();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => word.push(c),
+                        None => return Err(QueryError::new("unterminated quote")),
+                    }
+                }
+            }
+            c if c.is_whitespace() || c == '(' || c == ')' => break,
+            c => {
+                chars.next();
+                word.push(c);
+            }
+        }
+    }
+    if word.is_empty() {
+        return Err(QueryError::new("empty quoted term"));
+    }
+    Ok(word)
+}
+
+struct Parser {
+    tokens: Peekable<std::vec::IntoIter<Token>>,
+}
+
+impl Parser {
+    fn or(&mut self) -> Result<Query, QueryError> {
+        let mut query = self.and()?;
+        while self.keyword("or") {
+            self.tokens.next();
+            query = Query::Or(Box::new(query), Box::new(self.and()?));
+        }
+        Ok(query)
+    }
+
+    fn and(&mut self) -> Result<Query, QueryError> {
+        let mut query = self.not()?;
+        loop {
+            if self.keyword("and") {
+                self.tokens.next();
+            } else if self.keyword("or") || matches!(self.tokens.peek(), None | Some(Token::Close))
+            {
+                return Ok(query);
+            }
+            query = Query::And(Box::new(query), Box::new(self.not()?));
+        }
+    }
+
+    fn not(&mut self) -> Result<Query, QueryError> {
+        if self.keyword("not") || self.tokens.peek() == Some(&Token::Negate) {
+            self.tokens.next();
+            return Ok(Query::Not(Box::new(self.not()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Query, QueryError> {
+        match self.tokens.next() {
+            Some(Token::Open) => {
+                let query = self.or()?;
+                match self.tokens.next() {
+                    Some(Token::Close) => Ok(query),
+                    _ => Err(QueryError::new("missing \")\"")),
+                }
+            }
+            Some(Token::Word(word)) => Ok(Query::Filter(filter(&word)?)),
+            Some(Token::Close) => Err(QueryError::new("unexpected \")\"")),
+            Some(Token::Negate) => Err(QueryError::new("unexpected \"-\"")),
+            None => Err(QueryError::new("unexpected end of query")),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        matches!(self.tokens.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword))
+    }
+}
+
+fn filter(word: &str) -> Result<Filter, QueryError> {
+    let Some((key, value)) = word.split_once(':') else {
+        return Ok(Filter::Name(word.to_lowercase()));
+    };
+    if value.is_empty() {
+        return Err(QueryError::new(format!("missing value for \"{}:\"", key)));
+    }
+
+    let number = |value: &str| {
+        let (comparison, number) = Comparison::parse(value);
+        number
+            .parse::<i32>()
+            .map(|number| (comparison, number))
+            .map_err(|_| QueryError::new(format!("invalid number \"{}\"", number)))
+    };
+
+    match key.to_lowercase().as_str() {
+        "is" => Ok(Filter::Is(normalize(value))),
+        "name" => Ok(Filter::Name(value.to_lowercase())),
+        "perk" | "perkname" => Ok(Filter::Perk(value.to_lowercase())),
+        "season" => number(value).map(|(comparison, season)| Filter::Season(comparison, season)),
+        "stat" => {
+            let (stat, value) = value
+                .split_once(':')
+                .ok_or_else(|| QueryError::new("expected stat:<name>:<value>"))?;
+            number(value)
+                .map(|(comparison, value)| Filter::Stat(normalize(stat), comparison, value))
+        }
+        "tag" => Ok(Filter::Tag(value.to_lowercase())),
+        "trait" => Ok(Filter::Trait(value.to_lowercase())),
+        _ => Err(QueryError::new(format!("unknown filter \"{}:\"", key))),
+    }
+}
+
+/// Lowercases a keyword and drops everything but letters and digits, so that
+/// "Hand Cannon", "hand_cannon" and "handcannon" are the same keyword.
+fn normalize(keyword: &str) -> String {
+    keyword
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Evaluates queries against resolved items.
+#[derive(Debug)]
+pub struct ItemSearch<'a, S> {
+    content: &'a S,
+    /// The stats by normalized name. Several stats can share a name, so all
+    /// of them are kept, in the order of their hashes.
+    stat_hashes: HashMap<String, Vec<Hash<DestinyStatDefinition>>>,
+}
+
+impl<'a, S: DefinitionStore> ItemSearch<'a, S> {
+    pub fn new(content: &'a S) -> Self {
+        let mut stat_hashes: HashMap<String, Vec<Hash<DestinyStatDefinition>>> = HashMap::new();
+        let stats = content
+            .definitions::<DestinyStatDefinition>()
+            .filter_map(|(hash, stat)| {
+                let name = stat.display_properties()?.name()?;
+                Some((normalize(name), hash))
+            });
+        for (name, hash) in stats.filter(|(name, _)| !name.is_empty()) {
+            stat_hashes.entry(name).or_default().push(hash);
+        }
+        for hashes in stat_hashes.values_mut() {
+            hashes.sort();
+        }
+
+        Self {
+            content,
+            stat_hashes,
+        }
+    }
+
+    /// Whether the item matches the query.
+    pub fn matches(&self, query: &Query, item: &ResolvedItem) -> bool {
+        match query {
+            Query::And(left, right) => self.matches(left, item) && self.matches(right, item),
+            Query::Filter(filter) => self.matches_filter(filter, item),
+            Query::Not(query) => !self.matches(query, item),
+            Query::Or(left, right) => self.matches(left, item) || self.matches(right, item),
+        }
+    }
+
+    /// Parses the query and keeps the items that match it.
+    pub fn search<'i>(
+        &self,
+        query: &str,
+        items: &'i [ResolvedItem<'a>],
+    ) -> Result<Vec<&'i ResolvedItem<'a>>, QueryError> {
+        let query = Query::parse(query)?;
+        Ok(items
+            .iter()
+            .filter(|item| self.matches(&query, item))
+            .collect())
+    }
+
+    fn matches_filter(&self, filter: &Filter, item: &ResolvedItem) -> bool {
+        let item_definition = item.definition;
+        match filter {
+            Filter::Is(keyword) => self.matches_keyword(keyword, item),
+            Filter::Name(name) => item_definition
+                .display_properties()
+                .and_then(|d| d.name())
+                .is_some_and(|item_name| item_name.to_lowercase().contains(name.as_str())),
+            Filter::Perk(name) => self.plug_hashes(item).into_iter().any(|hash| {
+                hash.resolve(self.content)
+                    .and_then(|plug| plug.display_properties())
+                    .and_then(|d| d.name())
+                    .is_some_and(|plug_name| plug_name.to_lowercase().contains(name.as_str()))
+            }),
+            Filter::Season(comparison, season) => item_definition
+                .season_hash()
+                .and_then(|hash| hash.resolve(self.content))
+                .and_then(|season| season.season_number())
+                .is_some_and(|number| comparison.compare(number, *season)),
+            Filter::Stat(stat, comparison, value) => self
+                .stat_value(stat, item)
+                .is_some_and(|stat_value| comparison.compare(stat_value, *value)),
+            Filter::Tag(tag) => item.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)),
+            Filter::Trait(trait_id) => item_definition
+                .trait_ids()
+                .is_some_and(|ids| ids.iter().any(|id| id.eq_ignore_ascii_case(trait_id))),
+        }
+    }
+
+    fn matches_keyword(&self, keyword: &str, item: &ResolvedItem) -> bool {
+        let item_definition = item.definition;
+        if let Some((_, hash)) = CATEGORY_KEYWORDS.iter().find(|(k, _)| *k == keyword) {
+            return item_definition
+                .item_category_hashes()
+                .is_some_and(|hashes| hashes.contains(hash));
+        }
+        if let Some((_, hash)) = DAMAGE_KEYWORDS.iter().find(|(k, _)| *k == keyword) {
+            return item_definition
+                .damage_type_hashes()
+                .is_some_and(|hashes| hashes.contains(hash));
+        }
+        if let Some((_, tier)) = TIER_KEYWORDS.iter().find(|(k, _)| *k == keyword) {
+            return item_definition.inventory().and_then(|i| i.tier_type()) == Some(*tier);
+        }
+        if let Some((_, bit)) = STATE_KEYWORDS.iter().find(|(k, _)| *k == keyword) {
+            return item.item.is_some_and(|i| i.state().contains(*bit));
+        }
+
+        item_definition.trait_ids().is_some_and(|ids| {
+            ids.iter()
+                .filter_map(|id| id.rsplit('.').next())
+                .any(|segment| normalize(segment) == keyword)
+        })
+    }
+
+    /// The plugs in the item's sockets, or its default plugs when no live
+    /// socket data is known.
+    fn plug_hashes(&self, item: &ResolvedItem) -> Vec<Hash<DestinyInventoryItemDefinition>> {
+        match item.sockets {
+            Some(sockets) => sockets
+                .sockets()
+                .iter()
+                .filter_map(|socket| socket.plug_hash())
+                .collect(),
+            None => item
+                .definition
+                .sockets()
+                .and_then(|sockets| sockets.socket_entries())
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry.single_initial_item_hash())
+                .collect(),
+        }
+    }
+
+    /// The value of a stat, from the live stats when known and from the
+    /// definition otherwise. When several stats share the name, the first one
+    /// the item has is used.
+    fn stat_value(&self, stat: &str, item: &ResolvedItem) -> Option<i32> {
+        let value = |hash: Hash<DestinyStatDefinition>| match item.stats {
+            Some(stats) => stats.stats().get(&hash).map(|stat| stat.value()),
+            None => item
+                .definition
+                .stats()
+                .and_then(|stats| stats.stats())
+                .and_then(|stats| stats.get(&hash))
+                .and_then(|stat| stat.value()),
+        };
+
+        if stat == "total" {
+            return ARMOR_STAT_HASHES.iter().map(|hash| value(*hash)).sum();
+        }
+        self.stat_hashes
+            .get(stat)?
+            .iter()
+            .find_map(|hash| value(*hash))
+    }
+}
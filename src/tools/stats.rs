@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::bnet::entities::destiny::historical_stats::{
+    definitions::{
+        DestinyHistoricalStatsDefinition, DestinyStatsCategoryType, DestinyStatsGroupType, UnitType,
+    },
+    DestinyHistoricalStatsByPeriod, DestinyHistoricalStatsValue, StatId,
+};
+
+/// The periods GetHistoricalStats groups stats by.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StatsPeriod {
+    AllTime,
+    Daily,
+    Monthly,
+}
+
+/// A set of stats for one period. All-time stats have no date, daily and
+/// monthly ones are dated the day or month they cover.
+#[derive(Debug)]
+pub struct PeriodStats<'v> {
+    period: Option<DateTime<Utc>>,
+    values: &'v HashMap<StatId, DestinyHistoricalStatsValue>,
+}
+
+impl<'v> PeriodStats<'v> {
+    pub fn period(&self) -> Option<DateTime<Utc>> {
+        self.period
+    }
+
+    pub fn values(&self) -> &'v HashMap<StatId, DestinyHistoricalStatsValue> {
+        self.values
+    }
+}
+
+/// The sets of stats for a period type, oldest first.
+pub fn period_stats(
+    stats: &DestinyHistoricalStatsByPeriod,
+    period: StatsPeriod,
+) -> Vec<PeriodStats<'_>> {
+    match period {
+        StatsPeriod::AllTime => vec![PeriodStats {
+            period: None,
+            values: stats.all_time(),
+        }],
+        StatsPeriod::Daily | StatsPeriod::Monthly => {
+            let groups = match period {
+                StatsPeriod::Daily => stats.daily(),
+                _ => stats.monthly(),
+            };
+            let mut sets: Vec<PeriodStats> = groups
+                .iter()
+                .map(|group| PeriodStats {
+                    period: Some(group.period()),
+                    values: group.values(),
+                })
+                .collect();
+            sets.sort_by_key(|set| set.period);
+            sets
+        }
+    }
+}
+
+/// A stat value along with the definition describing it, when the stat is
+/// known.
+#[derive(Debug)]
+pub struct ResolvedStat<'a, 'v> {
+    definition: Option<&'a DestinyHistoricalStatsDefinition>,
+    stat_id: &'v StatId,
+    value: &'v DestinyHistoricalStatsValue,
+}
+
+impl<'a, 'v> ResolvedStat<'a, 'v> {
+    pub fn definition(&self) -> Option<&'a DestinyHistoricalStatsDefinition> {
+        self.definition
+    }
+
+    pub fn value(&self) -> &'v DestinyHistoricalStatsValue {
+        self.value
+    }
+
+    /// The stat's id, as it's keyed in the values it was resolved from.
+    pub fn stat_id(&self) -> &'v StatId {
+        self.stat_id
+    }
+
+    /// The stat's display name, or its id when it has none.
+    pub fn name(&self) -> &str {
+        self.definition
+            .and_then(|d| d.stat_name())
+            .map_or_else(|| self.stat_id.as_str(), |name| name.as_str())
+    }
+
+    pub fn category(&self) -> DestinyStatsCategoryType {
+        self.definition
+            .map_or(DestinyStatsCategoryType::None, |d| d.category())
+    }
+
+    pub fn group(&self) -> DestinyStatsGroupType {
+        self.definition
+            .map_or(DestinyStatsGroupType::None, |d| d.group())
+    }
+
+    pub fn unit_type(&self) -> UnitType {
+        self.definition.map_or(UnitType::None, |d| d.unit_type())
+    }
+
+    pub fn display_value(&self) -> &'v str {
+        self.value.basic().display_value()
+    }
+}
+
+/// The historical stats definitions, for telling what stat ids stand for.
+#[derive(Debug)]
+pub struct StatsCatalog<'a> {
+    definitions: &'a HashMap<StatId, DestinyHistoricalStatsDefinition>,
+}
+
+impl<'a> StatsCatalog<'a> {
+    pub fn new(definitions: &'a HashMap<StatId, DestinyHistoricalStatsDefinition>) -> Self {
+        Self { definitions }
+    }
+
+    pub fn definition(&self, stat_id: &StatId) -> Option<&'a DestinyHistoricalStatsDefinition> {
+        self.definitions.get(stat_id)
+    }
+
+    /// The stat's display name, or its id when it has none.
+    pub fn name<'s>(&self, stat_id: &'s StatId) -> &'s str
+    where
+        'a: 's,
+    {
+        self.definition(stat_id)
+            .and_then(|d| d.stat_name())
+            .map_or(stat_id.as_str(), |name| name.as_str())
+    }
+
+    /// The definitions of a category's stats, in order of their weight.
+    pub fn in_category(
+        &self,
+        category: DestinyStatsCategoryType,
+    ) -> Vec<&'a DestinyHistoricalStatsDefinition> {
+        self.matching(|d| d.category() == category)
+    }
+
+    /// The definitions of a group's stats, in order of their weight.
+    pub fn in_group(
+        &self,
+        group: DestinyStatsGroupType,
+    ) -> Vec<&'a DestinyHistoricalStatsDefinition> {
+        self.matching(|d| d.group() == group)
+    }
+
+    /// Pairs each value with its definition, sorted by group, category and
+    /// then name.
+    pub fn resolve<'v>(
+        &self,
+        values: &'v HashMap<StatId, DestinyHistoricalStatsValue>,
+    ) -> Vec<ResolvedStat<'a, 'v>> {
+        let mut resolved: Vec<ResolvedStat> = values
+            .iter()
+            .map(|(stat_id, value)| ResolvedStat {
+                definition: self.definition(stat_id),
+                stat_id,
+                value,
+            })
+            .collect();
+        resolved.sort_by(|a, b| {
+            i32::from(a.group())
+                .cmp(&i32::from(b.group()))
+                .then(i32::from(a.category()).cmp(&i32::from(b.category())))
+                .then_with(|| a.name().cmp(b.name()))
+        });
+        resolved
+    }
+
+    fn matching(
+        &self,
+        predicate: impl Fn(&DestinyHistoricalStatsDefinition) -> bool,
+    ) -> Vec<&'a DestinyHistoricalStatsDefinition> {
+        let mut definitions: Vec<&DestinyHistoricalStatsDefinition> =
+            self.definitions.values().filter(|d| predicate(d)).collect();
+        definitions.sort_by(|a, b| {
+            b.weight()
+                .cmp(&a.weight())
+                .then(a.stat_id().as_str().cmp(b.stat_id().as_str()))
+        });
+        definitions
+    }
+}
@@ -0,0 +1,629 @@
+use std::{error::Error, fmt};
+
+use crate::{
+    bnet::{
+        endpoints::{destiny::Destiny, Response, PLATFORM_ERROR_SUCCESS},
+        entities::{
+            destiny::{
+                definitions::{DestinyInventoryBucketDefinition, DestinyInventoryItemDefinition},
+                entities::items::DestinyItemComponent,
+                hashes::{DefinitionStore, Hash},
+                requests::{
+                    actions::{DestinyItemActionRequest, DestinyPostmasterTransferRequest},
+                    DestinyItemTransferRequest,
+                },
+                ItemLocation, ItemState, TransferStatuses,
+            },
+            BungieMembershipType,
+        },
+    },
+    Result,
+};
+
+/// The bucket holding everything in the vault.
+pub const VAULT_BUCKET_HASH: Hash<DestinyInventoryBucketDefinition> = Hash::new(138197802);
+
+/// Where an item is held.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Location {
+    Character(i64),
+    Vault,
+}
+
+/// How the planner tells items apart.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ItemKey {
+    /// An instanced item, by its instance id.
+    Instance(i64),
+    /// A stack of a non-instanced item, which has no instance id of its own,
+    /// by what it is and where it's held.
+    Stack {
+        item_hash: Hash<DestinyInventoryItemDefinition>,
+        location: Location,
+    },
+}
+
+/// An item as the planner believes it currently is.
+#[derive(Clone, Debug)]
+pub struct InventoryItem {
+    bucket_hash: Hash<DestinyInventoryBucketDefinition>,
+    equipped: bool,
+    in_postmaster: bool,
+    item_hash: Hash<DestinyInventoryItemDefinition>,
+    item_instance_id: i64,
+    location: Location,
+    locked: bool,
+    quantity: i32,
+    transfer_status: TransferStatuses,
+}
+
+impl InventoryItem {
+    /// The bucket the item goes into on a character, even while it sits in
+    /// the vault or the postmaster.
+    pub fn bucket_hash(&self) -> Hash<DestinyInventoryBucketDefinition> {
+        self.bucket_hash
+    }
+
+    pub fn equipped(&self) -> bool {
+        self.equipped
+    }
+
+    pub fn in_postmaster(&self) -> bool {
+        self.in_postmaster
+    }
+
+    pub fn item_hash(&self) -> Hash<DestinyInventoryItemDefinition> {
+        self.item_hash
+    }
+
+    /// The instance id of the item, or 0 for a stack of a non-instanced item.
+    pub fn item_instance_id(&self) -> i64 {
+        self.item_instance_id
+    }
+
+    /// The key the item is planned by. A stack's key changes when it moves.
+    pub fn key(&self) -> ItemKey {
+        if self.item_instance_id == 0 {
+            ItemKey::Stack {
+                item_hash: self.item_hash,
+                location: self.location,
+            }
+        } else {
+            ItemKey::Instance(self.item_instance_id)
+        }
+    }
+
+    pub fn location(&self) -> Location {
+        self.location
+    }
+
+    pub fn locked(&self) -> bool {
+        self.locked
+    }
+
+    pub fn quantity(&self) -> i32 {
+        self.quantity
+    }
+
+    /// The TransferStatuses flags reported by the API.
+    pub fn transfer_status(&self) -> TransferStatuses {
+        self.transfer_status
+    }
+}
+
+/// A single action the API needs to perform for an item to be moved.
+#[derive(Clone, Debug)]
+pub enum MoveStep {
+    Equip(DestinyItemActionRequest),
+    PullFromPostmaster(DestinyPostmasterTransferRequest),
+    Transfer(DestinyItemTransferRequest),
+}
+
+impl MoveStep {
+    /// Sends the step's request.
+    pub async fn send(&self, destiny: &Destiny<'_>) -> Result<Response<i32>> {
+        match self {
+            MoveStep::Equip(request) => destiny.equip_item(request).await,
+            MoveStep::PullFromPostmaster(request) => destiny.pull_from_postmaster(request).await,
+            MoveStep::Transfer(request) => destiny.transfer_item(request).await,
+        }
+    }
+}
+
+impl fmt::Display for MoveStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveStep::Equip(request) => write!(
+                f,
+                "equip item {} on character {}",
+                request.item_id(),
+                request.character_id()
+            ),
+            MoveStep::PullFromPostmaster(request) => write!(
+                f,
+                "pull item {} ({}) from the postmaster of character {}",
+                request.item_id(),
+                request.item_reference_hash(),
+                request.character_id()
+            ),
+            MoveStep::Transfer(request) if request.transfer_to_vault() => write!(
+                f,
+                "transfer item {} ({}) from character {} to the vault",
+                request.item_id(),
+                request.item_reference_hash(),
+                request.character_id()
+            ),
+            MoveStep::Transfer(request) => write!(
+                f,
+                "transfer item {} ({}) from the vault to character {}",
+                request.item_id(),
+                request.item_reference_hash(),
+                request.character_id()
+            ),
+        }
+    }
+}
+
+/// Sends the steps in order, stopping at the first one the API rejects.
+pub async fn execute(destiny: &Destiny<'_>, steps: &[MoveStep]) -> Result<()> {
+    for step in steps {
+        let response = step.send(destiny).await?;
+        if response.error_code() != PLATFORM_ERROR_SUCCESS {
+            return Err(Box::new(MoveError::Rejected {
+                error_status: response.error_status().to_string(),
+                message: response.message().to_string(),
+            }));
+        }
+    }
+    Ok(())
+}
+
+/// Why a move couldn't be planned or carried out.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MoveError {
+    /// The planner doesn't know about the item.
+    UnknownItem { item: ItemKey },
+    /// The item's bucket isn't in the world content.
+    UnknownBucket {
+        bucket_hash: Hash<DestinyInventoryBucketDefinition>,
+    },
+    /// The item can't leave the character or vault it's in.
+    NotTransferrable { item: ItemKey },
+    /// The item is equipped and nothing else in its bucket can take its place.
+    NoReplacement { item_instance_id: i64 },
+    /// The bucket is full and nothing in it can be moved out of the way.
+    BucketFull {
+        character_id: i64,
+        bucket_hash: Hash<DestinyInventoryBucketDefinition>,
+    },
+    /// The vault is full.
+    VaultFull,
+    /// The API refused one of the steps.
+    Rejected {
+        error_status: String,
+        message: String,
+    },
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::UnknownItem { item } => {
+                write!(f, "{} is not in the inventory", item)
+            }
+            MoveError::UnknownBucket { bucket_hash } => {
+                write!(f, "bucket {} is not in the world content", bucket_hash)
+            }
+            MoveError::NotTransferrable { item } => {
+                write!(f, "{} can't be transferred", item)
+            }
+            MoveError::NoReplacement { item_instance_id } => write!(
+                f,
+                "item {} is equipped and there's nothing to equip in its place",
+                item_instance_id
+            ),
+            MoveError::BucketFull {
+                character_id,
+                bucket_hash,
+            } => write!(
+                f,
+                "bucket {} on character {} is full",
+                bucket_hash, character_id
+            ),
+            MoveError::VaultFull => write!(f, "the vault is full"),
+            MoveError::Rejected {
+                error_status,
+                message,
+            } => write!(f, "the move was rejected ({}): {}", error_status, message),
+        }
+    }
+}
+
+impl Error for MoveError {}
+
+impl fmt::Display for ItemKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ItemKey::Instance(item_instance_id) => write!(f, "item {}", item_instance_id),
+            ItemKey::Stack {
+                item_hash,
+                location: Location::Character(character_id),
+            } => write!(
+                f,
+                "the stack of {} on character {}",
+                item_hash, character_id
+            ),
+            ItemKey::Stack {
+                item_hash,
+                location: Location::Vault,
+            } => write!(f, "the stack of {} in the vault", item_hash),
+        }
+    }
+}
+
+/// Plans the transfers needed to move items between characters and the vault.
+///
+/// The planner keeps track of where it has moved things, so that several
+/// moves planned one after the other are consistent with each other. Items
+/// only ever move between characters through the vault, and a full bucket is
+/// made room in by sending one of its unequipped items to the vault, unlocked
+/// items first.
+#[derive(Debug)]
+pub struct MovePlanner<'a, S> {
+    content: &'a S,
+    items: Vec<InventoryItem>,
+    membership_type: BungieMembershipType,
+}
+
+impl<'a, S: DefinitionStore> MovePlanner<'a, S> {
+    pub fn new(content: &'a S, membership_type: BungieMembershipType) -> Self {
+        Self {
+            content,
+            items: Vec::new(),
+            membership_type,
+        }
+    }
+
+    /// Adds a character's inventory, including its postmaster, and its
+    /// equipment.
+    pub fn with_character(
+        mut self,
+        character_id: i64,
+        inventory: &[DestinyItemComponent],
+        equipment: &[DestinyItemComponent],
+    ) -> Self {
+        let location = Location::Character(character_id);
+        for item in inventory {
+            self.add(item, location, false);
+        }
+        for item in equipment {
+            self.add(item, location, true);
+        }
+        self
+    }
+
+    /// Adds the items in the vault out of the profile inventory. Everything
+    /// else in the profile inventory is shared between characters and never
+    /// needs moving.
+    pub fn with_vault(mut self, profile_inventory: &[DestinyItemComponent]) -> Self {
+        for item in profile_inventory {
+            if item.bucket_hash() == VAULT_BUCKET_HASH {
+                self.add(item, Location::Vault, false);
+            }
+        }
+        self
+    }
+
+    fn add(&mut self, item: &DestinyItemComponent, location: Location, equipped: bool) {
+        let bucket_hash = item
+            .item_hash()
+            .resolve(self.content)
+            .and_then(|d| d.inventory())
+            .and_then(|i| i.bucket_type_hash())
+            .unwrap_or_else(|| item.bucket_hash());
+
+        self.items.push(InventoryItem {
+            bucket_hash,
+            equipped,
+            in_postmaster: item.location() == ItemLocation::Postmaster,
+            item_hash: item.item_hash(),
+            item_instance_id: item.item_instance_id(),
+            location,
+            locked: item.state().contains(ItemState::LOCKED),
+            quantity: item.quantity(),
+            transfer_status: item.transfer_status(),
+        });
+    }
+
+    pub fn membership_type(&self) -> BungieMembershipType {
+        self.membership_type
+    }
+
+    /// Every item, as it will be once the moves planned so far are done.
+    pub fn items(&self) -> &[InventoryItem] {
+        self.items.as_ref()
+    }
+
+    pub fn item(&self, key: ItemKey) -> Option<&InventoryItem> {
+        self.items.iter().find(|item| item.key() == key)
+    }
+
+    /// Plans moving an item or a whole stack to a character or the vault,
+    /// pulling it from the postmaster first when it's there.
+    ///
+    /// Nothing is remembered of a move that can't be planned.
+    pub fn plan_move(
+        &mut self,
+        key: ItemKey,
+        destination: Location,
+    ) -> std::result::Result<Vec<MoveStep>, MoveError> {
+        self.atomically(|planner, steps| planner.move_item(key, destination, steps))
+    }
+
+    /// Plans moving an item to a character and equipping it there.
+    pub fn plan_equip(
+        &mut self,
+        item_instance_id: i64,
+        character_id: i64,
+    ) -> std::result::Result<Vec<MoveStep>, MoveError> {
+        self.atomically(|planner, steps| {
+            let key = ItemKey::Instance(item_instance_id);
+            planner.move_item(key, Location::Character(character_id), steps)?;
+            planner.equip(item_instance_id, character_id, steps);
+            Ok(())
+        })
+    }
+
+    fn atomically(
+        &mut self,
+        plan: impl FnOnce(&mut Self, &mut Vec<MoveStep>) -> std::result::Result<(), MoveError>,
+    ) -> std::result::Result<Vec<MoveStep>, MoveError> {
+        let items = self.items.clone();
+        let mut steps = Vec::new();
+        match plan(self, &mut steps) {
+            Ok(()) => {
+                self.merge_stacks();
+                Ok(steps)
+            }
+            Err(e) => {
+                self.items = items;
+                Err(e)
+            }
+        }
+    }
+
+    /// Merges the stacks of an item that have been moved to where another
+    /// stack of it was, as the game does as long as they fit in one stack.
+    fn merge_stacks(&mut self) {
+        let mut merged: Vec<InventoryItem> = Vec::with_capacity(self.items.len());
+        for item in self.items.drain(..) {
+            let max_stack_size = item
+                .item_hash
+                .resolve(self.content)
+                .and_then(|d| d.inventory())
+                .and_then(|i| i.max_stack_size())
+                .unwrap_or(i32::MAX);
+            let stack = merged.iter_mut().find(|other| {
+                item.item_instance_id == 0
+                    && !item.in_postmaster
+                    && !other.in_postmaster
+                    && other.key() == item.key()
+                    && other.quantity.saturating_add(item.quantity) <= max_stack_size
+            });
+            match stack {
+                Some(stack) => stack.quantity += item.quantity,
+                None => merged.push(item),
+            }
+        }
+        self.items = merged;
+    }
+
+    fn move_item(
+        &mut self,
+        key: ItemKey,
+        destination: Location,
+        steps: &mut Vec<MoveStep>,
+    ) -> std::result::Result<(), MoveError> {
+        let index = self.index(key)?;
+        let item = &self.items[index];
+
+        if item.in_postmaster {
+            let Location::Character(character_id) = item.location else {
+                return Err(MoveError::UnknownItem { item: key });
+            };
+            self.make_room(character_id, item.bucket_hash, index, steps)?;
+            let item = &mut self.items[index];
+            steps.push(MoveStep::PullFromPostmaster(
+                DestinyPostmasterTransferRequest::new(
+                    item.item_hash,
+                    item.quantity,
+                    item.item_instance_id,
+                    character_id,
+                    self.membership_type,
+                ),
+            ));
+            item.in_postmaster = false;
+        }
+
+        if self.items[index].location == destination {
+            return Ok(());
+        }
+        if !self.transferrable(&self.items[index]) {
+            return Err(MoveError::NotTransferrable { item: key });
+        }
+
+        if let Location::Character(character_id) = self.items[index].location {
+            if self.items[index].equipped {
+                let replacement = self.replacement(index).ok_or(MoveError::NoReplacement {
+                    item_instance_id: self.items[index].item_instance_id,
+                })?;
+                self.equip(replacement, character_id, steps);
+            }
+            self.send_to_vault(index, character_id, steps)?;
+        }
+
+        if let Location::Character(character_id) = destination {
+            let bucket_hash = self.items[index].bucket_hash;
+            self.make_room(character_id, bucket_hash, index, steps)?;
+            let item = &mut self.items[index];
+            steps.push(MoveStep::Transfer(DestinyItemTransferRequest::new(
+                item.item_hash,
+                item.quantity,
+                false,
+                item.item_instance_id,
+                character_id,
+                self.membership_type,
+            )));
+            item.location = destination;
+        }
+
+        Ok(())
+    }
+
+    /// Equips an item that's already on the character, unequipping whatever
+    /// was in its bucket.
+    fn equip(&mut self, item_instance_id: i64, character_id: i64, steps: &mut Vec<MoveStep>) {
+        let Ok(index) = self.index(ItemKey::Instance(item_instance_id)) else {
+            return;
+        };
+        if self.items[index].equipped {
+            return;
+        }
+
+        let bucket_hash = self.items[index].bucket_hash;
+        for item in &mut self.items {
+            if item.location == Location::Character(character_id) && item.bucket_hash == bucket_hash
+            {
+                item.equipped = item.item_instance_id == item_instance_id;
+            }
+        }
+        steps.push(MoveStep::Equip(DestinyItemActionRequest::new(
+            item_instance_id,
+            character_id,
+            self.membership_type,
+        )));
+    }
+
+    fn send_to_vault(
+        &mut self,
+        index: usize,
+        character_id: i64,
+        steps: &mut Vec<MoveStep>,
+    ) -> std::result::Result<(), MoveError> {
+        let capacity = self.capacity(VAULT_BUCKET_HASH)?;
+        let used = self
+            .items
+            .iter()
+            .filter(|item| item.location == Location::Vault)
+            .count();
+        if used >= capacity {
+            return Err(MoveError::VaultFull);
+        }
+
+        let item = &mut self.items[index];
+        steps.push(MoveStep::Transfer(DestinyItemTransferRequest::new(
+            item.item_hash,
+            item.quantity,
+            true,
+            item.item_instance_id,
+            character_id,
+            self.membership_type,
+        )));
+        item.location = Location::Vault;
+        item.equipped = false;
+        Ok(())
+    }
+
+    /// Sends something to the vault if the character's bucket is full, never
+    /// the item that's being made room for, at index `keep`.
+    fn make_room(
+        &mut self,
+        character_id: i64,
+        bucket_hash: Hash<DestinyInventoryBucketDefinition>,
+        keep: usize,
+        steps: &mut Vec<MoveStep>,
+    ) -> std::result::Result<(), MoveError> {
+        let capacity = self.capacity(bucket_hash)?;
+        let in_bucket = |item: &InventoryItem| {
+            item.location == Location::Character(character_id)
+                && item.bucket_hash == bucket_hash
+                && !item.in_postmaster
+        };
+        if self.items.iter().filter(|item| in_bucket(item)).count() < capacity {
+            return Ok(());
+        }
+
+        let index = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(index, item)| {
+                in_bucket(item) && !item.equipped && *index != keep && self.transferrable(item)
+            })
+            .min_by_key(|(_, item)| item.locked)
+            .map(|(index, _)| index)
+            .ok_or(MoveError::BucketFull {
+                character_id,
+                bucket_hash,
+            })?;
+        self.send_to_vault(index, character_id, steps)
+    }
+
+    /// An unequipped instanced item in the same bucket on the same character.
+    fn replacement(&self, index: usize) -> Option<i64> {
+        let item = &self.items[index];
+        self.items
+            .iter()
+            .enumerate()
+            .find(|(other_index, other)| {
+                other.location == item.location
+                    && other.bucket_hash == item.bucket_hash
+                    && !other.equipped
+                    && !other.in_postmaster
+                    && other.item_instance_id != 0
+                    && *other_index != index
+            })
+            .map(|(_, other)| other.item_instance_id)
+    }
+
+    fn transferrable(&self, item: &InventoryItem) -> bool {
+        let non_transferrable = item
+            .item_hash
+            .resolve(self.content)
+            .and_then(|d| d.non_transferrable())
+            .unwrap_or(false);
+
+        !non_transferrable
+            && !item
+                .transfer_status
+                .contains(TransferStatuses::NOT_TRANSFERRABLE)
+    }
+
+    fn capacity(
+        &self,
+        bucket_hash: Hash<DestinyInventoryBucketDefinition>,
+    ) -> std::result::Result<usize, MoveError> {
+        bucket_hash
+            .resolve(self.content)
+            .and_then(|bucket| bucket.item_count())
+            .map(|count| count.max(0) as usize)
+            .ok_or(MoveError::UnknownBucket { bucket_hash })
+    }
+
+    /// The index of the item with the key. A stack in the postmaster shares
+    /// its key with a stack of the same item in the character's inventory, in
+    /// which case the one in the inventory is used.
+    fn index(&self, key: ItemKey) -> std::result::Result<usize, MoveError> {
+        let matching = || {
+            self.items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.key() == key)
+        };
+        matching()
+            .find(|(_, item)| !item.in_postmaster)
+            .or_else(|| matching().next())
+            .map(|(index, _)| index)
+            .ok_or(MoveError::UnknownItem { item: key })
+    }
+}
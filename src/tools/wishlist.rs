@@ -0,0 +1,433 @@
+use std::{collections::HashSet, error::Error, fmt, str::FromStr};
+
+use crate::bnet::entities::destiny::{
+    components::items::DestinyItemReusablePlugsComponent,
+    definitions::DestinyInventoryItemDefinition,
+    entities::items::DestinyItemSocketsComponent,
+    hashes::{DefinitionStore, Hash},
+};
+
+/// Prefix of every roll line in a DIM wishlist file.
+const ROLL_PREFIX: &str = "dimwishlist:";
+
+/// Item hash DIM uses for rolls that apply to every item.
+const ANY_ITEM_HASH: i64 = -69420;
+
+/// A wishlist in the DIM wishlist text format.
+///
+/// Every roll lives on its own line, like
+/// `dimwishlist:item=1234&perks=5678,9012#notes:PvE god roll`. A negative item
+/// hash marks a roll to avoid, and item `-69420` matches every item. Lines
+/// starting with `//notes:` set the notes of every following roll up to the
+/// next blank line, unless a roll carries its own `#notes:`. The optional
+/// `title:` and `description:` lines describe the list itself; every other
+/// line is ignored.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Wishlist {
+    description: Option<String>,
+    rolls: Vec<WishlistRoll>,
+    title: Option<String>,
+}
+
+impl Wishlist {
+    pub fn parse(text: &str) -> Result<Self, WishlistParseError> {
+        let mut wishlist = Wishlist::default();
+        let mut block_notes: Option<String> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                block_notes = None;
+            } else if let Some(notes) = line.strip_prefix("//notes:") {
+                block_notes = Some(notes.trim().to_string()).filter(|n| !n.is_empty());
+            } else if let Some(title) = line.strip_prefix("title:") {
+                wishlist.title = Some(title.trim().to_string());
+            } else if let Some(description) = line.strip_prefix("description:") {
+                wishlist.description = Some(description.trim().to_string());
+            } else if let Some(roll) = line.strip_prefix(ROLL_PREFIX) {
+                let mut roll = WishlistRoll::parse(roll, index + 1)?;
+                if roll.notes.is_none() {
+                    roll.notes = block_notes.clone();
+                }
+                wishlist.rolls.push(roll);
+            }
+        }
+
+        Ok(wishlist)
+    }
+
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+
+    pub fn rolls(&self) -> &[WishlistRoll] {
+        self.rolls.as_ref()
+    }
+
+    pub fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+
+    /// The rolls that apply to the given item, including the ones that apply
+    /// to every item.
+    pub fn rolls_for(
+        &self,
+        item_hash: Hash<DestinyInventoryItemDefinition>,
+    ) -> impl Iterator<Item = &WishlistRoll> {
+        self.rolls
+            .iter()
+            .filter(move |roll| roll.item_hash.is_none_or(|hash| hash == item_hash))
+    }
+}
+
+impl FromStr for Wishlist {
+    type Err = WishlistParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Wishlist::parse(s)
+    }
+}
+
+/// A single `dimwishlist:` line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WishlistRoll {
+    item_hash: Option<Hash<DestinyInventoryItemDefinition>>,
+    line: usize,
+    notes: Option<String>,
+    perk_hashes: Vec<Hash<DestinyInventoryItemDefinition>>,
+    trash: bool,
+}
+
+impl WishlistRoll {
+    fn parse(roll: &str, line: usize) -> Result<Self, WishlistParseError> {
+        let error = |message: String| WishlistParseError { line, message };
+        let (roll, notes) = match roll.split_once("#notes:") {
+            Some((roll, notes)) => (roll, Some(notes.trim().to_string())),
+            None => (roll, None),
+        };
+
+        let mut item = None;
+        let mut perk_hashes = Vec::new();
+        for pair in roll.split('&') {
+            match pair.split_once('=') {
+                Some(("item", value)) => {
+                    let hash = value
+                        .trim()
+                        .parse::<i64>()
+                        .map_err(|_| error(format!("invalid item hash \"{}\"", value)))?;
+                    item = Some(hash);
+                }
+                Some(("perks", value)) => {
+                    for perk in value.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+                        let hash = perk
+                            .parse::<u32>()
+                            .map_err(|_| error(format!("invalid perk hash \"{}\"", perk)))?;
+                        perk_hashes.push(Hash::new(hash));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let item = item.ok_or_else(|| error("missing item=".to_string()))?;
+        let (item_hash, trash) = if item == ANY_ITEM_HASH {
+            (None, false)
+        } else {
+            let hash = u32::try_from(item.abs())
+                .map_err(|_| error(format!("item hash {} is out of range", item)))?;
+            (Some(Hash::new(hash)), item < 0)
+        };
+
+        Ok(Self {
+            item_hash,
+            line,
+            notes: notes.filter(|notes| !notes.is_empty()),
+            perk_hashes,
+            trash,
+        })
+    }
+
+    /// The item this roll applies to, or None if it applies to every item.
+    pub fn item_hash(&self) -> Option<Hash<DestinyInventoryItemDefinition>> {
+        self.item_hash
+    }
+
+    /// The line of the wishlist this roll was read from, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn notes(&self) -> Option<&String> {
+        self.notes.as_ref()
+    }
+
+    /// The perks the item must have for the roll to match. An empty list
+    /// matches any roll of the item.
+    pub fn perk_hashes(&self) -> &[Hash<DestinyInventoryItemDefinition>] {
+        self.perk_hashes.as_ref()
+    }
+
+    /// Whether this is a roll to avoid rather than one to keep.
+    pub fn trash(&self) -> bool {
+        self.trash
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WishlistParseError {
+    line: usize,
+    message: String,
+}
+
+impl WishlistParseError {
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for WishlistParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for WishlistParseError {}
+
+/// How a weapon compares to one roll of a wishlist.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RollMatch<'w> {
+    missing_perk_hashes: Vec<Hash<DestinyInventoryItemDefinition>>,
+    roll: &'w WishlistRoll,
+}
+
+impl<'w> RollMatch<'w> {
+    /// Whether the weapon has every perk of the roll.
+    pub fn is_match(&self) -> bool {
+        self.missing_perk_hashes.is_empty()
+    }
+
+    /// The perks of the roll the weapon doesn't have.
+    pub fn missing_perk_hashes(&self) -> &[Hash<DestinyInventoryItemDefinition>] {
+        self.missing_perk_hashes.as_ref()
+    }
+
+    pub fn notes(&self) -> Option<&'w String> {
+        self.roll.notes.as_ref()
+    }
+
+    pub fn roll(&self) -> &'w WishlistRoll {
+        self.roll
+    }
+}
+
+/// A problem found while checking a wishlist against the world content.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WishlistIssue {
+    /// The roll refers to an item that isn't in the world content.
+    UnknownItem {
+        line: usize,
+        item_hash: Hash<DestinyInventoryItemDefinition>,
+    },
+    /// The roll refers to a perk that isn't in the world content.
+    UnknownPerk {
+        line: usize,
+        perk_hash: Hash<DestinyInventoryItemDefinition>,
+    },
+    /// The perk exists, but no socket of the item can ever hold it.
+    PerkCannotRoll {
+        line: usize,
+        item_hash: Hash<DestinyInventoryItemDefinition>,
+        perk_hash: Hash<DestinyInventoryItemDefinition>,
+    },
+}
+
+impl fmt::Display for WishlistIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WishlistIssue::UnknownItem { line, item_hash } => {
+                write!(f, "line {}: unknown item {}", line, item_hash)
+            }
+            WishlistIssue::UnknownPerk { line, perk_hash } => {
+                write!(f, "line {}: unknown perk {}", line, perk_hash)
+            }
+            WishlistIssue::PerkCannotRoll {
+                line,
+                item_hash,
+                perk_hash,
+            } => write!(
+                f,
+                "line {}: perk {} can't roll on item {}",
+                line, perk_hash, item_hash
+            ),
+        }
+    }
+}
+
+/// Appraises weapons against a wishlist, using the socket and plug
+/// definitions of the world content.
+#[derive(Debug)]
+pub struct WishlistMatcher<'a, S> {
+    content: &'a S,
+    wishlist: &'a Wishlist,
+}
+
+impl<'a, S: DefinitionStore> WishlistMatcher<'a, S> {
+    pub fn new(content: &'a S, wishlist: &'a Wishlist) -> Self {
+        Self { content, wishlist }
+    }
+
+    /// Compares a weapon to every roll of the wishlist that applies to it.
+    ///
+    /// The weapon's perks are the plugs currently in its sockets, along with
+    /// every reusable plug it can switch to, when given. Enhanced perks count
+    /// as their base perk, since both share a name and a plug category.
+    pub fn appraise(
+        &self,
+        item_hash: Hash<DestinyInventoryItemDefinition>,
+        sockets: &DestinyItemSocketsComponent,
+        reusable_plugs: Option<&DestinyItemReusablePlugsComponent>,
+    ) -> Vec<RollMatch<'a>> {
+        let socketed = sockets
+            .sockets()
+            .iter()
+            .filter_map(|socket| socket.plug_hash());
+        let reusable = reusable_plugs
+            .into_iter()
+            .flat_map(|plugs| plugs.plugs().values().flatten())
+            .map(|plug| plug.plug_item_hash());
+        let available: HashSet<Hash<DestinyInventoryItemDefinition>> =
+            socketed.chain(reusable).collect();
+        let available_items: Vec<&DestinyInventoryItemDefinition> = available
+            .iter()
+            .filter_map(|hash| hash.resolve(self.content))
+            .collect();
+
+        self.wishlist
+            .rolls_for(item_hash)
+            .map(|roll| RollMatch {
+                missing_perk_hashes: roll
+                    .perk_hashes
+                    .iter()
+                    .filter(|perk| {
+                        !available.contains(perk) && !self.has_variant(**perk, &available_items)
+                    })
+                    .copied()
+                    .collect(),
+                roll,
+            })
+            .collect()
+    }
+
+    /// Checks every roll that names an item against that item's definition
+    /// and plug sets, flagging the perks that can never roll on it.
+    pub fn validate(&self) -> Vec<WishlistIssue> {
+        let mut issues = Vec::new();
+
+        for roll in &self.wishlist.rolls {
+            for perk_hash in &roll.perk_hashes {
+                if perk_hash.resolve(self.content).is_none() {
+                    issues.push(WishlistIssue::UnknownPerk {
+                        line: roll.line,
+                        perk_hash: *perk_hash,
+                    });
+                }
+            }
+
+            let Some(item_hash) = roll.item_hash else {
+                continue;
+            };
+            let Some(item) = item_hash.resolve(self.content) else {
+                issues.push(WishlistIssue::UnknownItem {
+                    line: roll.line,
+                    item_hash,
+                });
+                continue;
+            };
+
+            let rollable = self.rollable_plugs(item);
+            let rollable_items: Vec<&DestinyInventoryItemDefinition> = rollable
+                .iter()
+                .filter_map(|hash| hash.resolve(self.content))
+                .collect();
+            for perk_hash in &roll.perk_hashes {
+                let known = perk_hash.resolve(self.content).is_some();
+                if known
+                    && !rollable.contains(perk_hash)
+                    && !self.has_variant(*perk_hash, &rollable_items)
+                {
+                    issues.push(WishlistIssue::PerkCannotRoll {
+                        line: roll.line,
+                        item_hash,
+                        perk_hash: *perk_hash,
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Every plug any socket of the item can hold, according to its socket
+    /// entries and their plug sets.
+    pub fn rollable_plugs(
+        &self,
+        item: &DestinyInventoryItemDefinition,
+    ) -> HashSet<Hash<DestinyInventoryItemDefinition>> {
+        let mut plugs = HashSet::new();
+        let entries = item.sockets().and_then(|sockets| sockets.socket_entries());
+
+        for entry in entries.into_iter().flatten() {
+            plugs.extend(
+                entry
+                    .single_initial_item_hash()
+                    .filter(|hash| hash.value() != 0),
+            );
+            plugs.extend(
+                entry
+                    .reusable_plug_items()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|plug| plug.plug_item_hash()),
+            );
+
+            let plug_sets = [
+                entry.randomized_plug_set_hash(),
+                entry.reusable_plug_set_hash(),
+            ];
+            for plug_set_hash in plug_sets.into_iter().flatten() {
+                plugs.extend(
+                    plug_set_hash
+                        .resolve(self.content)
+                        .and_then(|plug_set| plug_set.reusable_plug_items())
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|plug| plug.plug_item_hash()),
+                );
+            }
+        }
+
+        plugs
+    }
+
+    /// Whether one of the plugs is a variant of the perk, such as its enhanced
+    /// version: a different plug with the same name and plug category.
+    fn has_variant(
+        &self,
+        perk_hash: Hash<DestinyInventoryItemDefinition>,
+        plugs: &[&DestinyInventoryItemDefinition],
+    ) -> bool {
+        let Some(perk) = perk_hash.resolve(self.content) else {
+            return false;
+        };
+        let name = perk.display_properties().and_then(|d| d.name());
+        let category = perk.plug().and_then(|plug| plug.plug_category_hash());
+        if name.is_none_or(|name| name.is_empty()) || category.is_none() {
+            return false;
+        }
+
+        plugs.iter().any(|plug| {
+            plug.display_properties().and_then(|d| d.name()) == name
+                && plug.plug().and_then(|p| p.plug_category_hash()) == category
+        })
+    }
+}
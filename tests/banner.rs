@@ -0,0 +1,150 @@
+//! Clan banners put together out of a small clan banner database and the
+//! layer images cached for it.
+//!
+//! The fixture's banner is the one of `groups_for_member.json`. Its layers
+//! are all 8-bit PNGs, each in another color type:
+//!
+//! - the gonfalon, 4×4 opaque white RGB, tinted (200, 100, 50);
+//! - the gonfalon detail, 4×4 RGBA, opaque white along the top row only,
+//!   tinted (0, 0, 255);
+//! - the decal background, 2×2 RGBA, white at half alpha (128), tinted
+//!   (0, 255, 0);
+//! - the decal foreground, 2×2 grayscale with alpha, gray 128 at its bottom
+//!   right pixel and transparent elsewhere, tinted white.
+//!
+//! Decal 1000 is in the database but its images aren't cached.
+
+use std::path::Path;
+
+use guardian_multi_tool::{
+    bnet::entities::groups_v2::ClanBanner,
+    tools::banner::{BannerCanvas, BannerColor, BannerError, BannerRenderer, ClanBannerDatabase},
+};
+use serde_json::json;
+use tempfile::TempDir;
+
+const FIXTURE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/clan_banner");
+
+fn database() -> ClanBannerDatabase {
+    ClanBannerDatabase::open(Path::new(FIXTURE_DIR).join("clan_banner.sqlite")).unwrap()
+}
+
+fn banner(decal_id: u32) -> ClanBanner {
+    serde_json::from_value(json!({
+        "decalId": decal_id,
+        "decalColorId": 3379387795u32,
+        "decalBackgroundColorId": 3568748756u32,
+        "gonfalonId": 1473910866,
+        "gonfalonColorId": 2174413148u32,
+        "gonfalonDetailId": 1681785683,
+        "gonfalonDetailColorId": 1664695568
+    }))
+    .unwrap()
+}
+
+#[test]
+fn reads_the_database() {
+    let database = database();
+    assert_eq!(database.gonfalons().len(), 1);
+    assert_eq!(database.decals().len(), 2);
+    // Ids past i32::MAX are stored negative, and read back as they were.
+    assert!(database.decals().contains_key(&4142223379));
+    assert_eq!(
+        database.gonfalon_colors()[&2174413148],
+        BannerColor::new(200, 100, 50, 255)
+    );
+
+    let layers = database.layers(&banner(4142223379)).unwrap();
+    let paths: Vec<&str> = layers.iter().map(|(path, _)| *path).collect();
+    assert_eq!(
+        paths,
+        [
+            "/common/destiny2_content/clanbanner/gonfalons/gonfalon_1.png",
+            "/common/destiny2_content/clanbanner/gonfalon_details/detail_1.png",
+            "/common/destiny2_content/clanbanner/decals/decal_1_bg.png",
+            "/common/destiny2_content/clanbanner/decals/decal_1_fg.png",
+        ]
+    );
+}
+
+#[test]
+fn renders_a_known_banner() {
+    let database = database();
+    let renderer = BannerRenderer::new(&database, Path::new(FIXTURE_DIR).join("images"));
+    let canvas = renderer.render(&banner(4142223379)).unwrap();
+    assert_eq!((canvas.width(), canvas.height()), (4, 4));
+
+    let gonfalon = [200, 100, 50, 255];
+    let detail = [0, 0, 255, 255];
+    // Green at half alpha over the gonfalon.
+    let decal_background = [100, 178, 25, 255];
+    let decal_foreground = [128, 128, 128, 255];
+    let expected = [
+        [detail, detail, detail, detail],
+        [gonfalon, decal_background, decal_background, gonfalon],
+        [gonfalon, decal_background, decal_foreground, gonfalon],
+        [gonfalon, gonfalon, gonfalon, gonfalon],
+    ];
+    for (y, row) in expected.iter().enumerate() {
+        for (x, pixel) in row.iter().enumerate() {
+            let offset = (y * 4 + x) * 4;
+            assert_eq!(
+                &canvas.pixels()[offset..offset + 4],
+                pixel,
+                "pixel ({}, {})",
+                x,
+                y
+            );
+        }
+    }
+
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("banner.png");
+    renderer.render_png(&banner(4142223379), &path).unwrap();
+    assert_eq!(BannerCanvas::read_png(&path).unwrap(), canvas);
+}
+
+#[test]
+fn lists_the_images_that_are_missing() {
+    let database = database();
+    let renderer = BannerRenderer::new(&database, Path::new(FIXTURE_DIR).join("images"));
+    assert!(renderer
+        .missing_images(&banner(4142223379))
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        renderer.missing_images(&banner(1000)).unwrap(),
+        [
+            "/common/destiny2_content/clanbanner/decals/decal_2_bg.png",
+            "/common/destiny2_content/clanbanner/decals/decal_2_fg.png",
+        ]
+    );
+
+    let error = renderer.render(&banner(1000)).err().unwrap();
+    assert_eq!(
+        error.downcast_ref::<BannerError>(),
+        Some(&BannerError::NotCached {
+            path: renderer.cached_path("/common/destiny2_content/clanbanner/decals/decal_2_bg.png"),
+        })
+    );
+
+    let empty = TempDir::new().unwrap();
+    let renderer = BannerRenderer::new(&database, empty.path());
+    assert_eq!(
+        renderer.missing_images(&banner(4142223379)).unwrap().len(),
+        4
+    );
+}
+
+#[test]
+fn an_unknown_piece_is_an_error() {
+    let database = database();
+    let renderer = BannerRenderer::new(&database, Path::new(FIXTURE_DIR).join("images"));
+    assert_eq!(
+        renderer.missing_images(&banner(7)).err(),
+        Some(BannerError::UnknownPiece {
+            table: "Decals",
+            id: 7
+        })
+    );
+}
@@ -0,0 +1,175 @@
+//! Round trips of manifest definitions through the enums the API sends as
+//! integers, and the maps the manifest keys by their names.
+
+use std::collections::HashMap;
+
+use guardian_multi_tool::bnet::entities::{
+    destiny::{
+        definitions::{
+            energy_types::DestinyEnergyTypeDefinition, records::DestinyRecordDefinition,
+            DestinyClassDefinition, DestinyGenderDefinition, DestinyRaceDefinition,
+        },
+        hashes::Hash,
+        historical_stats::StatId,
+        DestinyClass, DestinyEnergyType, DestinyGender, DestinyRace,
+    },
+    BungieMembershipType,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+const WORLD_CONTENT: &str = include_str!("fixtures/world_content.json");
+
+fn fragment(table: &str, hash: &str) -> Value {
+    let content: Value = serde_json::from_str(WORLD_CONTENT).unwrap();
+    content[table][hash].clone()
+}
+
+/// Deserializes the definition and checks it serializes back to what the
+/// manifest has, apart from the fields it doesn't model.
+fn round_trip<T: DeserializeOwned + Serialize>(original: &Value) -> T {
+    let definition: T = serde_json::from_value(original.clone()).unwrap();
+    let serialized = serde_json::to_value(&definition).unwrap();
+    assert_subset(&serialized, original, "");
+    definition
+}
+
+fn assert_subset(serialized: &Value, original: &Value, path: &str) {
+    match (serialized, original) {
+        (Value::Object(serialized), Value::Object(original)) => {
+            for (key, value) in serialized.iter().filter(|(_, value)| !value.is_null()) {
+                let path = format!("{}.{}", path, key);
+                let original = original
+                    .get(key)
+                    .unwrap_or_else(|| panic!("{} isn't in the manifest", path));
+                assert_subset(value, original, &path);
+            }
+        }
+        (Value::Array(serialized), Value::Array(original)) => {
+            assert_eq!(serialized.len(), original.len(), "{}", path);
+            for (i, (serialized, original)) in serialized.iter().zip(original).enumerate() {
+                assert_subset(serialized, original, &format!("{}[{}]", path, i));
+            }
+        }
+        _ => assert_eq!(serialized, original, "{}", path),
+    }
+}
+
+#[test]
+fn class_definitions() {
+    let hunter: DestinyClassDefinition =
+        round_trip(&fragment("DestinyClassDefinition", "671679327"));
+    assert_eq!(hunter.class_type(), Some(DestinyClass::Hunter));
+    let names = hunter.gendered_class_names().unwrap();
+    assert_eq!(names[&DestinyGender::Male], "Hunter");
+    assert_eq!(names[&DestinyGender::Female], "Hunter");
+
+    let titan: DestinyClassDefinition =
+        round_trip(&fragment("DestinyClassDefinition", "3655393761"));
+    assert_eq!(titan.class_type(), Some(DestinyClass::Titan));
+}
+
+#[test]
+fn gender_definitions() {
+    let female: DestinyGenderDefinition =
+        round_trip(&fragment("DestinyGenderDefinition", "2204441813"));
+    assert_eq!(female.gender_type(), Some(DestinyGender::Female));
+    let male: DestinyGenderDefinition =
+        round_trip(&fragment("DestinyGenderDefinition", "3111576190"));
+    assert_eq!(male.gender_type(), Some(DestinyGender::Male));
+}
+
+#[test]
+fn race_definitions() {
+    let awoken: DestinyRaceDefinition =
+        round_trip(&fragment("DestinyRaceDefinition", "2803282938"));
+    assert_eq!(awoken.race_type(), Some(DestinyRace::Awoken));
+    assert_eq!(
+        awoken.gendered_race_names().unwrap()[&DestinyGender::Female],
+        "Awoken"
+    );
+}
+
+#[test]
+fn record_titles_by_gender() {
+    let record: DestinyRecordDefinition =
+        round_trip(&fragment("DestinyRecordDefinition", "2182090828"));
+    let titles = record.title_info().unwrap().titles_by_gender().unwrap();
+    assert_eq!(titles[&DestinyGender::Male], "Rivensbane");
+    assert_eq!(titles[&DestinyGender::Female], "Rivensbane");
+
+    let titles = record
+        .title_info()
+        .unwrap()
+        .titles_by_gender_hash()
+        .unwrap();
+    let male: Hash<DestinyGenderDefinition> = Hash::new(3111576190);
+    assert_eq!(titles[&male], "Rivensbane");
+}
+
+#[test]
+fn unknown_values_are_kept() {
+    let mut fragment = fragment("DestinyClassDefinition", "671679327");
+    fragment["classType"] = json!(3);
+    fragment["genderedClassNames"] = json!({ "Male": "Hunter", "7": "Hunter" });
+    let class: DestinyClassDefinition = round_trip(&fragment);
+    assert_eq!(class.class_type(), Some(DestinyClass::Unknown(3)));
+    assert_eq!(
+        class.gendered_class_names().unwrap()[&DestinyGender::Unknown(7)],
+        "Hunter"
+    );
+}
+
+#[test]
+fn energy_type_definitions() {
+    let void: DestinyEnergyTypeDefinition =
+        round_trip(&fragment("DestinyEnergyTypeDefinition", "591714140"));
+    assert_eq!(void.enum_value(), Some(DestinyEnergyType::Void));
+
+    let mut fragment = fragment("DestinyEnergyTypeDefinition", "1198124803");
+    fragment["enumValue"] = json!(9);
+    let unknown: DestinyEnergyTypeDefinition = round_trip(&fragment);
+    assert_eq!(unknown.enum_value(), Some(DestinyEnergyType::Unknown(9)));
+}
+
+#[test]
+fn membership_types() {
+    let types: Vec<BungieMembershipType> = serde_json::from_str("[-1, 0, 3, 6, 254, 42]").unwrap();
+    assert_eq!(
+        types,
+        [
+            BungieMembershipType::All,
+            BungieMembershipType::None,
+            BungieMembershipType::TigerSteam,
+            BungieMembershipType::TigerEgs,
+            BungieMembershipType::BungieNext,
+            BungieMembershipType::Unknown(42),
+        ]
+    );
+    assert_eq!(serde_json::to_string(&types).unwrap(), "[-1,0,3,6,254,42]");
+
+    let by_type: HashMap<BungieMembershipType, bool> =
+        serde_json::from_str(r#"{"3": true, "TigerPsn": false}"#).unwrap();
+    assert!(by_type[&BungieMembershipType::TigerSteam]);
+    assert!(!by_type[&BungieMembershipType::TigerPsn]);
+}
+
+#[test]
+fn stat_ids() {
+    let values: HashMap<StatId, f64> =
+        serde_json::from_str(r#"{"kills": 21, "activityDurationSeconds": 614, "medalMulti2x": 3}"#)
+            .unwrap();
+    assert_eq!(values[&StatId::Kills], 21.0);
+    assert_eq!(values[&StatId::ActivityDurationSeconds], 614.0);
+    let medal = StatId::from("medalMulti2x");
+    assert_eq!(medal, StatId::Unknown("medalMulti2x".to_string()));
+    assert!(medal.is_medal());
+    assert_eq!(values[&medal], 3.0);
+
+    assert_eq!(StatId::from("completionReason"), StatId::CompletionReason);
+    assert_eq!(StatId::from(String::from("deaths")), StatId::Deaths);
+    assert_eq!(
+        serde_json::to_string(&[StatId::UniqueWeaponPrecisionKills, medal]).unwrap(),
+        r#"["uniqueWeaponPrecisionKills","medalMulti2x"]"#
+    );
+}
@@ -0,0 +1,361 @@
+//! Mods fitted to an armor piece of the world content: its energy, its
+//! sockets' whitelists, unique mods and the rules the API reports on plugs.
+
+use std::collections::HashMap;
+
+use guardian_multi_tool::{
+    bnet::entities::destiny::{
+        definitions::DestinyInventoryItemDefinition,
+        entities::items::DestinyItemSocketState,
+        hashes::{DefinitionStore, Hash},
+        sockets::DestinyItemPlugBase,
+        DestinyWorldContent,
+    },
+    tools::fitting::{ArmorEnergy, FitProblem, ModFitter, ProposedMod},
+};
+use serde_json::json;
+
+const WORLD_CONTENT: &str = include_str!("fixtures/world_content.json");
+
+const HELMET_HASH: u32 = 2539150055;
+const ACE_OF_SPADES_HASH: u32 = 347366834;
+
+const RESILIENCE_MOD_HASH: u32 = 2850583378;
+const RECOVERY_MOD_HASH: u32 = 2645858828;
+const HAND_CANNON_TARGETING_HASH: u32 = 2719698929;
+const HEAVY_AMMO_FINDER_HASH: u32 = 1274140735;
+const SPECIAL_AMMO_FINDER_HASH: u32 = 3183180185;
+const VOID_MASTERWORK_HASH: u32 = 2299599999;
+const ARC_TIER_7_HASH: u32 = 3728733956;
+
+const ANY_ENERGY_HASH: u32 = 1198124803;
+const ARC_ENERGY_HASH: u32 = 728351493;
+const VOID_ENERGY_HASH: u32 = 591714140;
+
+// The helmet's sockets.
+const GENERAL_SOCKET: i32 = 0;
+const HEAD_SOCKET: i32 = 1;
+const SECOND_HEAD_SOCKET: i32 = 2;
+
+fn content() -> DestinyWorldContent {
+    serde_json::from_str(WORLD_CONTENT).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn item(content: &DestinyWorldContent, hash: u32) -> &DestinyInventoryItemDefinition {
+    content.get(Hash::new(hash)).unwrap()
+}
+
+fn proposed(socket_index: i32, plug_item_hash: u32) -> ProposedMod {
+    ProposedMod::new(socket_index, Hash::new(plug_item_hash))
+}
+
+fn void_energy(capacity: i32) -> ArmorEnergy {
+    ArmorEnergy::new(capacity, Some(Hash::new(VOID_ENERGY_HASH)))
+}
+
+fn problems(
+    fitter: &ModFitter<'_, DestinyWorldContent>,
+    content: &DestinyWorldContent,
+    energy: ArmorEnergy,
+    mods: &[ProposedMod],
+) -> Vec<FitProblem> {
+    fitter
+        .validate(item(content, HELMET_HASH), energy, mods)
+        .problems()
+        .to_vec()
+}
+
+#[test]
+fn armor_energy_comes_from_the_masterwork_plug() {
+    let content = content();
+    let fitter = ModFitter::new(&content);
+
+    // From the plug's energy capacity entry.
+    assert_eq!(
+        fitter.armor_energy(&[
+            Hash::new(RESILIENCE_MOD_HASH),
+            Hash::new(VOID_MASTERWORK_HASH)
+        ]),
+        Some(void_energy(10))
+    );
+    // From its investment stats, matched to an energy type's capacity stat.
+    assert_eq!(
+        fitter.armor_energy(&[Hash::new(ARC_TIER_7_HASH)]),
+        Some(ArmorEnergy::new(7, Some(Hash::new(ARC_ENERGY_HASH))))
+    );
+    // The plug granting the most wins.
+    assert_eq!(
+        fitter.armor_energy(&[Hash::new(ARC_TIER_7_HASH), Hash::new(VOID_MASTERWORK_HASH)]),
+        Some(void_energy(10))
+    );
+    assert_eq!(fitter.armor_energy(&[Hash::new(RESILIENCE_MOD_HASH)]), None);
+}
+
+#[test]
+fn energy_costs_come_from_the_entry_or_the_investment_stats() {
+    let content = content();
+    let fitter = ModFitter::new(&content);
+
+    assert_eq!(
+        fitter.energy_cost(item(&content, HAND_CANNON_TARGETING_HASH)),
+        Some((3, Some(Hash::new(ARC_ENERGY_HASH))))
+    );
+    assert_eq!(
+        fitter.energy_cost(item(&content, HEAVY_AMMO_FINDER_HASH)),
+        Some((3, Some(Hash::new(ANY_ENERGY_HASH))))
+    );
+    assert_eq!(
+        fitter.energy_cost(item(&content, VOID_MASTERWORK_HASH)),
+        None
+    );
+}
+
+#[test]
+fn mods_that_fit() {
+    let content = content();
+    let report = ModFitter::new(&content).validate(
+        item(&content, HELMET_HASH),
+        void_energy(10),
+        &[
+            proposed(GENERAL_SOCKET, RESILIENCE_MOD_HASH),
+            proposed(HEAD_SOCKET, SPECIAL_AMMO_FINDER_HASH),
+        ],
+    );
+    assert!(report.fits(), "{:?}", report.problems());
+    assert_eq!(report.energy_capacity(), 10);
+    assert_eq!(report.energy_used(), 6);
+    assert_eq!(report.energy_remaining(), 4);
+}
+
+#[test]
+fn plugs_missing_from_the_world_content_or_not_plugs() {
+    let content = content();
+    let fitter = ModFitter::new(&content);
+    assert_eq!(
+        problems(
+            &fitter,
+            &content,
+            void_energy(10),
+            &[
+                proposed(GENERAL_SOCKET, 1),
+                proposed(HEAD_SOCKET, ACE_OF_SPADES_HASH)
+            ]
+        ),
+        [
+            FitProblem::UnknownPlug {
+                plug_item_hash: Hash::new(1)
+            },
+            FitProblem::NotAPlug {
+                plug_item_hash: Hash::new(ACE_OF_SPADES_HASH)
+            },
+        ]
+    );
+}
+
+#[test]
+fn mods_go_in_sockets_the_armor_has_one_at_a_time() {
+    let content = content();
+    let fitter = ModFitter::new(&content);
+    assert_eq!(
+        problems(
+            &fitter,
+            &content,
+            void_energy(20),
+            &[
+                proposed(7, RESILIENCE_MOD_HASH),
+                proposed(-1, RESILIENCE_MOD_HASH),
+                proposed(GENERAL_SOCKET, RESILIENCE_MOD_HASH),
+                proposed(GENERAL_SOCKET, RECOVERY_MOD_HASH),
+            ]
+        ),
+        [
+            FitProblem::UnknownSocket { socket_index: 7 },
+            FitProblem::UnknownSocket { socket_index: -1 },
+            FitProblem::SocketAssignedTwice {
+                socket_index: GENERAL_SOCKET
+            },
+        ]
+    );
+}
+
+#[test]
+fn sockets_only_take_the_categories_they_whitelist() {
+    let content = content();
+    let fitter = ModFitter::new(&content);
+    assert_eq!(
+        problems(
+            &fitter,
+            &content,
+            void_energy(10),
+            &[
+                proposed(HEAD_SOCKET, RESILIENCE_MOD_HASH),
+                proposed(GENERAL_SOCKET, SPECIAL_AMMO_FINDER_HASH),
+            ]
+        ),
+        [
+            FitProblem::NotWhitelisted {
+                socket_index: HEAD_SOCKET,
+                plug_item_hash: Hash::new(RESILIENCE_MOD_HASH),
+                plug_category_identifier: Some("enhancements.v2_general".to_owned()),
+            },
+            FitProblem::NotWhitelisted {
+                socket_index: GENERAL_SOCKET,
+                plug_item_hash: Hash::new(SPECIAL_AMMO_FINDER_HASH),
+                plug_category_identifier: Some("enhancements.v2_head".to_owned()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn mods_cost_the_armor_s_energy_type_or_any() {
+    let content = content();
+    let fitter = ModFitter::new(&content);
+    let mods = [
+        proposed(GENERAL_SOCKET, RESILIENCE_MOD_HASH),
+        proposed(HEAD_SOCKET, HAND_CANNON_TARGETING_HASH),
+    ];
+
+    // Arc costs don't fit void armor, but costs of any energy do.
+    assert_eq!(
+        problems(&fitter, &content, void_energy(10), &mods),
+        [FitProblem::EnergyTypeMismatch {
+            plug_item_hash: Hash::new(HAND_CANNON_TARGETING_HASH),
+            required_energy_type_hash: Hash::new(ARC_ENERGY_HASH),
+            armor_energy_type_hash: Hash::new(VOID_ENERGY_HASH),
+        }]
+    );
+    let arc = ArmorEnergy::new(10, Some(Hash::new(ARC_ENERGY_HASH)));
+    assert!(problems(&fitter, &content, arc, &mods).is_empty());
+    // Armor of no known energy type takes any.
+    assert!(problems(&fitter, &content, ArmorEnergy::new(10, None), &mods).is_empty());
+}
+
+#[test]
+fn mods_cost_no_more_than_the_armor_s_energy() {
+    let content = content();
+    let report = ModFitter::new(&content).validate(
+        item(&content, HELMET_HASH),
+        void_energy(5),
+        &[
+            proposed(GENERAL_SOCKET, RECOVERY_MOD_HASH),
+            proposed(HEAD_SOCKET, SPECIAL_AMMO_FINDER_HASH),
+        ],
+    );
+    assert_eq!(
+        report.problems(),
+        [FitProblem::InsufficientEnergy {
+            required: 7,
+            capacity: 5
+        }]
+    );
+    assert_eq!(report.energy_remaining(), -2);
+}
+
+#[test]
+fn only_one_mod_of_a_unique_label() {
+    let content = content();
+    let fitter = ModFitter::new(&content);
+    assert_eq!(
+        problems(
+            &fitter,
+            &content,
+            void_energy(10),
+            &[
+                proposed(HEAD_SOCKET, SPECIAL_AMMO_FINDER_HASH),
+                proposed(SECOND_HEAD_SOCKET, HEAVY_AMMO_FINDER_HASH),
+            ]
+        ),
+        [FitProblem::DuplicateUniqueMod {
+            unique_label: "enhancements.ammo_finder".to_owned(),
+            plug_item_hashes: vec![
+                Hash::new(SPECIAL_AMMO_FINDER_HASH),
+                Hash::new(HEAVY_AMMO_FINDER_HASH)
+            ],
+        }]
+    );
+}
+
+#[test]
+fn insertion_and_enabled_rules_come_from_the_plug_states() {
+    let content = content();
+    let sockets: Vec<DestinyItemSocketState> = serde_json::from_value(json!([
+        { "plugHash": RESILIENCE_MOD_HASH, "isEnabled": true, "isVisible": true },
+        { "plugHash": SPECIAL_AMMO_FINDER_HASH, "isEnabled": true, "isVisible": true },
+        {
+            "plugHash": SPECIAL_AMMO_FINDER_HASH,
+            "isEnabled": false,
+            "isVisible": true,
+            "enableFailIndexes": [],
+        },
+    ]))
+    .unwrap();
+    let reusable_plugs: HashMap<i32, Vec<DestinyItemPlugBase>> = serde_json::from_value(json!({
+        "1": [{
+            "plugItemHash": HEAVY_AMMO_FINDER_HASH,
+            "canInsert": false,
+            "enabled": false,
+            "insertFailIndexes": [0],
+            "enableFailIndexes": [0, 5],
+        }],
+    }))
+    .unwrap();
+    let mods = [
+        proposed(HEAD_SOCKET, HEAVY_AMMO_FINDER_HASH),
+        proposed(SECOND_HEAD_SOCKET, SPECIAL_AMMO_FINDER_HASH),
+    ];
+
+    // Without the plug states, the rules aren't checked.
+    let fitter = ModFitter::new(&content);
+    assert!(!problems(&fitter, &content, void_energy(10), &mods)
+        .iter()
+        .any(|problem| matches!(
+            problem,
+            FitProblem::InsertionRuleFailed { .. } | FitProblem::EnabledRuleFailed { .. }
+        )));
+
+    // The reusable plug reports the rules it fails, and an index out of the
+    // rules' range has no message. The plug in the other socket is disabled
+    // without a reason given.
+    let fitter = ModFitter::new(&content).with_plug_states(&sockets, &reusable_plugs);
+    let problems = problems(&fitter, &content, void_energy(10), &mods);
+    assert_eq!(
+        problems[..4],
+        [
+            FitProblem::InsertionRuleFailed {
+                socket_index: HEAD_SOCKET,
+                plug_item_hash: Hash::new(HEAVY_AMMO_FINDER_HASH),
+                failure_message: Some("Requires the Heavy Ammo Finder artifact unlock".to_owned()),
+            },
+            FitProblem::EnabledRuleFailed {
+                socket_index: HEAD_SOCKET,
+                plug_item_hash: Hash::new(HEAVY_AMMO_FINDER_HASH),
+                failure_message: Some("Only one ammo finder is active at a time".to_owned()),
+            },
+            FitProblem::EnabledRuleFailed {
+                socket_index: HEAD_SOCKET,
+                plug_item_hash: Hash::new(HEAVY_AMMO_FINDER_HASH),
+                failure_message: None,
+            },
+            FitProblem::EnabledRuleFailed {
+                socket_index: SECOND_HEAD_SOCKET,
+                plug_item_hash: Hash::new(SPECIAL_AMMO_FINDER_HASH),
+                failure_message: None,
+            },
+        ]
+    );
+    assert!(matches!(
+        problems[4..],
+        [FitProblem::DuplicateUniqueMod { .. }]
+    ));
+
+    // A plug already in its socket and enabled passes.
+    assert!(ModFitter::new(&content)
+        .with_plug_states(&sockets, &reusable_plugs)
+        .validate(
+            item(&content, HELMET_HASH),
+            void_energy(10),
+            &[proposed(HEAD_SOCKET, SPECIAL_AMMO_FINDER_HASH)]
+        )
+        .fits());
+}
@@ -0,0 +1,184 @@
+//! The post game carnage report archive: that reports come back out of it as
+//! they went in, including after it's reopened, and how it pages through the
+//! activity history to sync.
+
+use std::cell::RefCell;
+
+use guardian_multi_tool::{
+    bnet::{
+        endpoints::{PlatformError, Response},
+        entities::{
+            destiny::historical_stats::{
+                definitions::DestinyActivityModeType, DestinyActivityHistoryResults,
+                DestinyPostGameCarnageReportData,
+            },
+            BungieMembershipType,
+        },
+    },
+    tools::history::{HistoryOwner, HistorySource, PgcrArchive, ACTIVITY_PAGE_SIZE},
+    Result,
+};
+use serde_json::Value;
+use tempfile::TempDir;
+
+const PGCR: &str = include_str!("fixtures/pgcr.json");
+const ACTIVITY_HISTORY: &str = include_str!("fixtures/activity_history.json");
+const THROTTLED: &str = include_str!("fixtures/throttled.json");
+
+/// The fixture, as the report of another activity instance.
+fn report(instance_id: i64) -> DestinyPostGameCarnageReportData {
+    let mut json: Value = serde_json::from_str(PGCR).unwrap();
+    json["Response"]["activityDetails"]["instanceId"] = Value::from(instance_id.to_string());
+    let response: Response<DestinyPostGameCarnageReportData> =
+        serde_json::from_value(json).unwrap_or_else(|e| panic!("{}", e));
+    response.into_result().unwrap()
+}
+
+#[test]
+fn reports_round_trip_through_the_archive() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("pgcrs.sqlite");
+
+    let mut archive = PgcrArchive::open(&path).unwrap();
+    assert!(archive.is_empty());
+    assert!(archive.get(14602133058).unwrap().is_none());
+    archive.insert(&report(14602133058)).unwrap();
+    archive.insert(&report(14602133059)).unwrap();
+    // Inserting a report again replaces it.
+    archive.insert(&report(14602133058)).unwrap();
+    assert_eq!(archive.len(), 2);
+    drop(archive);
+
+    let archive = PgcrArchive::open(&path).unwrap();
+    assert_eq!(archive.len(), 2);
+    assert!(archive.contains(14602133058));
+    assert!(archive.contains(14602133059));
+    assert!(!archive.contains(14602133060));
+
+    let expected = serde_json::to_value(report(14602133059)).unwrap();
+    let actual = serde_json::to_value(archive.get(14602133059).unwrap().unwrap()).unwrap();
+    assert_eq!(actual, expected);
+
+    let mut instance_ids: Vec<i64> = archive
+        .reports()
+        .map(|report| report.unwrap().activity_details().instance_id())
+        .collect();
+    instance_ids.sort();
+    assert_eq!(instance_ids, [14602133058, 14602133059]);
+}
+
+/// Activity history pages and reports standing in for the API, remembering
+/// what was asked of it.
+struct History {
+    pages: Vec<Value>,
+    requested_pages: RefCell<Vec<i32>>,
+    requested_reports: RefCell<Vec<i64>>,
+}
+
+impl History {
+    fn new(pages: Vec<Value>) -> Self {
+        Self {
+            pages,
+            requested_pages: RefCell::new(Vec::new()),
+            requested_reports: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl HistorySource for History {
+    async fn activity_page(
+        &self,
+        _owner: HistoryOwner,
+        _mode: Option<DestinyActivityModeType>,
+        page: i32,
+    ) -> Result<Response<DestinyActivityHistoryResults>> {
+        self.requested_pages.borrow_mut().push(page);
+        Ok(serde_json::from_value(self.pages[page as usize].clone())?)
+    }
+
+    async fn carnage_report(
+        &self,
+        instance_id: i64,
+    ) -> Result<Response<DestinyPostGameCarnageReportData>> {
+        self.requested_reports.borrow_mut().push(instance_id);
+        let mut json: Value = serde_json::from_str(PGCR)?;
+        json["Response"]["activityDetails"]["instanceId"] = Value::from(instance_id.to_string());
+        Ok(serde_json::from_value(json)?)
+    }
+}
+
+/// A page of the activity history fixture's first activity, as the
+/// activities with the instance ids.
+fn page(instance_ids: impl IntoIterator<Item = i64>) -> Value {
+    let mut json: Value = serde_json::from_str(ACTIVITY_HISTORY).unwrap();
+    let activity = json["Response"]["activities"][0].clone();
+    json["Response"]["activities"] = instance_ids
+        .into_iter()
+        .map(|instance_id| {
+            let mut activity = activity.clone();
+            activity["activityDetails"]["instanceId"] = Value::from(instance_id.to_string());
+            activity
+        })
+        .collect();
+    json
+}
+
+fn owner() -> HistoryOwner {
+    HistoryOwner::new(
+        BungieMembershipType::TigerSteam,
+        4611686018467284386,
+        2305843009409342731,
+    )
+}
+
+#[tokio::test]
+async fn sync_stops_at_the_first_page_that_is_all_archived() {
+    let dir = TempDir::new().unwrap();
+    let mut archive = PgcrArchive::open(dir.path().join("pgcrs.sqlite")).unwrap();
+    let page_size = ACTIVITY_PAGE_SIZE as i64;
+    for instance_id in 1..=page_size {
+        archive.insert(&report(instance_id)).unwrap();
+    }
+
+    // Most recent first: a page of new activities, a page of archived ones,
+    // and one that mustn't be asked for.
+    let newest = 2 * page_size;
+    let history = History::new(vec![
+        page((page_size + 1..=newest).rev()),
+        page((1..=page_size).rev()),
+        page([]),
+    ]);
+    let added = archive.sync(&history, owner(), None).await.unwrap();
+    assert_eq!(added, ACTIVITY_PAGE_SIZE as usize);
+    assert_eq!(history.requested_pages.take(), [0, 1]);
+    // Oldest first, so an interrupted sync leaves no gaps behind it.
+    assert_eq!(
+        history.requested_reports.take(),
+        (page_size + 1..=newest).collect::<Vec<_>>()
+    );
+    assert_eq!(archive.len(), 2 * ACTIVITY_PAGE_SIZE as usize);
+
+    // Nothing's new the next time, so the first page is the last.
+    assert_eq!(archive.sync(&history, owner(), None).await.unwrap(), 0);
+    assert_eq!(history.requested_pages.take(), [0]);
+    assert!(history.requested_reports.take().is_empty());
+}
+
+#[tokio::test]
+async fn sync_fails_on_a_page_that_failed() {
+    let dir = TempDir::new().unwrap();
+    let mut archive = PgcrArchive::open(dir.path().join("pgcrs.sqlite")).unwrap();
+    let page_size = ACTIVITY_PAGE_SIZE as i64;
+    let history = History::new(vec![
+        page((1..=page_size).rev()),
+        serde_json::from_str(THROTTLED).unwrap(),
+    ]);
+
+    let error = archive.sync(&history, owner(), None).await.unwrap_err();
+    let error = error.downcast_ref::<PlatformError>().unwrap();
+    assert_eq!(error.error_code(), 51);
+    assert_eq!(history.requested_pages.take(), [0, 1]);
+    // None of the history is archived, rather than only part of it.
+    assert!(history.requested_reports.take().is_empty());
+    assert!(archive.is_empty());
+}
@@ -0,0 +1,257 @@
+//! Loadouts: their JSON format, conversions from what a character has
+//! equipped and to and from in-game loadouts, and the dry run of applying one
+//! to a fixture profile.
+
+use std::collections::HashMap;
+
+use guardian_multi_tool::{
+    bnet::entities::{
+        destiny::{
+            components::loadouts::DestinyLoadoutsComponent,
+            entities::items::{DestinyItemComponent, DestinyItemSocketsComponent},
+            hashes::Hash,
+            DestinyWorldContent,
+        },
+        BungieMembershipType,
+    },
+    tools::{
+        loadouts::{Loadout, LoadoutItem, LoadoutSlots, SUBCLASS_BUCKET_HASH, UNSET_PLUG_HASH},
+        transfer::MovePlanner,
+    },
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+const WORLD_CONTENT: &str = include_str!("fixtures/world_content.json");
+const PROFILE: &str = include_str!("fixtures/profile.json");
+
+const HUNTER: i64 = 2305843009409342731;
+const WARLOCK: i64 = 2305843009409342732;
+
+const ACE_OF_SPADES: i64 = 6917529862418451201;
+const EQUIPPED_HELMET: i64 = 6917529862418451202;
+const NIGHTSTALKER: i64 = 6917529862418451203;
+const IKELOS: i64 = 6917529862418451204;
+const VAULTED_HELMET: i64 = 6917529862418451206;
+
+const ACE_OF_SPADES_HASH: u32 = 347366834;
+const HELMET_HASH: u32 = 2539150055;
+const NIGHTSTALKER_HASH: u32 = 2453351420;
+const MEMENTO_MORI_HASH: u32 = 3300816228;
+const STYLISH_EXECUTIONER_HASH: u32 = 2934767220;
+const ECHO_OF_STARVATION_HASH: u32 = 4029346515;
+
+const KINETIC_BUCKET_HASH: u32 = 1498876634;
+const HELMET_BUCKET_HASH: u32 = 3448274439;
+
+fn content() -> DestinyWorldContent {
+    serde_json::from_str(WORLD_CONTENT).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// A component of the fixture profile, by its path under the response.
+fn component<T: DeserializeOwned>(pointer: &str) -> T {
+    let profile: Value = serde_json::from_str(PROFILE).unwrap();
+    let value = profile["Response"]
+        .pointer(pointer)
+        .unwrap_or_else(|| panic!("no {}", pointer))
+        .clone();
+    serde_json::from_value(value).unwrap_or_else(|e| panic!("{}: {}", pointer, e))
+}
+
+fn items(pointer: &str) -> Vec<DestinyItemComponent> {
+    component(&format!("{}/items", pointer))
+}
+
+fn planner(content: &DestinyWorldContent) -> MovePlanner<'_, DestinyWorldContent> {
+    let inventory = |id: i64| items(&format!("/characterInventories/data/{}", id));
+    let equipment = |id: i64| items(&format!("/characterEquipment/data/{}", id));
+    MovePlanner::new(content, BungieMembershipType::TigerSteam)
+        .with_character(HUNTER, &inventory(HUNTER), &equipment(HUNTER))
+        .with_character(WARLOCK, &inventory(WARLOCK), &equipment(WARLOCK))
+        .with_vault(&items("/profileInventory/data"))
+}
+
+/// The fixture profile's only in-game loadout.
+fn in_game(content: &DestinyWorldContent) -> Loadout {
+    let loadouts: DestinyLoadoutsComponent =
+        component(&format!("/characterLoadouts/data/{}", HUNTER));
+    let equipment = items(&format!("/characterEquipment/data/{}", HUNTER));
+    let vault = items("/profileInventory/data");
+    Loadout::from_in_game(
+        content,
+        &loadouts.loadouts()[0],
+        equipment.iter().chain(&vault),
+    )
+}
+
+fn subclass() -> LoadoutItem {
+    LoadoutItem::new(Hash::new(NIGHTSTALKER_HASH), NIGHTSTALKER)
+        .with_socket_override(0, Hash::new(STYLISH_EXECUTIONER_HASH))
+        .with_socket_override(2, Hash::new(ECHO_OF_STARVATION_HASH))
+}
+
+#[test]
+fn loadouts_round_trip_through_json() {
+    let loadout = Loadout::new("Crucible")
+        .with_item(
+            Hash::new(KINETIC_BUCKET_HASH),
+            LoadoutItem::new(Hash::new(ACE_OF_SPADES_HASH), ACE_OF_SPADES)
+                .with_socket_override(1, Hash::new(MEMENTO_MORI_HASH)),
+        )
+        .with_item(SUBCLASS_BUCKET_HASH, subclass());
+    let json = loadout.to_json().unwrap();
+    assert_eq!(
+        serde_json::from_str::<Value>(&json).unwrap(),
+        json!({
+            "items": {
+                "1498876634": {
+                    "itemHash": ACE_OF_SPADES_HASH,
+                    "itemInstanceId": ACE_OF_SPADES,
+                    "socketOverrides": { "1": MEMENTO_MORI_HASH },
+                },
+            },
+            "name": "Crucible",
+            "subclass": {
+                "itemHash": NIGHTSTALKER_HASH,
+                "itemInstanceId": NIGHTSTALKER,
+                "socketOverrides": {
+                    "0": STYLISH_EXECUTIONER_HASH,
+                    "2": ECHO_OF_STARVATION_HASH,
+                },
+            },
+        })
+    );
+    assert_eq!(Loadout::from_json(&json).unwrap(), loadout);
+
+    // Items and socket overrides can be left out.
+    let json = json!({
+        "name": "Empty handed",
+        "subclass": { "itemHash": NIGHTSTALKER_HASH, "itemInstanceId": NIGHTSTALKER },
+    });
+    assert_eq!(
+        Loadout::from_json(&json.to_string()).unwrap(),
+        Loadout::new("Empty handed")
+            .with_subclass(LoadoutItem::new(Hash::new(NIGHTSTALKER_HASH), NIGHTSTALKER))
+    );
+}
+
+#[test]
+fn a_snapshot_keeps_the_subclass_plugs_only() {
+    let content = content();
+    let slots = LoadoutSlots::new(&content);
+    let equipment = items(&format!("/characterEquipment/data/{}", HUNTER));
+    let sockets: HashMap<i64, DestinyItemSocketsComponent> =
+        component("/itemComponents/sockets/data");
+
+    let loadout = Loadout::snapshot("Hunter", &slots, &equipment, &sockets);
+    assert_eq!(
+        loadout,
+        Loadout::new("Hunter")
+            .with_subclass(subclass())
+            .with_item(
+                Hash::new(KINETIC_BUCKET_HASH),
+                LoadoutItem::new(Hash::new(ACE_OF_SPADES_HASH), ACE_OF_SPADES)
+            )
+            .with_item(
+                Hash::new(HELMET_BUCKET_HASH),
+                LoadoutItem::new(Hash::new(HELMET_HASH), EQUIPPED_HELMET)
+            )
+    );
+}
+
+#[test]
+fn in_game_loadouts_convert_both_ways() {
+    let content = content();
+    let loadout = in_game(&content);
+
+    // Unset plugs are left alone, and the item that's gone is left out. The
+    // vaulted helmet goes in the bucket it's equipped in.
+    assert_eq!(
+        loadout,
+        Loadout::new("Raid")
+            .with_subclass(subclass())
+            .with_item(
+                Hash::new(KINETIC_BUCKET_HASH),
+                LoadoutItem::new(Hash::new(ACE_OF_SPADES_HASH), ACE_OF_SPADES)
+            )
+            .with_item(
+                Hash::new(HELMET_BUCKET_HASH),
+                LoadoutItem::new(Hash::new(HELMET_HASH), VAULTED_HELMET)
+            )
+    );
+
+    let slots = LoadoutSlots::new(&content);
+    assert_eq!(
+        slots.buckets(),
+        [
+            SUBCLASS_BUCKET_HASH,
+            Hash::new(KINETIC_BUCKET_HASH),
+            Hash::new(HELMET_BUCKET_HASH)
+        ]
+    );
+    let converted = loadout.to_in_game(
+        &slots,
+        Hash::new(3871954990),
+        Hash::new(797731452),
+        Hash::new(1237488984),
+    );
+    assert_eq!(
+        serde_json::to_value(&converted).unwrap(),
+        json!({
+            "colorHash": 3871954990u32,
+            "iconHash": 797731452,
+            "items": [
+                {
+                    "itemInstanceId": NIGHTSTALKER,
+                    "plugItemHashes": [
+                        STYLISH_EXECUTIONER_HASH,
+                        u32::from(UNSET_PLUG_HASH),
+                        ECHO_OF_STARVATION_HASH,
+                    ],
+                },
+                { "itemInstanceId": ACE_OF_SPADES, "plugItemHashes": [] },
+                { "itemInstanceId": VAULTED_HELMET, "plugItemHashes": [] },
+            ],
+            "nameHash": 1237488984,
+        })
+    );
+}
+
+#[test]
+fn a_plan_moves_the_items_and_equips_them_at_once() {
+    let content = content();
+    let loadout = in_game(&content);
+
+    // On the warlock, the subclass can't follow, and the hunter needs another
+    // weapon equipped before theirs can leave.
+    let plan = loadout.plan(&mut planner(&content), WARLOCK);
+    assert_eq!(
+        plan.to_string(),
+        format!(
+            "equip item {ikelos} on character {hunter}\n\
+             transfer item {ace} ({ace_hash}) from character {hunter} to the vault\n\
+             transfer item {ace} ({ace_hash}) from the vault to character {warlock}\n\
+             transfer item {helmet} ({helmet_hash}) from the vault to character {warlock}\n\
+             equip items {ace}, {helmet} on character {warlock}\n\
+             can't move: item {nightstalker} can't be transferred\n",
+            ikelos = IKELOS,
+            ace = ACE_OF_SPADES,
+            ace_hash = ACE_OF_SPADES_HASH,
+            helmet = VAULTED_HELMET,
+            helmet_hash = HELMET_HASH,
+            nightstalker = NIGHTSTALKER,
+            hunter = HUNTER,
+            warlock = WARLOCK,
+        )
+    );
+    assert_eq!(
+        plan.equip().unwrap().item_ids(),
+        [ACE_OF_SPADES, VAULTED_HELMET]
+    );
+
+    // On the hunter, what's equipped already stays as it is.
+    let plan = loadout.plan(&mut planner(&content), HUNTER);
+    assert_eq!(plan.moves().len(), 1);
+    assert!(plan.problems().is_empty());
+    assert_eq!(plan.equip().unwrap().item_ids(), [VAULTED_HELMET]);
+}
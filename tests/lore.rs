@@ -0,0 +1,279 @@
+//! Lore books read out of the world content's presentation nodes, masked by
+//! a player's progress and exported to Markdown and EPUB.
+
+use std::{
+    collections::HashMap,
+    io::{Cursor, Read},
+};
+
+use guardian_multi_tool::{
+    bnet::entities::destiny::{
+        components::{collectibles::DestinyCollectibleComponent, records::DestinyRecordComponent},
+        hashes::Hash,
+        DestinyWorldContent,
+    },
+    tools::lore::{LoreReader, LoreSource},
+};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use zip::{CompressionMethod, ZipArchive};
+
+const WORLD_CONTENT: &str = include_str!("fixtures/world_content.json");
+
+const ROOT_NODE_HASH: u32 = 4077680549;
+const BOOKS_OF_SORROW_HASH: u32 = 1000000100;
+const ISHTAR_COLLECTIVE_HASH: u32 = 1000000101;
+
+const AKKA_RECORD_HASH: u32 = 1000000301;
+const ERIS_LETTER_RECORD_HASH: u32 = 1000000302;
+const WARMIND_RECORD_HASH: u32 = 1000000304;
+const TOME_OF_WANT_COLLECTIBLE_HASH: u32 = 1000000401;
+
+const WORM_GOD_LORE_HASH: u32 = 1000000200;
+const AKKA_LORE_HASH: u32 = 1000000201;
+
+fn content() -> DestinyWorldContent {
+    serde_json::from_str(WORLD_CONTENT).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Components keyed by their definition hash, with the state bits given.
+fn states<T: DeserializeOwned>(states: &[(u32, i32)]) -> HashMap<u32, T> {
+    states
+        .iter()
+        .map(|(hash, state)| {
+            let component = serde_json::from_value(json!({ "state": state })).unwrap();
+            (*hash, component)
+        })
+        .collect()
+}
+
+#[test]
+fn books_follow_the_presentation_order() {
+    let content = content();
+    let reader = LoreReader::new(&content);
+
+    // The root lists itself and the first book lists itself and the root, but
+    // every node is read once. The node without lore isn't a book.
+    let books = reader.books(Hash::new(ROOT_NODE_HASH));
+    assert_eq!(
+        books.iter().map(|book| book.title()).collect::<Vec<_>>(),
+        ["Books of Sorrow", "Ishtar Collective"]
+    );
+    assert_eq!(
+        reader
+            .books(Hash::new(BOOKS_OF_SORROW_HASH))
+            .iter()
+            .map(|book| book.title())
+            .collect::<Vec<_>>(),
+        ["Books of Sorrow", "Ishtar Collective"]
+    );
+
+    // Records and collectibles are merged by display priority, then index.
+    // The record without lore is left out, and the untitled lore takes the
+    // name of its record.
+    let book = &books[0];
+    assert_eq!(book.description(), "Tales of the Hive.");
+    assert_eq!(
+        book.entries()
+            .iter()
+            .map(|entry| entry.title())
+            .collect::<Vec<_>>(),
+        ["The Worm God", "Akka", "Eris' Letter"]
+    );
+    assert!(matches!(book.entries()[0].source(), LoreSource::Item(_)));
+    assert_eq!(book.entries()[0].subtitle(), Some("Verse 1"));
+    assert_eq!(book.entries()[1].source().hash(), Some(AKKA_RECORD_HASH));
+    assert_eq!(book.entries()[1].subtitle(), None);
+    assert_eq!(book.unlocked_count(), 3);
+
+    assert!(reader.book(Hash::new(1)).is_none());
+}
+
+#[test]
+fn lore_is_read_from_items_and_records() {
+    let content = content();
+    let reader = LoreReader::new(&content);
+    let names = |lore_hash| {
+        reader
+            .sources(Hash::new(lore_hash))
+            .iter()
+            .map(|source| source.name().unwrap().as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(WORM_GOD_LORE_HASH), ["Tome of Want"]);
+    assert_eq!(names(AKKA_LORE_HASH), ["Akka's Record"]);
+}
+
+#[test]
+fn entries_are_masked_until_unlocked() {
+    let content = content();
+    // Incomplete records and collectibles not acquired are locked, as are the
+    // ones missing from the profile. Other bits don't matter.
+    let records = states::<DestinyRecordComponent>(&[
+        (AKKA_RECORD_HASH, 1 | 64),
+        (ERIS_LETTER_RECORD_HASH, 4),
+    ]);
+    let collectibles = states::<DestinyCollectibleComponent>(&[(TOME_OF_WANT_COLLECTIBLE_HASH, 1)]);
+    let reader = LoreReader::new(&content).with_unlocks(&records, &collectibles);
+
+    let book = reader.book(Hash::new(BOOKS_OF_SORROW_HASH)).unwrap();
+    assert_eq!(
+        book.entries()
+            .iter()
+            .map(|entry| entry.unlocked())
+            .collect::<Vec<_>>(),
+        [false, true, false]
+    );
+    assert_eq!(book.unlocked_count(), 1);
+    assert_eq!(
+        book.to_markdown(),
+        "# Books of Sorrow\n\
+         \n\
+         _Tales of the Hive._\n\
+         \n\
+         ## ???\n\
+         \n\
+         _This entry has not been unlocked._\n\
+         \n\
+         ## Akka\n\
+         \n\
+         The Deep's first word.\n\
+         \n\
+         > Source: Record \"Akka's Record\"\n\
+         \n\
+         ## ???\n\
+         \n\
+         _This entry has not been unlocked._\n"
+    );
+
+    let obscured = states::<DestinyRecordComponent>(&[(WARMIND_RECORD_HASH, 8)]);
+    let acquired = states::<DestinyCollectibleComponent>(&[(TOME_OF_WANT_COLLECTIBLE_HASH, 2)]);
+    let reader = LoreReader::new(&content).with_unlocks(&obscured, &acquired);
+    let book = reader.book(Hash::new(ISHTAR_COLLECTIVE_HASH)).unwrap();
+    assert!(!book.entries()[0].unlocked());
+    let book = reader.book(Hash::new(BOOKS_OF_SORROW_HASH)).unwrap();
+    assert!(book.entries()[0].unlocked());
+}
+
+#[test]
+fn books_export_to_markdown() {
+    let content = content();
+    let reader = LoreReader::new(&content);
+    let book = reader.book(Hash::new(BOOKS_OF_SORROW_HASH)).unwrap();
+    assert_eq!(
+        book.to_markdown(),
+        "# Books of Sorrow\n\
+         \n\
+         _Tales of the Hive._\n\
+         \n\
+         ## The Worm God\n\
+         \n\
+         _Verse 1_\n\
+         \n\
+         First verse.\n\
+         \n\
+         Second verse.\n\
+         \n\
+         > Source: Item \"Tome of Want\"\n\
+         \n\
+         ## Akka\n\
+         \n\
+         The Deep's first word.\n\
+         \n\
+         > Source: Record \"Akka's Record\"\n\
+         \n\
+         ## Eris' Letter\n\
+         \n\
+         A letter left on the Moon.\n\
+         \n\
+         > Source: Record \"Eris' Letter\"\n"
+    );
+
+    // Without a description, the book starts with its first entry.
+    let book = reader.book(Hash::new(ISHTAR_COLLECTIVE_HASH)).unwrap();
+    assert!(book
+        .to_markdown()
+        .starts_with("# Ishtar Collective\n\n## Rasputin & <Warmind>\n"));
+}
+
+#[test]
+fn books_export_to_epub() {
+    let content = content();
+    let records = states::<DestinyRecordComponent>(&[(AKKA_RECORD_HASH, 0)]);
+    let collectibles = states::<DestinyCollectibleComponent>(&[]);
+    let reader = LoreReader::new(&content).with_unlocks(&records, &collectibles);
+    let book = reader.book(Hash::new(BOOKS_OF_SORROW_HASH)).unwrap();
+
+    let mut epub = Cursor::new(Vec::new());
+    book.write_epub(&mut epub).unwrap();
+    let mut archive = ZipArchive::new(epub).unwrap();
+    let read = |archive: &mut ZipArchive<_>, name: &str| {
+        let mut text = String::new();
+        archive
+            .by_name(name)
+            .unwrap_or_else(|e| panic!("{}: {}", name, e))
+            .read_to_string(&mut text)
+            .unwrap();
+        text
+    };
+
+    // The mimetype comes first, uncompressed.
+    let mimetype = archive.by_index(0).unwrap();
+    assert_eq!(mimetype.name(), "mimetype");
+    assert_eq!(mimetype.compression(), CompressionMethod::Stored);
+    drop(mimetype);
+    assert_eq!(read(&mut archive, "mimetype"), "application/epub+zip");
+    assert_eq!(
+        archive.file_names().count(),
+        // The mimetype, container, package, navigation and three entries.
+        7
+    );
+
+    let package = read(&mut archive, "OEBPS/content.opf");
+    assert!(package.contains(&format!(
+        "<dc:identifier id=\"book-id\">urn:destiny:lore:{}</dc:identifier>",
+        BOOKS_OF_SORROW_HASH
+    )));
+    assert!(package.contains("<dc:title>Books of Sorrow</dc:title>"));
+    assert!(package.contains(
+        "    <itemref idref=\"entry-0\"/>\n    <itemref idref=\"entry-1\"/>\n    <itemref idref=\"entry-2\"/>\n"
+    ));
+
+    // Locked entries are masked, in the navigation and in their chapter.
+    let nav = read(&mut archive, "OEBPS/nav.xhtml");
+    assert!(nav.contains(
+        "      <li><a href=\"entry-0.xhtml\">???</a></li>\n      \
+         <li><a href=\"entry-1.xhtml\">Akka</a></li>\n      \
+         <li><a href=\"entry-2.xhtml\">???</a></li>\n"
+    ));
+    let locked = read(&mut archive, "OEBPS/entry-0.xhtml");
+    assert!(locked.contains("<h2>???</h2>\n<p><em>This entry has not been unlocked.</em></p>\n"));
+    assert!(!locked.contains("Worm God"));
+    let unlocked = read(&mut archive, "OEBPS/entry-1.xhtml");
+    assert!(unlocked.contains("<h2>Akka</h2>\n<p>The Deep&apos;s first word.</p>\n"));
+
+    // Titles and text are escaped, and each line of text is a paragraph.
+    let reader = LoreReader::new(&content);
+    let mut epub = Cursor::new(Vec::new());
+    reader
+        .book(Hash::new(ISHTAR_COLLECTIVE_HASH))
+        .unwrap()
+        .write_epub(&mut epub)
+        .unwrap();
+    let mut archive = ZipArchive::new(epub).unwrap();
+    let chapter = read(&mut archive, "OEBPS/entry-0.xhtml");
+    assert!(chapter.contains("<title>Rasputin &amp; &lt;Warmind&gt;</title>"));
+    assert!(chapter.contains("<p>&quot;Sing&quot; &amp; &apos;listen&apos;</p>"));
+
+    let mut epub = Cursor::new(Vec::new());
+    LoreReader::new(&content)
+        .book(Hash::new(BOOKS_OF_SORROW_HASH))
+        .unwrap()
+        .write_epub(&mut epub)
+        .unwrap();
+    let mut archive = ZipArchive::new(epub).unwrap();
+    let chapter = read(&mut archive, "OEBPS/entry-0.xhtml");
+    assert!(chapter.contains(
+        "<h2>The Worm God</h2>\n<p><em>Verse 1</em></p>\n<p>First verse.</p>\n<p>Second verse.</p>\n"
+    ));
+}
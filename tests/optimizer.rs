@@ -0,0 +1,272 @@
+//! The armor optimizer against a brute force search of every combination, on
+//! inventories small enough to enumerate.
+
+use std::collections::HashMap;
+
+use guardian_multi_tool::{
+    bnet::entities::destiny::{
+        definitions::DestinyInventoryItemDefinition, hashes::Hash, DestinyClass,
+    },
+    tools::optimizer::{optimize, ArmorPiece, OptimizerQuery, ARMOR_BUCKET_HASHES},
+};
+
+const EXOTIC_HASH: Hash<DestinyInventoryItemDefinition> = Hash::new(1001);
+const OTHER_EXOTIC_HASH: Hash<DestinyInventoryItemDefinition> = Hash::new(1002);
+const LEGENDARY_HASH: Hash<DestinyInventoryItemDefinition> = Hash::new(2000);
+
+/// A linear congruential generator, so the inventories are the same on every
+/// run.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self, below: i32) -> i32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.0 >> 33) % below as u64) as i32
+    }
+}
+
+/// Four legendaries per slot, an exotic in two of the slots, and a piece of
+/// another class that must never be picked.
+fn inventory(seed: u64) -> Vec<ArmorPiece> {
+    let mut random = Random(seed);
+    let mut pieces = Vec::new();
+    let mut piece = |random: &mut Random, hash, slot, class, exotic| {
+        let stats = [0; 6].map(|_| 2 + random.next(29));
+        let masterworked = random.next(2) == 0;
+        let id = pieces.len() as i64;
+        pieces.push(ArmorPiece::new(
+            id,
+            hash,
+            slot,
+            class,
+            exotic,
+            masterworked,
+            stats,
+        ));
+    };
+
+    for slot in 0..ARMOR_BUCKET_HASHES.len() {
+        for _ in 0..4 {
+            piece(
+                &mut random,
+                LEGENDARY_HASH,
+                slot,
+                DestinyClass::Hunter,
+                false,
+            );
+        }
+        piece(
+            &mut random,
+            LEGENDARY_HASH,
+            slot,
+            DestinyClass::Titan,
+            false,
+        );
+    }
+    piece(&mut random, EXOTIC_HASH, 0, DestinyClass::Hunter, true);
+    piece(
+        &mut random,
+        OTHER_EXOTIC_HASH,
+        2,
+        DestinyClass::Hunter,
+        true,
+    );
+    pieces
+}
+
+fn tier(stat: i32) -> u8 {
+    (stat.clamp(0, 100) / 10) as u8
+}
+
+fn dominates(a: &[u8; 6], b: &[u8; 6]) -> bool {
+    a.iter().zip(b).all(|(a, b)| a >= b) && a != b
+}
+
+/// Every distribution of up to `mods` stat mods over the six stats.
+fn distributions(mods: u8) -> Vec<[u8; 6]> {
+    let mut distributions = vec![[0; 6]];
+    for _ in 0..mods {
+        let mut next = distributions.clone();
+        for distribution in &distributions {
+            for stat in 0..6 {
+                let mut more = *distribution;
+                more[stat] += 1;
+                next.push(more);
+            }
+        }
+        next.sort();
+        next.dedup();
+        distributions = next;
+    }
+    distributions
+}
+
+/// Every set the query allows, by enumerating every combination of pieces
+/// and every way of spending the stat mods on it. Returns, for each set, the
+/// tiers it reaches and the total of its raw stats.
+fn brute_force(
+    pieces: &[ArmorPiece],
+    class: DestinyClass,
+    exotic: Option<Hash<DestinyInventoryItemDefinition>>,
+    min_tiers: [u8; 6],
+    mods: u8,
+    assume_masterworked: bool,
+) -> Vec<([u8; 6], i32)> {
+    let by_slot: Vec<Vec<&ArmorPiece>> = (0..ARMOR_BUCKET_HASHES.len())
+        .map(|slot| {
+            pieces
+                .iter()
+                .filter(|piece| piece.slot() == slot && piece.class() == class)
+                .collect()
+        })
+        .collect();
+    let distributions = distributions(mods);
+
+    let mut sets = Vec::new();
+    let mut indexes = [0; 5];
+    loop {
+        let set: Vec<&ArmorPiece> = (0..5).map(|slot| by_slot[slot][indexes[slot]]).collect();
+        let exotics = set.iter().filter(|piece| piece.exotic()).count();
+        let has_exotic =
+            exotic.is_none_or(|hash| set.iter().any(|piece| piece.item_hash() == hash));
+        if exotics <= 1 && has_exotic {
+            let mut stats = [0; 6];
+            for piece in &set {
+                let bonus = if piece.masterworked() || assume_masterworked {
+                    2
+                } else {
+                    0
+                };
+                for (stat, value) in stats.iter_mut().zip(piece.stats()) {
+                    *stat += value + bonus;
+                }
+            }
+            for distribution in &distributions {
+                let tiers: Vec<u8> = stats
+                    .iter()
+                    .zip(distribution)
+                    .map(|(stat, mods)| tier(stat + i32::from(*mods) * 10))
+                    .collect();
+                let tiers: [u8; 6] = tiers.try_into().unwrap();
+                if tiers.iter().zip(min_tiers).all(|(tier, min)| *tier >= min) {
+                    sets.push((tiers, stats.iter().sum()));
+                }
+            }
+        }
+
+        let mut slot = 0;
+        while slot < 5 {
+            indexes[slot] += 1;
+            if indexes[slot] < by_slot[slot].len() {
+                break;
+            }
+            indexes[slot] = 0;
+            slot += 1;
+        }
+        if slot == 5 {
+            return sets;
+        }
+    }
+}
+
+/// The tiers of the sets no other set dominates, with the highest raw total
+/// reached for each.
+fn pareto_front(sets: &[([u8; 6], i32)]) -> HashMap<[u8; 6], i32> {
+    let mut best: HashMap<[u8; 6], i32> = HashMap::new();
+    for (tiers, raw_total) in sets {
+        let entry = best.entry(*tiers).or_insert(*raw_total);
+        *entry = (*entry).max(*raw_total);
+    }
+    let all: Vec<[u8; 6]> = best.keys().copied().collect();
+    best.retain(|tiers, _| !all.iter().any(|other| dominates(other, tiers)));
+    best
+}
+
+#[test]
+fn without_mods_the_front_matches_brute_force() {
+    let queries = [
+        (None, [0; 6], false),
+        (None, [3, 0, 5, 0, 2, 0], false),
+        (Some(EXOTIC_HASH), [0; 6], false),
+        (Some(OTHER_EXOTIC_HASH), [2, 2, 2, 2, 2, 2], true),
+        (None, [6, 6, 6, 6, 6, 6], true),
+    ];
+    for seed in 1..=5 {
+        let pieces = inventory(seed);
+        for (exotic, min_tiers, assume_masterworked) in queries {
+            let query = OptimizerQuery::new(DestinyClass::Hunter, exotic)
+                .with_min_tiers(min_tiers)
+                .assume_masterworked(assume_masterworked);
+            let sets = optimize(&pieces, &query);
+
+            let expected = pareto_front(&brute_force(
+                &pieces,
+                DestinyClass::Hunter,
+                exotic,
+                min_tiers,
+                0,
+                assume_masterworked,
+            ));
+            let actual: HashMap<[u8; 6], i32> = sets
+                .iter()
+                .map(|set| (set.tiers(), set.stats().iter().sum()))
+                .collect();
+            assert_eq!(actual.len(), sets.len(), "seed {}: duplicate tiers", seed);
+            assert_eq!(actual, expected, "seed {}, {:?}", seed, query);
+        }
+    }
+}
+
+#[test]
+fn with_mods_the_front_matches_brute_force() {
+    let queries = [
+        (None, [0; 6], 2),
+        (None, [7, 0, 7, 0, 7, 0], 3),
+        (Some(EXOTIC_HASH), [5, 5, 5, 5, 5, 5], 2),
+        (None, [10, 10, 10, 10, 10, 10], 5),
+        (Some(OTHER_EXOTIC_HASH), [0, 9, 0, 9, 0, 0], 5),
+    ];
+    for seed in 1..=5 {
+        let pieces = inventory(seed);
+        for (exotic, min_tiers, mods) in queries {
+            let query = OptimizerQuery::new(DestinyClass::Hunter, exotic)
+                .with_min_tiers(min_tiers)
+                .with_stat_mods(mods);
+            let sets = optimize(&pieces, &query);
+
+            let expected = pareto_front(&brute_force(
+                &pieces,
+                DestinyClass::Hunter,
+                exotic,
+                min_tiers,
+                mods,
+                false,
+            ));
+            // The stats of a set include its mods, which the raw total leaves
+            // out.
+            let actual: HashMap<[u8; 6], i32> = sets
+                .iter()
+                .map(|set| {
+                    let mods: i32 = set.stat_mods().iter().map(|mods| i32::from(*mods)).sum();
+                    (set.tiers(), set.stats().iter().sum::<i32>() - mods * 10)
+                })
+                .collect();
+            assert_eq!(actual.len(), sets.len(), "seed {}: duplicate tiers", seed);
+            assert_eq!(actual, expected, "seed {}, {:?}", seed, query);
+
+            for set in &sets {
+                assert!(set.stat_mods().iter().sum::<u8>() <= mods);
+                assert!(set
+                    .pieces()
+                    .iter()
+                    .all(|piece| piece.class() == DestinyClass::Hunter));
+                assert!(set.pieces().iter().filter(|piece| piece.exotic()).count() <= 1);
+            }
+            let totals: Vec<u32> = sets.iter().map(|set| set.total_tier()).collect();
+            assert!(totals.windows(2).all(|pair| pair[0] >= pair[1]));
+        }
+    }
+}
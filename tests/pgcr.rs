@@ -0,0 +1,164 @@
+//! A post game carnage report in the shape Bungie sends it, and its joins to
+//! the activity and mode definitions of the world content.
+
+use guardian_multi_tool::{
+    bnet::{
+        endpoints::Response,
+        entities::destiny::{
+            historical_stats::{DestinyPostGameCarnageReportData, StatId},
+            world_content::LazyWorldContent,
+            DestinyWorldContent,
+        },
+    },
+    tools::pgcr::{stat_value, CarnageReport},
+};
+use serde_json::{json, Value};
+
+const PGCR: &str = include_str!("fixtures/pgcr.json");
+const WORLD_CONTENT: &str = include_str!("fixtures/world_content.json");
+
+/// A hash no definition of the world content has.
+const UNKNOWN_HASH: u32 = 1;
+
+fn content() -> DestinyWorldContent {
+    serde_json::from_str(WORLD_CONTENT).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// The fixture, with its activity details changed by `edit`.
+fn report(edit: impl FnOnce(&mut Value)) -> DestinyPostGameCarnageReportData {
+    let mut json: Value = serde_json::from_str(PGCR).unwrap();
+    edit(&mut json["Response"]["activityDetails"]);
+    let response: Response<DestinyPostGameCarnageReportData> =
+        serde_json::from_value(json).unwrap_or_else(|e| panic!("{}", e));
+    response.into_result().unwrap()
+}
+
+#[test]
+fn deserializes() {
+    let response: Response<DestinyPostGameCarnageReportData> =
+        serde_json::from_str(PGCR).unwrap_or_else(|e| panic!("{}", e));
+    assert_eq!(response.error_code(), 1);
+
+    let report = response.response();
+    let details = report.activity_details();
+    assert_eq!(details.instance_id(), 14602133058);
+    assert_eq!(details.mode(), 10);
+    assert_eq!(details.modes(), [10, 5, 73]);
+
+    let entries = report.entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].character_id(), 2305843009301405643);
+    assert_eq!(
+        entries[0].player().destiny_user_info().membership_id(),
+        4611686018467284386
+    );
+    assert_eq!(stat_value(entries[0].values(), &StatId::Kills), Some(21.0));
+    assert_eq!(entries[0].score().basic().value(), 2315.0);
+    assert!(entries[0].score().stat_id().is_none());
+    let weapons = entries[0].extended().and_then(|e| e.weapons()).unwrap();
+    assert_eq!(weapons[0].reference_id().value(), 1363886209);
+    assert!(entries[1].extended().unwrap().weapons().is_none());
+
+    let teams = report.teams();
+    assert_eq!(teams.len(), 2);
+    assert_eq!(teams[0].team_id(), 18);
+    assert_eq!(teams[0].team_name().map(String::as_str), Some("Alpha"));
+    assert_eq!(teams[1].standing().basic().display_value(), "Defeat");
+}
+
+#[test]
+fn joins_the_activity_map_and_mode() {
+    let content = content();
+    let data = report(|_| {});
+    let report = CarnageReport::new(&content, &data);
+
+    assert_eq!(report.activity_name(), Some("Control"));
+    assert_eq!(report.map_name(), Some("Javelin-4"));
+    assert_eq!(report.mode_name(), Some("Control"));
+    assert_eq!(
+        report.pgcr_image(),
+        Some("/img/destiny_content/pgcr/crucible_javelin_4.jpg")
+    );
+    assert_eq!(report.activity_mode_types(), [10, 5, 73]);
+    assert!(report.is_pvp());
+
+    assert_eq!(report.entries(Some(18)).len(), 1);
+    assert_eq!(report.entries(None).len(), 2);
+    let medals: Vec<(&str, f64)> = report
+        .medals(report.entries(Some(18))[0])
+        .into_iter()
+        .map(|(stat_id, count)| (stat_id.as_str(), count))
+        .collect();
+    assert_eq!(medals, [("medalMulti2x", 3.0), ("medalStreak5x", 1.0)]);
+}
+
+#[test]
+fn joins_against_the_lazy_world_content() {
+    let content = LazyWorldContent::from_json(WORLD_CONTENT.as_bytes().to_vec()).unwrap();
+    let data = report(|_| {});
+    let report = CarnageReport::new(&content, &data);
+
+    assert_eq!(report.activity_name(), Some("Control"));
+    assert_eq!(report.map_name(), Some("Javelin-4"));
+    assert_eq!(report.mode_name(), Some("Control"));
+    assert!(report.is_pvp());
+}
+
+#[test]
+fn falls_back_to_the_activity_definition() {
+    let content = content();
+    let data = report(|details| {
+        details["referenceId"] = json!(UNKNOWN_HASH);
+        details.as_object_mut().unwrap().remove("modes");
+    });
+    let report = CarnageReport::new(&content, &data);
+
+    assert!(report.map().is_none());
+    assert_eq!(report.activity_name(), Some("Control"));
+    assert_eq!(
+        report.pgcr_image(),
+        Some("/img/destiny_content/pgcr/crucible_control.jpg")
+    );
+    assert_eq!(report.activity_mode_types(), [10, 5]);
+    assert!(report.is_pvp());
+}
+
+#[test]
+fn falls_back_to_the_mode_definition() {
+    let content = content();
+    let data = report(|details| {
+        details["referenceId"] = json!(UNKNOWN_HASH);
+        details["directorActivityHash"] = json!(UNKNOWN_HASH);
+        details.as_object_mut().unwrap().remove("modes");
+    });
+    let report = CarnageReport::new(&content, &data);
+
+    assert!(report.activity().is_none());
+    assert_eq!(
+        report.pgcr_image(),
+        Some("/img/theme/destiny/bgs/stats/banner_crucible_control.jpg")
+    );
+    assert!(report.activity_mode_types().is_empty());
+    assert!(report.is_pvp());
+}
+
+#[test]
+fn pve_activities_are_not_pvp() {
+    let content = content();
+    let data = report(|details| {
+        details["referenceId"] = json!(3419225426u32);
+        details["directorActivityHash"] = json!(UNKNOWN_HASH);
+        details["mode"] = json!(18);
+        details.as_object_mut().unwrap().remove("modes");
+    });
+    let report = CarnageReport::new(&content, &data);
+
+    assert_eq!(report.activity_name(), Some("The Inverted Spire"));
+    assert_eq!(report.mode_name(), Some("Strikes"));
+    assert_eq!(
+        report.pgcr_image(),
+        Some("/img/theme/destiny/bgs/stats/banner_strikes_1.jpg")
+    );
+    assert_eq!(report.activity_mode_types(), [18, 7]);
+    assert!(!report.is_pvp());
+}
@@ -0,0 +1,246 @@
+//! Plug insertions checked against the world content's sockets, plug
+//! whitelists and material costs, and an item's reusable plugs.
+
+use guardian_multi_tool::{
+    bnet::entities::{
+        destiny::{
+            components::items::DestinyItemReusablePlugsComponent, hashes::Hash,
+            requests::actions::DestinyInsertPlugsRequestEntry, DestinySocketArrayType,
+            DestinyWorldContent,
+        },
+        BungieMembershipType,
+    },
+    tools::plugs::{InsertProblem, PlugInsertionCheck},
+};
+use serde_json::{json, Value};
+
+const WORLD_CONTENT: &str = include_str!("fixtures/world_content.json");
+
+const IKELOS_HASH: u32 = 3260753130;
+const HELMET_HASH: u32 = 2539150055;
+const ACE_OF_SPADES_HASH: u32 = 347366834;
+
+const OUTLAW_HASH: u32 = 1168162263;
+const KILL_CLIP_HASH: u32 = 1015611457;
+const RAMPAGE_HASH: u32 = 3425386926;
+const RESILIENCE_MOD_HASH: u32 = 2850583378;
+const DEFAULT_SHADER_HASH: u32 = 4248210736;
+const GAMBIT_CHROME_HASH: u32 = 1000000020;
+const BALLROOM_FIZZ_HASH: u32 = 1000000021;
+
+// Ikelos' trait socket, and the helmet's shader socket.
+const TRAIT_SOCKET: i32 = 1;
+const SHADER_SOCKET: i32 = 4;
+
+fn content() -> DestinyWorldContent {
+    serde_json::from_str(WORLD_CONTENT).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn entry(socket_index: i32, plug_item_hash: u32) -> DestinyInsertPlugsRequestEntry {
+    DestinyInsertPlugsRequestEntry::new(
+        socket_index,
+        DestinySocketArrayType::Default,
+        Hash::new(plug_item_hash),
+    )
+}
+
+/// Ikelos' reusable plugs for its trait socket.
+fn reusable_plugs(plugs: Value) -> DestinyItemReusablePlugsComponent {
+    serde_json::from_value(json!({ "plugs": { TRAIT_SOCKET.to_string(): plugs } })).unwrap()
+}
+
+#[test]
+fn free_whitelisted_plugs_pass() {
+    let content = content();
+    let check = PlugInsertionCheck::new(&content);
+    let reusable = reusable_plugs(json!([
+        { "plugItemHash": OUTLAW_HASH, "canInsert": true, "enabled": true },
+        { "plugItemHash": KILL_CLIP_HASH, "canInsert": true, "enabled": true },
+    ]));
+
+    let kill_clip = entry(TRAIT_SOCKET, KILL_CLIP_HASH);
+    assert_eq!(
+        check.check(Hash::new(IKELOS_HASH), &kill_clip, Some(&reusable)),
+        Ok(())
+    );
+    // Without the reusable plugs, only the definitions are checked.
+    assert_eq!(
+        check.check(Hash::new(IKELOS_HASH), &kill_clip, None),
+        Ok(())
+    );
+    // Requirements of nothing, or of materials left out of them, are free.
+    assert_eq!(
+        check.check(
+            Hash::new(HELMET_HASH),
+            &entry(SHADER_SOCKET, DEFAULT_SHADER_HASH),
+            None
+        ),
+        Ok(())
+    );
+
+    let request = check
+        .request(
+            Hash::new(IKELOS_HASH),
+            kill_clip,
+            Some(&reusable),
+            6917529862418451204,
+            2305843009409342731,
+            BungieMembershipType::TigerSteam,
+        )
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "characterId": 2305843009409342731i64,
+            "itemId": 6917529862418451204i64,
+            "membershipType": 3,
+            "plug": {
+                "plugItemHash": KILL_CLIP_HASH,
+                "socketArrayType": 0,
+                "socketIndex": TRAIT_SOCKET,
+            },
+        })
+    );
+}
+
+#[test]
+fn items_and_plugs_missing_from_the_world_content() {
+    let content = content();
+    let check = PlugInsertionCheck::new(&content);
+    assert_eq!(
+        check.check(Hash::new(2), &entry(TRAIT_SOCKET, KILL_CLIP_HASH), None),
+        Err(InsertProblem::UnknownItem {
+            item_hash: Hash::new(2)
+        })
+    );
+    assert_eq!(
+        check.check(Hash::new(IKELOS_HASH), &entry(TRAIT_SOCKET, 1), None),
+        Err(InsertProblem::UnknownPlug {
+            plug_item_hash: Hash::new(1)
+        })
+    );
+    assert_eq!(
+        check.check(
+            Hash::new(IKELOS_HASH),
+            &entry(TRAIT_SOCKET, ACE_OF_SPADES_HASH),
+            None
+        ),
+        Err(InsertProblem::NotAPlug {
+            plug_item_hash: Hash::new(ACE_OF_SPADES_HASH)
+        })
+    );
+}
+
+#[test]
+fn sockets_the_item_does_not_have() {
+    let content = content();
+    let check = PlugInsertionCheck::new(&content);
+    assert_eq!(
+        check.check(Hash::new(IKELOS_HASH), &entry(3, KILL_CLIP_HASH), None),
+        Err(InsertProblem::UnknownSocket {
+            socket_array_type: DestinySocketArrayType::Default,
+            socket_index: 3,
+        })
+    );
+    // Ikelos has no intrinsic sockets at all.
+    let intrinsic = DestinyInsertPlugsRequestEntry::new(
+        0,
+        DestinySocketArrayType::Intrinsic,
+        Hash::new(KILL_CLIP_HASH),
+    );
+    assert_eq!(
+        check.check(Hash::new(IKELOS_HASH), &intrinsic, None),
+        Err(InsertProblem::UnknownSocket {
+            socket_array_type: DestinySocketArrayType::Intrinsic,
+            socket_index: 0,
+        })
+    );
+}
+
+#[test]
+fn sockets_only_take_the_categories_they_whitelist() {
+    let content = content();
+    let check = PlugInsertionCheck::new(&content);
+    assert_eq!(
+        check.check(
+            Hash::new(IKELOS_HASH),
+            &entry(TRAIT_SOCKET, RESILIENCE_MOD_HASH),
+            None
+        ),
+        Err(InsertProblem::NotWhitelisted {
+            socket_index: TRAIT_SOCKET,
+            plug_item_hash: Hash::new(RESILIENCE_MOD_HASH),
+            plug_category_identifier: Some("enhancements.v2_general".to_owned()),
+        })
+    );
+}
+
+#[test]
+fn plugs_that_cost_materials() {
+    let content = content();
+    let check = PlugInsertionCheck::new(&content);
+    // A requirement set asking for glimmer, and one missing from the world
+    // content, which is assumed to ask for something.
+    for (plug_item_hash, material_requirement_hash) in [
+        (GAMBIT_CHROME_HASH, 1000000030),
+        (BALLROOM_FIZZ_HASH, 1000000032),
+    ] {
+        assert_eq!(
+            check.check(
+                Hash::new(HELMET_HASH),
+                &entry(SHADER_SOCKET, plug_item_hash),
+                None
+            ),
+            Err(InsertProblem::CostsMaterials {
+                plug_item_hash: Hash::new(plug_item_hash),
+                material_requirement_hash: Hash::new(material_requirement_hash),
+            })
+        );
+    }
+}
+
+#[test]
+fn reusable_plugs_that_are_missing_or_cannot_be_inserted() {
+    let content = content();
+    let check = PlugInsertionCheck::new(&content);
+    let reusable = reusable_plugs(json!([
+        { "plugItemHash": OUTLAW_HASH, "canInsert": true, "enabled": true },
+        {
+            "plugItemHash": KILL_CLIP_HASH,
+            "canInsert": false,
+            "enabled": true,
+            "insertFailIndexes": [0],
+        },
+    ]));
+
+    for plug_item_hash in [KILL_CLIP_HASH, RAMPAGE_HASH] {
+        assert_eq!(
+            check.check(
+                Hash::new(IKELOS_HASH),
+                &entry(TRAIT_SOCKET, plug_item_hash),
+                Some(&reusable)
+            ),
+            Err(InsertProblem::NotAvailable {
+                socket_index: TRAIT_SOCKET,
+                plug_item_hash: Hash::new(plug_item_hash),
+            })
+        );
+    }
+    // Sockets without reusable plugs aren't checked against them.
+    assert_eq!(
+        check.check(
+            Hash::new(IKELOS_HASH),
+            &entry(2, RAMPAGE_HASH),
+            Some(&reusable)
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        check.check(
+            Hash::new(IKELOS_HASH),
+            &entry(TRAIT_SOCKET, RAMPAGE_HASH),
+            Some(&reusable_plugs(json!([])))
+        ),
+        Ok(())
+    );
+}
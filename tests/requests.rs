@@ -0,0 +1,38 @@
+//! Request bodies, as they're sent.
+
+use guardian_multi_tool::bnet::entities::{
+    destiny::{hashes::Hash, requests::actions::DestinyLoadoutUpdateActionRequest},
+    BungieMembershipType,
+};
+use serde_json::json;
+
+#[test]
+fn unset_loadout_identifiers_are_left_out() {
+    let request = DestinyLoadoutUpdateActionRequest::new(
+        2,
+        2305843009409342731,
+        BungieMembershipType::TigerSteam,
+    );
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "characterId": 2305843009409342731i64,
+            "loadoutIndex": 2,
+            "membershipType": 3,
+        })
+    );
+
+    let request = request
+        .with_color_hash(Hash::new(3871954990))
+        .with_name_hash(Hash::new(1237488984));
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "characterId": 2305843009409342731i64,
+            "colorHash": 3871954990u32,
+            "loadoutIndex": 2,
+            "membershipType": 3,
+            "nameHash": 1237488984,
+        })
+    );
+}
@@ -0,0 +1,198 @@
+//! Regression tests deserializing responses in the shape Bungie sends them,
+//! with int64 ids as strings and the fields it leaves out missing.
+
+use guardian_multi_tool::bnet::{
+    endpoints::Response,
+    entities::{
+        destiny::{
+            historical_stats::DestinyActivityHistoryResults,
+            responses::{DestinyItemResponse, DestinyLinkedProfilesResponse},
+        },
+        groups_v2::{GetGroupsForMemberResponse, GroupMember, RuntimeGroupMemberType},
+        queries::SearchResult,
+        user::{UserInfoCard, UserMembershipData, UserSearchResponse},
+        BungieMembershipType,
+    },
+};
+use serde::de::DeserializeOwned;
+
+fn parse<T: DeserializeOwned>(json: &str) -> Response<T> {
+    serde_json::from_str(json).unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[test]
+fn linked_profiles() {
+    let linked: Response<DestinyLinkedProfilesResponse> =
+        parse(include_str!("fixtures/linked_profiles.json"));
+    assert_eq!(linked.error_code(), 1);
+
+    let linked = linked.response();
+    let profiles = linked.profiles();
+    assert_eq!(profiles.len(), 2);
+    assert_eq!(profiles[0].membership_id(), 4611686018467284386);
+    assert!(profiles[0].is_cross_save_primary());
+    assert_eq!(profiles[1].membership_id(), 4611686018428388123);
+    assert_eq!(profiles[0].supplemental_display_name(), "");
+
+    let bnet = linked.bnet_membership().expect("bnetMembership");
+    assert_eq!(bnet.membership_id(), 20170541);
+    assert_eq!(bnet.membership_type(), 254);
+
+    let errors = linked.profiles_with_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_code(), 1601);
+    assert_eq!(errors[0].info_card().membership_id(), 4611686018497311207);
+    assert!(errors[0]
+        .info_card()
+        .applicable_membership_types()
+        .is_empty());
+}
+
+#[test]
+fn linked_profiles_round_trip() {
+    let linked: Response<DestinyLinkedProfilesResponse> =
+        parse(include_str!("fixtures/linked_profiles.json"));
+    let json = serde_json::to_string(&linked).unwrap();
+    let again: Response<DestinyLinkedProfilesResponse> = parse(&json);
+    assert_eq!(
+        again.response().profiles()[0].membership_id(),
+        4611686018467284386
+    );
+}
+
+#[test]
+fn membership_data() {
+    let data: Response<UserMembershipData> = parse(include_str!("fixtures/membership_data.json"));
+    let data = data.response();
+    assert_eq!(data.primary_membership_id(), Some(4611686018467284386));
+    assert_eq!(data.bungie_net_user().membership_id(), 20170541);
+    assert_eq!(data.destiny_memberships().len(), 2);
+    assert_eq!(
+        data.destiny_memberships()[1].user_info().membership_id(),
+        4611686018428388123
+    );
+}
+
+#[test]
+fn search_by_global_name() {
+    let search: Response<UserSearchResponse> =
+        parse(include_str!("fixtures/search_by_global_name.json"));
+    let results = search.response().search_results();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].bungie_net_membership_id(), Some(20170541));
+    assert_eq!(
+        results[0].destiny_memberships()[0].membership_id(),
+        4611686018467284386
+    );
+    assert_eq!(results[1].bungie_net_membership_id(), None);
+    assert!(results[1].destiny_memberships().is_empty());
+}
+
+#[test]
+fn search_destiny_player() {
+    let cards: Response<Vec<UserInfoCard>> =
+        parse(include_str!("fixtures/search_destiny_player.json"));
+    assert_eq!(
+        cards.response()[0].membership_id(),
+        4611686018467284386
+    );
+    assert_eq!(
+        cards.response()[0].bungie_global_display_name_code(),
+        1470
+    );
+}
+
+#[test]
+fn activity_history() {
+    let history: Response<DestinyActivityHistoryResults> =
+        parse(include_str!("fixtures/activity_history.json"));
+    let activities = history.response().activities();
+    assert_eq!(activities.len(), 2);
+    let details = activities[0].activity_details().expect("activityDetails");
+    assert_eq!(details.instance_id(), 14584918112);
+    assert_eq!(details.modes(), &[7, 4]);
+
+    let end: Response<DestinyActivityHistoryResults> =
+        parse(include_str!("fixtures/activity_history_end.json"));
+    assert!(end.response().activities().is_empty());
+}
+
+#[test]
+fn a_throttled_call_is_an_error() {
+    let throttled: Response<DestinyActivityHistoryResults> =
+        parse(include_str!("fixtures/throttled.json"));
+    assert!(throttled.try_response().is_none());
+
+    let error = throttled.into_result().err().unwrap();
+    assert_eq!(error.error_code(), 51);
+    assert_eq!(error.error_status(), "PerEndpointRequestThrottleExceeded");
+    assert_eq!(error.throttle_seconds(), 10);
+
+    let history: Response<DestinyActivityHistoryResults> =
+        parse(include_str!("fixtures/activity_history.json"));
+    assert_eq!(history.into_result().unwrap().activities().len(), 2);
+}
+
+#[test]
+fn group_members() {
+    let members: Response<SearchResult<GroupMember>> =
+        parse(include_str!("fixtures/group_members.json"));
+    let members = members.response().results();
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].group_id(), 4267540);
+    assert_eq!(members[0].last_online_status_change(), 1717981232);
+    assert_eq!(
+        members[0].destiny_user_info().user_info().membership_id(),
+        4611686018467284386
+    );
+    assert!(members[1].bungie_net_user_info().is_none());
+    assert_eq!(members[0].member_type(), RuntimeGroupMemberType::Founder);
+    assert_eq!(members[1].member_type(), RuntimeGroupMemberType::Member);
+}
+
+#[test]
+fn groups_for_member() {
+    let groups: Response<GetGroupsForMemberResponse> =
+        parse(include_str!("fixtures/groups_for_member.json"));
+    let membership = &groups.response().search().results()[0];
+    let group = membership.group();
+    assert_eq!(group.group_id(), 4267540);
+    assert_eq!(group.membership_id_created(), 20170541);
+    assert_eq!(group.conversation_id(), 7612345);
+    assert_eq!(group.remote_group_id(), Some(8571234));
+    assert_eq!(membership.member().group_id(), 4267540);
+    assert_eq!(
+        group.features().membership_types(),
+        [
+            BungieMembershipType::TigerXbox,
+            BungieMembershipType::TigerPsn,
+            BungieMembershipType::TigerSteam,
+            BungieMembershipType::TigerStadia,
+            BungieMembershipType::TigerEgs,
+        ]
+    );
+    assert_eq!(
+        group.features().join_level(),
+        RuntimeGroupMemberType::Beginner
+    );
+}
+
+#[test]
+fn item() {
+    let item: Response<DestinyItemResponse> = parse(include_str!("fixtures/item.json"));
+    let item = item.response();
+    assert_eq!(item.character_id(), Some(2305843009409342731));
+
+    let component = item.item().and_then(|item| item.data()).expect("item");
+    assert_eq!(component.item_instance_id(), 6917529862418451214);
+    assert_eq!(component.item_hash().value(), 3260753130);
+    assert!(component.metric_objective().is_none());
+    assert!(component.expiration_date().is_none());
+
+    let sockets = item
+        .sockets()
+        .and_then(|sockets| sockets.data())
+        .expect("sockets");
+    assert_eq!(sockets.sockets().len(), 3);
+    assert!(sockets.sockets()[2].plug_hash().is_none());
+}
@@ -0,0 +1,267 @@
+//! Parsing of DIM-style search queries, and their evaluation against items of
+//! the world content.
+
+use guardian_multi_tool::{
+    bnet::entities::destiny::{
+        definitions::DestinyInventoryItemDefinition,
+        entities::items::{
+            DestinyItemComponent, DestinyItemSocketsComponent, DestinyItemStatsComponent,
+        },
+        hashes::{DefinitionStore, Hash},
+        DestinyWorldContent,
+    },
+    tools::search::{Filter, ItemSearch, Query, ResolvedItem},
+};
+use serde_json::json;
+
+const WORLD_CONTENT: &str = include_str!("fixtures/world_content.json");
+
+const ACE_OF_SPADES_HASH: u32 = 347366834;
+const IKELOS_HASH: u32 = 3260753130;
+const HELMET_HASH: u32 = 2539150055;
+const OUTLAW_HASH: u32 = 1168162263;
+
+fn content() -> DestinyWorldContent {
+    serde_json::from_str(WORLD_CONTENT).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn definition(content: &DestinyWorldContent, hash: u32) -> &DestinyInventoryItemDefinition {
+    content
+        .get(Hash::new(hash))
+        .unwrap_or_else(|| panic!("no item {}", hash))
+}
+
+/// Whether the query matches the item.
+fn matches(search: &ItemSearch<DestinyWorldContent>, query: &str, item: &ResolvedItem) -> bool {
+    search.matches(&Query::parse(query).unwrap(), item)
+}
+
+fn item(hash: u32, state: i32) -> DestinyItemComponent {
+    serde_json::from_value(json!({
+        "itemHash": hash,
+        "itemInstanceId": "6917529862418451214",
+        "quantity": 1,
+        "bindStatus": 0,
+        "location": 1,
+        "bucketHash": 1498876634,
+        "transferStatus": 0,
+        "lockable": true,
+        "state": state,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn quoted_values_keep_their_whitespace() {
+    assert_eq!(
+        Query::parse(r#"name:"ace of spades""#),
+        Ok(Query::Filter(Filter::Name("ace of spades".to_string())))
+    );
+    assert_eq!(
+        Query::parse(r#""ace of" or spades"#),
+        Ok(Query::Or(
+            Box::new(Query::Filter(Filter::Name("ace of".to_string()))),
+            Box::new(Query::Filter(Filter::Name("spades".to_string()))),
+        ))
+    );
+}
+
+#[test]
+fn an_empty_quoted_term_is_an_error() {
+    assert!(Query::parse(r#""""#).is_err());
+    assert!(Query::parse(r#"is:weapon """#).is_err());
+    assert!(Query::parse(r#"("")"#).is_err());
+    assert!(Query::parse(r#"name:"""#).is_err());
+}
+
+#[test]
+fn unterminated_quotes_are_an_error() {
+    assert!(Query::parse(r#"name:"ace"#).is_err());
+}
+
+#[test]
+fn is_matches_categories_damage_types_and_tiers() {
+    let content = content();
+    let search = ItemSearch::new(&content);
+    let ace = ResolvedItem::new(definition(&content, ACE_OF_SPADES_HASH));
+    let ikelos = ResolvedItem::new(definition(&content, IKELOS_HASH));
+    let helmet = ResolvedItem::new(definition(&content, HELMET_HASH));
+
+    assert!(matches(&search, "is:weapon", &ace));
+    assert!(matches(&search, "is:handcannon", &ikelos));
+    assert!(!matches(&search, "is:weapon", &helmet));
+    assert!(matches(&search, "is:titan is:helmet", &helmet));
+
+    assert!(matches(&search, "is:kineticdamage", &ace));
+    assert!(matches(&search, "is:solar", &ikelos));
+    assert!(!matches(&search, "is:solar", &ace));
+
+    assert!(matches(&search, "is:exotic", &ace));
+    assert!(!matches(&search, "is:exotic", &ikelos));
+    assert!(matches(&search, "is:legendary", &helmet));
+}
+
+#[test]
+fn is_falls_back_to_the_last_segment_of_the_trait_ids() {
+    let content = content();
+    let search = ItemSearch::new(&content);
+    let ace = ResolvedItem::new(definition(&content, ACE_OF_SPADES_HASH));
+    let ikelos = ResolvedItem::new(definition(&content, IKELOS_HASH));
+
+    assert!(matches(&search, "is:hakke", &ace));
+    assert!(!matches(&search, "is:hakke", &ikelos));
+    assert!(matches(&search, "trait:item.weapon.hand_cannon", &ikelos));
+    assert!(!matches(&search, "is:unknownkeyword", &ace));
+}
+
+#[test]
+fn is_matches_the_state_of_live_items_only() {
+    let content = content();
+    let search = ItemSearch::new(&content);
+    let definition = definition(&content, IKELOS_HASH);
+    let locked_masterwork = item(IKELOS_HASH, 5);
+    let plain = item(IKELOS_HASH, 0);
+
+    let live = ResolvedItem::new(definition).with_item(&locked_masterwork);
+    assert!(matches(&search, "is:locked", &live));
+    assert!(matches(&search, "is:masterwork", &live));
+    assert!(!matches(&search, "is:crafted", &live));
+    assert!(!matches(
+        &search,
+        "is:locked",
+        &ResolvedItem::new(definition).with_item(&plain)
+    ));
+    assert!(!matches(
+        &search,
+        "is:locked",
+        &ResolvedItem::new(definition)
+    ));
+}
+
+#[test]
+fn stat_uses_the_live_stats_over_the_definition() {
+    let content = content();
+    let search = ItemSearch::new(&content);
+    let definition = definition(&content, IKELOS_HASH);
+    let stats: DestinyItemStatsComponent = serde_json::from_value(json!({
+        "stats": { "4043523819": { "statHash": 4043523819u32, "value": 70 } }
+    }))
+    .unwrap();
+
+    let item = ResolvedItem::new(definition);
+    assert!(matches(&search, "stat:impact:95", &item));
+    assert!(matches(&search, "stat:impact:>90", &item));
+    assert!(!matches(&search, "stat:impact:<90", &item));
+
+    let item = item.with_stats(&stats);
+    assert!(matches(&search, "stat:impact:<=70", &item));
+    assert!(!matches(&search, "stat:impact:95", &item));
+}
+
+#[test]
+fn stat_tries_every_stat_sharing_the_name() {
+    let content = content();
+    let search = ItemSearch::new(&content);
+    // The helmet has the armor Recovery, not the one with the lower hash that
+    // shares its name.
+    let helmet = ResolvedItem::new(definition(&content, HELMET_HASH));
+
+    assert!(matches(&search, "stat:recovery:10", &helmet));
+    assert!(matches(&search, "stat:resilience:>=20", &helmet));
+    assert!(!matches(&search, "stat:impact:>0", &helmet));
+}
+
+#[test]
+fn stat_total_sums_the_armor_stats() {
+    let content = content();
+    let search = ItemSearch::new(&content);
+    let helmet = ResolvedItem::new(definition(&content, HELMET_HASH));
+    let ace = ResolvedItem::new(definition(&content, ACE_OF_SPADES_HASH));
+
+    assert!(matches(&search, "stat:total:62", &helmet));
+    assert!(!matches(&search, "stat:total:>62", &helmet));
+    assert!(!matches(&search, "stat:total:>=0", &ace));
+}
+
+#[test]
+fn perk_matches_the_live_plugs_or_the_default_ones() {
+    let content = content();
+    let search = ItemSearch::new(&content);
+    let definition = definition(&content, ACE_OF_SPADES_HASH);
+    let sockets: DestinyItemSocketsComponent = serde_json::from_value(json!({
+        "sockets": [{ "plugHash": OUTLAW_HASH, "isEnabled": true, "isVisible": true }]
+    }))
+    .unwrap();
+
+    let item = ResolvedItem::new(definition);
+    assert!(matches(&search, r#"perk:"memento mori""#, &item));
+    assert!(!matches(&search, "perk:outlaw", &item));
+
+    let item = item.with_sockets(&sockets);
+    assert!(matches(&search, "perk:outlaw", &item));
+    assert!(!matches(&search, "perk:memento", &item));
+}
+
+#[test]
+fn season_compares_the_season_number() {
+    let content = content();
+    let search = ItemSearch::new(&content);
+    let ace = ResolvedItem::new(definition(&content, ACE_OF_SPADES_HASH));
+    let ikelos = ResolvedItem::new(definition(&content, IKELOS_HASH));
+
+    assert!(matches(&search, "season:7", &ace));
+    assert!(matches(&search, "season:>=14", &ikelos));
+    assert!(!matches(&search, "season:>=14", &ace));
+}
+
+#[test]
+fn tag_matches_the_user_tags() {
+    let content = content();
+    let search = ItemSearch::new(&content);
+    let tags = ["Favorite".to_string()];
+    let ace = ResolvedItem::new(definition(&content, ACE_OF_SPADES_HASH));
+
+    assert!(!matches(&search, "tag:favorite", &ace));
+    assert!(matches(&search, "tag:favorite", &ace.with_tags(&tags)));
+}
+
+#[test]
+fn search_combines_terms() {
+    let content = content();
+    let search = ItemSearch::new(&content);
+    let items = [
+        ResolvedItem::new(definition(&content, ACE_OF_SPADES_HASH)),
+        ResolvedItem::new(definition(&content, IKELOS_HASH)),
+        ResolvedItem::new(definition(&content, HELMET_HASH)),
+    ];
+    let names = |query: &str| -> Vec<&str> {
+        search
+            .search(query, &items)
+            .unwrap()
+            .into_iter()
+            .filter_map(|item| {
+                item.definition()
+                    .display_properties()?
+                    .name()
+                    .map(String::as_str)
+            })
+            .collect()
+    };
+
+    assert_eq!(
+        names("is:handcannon"),
+        ["Ace of Spades", "Ikelos_HC_v1.0.2"]
+    );
+    assert_eq!(names("is:handcannon -is:exotic"), ["Ikelos_HC_v1.0.2"]);
+    assert_eq!(names("is:handcannon not is:exotic"), ["Ikelos_HC_v1.0.2"]);
+    assert_eq!(
+        names("is:exotic or stat:total:>60"),
+        ["Ace of Spades", "Crystocrene Helm"]
+    );
+    assert_eq!(
+        names("(is:solar or is:helmet) and season:14"),
+        ["Ikelos_HC_v1.0.2", "Crystocrene Helm"]
+    );
+    assert_eq!(names("ikelos"), ["Ikelos_HC_v1.0.2"]);
+    assert!(search.search("(is:weapon", &items).is_err());
+}
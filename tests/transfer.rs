@@ -0,0 +1,313 @@
+//! Moves planned against inventories of the world content's items and buckets.
+
+use guardian_multi_tool::{
+    bnet::entities::{
+        destiny::{entities::items::DestinyItemComponent, hashes::Hash, DestinyWorldContent},
+        BungieMembershipType,
+    },
+    tools::transfer::{ItemKey, Location, MoveError, MovePlanner, MoveStep},
+};
+use serde_json::json;
+
+const WORLD_CONTENT: &str = include_str!("fixtures/world_content.json");
+
+const ACE_OF_SPADES_HASH: u32 = 347366834;
+const IKELOS_HASH: u32 = 3260753130;
+const THREE_OF_COINS_HASH: u32 = 417308266;
+const LOST_SIGNAL_HASH: u32 = 1176245340;
+
+const KINETIC_BUCKET_HASH: u32 = 1498876634;
+const CONSUMABLES_BUCKET_HASH: u32 = 1469714392;
+const QUESTS_BUCKET_HASH: u32 = 1345459588;
+const VAULT_BUCKET_HASH: u32 = 138197802;
+
+const HUNTER: i64 = 2305843009409342731;
+const WARLOCK: i64 = 2305843009409342732;
+
+fn content() -> DestinyWorldContent {
+    serde_json::from_str(WORLD_CONTENT).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn planner(content: &DestinyWorldContent) -> MovePlanner<'_, DestinyWorldContent> {
+    MovePlanner::new(content, BungieMembershipType::TigerSteam)
+}
+
+/// An instanced item in a bucket, with the ItemState and TransferStatuses
+/// bits given.
+fn instance(
+    item_hash: u32,
+    item_instance_id: i64,
+    bucket_hash: u32,
+    state: i32,
+    transfer_status: i32,
+) -> DestinyItemComponent {
+    serde_json::from_value(json!({
+        "itemHash": item_hash,
+        "itemInstanceId": item_instance_id.to_string(),
+        "quantity": 1,
+        "bindStatus": 0,
+        "location": if bucket_hash == VAULT_BUCKET_HASH { 2 } else { 1 },
+        "bucketHash": bucket_hash,
+        "transferStatus": transfer_status,
+        "lockable": true,
+        "state": state,
+    }))
+    .unwrap()
+}
+
+fn weapon(item_instance_id: i64) -> DestinyItemComponent {
+    instance(IKELOS_HASH, item_instance_id, KINETIC_BUCKET_HASH, 0, 0)
+}
+
+fn stack(quantity: i32, bucket_hash: u32, location: i32) -> DestinyItemComponent {
+    serde_json::from_value(json!({
+        "itemHash": THREE_OF_COINS_HASH,
+        "quantity": quantity,
+        "bindStatus": 0,
+        "location": location,
+        "bucketHash": bucket_hash,
+        "transferStatus": 0,
+        "lockable": false,
+        "state": 0,
+    }))
+    .unwrap()
+}
+
+fn describe(steps: &[MoveStep]) -> Vec<String> {
+    steps.iter().map(|step| step.to_string()).collect()
+}
+
+#[test]
+fn a_full_bucket_sends_an_unlocked_item_to_the_vault_first() {
+    let content = content();
+    // An equipped weapon, eight locked ones and an unlocked one fill the
+    // ten slots of the bucket.
+    let equipment = [weapon(1)];
+    let mut inventory: Vec<_> = (2..10)
+        .map(|id| instance(IKELOS_HASH, id, KINETIC_BUCKET_HASH, 1, 0))
+        .collect();
+    inventory.push(weapon(10));
+    let vault = [instance(ACE_OF_SPADES_HASH, 11, VAULT_BUCKET_HASH, 1, 0)];
+    let mut planner = planner(&content)
+        .with_character(HUNTER, &inventory, &equipment)
+        .with_vault(&vault);
+
+    let steps = planner
+        .plan_move(ItemKey::Instance(11), Location::Character(HUNTER))
+        .unwrap();
+    assert_eq!(
+        describe(&steps),
+        [
+            format!(
+                "transfer item 10 ({}) from character {} to the vault",
+                IKELOS_HASH, HUNTER
+            ),
+            format!(
+                "transfer item 11 ({}) from the vault to character {}",
+                ACE_OF_SPADES_HASH, HUNTER
+            ),
+        ]
+    );
+    assert_eq!(
+        planner.item(ItemKey::Instance(10)).unwrap().location(),
+        Location::Vault
+    );
+
+    // With only locked weapons left to move out of the way, one of them goes.
+    let steps = planner
+        .plan_move(ItemKey::Instance(10), Location::Character(HUNTER))
+        .unwrap();
+    assert_eq!(steps.len(), 2);
+    assert_eq!(
+        planner.item(ItemKey::Instance(10)).unwrap().location(),
+        Location::Character(HUNTER)
+    );
+    assert_eq!(
+        planner
+            .items()
+            .iter()
+            .filter(|item| item.location() == Location::Vault)
+            .count(),
+        1
+    );
+    assert!(planner
+        .items()
+        .iter()
+        .any(|item| item.location() == Location::Vault && item.locked()));
+}
+
+#[test]
+fn a_full_bucket_with_nothing_to_move_out_of_the_way_fails() {
+    let content = content();
+    // Nine weapons that can't leave the character and an equipped one.
+    let equipment = [weapon(1)];
+    let inventory: Vec<_> = (2..11)
+        .map(|id| instance(IKELOS_HASH, id, KINETIC_BUCKET_HASH, 0, 2))
+        .collect();
+    let vault = [instance(IKELOS_HASH, 11, VAULT_BUCKET_HASH, 0, 0)];
+    let mut planner = planner(&content)
+        .with_character(HUNTER, &inventory, &equipment)
+        .with_vault(&vault);
+
+    assert_eq!(
+        planner
+            .plan_move(ItemKey::Instance(11), Location::Character(HUNTER))
+            .unwrap_err(),
+        MoveError::BucketFull {
+            character_id: HUNTER,
+            bucket_hash: Hash::new(KINETIC_BUCKET_HASH),
+        }
+    );
+    assert_eq!(
+        planner.item(ItemKey::Instance(11)).unwrap().location(),
+        Location::Vault
+    );
+}
+
+#[test]
+fn non_transferrable_items_stay_where_they_are() {
+    let content = content();
+    let inventory = [
+        // Non-transferrable by its definition.
+        instance(LOST_SIGNAL_HASH, 1, QUESTS_BUCKET_HASH, 0, 0),
+        // Non-transferrable by what the API reports of it.
+        instance(IKELOS_HASH, 2, KINETIC_BUCKET_HASH, 0, 2),
+        // Locked, which doesn't keep it from moving.
+        instance(IKELOS_HASH, 3, KINETIC_BUCKET_HASH, 1, 0),
+    ];
+    let mut planner = planner(&content).with_character(HUNTER, &inventory, &[]);
+
+    for id in [1, 2] {
+        let key = ItemKey::Instance(id);
+        assert_eq!(
+            planner.plan_move(key, Location::Vault).unwrap_err(),
+            MoveError::NotTransferrable { item: key }
+        );
+        assert_eq!(
+            planner.item(key).unwrap().location(),
+            Location::Character(HUNTER)
+        );
+    }
+    assert_eq!(
+        planner
+            .plan_move(ItemKey::Instance(3), Location::Vault)
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn items_move_between_characters_through_the_vault() {
+    let content = content();
+    let hunter_equipment = [weapon(1)];
+    let hunter_inventory = [weapon(2)];
+    let warlock_equipment = [weapon(3)];
+    let mut planner = planner(&content)
+        .with_character(HUNTER, &hunter_inventory, &hunter_equipment)
+        .with_character(WARLOCK, &[], &warlock_equipment)
+        .with_vault(&[]);
+
+    // At first, the warlock has nothing to equip in place of their only weapon.
+    assert_eq!(
+        planner
+            .plan_move(ItemKey::Instance(3), Location::Character(HUNTER))
+            .unwrap_err(),
+        MoveError::NoReplacement {
+            item_instance_id: 3
+        }
+    );
+
+    // The equipped weapon is replaced before it leaves.
+    let steps = planner
+        .plan_move(ItemKey::Instance(1), Location::Character(WARLOCK))
+        .unwrap();
+    assert_eq!(
+        describe(&steps),
+        [
+            format!("equip item 2 on character {}", HUNTER),
+            format!(
+                "transfer item 1 ({}) from character {} to the vault",
+                IKELOS_HASH, HUNTER
+            ),
+            format!(
+                "transfer item 1 ({}) from the vault to character {}",
+                IKELOS_HASH, WARLOCK
+            ),
+        ]
+    );
+    // The membership type is sent as its number.
+    let MoveStep::Transfer(request) = &steps[1] else {
+        panic!("{}", steps[1]);
+    };
+    assert_eq!(serde_json::to_value(request).unwrap()["membershipType"], 3);
+    assert!(planner.item(ItemKey::Instance(2)).unwrap().equipped());
+    let moved = planner.item(ItemKey::Instance(1)).unwrap();
+    assert_eq!(moved.location(), Location::Character(WARLOCK));
+    assert!(!moved.equipped());
+
+    // Equipping the moved one frees the other.
+    let steps = planner.plan_equip(1, WARLOCK).unwrap();
+    assert_eq!(
+        describe(&steps),
+        [format!("equip item 1 on character {}", WARLOCK)]
+    );
+    assert!(!planner.item(ItemKey::Instance(3)).unwrap().equipped());
+}
+
+#[test]
+fn a_stack_split_across_locations_moves_by_where_it_is() {
+    let content = content();
+    let hunter_inventory = [
+        stack(10, CONSUMABLES_BUCKET_HASH, 1),
+        // The postmaster holds more, sharing the key of the inventory's.
+        stack(3, CONSUMABLES_BUCKET_HASH, 4),
+    ];
+    let warlock_inventory = [stack(45, CONSUMABLES_BUCKET_HASH, 1)];
+    let vault = [stack(5, VAULT_BUCKET_HASH, 2)];
+    let mut planner = planner(&content)
+        .with_character(HUNTER, &hunter_inventory, &[])
+        .with_character(WARLOCK, &warlock_inventory, &[])
+        .with_vault(&vault);
+    let on = |location| ItemKey::Stack {
+        item_hash: Hash::new(THREE_OF_COINS_HASH),
+        location,
+    };
+
+    // The vault's stack joins the hunter's in their inventory.
+    let steps = planner
+        .plan_move(on(Location::Vault), Location::Character(HUNTER))
+        .unwrap();
+    assert_eq!(
+        describe(&steps),
+        [format!(
+            "transfer item 0 ({}) from the vault to character {}",
+            THREE_OF_COINS_HASH, HUNTER
+        )]
+    );
+    assert!(planner.item(on(Location::Vault)).is_none());
+    let hunter_stack = planner.item(on(Location::Character(HUNTER))).unwrap();
+    assert_eq!(hunter_stack.quantity(), 15);
+    assert!(!hunter_stack.in_postmaster());
+
+    // All of it goes on, through the vault, leaving the postmaster's behind,
+    // but doesn't fit in one stack with the warlock's.
+    let steps = planner
+        .plan_move(
+            on(Location::Character(HUNTER)),
+            Location::Character(WARLOCK),
+        )
+        .unwrap();
+    assert_eq!(steps.len(), 2);
+    let mut warlock_stacks: Vec<_> = planner
+        .items()
+        .iter()
+        .filter(|item| item.location() == Location::Character(WARLOCK))
+        .map(|item| item.quantity())
+        .collect();
+    warlock_stacks.sort();
+    assert_eq!(warlock_stacks, [15, 45]);
+    let postmaster = planner.item(on(Location::Character(HUNTER))).unwrap();
+    assert_eq!(postmaster.quantity(), 3);
+    assert!(postmaster.in_postmaster());
+}
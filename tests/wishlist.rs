@@ -0,0 +1,220 @@
+//! A DIM wishlist read from a fixture, checked against the world content and
+//! matched to weapon rolls.
+
+use guardian_multi_tool::{
+    bnet::entities::destiny::{
+        components::items::DestinyItemReusablePlugsComponent,
+        definitions::DestinyInventoryItemDefinition, entities::items::DestinyItemSocketsComponent,
+        hashes::Hash, DestinyWorldContent,
+    },
+    tools::wishlist::{Wishlist, WishlistIssue, WishlistMatcher},
+};
+use serde_json::json;
+
+const WORLD_CONTENT: &str = include_str!("fixtures/world_content.json");
+const WISHLIST: &str = include_str!("fixtures/wishlist.txt");
+
+const IKELOS_HASH: u32 = 3260753130;
+const ACE_OF_SPADES_HASH: u32 = 347366834;
+
+const PRECISION_FRAME_HASH: u32 = 1319823571;
+const OUTLAW_HASH: u32 = 1168162263;
+const ENHANCED_OUTLAW_HASH: u32 = 3400784728;
+const KILL_CLIP_HASH: u32 = 1015611457;
+const RAMPAGE_HASH: u32 = 3425386926;
+const MEMENTO_MORI_HASH: u32 = 3300816228;
+
+fn content() -> DestinyWorldContent {
+    serde_json::from_str(WORLD_CONTENT).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn wishlist() -> Wishlist {
+    Wishlist::parse(WISHLIST).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn hashes(hashes: &[u32]) -> Vec<Hash<DestinyInventoryItemDefinition>> {
+    hashes.iter().copied().map(Hash::new).collect()
+}
+
+/// The sockets of a weapon with these plugs in them, in order.
+fn sockets(plug_hashes: &[u32]) -> DestinyItemSocketsComponent {
+    let sockets: Vec<_> = plug_hashes
+        .iter()
+        .map(|hash| json!({ "plugHash": hash, "isEnabled": true, "isVisible": true }))
+        .collect();
+    serde_json::from_value(json!({ "sockets": sockets })).unwrap()
+}
+
+#[test]
+fn rolls_notes_and_the_list_s_description() {
+    let wishlist = wishlist();
+    assert_eq!(wishlist.title().unwrap(), "Hand cannons");
+    assert_eq!(
+        wishlist.description().unwrap(),
+        "Rolls for the hand cannons of the world content."
+    );
+
+    let rolls = wishlist.rolls();
+    assert_eq!(
+        rolls.iter().map(|roll| roll.line()).collect::<Vec<_>>(),
+        [5, 6, 8, 9, 11, 12, 13]
+    );
+
+    // The block's notes apply up to the blank line, unless the roll has its own.
+    assert_eq!(rolls[0].notes().unwrap(), "Outlaw and Kill Clip for PvE");
+    assert_eq!(rolls[1].notes().unwrap(), "The enhanced Outlaw counts too");
+    assert_eq!(rolls[2].notes(), None);
+    assert_eq!(
+        rolls[0].perk_hashes(),
+        hashes(&[OUTLAW_HASH, KILL_CLIP_HASH])
+    );
+
+    // A negative item hash is a roll to avoid, and -69420 is any item.
+    assert!(!rolls[0].trash());
+    assert!(rolls[2].trash());
+    assert_eq!(rolls[2].item_hash(), Some(Hash::new(IKELOS_HASH)));
+    assert!(!rolls[3].trash());
+    assert_eq!(rolls[3].item_hash(), None);
+    // Without perks, any roll of the item will do.
+    assert!(rolls[4].perk_hashes().is_empty());
+
+    assert_eq!(
+        wishlist
+            .rolls_for(Hash::new(ACE_OF_SPADES_HASH))
+            .map(|roll| roll.line())
+            .collect::<Vec<_>>(),
+        [9, 11]
+    );
+    assert_eq!(WISHLIST.parse::<Wishlist>().unwrap(), wishlist);
+}
+
+#[test]
+fn malformed_rolls_fail_with_their_line() {
+    for (text, message) in [
+        ("dimwishlist:perks=1015611457", "line 2: missing item="),
+        (
+            "dimwishlist:item=ikelos",
+            "line 2: invalid item hash \"ikelos\"",
+        ),
+        (
+            "dimwishlist:item=3260753130&perks=1015611457,outlaw",
+            "line 2: invalid perk hash \"outlaw\"",
+        ),
+        (
+            "dimwishlist:item=-99999999999",
+            "line 2: item hash -99999999999 is out of range",
+        ),
+    ] {
+        let error = Wishlist::parse(&format!("title: Broken\n{}\n", text)).unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert_eq!(error.to_string(), message);
+    }
+}
+
+#[test]
+fn rolls_are_checked_against_the_world_content() {
+    let content = content();
+    let wishlist = wishlist();
+    let matcher = WishlistMatcher::new(&content, &wishlist);
+
+    // The enhanced Outlaw can roll as the base one does. Rolls for any item
+    // aren't checked against one.
+    assert_eq!(
+        matcher.validate(),
+        [
+            WishlistIssue::UnknownPerk {
+                line: 12,
+                perk_hash: Hash::new(1),
+            },
+            WishlistIssue::PerkCannotRoll {
+                line: 12,
+                item_hash: Hash::new(IKELOS_HASH),
+                perk_hash: Hash::new(MEMENTO_MORI_HASH),
+            },
+            WishlistIssue::UnknownItem {
+                line: 13,
+                item_hash: Hash::new(2),
+            },
+        ]
+    );
+
+    // From the initial plugs, the plug set and the reusable plugs.
+    let ikelos = Hash::new(IKELOS_HASH).resolve(&content).unwrap();
+    let mut rollable: Vec<u32> = matcher
+        .rollable_plugs(ikelos)
+        .into_iter()
+        .map(u32::from)
+        .collect();
+    rollable.sort();
+    assert_eq!(
+        rollable,
+        [
+            KILL_CLIP_HASH,
+            OUTLAW_HASH,
+            PRECISION_FRAME_HASH,
+            RAMPAGE_HASH
+        ]
+    );
+}
+
+#[test]
+fn weapons_are_appraised_by_their_plugs() {
+    let content = content();
+    let wishlist = wishlist();
+    let matcher = WishlistMatcher::new(&content, &wishlist);
+    let missing = |sockets: &DestinyItemSocketsComponent,
+                   reusable_plugs: Option<&DestinyItemReusablePlugsComponent>| {
+        matcher
+            .appraise(Hash::new(IKELOS_HASH), sockets, reusable_plugs)
+            .iter()
+            .map(|roll| {
+                (
+                    roll.roll().line(),
+                    roll.is_match(),
+                    roll.missing_perk_hashes().to_vec(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Kill Clip is one of the weapon's reusable plugs, and the base Outlaw
+    // stands in for the enhanced one.
+    let reusable_plugs: DestinyItemReusablePlugsComponent = serde_json::from_value(json!({
+        "plugs": {
+            "1": [
+                { "plugItemHash": OUTLAW_HASH, "canInsert": true, "enabled": true },
+                { "plugItemHash": KILL_CLIP_HASH, "canInsert": true, "enabled": true },
+            ],
+        },
+    }))
+    .unwrap();
+    let socketed = sockets(&[PRECISION_FRAME_HASH, OUTLAW_HASH, RAMPAGE_HASH]);
+    assert_eq!(
+        missing(&socketed, Some(&reusable_plugs)),
+        [
+            (5, true, vec![]),
+            (6, true, vec![]),
+            (8, true, vec![]),
+            (9, false, hashes(&[MEMENTO_MORI_HASH])),
+            (12, false, hashes(&[MEMENTO_MORI_HASH, 1])),
+        ]
+    );
+    let appraisal = matcher.appraise(Hash::new(IKELOS_HASH), &socketed, Some(&reusable_plugs));
+    assert_eq!(
+        appraisal[0].notes().unwrap(),
+        "Outlaw and Kill Clip for PvE"
+    );
+    assert!(appraisal[2].roll().trash());
+
+    // Without its reusable plugs, the weapon only has what's socketed, and
+    // the enhanced Outlaw stands in for the base one.
+    let socketed = sockets(&[PRECISION_FRAME_HASH, ENHANCED_OUTLAW_HASH]);
+    assert_eq!(
+        missing(&socketed, None)[..3],
+        [
+            (5, false, hashes(&[KILL_CLIP_HASH])),
+            (6, false, hashes(&[KILL_CLIP_HASH])),
+            (8, false, hashes(&[RAMPAGE_HASH])),
+        ]
+    );
+}
@@ -0,0 +1,197 @@
+//! The world content cache: that definitions come back out of it as they went
+//! in, and that caches which can't be trusted are refused.
+
+use std::{fs, path::Path};
+
+use guardian_multi_tool::bnet::entities::destiny::{
+    definitions::{
+        records::DestinyRecordDefinition, DestinyClassDefinition, DestinyGenderDefinition,
+        DestinyRaceDefinition,
+    },
+    hashes::Definition,
+    world_content::{
+        cache::{CacheError, CACHE_SCHEMA_VERSION},
+        LazyWorldContent,
+    },
+};
+use serde::Serialize;
+use tempfile::TempDir;
+
+const WORLD_CONTENT: &[u8] = include_bytes!("fixtures/world_content.json");
+const MANIFEST_VERSION: &str = "225678.24.10.18.1800-1-bnet.56789";
+
+fn content() -> LazyWorldContent {
+    LazyWorldContent::from_json(WORLD_CONTENT.to_vec()).unwrap()
+}
+
+fn cache() -> Vec<u8> {
+    let mut cache = Vec::new();
+    content().write_cache(&mut cache, MANIFEST_VERSION).unwrap();
+    cache
+}
+
+/// Writes the bytes to a cache file and tries to load it.
+fn load(dir: &TempDir, bytes: &[u8]) -> Result<LazyWorldContent, CacheError> {
+    let path = dir.path().join("world_content.cache");
+    fs::write(&path, bytes).unwrap();
+    LazyWorldContent::open_cache(&path, MANIFEST_VERSION)
+}
+
+/// Checks every definition in `T`'s table is the same out of both stores.
+fn assert_same<T: Definition + Serialize>(json: &LazyWorldContent, cached: &LazyWorldContent) {
+    let mut hashes: Vec<_> = json.hashes::<T>().collect();
+    hashes.sort();
+    let mut cached_hashes: Vec<_> = cached.hashes::<T>().collect();
+    cached_hashes.sort();
+    assert_eq!(hashes, cached_hashes, "{}", T::TABLE);
+    assert!(!hashes.is_empty(), "{}", T::TABLE);
+
+    for hash in hashes {
+        let expected = serde_json::to_value(json.try_get(hash).unwrap().unwrap()).unwrap();
+        let actual = serde_json::to_value(cached.try_get(hash).unwrap().unwrap()).unwrap();
+        assert_eq!(expected, actual, "{} {}", T::TABLE, hash);
+    }
+}
+
+fn assert_all_same(json: &LazyWorldContent, cached: &LazyWorldContent) {
+    assert_same::<DestinyClassDefinition>(json, cached);
+    assert_same::<DestinyGenderDefinition>(json, cached);
+    assert_same::<DestinyRaceDefinition>(json, cached);
+    assert_same::<DestinyRecordDefinition>(json, cached);
+}
+
+#[test]
+fn definitions_round_trip_through_the_cache() {
+    let dir = TempDir::new().unwrap();
+    let cached = load(&dir, &cache()).unwrap();
+    assert_all_same(&content(), &cached);
+
+    let mapped = unsafe {
+        LazyWorldContent::map_cache(dir.path().join("world_content.cache"), MANIFEST_VERSION)
+    }
+    .unwrap();
+    assert_all_same(&content(), &mapped);
+}
+
+#[test]
+fn the_cache_holds_messagepack_not_json() {
+    let cached = load(&TempDir::new().unwrap(), &cache()).unwrap();
+    let hash = cached.hashes::<DestinyClassDefinition>().next().unwrap();
+    assert!(cached.json(hash).is_none());
+    assert!(content().json(hash).is_some());
+
+    let cache = cache();
+    let json = br#""displayProperties""#;
+    assert!(!cache.windows(json.len()).any(|window| window == json));
+}
+
+#[test]
+fn rewriting_a_loaded_cache_gives_the_same_bytes() {
+    let cache = cache();
+    let cached = load(&TempDir::new().unwrap(), &cache).unwrap();
+    let mut rewritten = Vec::new();
+    cached
+        .write_cache(&mut rewritten, MANIFEST_VERSION)
+        .unwrap();
+    assert_eq!(cache, rewritten);
+}
+
+#[test]
+fn a_wrong_magic_is_not_a_cache() {
+    let mut cache = cache();
+    cache[0] ^= 0xff;
+    let error = load(&TempDir::new().unwrap(), &cache).err().unwrap();
+    assert!(matches!(error, CacheError::NotACache), "{:?}", error);
+
+    let error = load(
+        &TempDir::new().unwrap(),
+        br#"{"DestinyClassDefinition":{}}"#,
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(error, CacheError::NotACache), "{:?}", error);
+}
+
+#[test]
+fn another_schema_version_is_stale() {
+    let mut cache = cache();
+    cache[8..12].copy_from_slice(&(CACHE_SCHEMA_VERSION + 1).to_le_bytes());
+    let error = load(&TempDir::new().unwrap(), &cache).err().unwrap();
+    assert!(
+        matches!(
+            error,
+            CacheError::Stale { schema_version, .. } if schema_version == CACHE_SCHEMA_VERSION + 1
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn another_manifest_version_is_stale() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("world_content.cache");
+    fs::write(&path, cache()).unwrap();
+    let error = LazyWorldContent::open_cache(&path, "225679.24.10.25.1800-1-bnet.56790")
+        .err()
+        .unwrap();
+    assert!(
+        matches!(
+            &error,
+            CacheError::Stale { manifest_version, .. } if manifest_version == MANIFEST_VERSION
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn a_checksum_mismatch_is_corrupt() {
+    let dir = TempDir::new().unwrap();
+
+    let mut cache = cache();
+    let last = cache.len() - 5;
+    cache[last] ^= 0x01;
+    let error = load(&dir, &cache).err().unwrap();
+    assert!(matches!(error, CacheError::Corrupt { .. }), "{:?}", error);
+
+    let mut cache = self::cache();
+    let checksum = cache.len() - 1;
+    cache[checksum] ^= 0x01;
+    let error = load(&dir, &cache).err().unwrap();
+    assert!(matches!(error, CacheError::Corrupt { .. }), "{:?}", error);
+}
+
+#[test]
+fn a_truncated_cache_is_corrupt() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache();
+    for len in 8..cache.len() {
+        let error = load(&dir, &cache[..len]).err().unwrap();
+        assert!(
+            matches!(error, CacheError::Corrupt { .. }),
+            "{} bytes: {:?}",
+            len,
+            error
+        );
+    }
+}
+
+#[test]
+fn open_cached_replaces_an_unusable_cache() {
+    let dir = TempDir::new().unwrap();
+    let json_path = dir.path().join("world_content.json");
+    let cache_path = dir.path().join("world_content.cache");
+    fs::write(&json_path, WORLD_CONTENT).unwrap();
+    fs::write(&cache_path, b"GMTWCACH but not really").unwrap();
+
+    let content = LazyWorldContent::open_cached(&json_path, &cache_path, MANIFEST_VERSION).unwrap();
+    let rewritten = LazyWorldContent::open_cache(&cache_path, MANIFEST_VERSION).unwrap();
+    assert_all_same(&content, &rewritten);
+    assert!(!Path::new(&format!("{}.partial", cache_path.display())).exists());
+
+    // The JSON is gone, so this can only have come out of the cache.
+    fs::remove_file(&json_path).unwrap();
+    let cached = LazyWorldContent::open_cached(&json_path, &cache_path, MANIFEST_VERSION).unwrap();
+    assert_all_same(&content, &cached);
+}
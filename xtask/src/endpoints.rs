@@ -0,0 +1,286 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use serde_json::Value;
+
+use crate::{
+    naming::{doc_comment, pascal_case, snake_case},
+    output::SourceFile,
+    spec::Spec,
+    types::RustType,
+};
+
+/// The module and struct each API tag's endpoints go in, where they differ
+/// from the tag itself.
+const TAGS: &[(&str, &str, &str)] = &[
+    ("Destiny2", "destiny", "Destiny"),
+    ("GroupV2", "groups", "Groups"),
+    ("User", "user", "User"),
+];
+
+/// An endpoint module: its file name and the struct wrapping the client.
+struct Tag {
+    module: String,
+    name: String,
+}
+
+impl Tag {
+    fn of(tag: &str) -> Self {
+        match TAGS.iter().find(|(t, _, _)| *t == tag) {
+            Some((_, module, name)) => Self {
+                module: module.to_string(),
+                name: name.to_string(),
+            },
+            None => Self {
+                module: snake_case(tag),
+                name: pascal_case(tag),
+            },
+        }
+    }
+}
+
+/// A parameter of an endpoint method.
+struct Parameter {
+    key: String,
+    name: String,
+    ty: RustType,
+    required: bool,
+    array: bool,
+}
+
+impl Parameter {
+    fn of(parameter: &Value, spec: &Spec) -> Self {
+        let key = parameter["name"].as_str().unwrap_or_default();
+        Self {
+            key: key.to_string(),
+            name: snake_case(key),
+            ty: RustType::of(&parameter["schema"], spec),
+            required: parameter["required"].as_bool().unwrap_or_default(),
+            array: parameter["schema"]["type"] == "array",
+        }
+    }
+
+    /// The parameter as the method takes it: slices for arrays and `&str`
+    /// for strings, with optional query parameters wrapped in an `Option`.
+    fn argument(&self) -> String {
+        let ty = if self.array {
+            format!("&[{}]", &self.ty.name()[4..self.ty.name().len() - 1])
+        } else if self.ty.name() == "String" {
+            "&str".to_string()
+        } else {
+            self.ty.name().to_string()
+        };
+        if self.required {
+            format!("{}: {}", self.name, ty)
+        } else {
+            format!("{}: Option<{}>", self.name, ty)
+        }
+    }
+
+    /// How a value of the parameter is written into the query string. Enums
+    /// are sent as their integer value and arrays joined with commas.
+    fn to_query(&self) -> String {
+        match (self.array, self.ty.is_enum()) {
+            (true, true) => format!(
+                "{}.iter().map(|v| i32::from(*v).to_string()).collect::<Vec<_>>().join(\",\")",
+                self.name
+            ),
+            (true, false) => format!(
+                "{}.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(\",\")",
+                self.name
+            ),
+            (false, true) => format!("i32::from({}).to_string()", self.name),
+            (false, false) => format!("{}.to_string()", self.name),
+        }
+    }
+}
+
+/// Generates a module per API tag, with a method per operation.
+pub fn generate(spec: &Spec) -> BTreeMap<PathBuf, SourceFile> {
+    let mut methods: BTreeMap<String, BTreeMap<String, (String, Vec<String>)>> = BTreeMap::new();
+    for (path, operations) in spec.paths() {
+        for verb in ["get", "post"] {
+            let Some(operation) = operations.get(verb) else {
+                continue;
+            };
+            let tag = operation["tags"][0].as_str().unwrap_or_default();
+            let name = operation["operationId"]
+                .as_str()
+                .and_then(|id| id.rsplit('.').next())
+                .map(snake_case)
+                .unwrap_or_default();
+            methods
+                .entry(tag.to_string())
+                .or_default()
+                .insert(name.clone(), method(&name, verb, path, operation, spec));
+        }
+    }
+
+    let mut files = BTreeMap::new();
+    for (tag, methods) in methods {
+        let tag = Tag::of(&tag);
+        let mut file = SourceFile::default();
+        file.uses(&[
+            "reqwest::Client".to_string(),
+            "crate::Result".to_string(),
+            "super::Response".to_string(),
+        ]);
+        file.push(&format!("pub struct {}<'a>(pub &'a Client);\n", tag.name));
+        let mut body = format!("impl {}<'_> {{\n", tag.name);
+        for (i, (code, uses)) in methods.values().enumerate() {
+            if i > 0 {
+                body.push('\n');
+            }
+            body.push_str(code);
+            file.uses(uses);
+        }
+        body.push_str("}\n");
+        file.push(&body);
+        files.insert(PathBuf::from(format!("endpoints/{}.rs", tag.module)), file);
+    }
+    files
+}
+
+fn method(
+    name: &str,
+    verb: &str,
+    path: &str,
+    operation: &Value,
+    spec: &Spec,
+) -> (String, Vec<String>) {
+    let parameters: Vec<(String, Parameter)> = operation["parameters"]
+        .as_array()
+        .map(|parameters| {
+            parameters
+                .iter()
+                .map(|p| {
+                    let place = p["in"].as_str().unwrap_or_default().to_string();
+                    (place, Parameter::of(p, spec))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Path parameters come in the order the URL has them, then the query.
+    let mut path_parameters: Vec<&Parameter> = parameters
+        .iter()
+        .filter(|(place, _)| place == "path")
+        .map(|(_, p)| p)
+        .collect();
+    path_parameters.sort_by_key(|p| path.find(&format!("{{{}}}", p.key)).unwrap_or(usize::MAX));
+    let query: Vec<&Parameter> = parameters
+        .iter()
+        .filter(|(place, _)| place == "query")
+        .map(|(_, p)| p)
+        .collect();
+
+    let request = operation["requestBody"]["content"]["application/json"]["schema"]
+        .get("$ref")
+        .map(|_| {
+            RustType::of(
+                &operation["requestBody"]["content"]["application/json"]["schema"],
+                spec,
+            )
+        });
+    let response = operation["responses"]["200"]["$ref"]
+        .as_str()
+        .and_then(|reference| spec.response_body(reference))
+        .map(|schema| RustType::of(schema, spec));
+    let response_name = response
+        .as_ref()
+        .map(|r| r.name().to_string())
+        .unwrap_or_else(|| "serde_json::Value".to_string());
+
+    let mut uses = Vec::new();
+    let mut arguments = vec!["&self".to_string()];
+    for parameter in path_parameters.iter().chain(query.iter()) {
+        arguments.push(parameter.argument());
+        uses.extend(parameter.ty.imports().iter().cloned());
+    }
+    if let Some(request) = &request {
+        arguments.push(format!("request: &{}", request.name()));
+        uses.extend(request.imports().iter().cloned());
+    }
+    if let Some(response) = &response {
+        uses.extend(response.imports().iter().cloned());
+    }
+
+    let mut out = String::new();
+    if let Some(description) = operation["description"].as_str() {
+        out.push_str(&doc_comment(description, "    "));
+    }
+    if arguments.len() > 7 {
+        out.push_str("    #[allow(clippy::too_many_arguments)]\n");
+    }
+    out.push_str(&format!(
+        "    pub async fn {}({}) -> Result<Response<{}>> {{\n",
+        name,
+        arguments.join(", "),
+        response_name
+    ));
+
+    // The URL is lowercase, as the crate writes them, with a `{}` for each
+    // path parameter.
+    let mut url = String::from("https://www.bungie.net/platform");
+    let mut rest = path;
+    while let Some(start) = rest.find('{') {
+        url.push_str(&rest[..start].to_lowercase());
+        url.push_str("{}");
+        rest = rest[start..]
+            .split_once('}')
+            .map(|(_, r)| r)
+            .unwrap_or_default();
+    }
+    url.push_str(&rest.to_lowercase());
+    let url = if path_parameters.is_empty() {
+        format!("\"{}\"", url)
+    } else {
+        let names: Vec<String> = path_parameters
+            .iter()
+            .map(|p| {
+                if p.ty.is_enum() {
+                    format!("i32::from({})", p.name)
+                } else {
+                    p.name.clone()
+                }
+            })
+            .collect();
+        out.push_str(&format!(
+            "        let url = format!(\"{}\", {});\n",
+            url,
+            names.join(", ")
+        ));
+        "url".to_string()
+    };
+
+    let mut call = format!("self.0.{}({})", verb, url);
+    if !query.is_empty() {
+        out.push_str("        let mut query: Vec<(&str, String)> = Vec::new();\n");
+        for parameter in &query {
+            if parameter.required {
+                out.push_str(&format!(
+                    "        query.push((\"{}\", {}));\n",
+                    parameter.key,
+                    parameter.to_query()
+                ));
+            } else {
+                out.push_str(&format!(
+                    "        if let Some({0}) = {0} {{\n            query.push((\"{1}\", {2}));\n        }}\n",
+                    parameter.name,
+                    parameter.key,
+                    parameter.to_query()
+                ));
+            }
+        }
+        call.push_str(".query(&query)");
+    }
+    if request.is_some() {
+        call.push_str(".json(request)");
+    }
+    out.push_str(&format!(
+        "        match {}.send().await {{\n            \
+         Ok(resp) => Ok(resp.json::<Response<{}>>().await?),\n            \
+         Err(e) => Err(Box::new(e)),\n        }}\n    }}\n",
+        call, response_name
+    ));
+    (out, uses)
+}
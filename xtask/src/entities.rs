@@ -0,0 +1,287 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
+
+use serde_json::Value;
+
+use crate::{
+    naming::{doc_comment, pascal_case, serde_camel_case, snake_case},
+    output::SourceFile,
+    spec::{SchemaPath, Spec},
+    types::RustType,
+};
+
+/// Generates the entity modules: a struct for every object schema and an
+/// enum for every enumeration, in the module their namespace maps to.
+pub fn generate(spec: &Spec) -> BTreeMap<Vec<String>, SourceFile> {
+    let mut files: BTreeMap<Vec<String>, SourceFile> = BTreeMap::new();
+    for (full_name, schema) in spec.schemas() {
+        let path = SchemaPath::new(full_name);
+        let item = if schema.get("enum").is_some() {
+            enumeration(full_name, &path, schema)
+        } else if schema.get("properties").is_some() {
+            let (item, uses) = structure(full_name, &path, schema, spec);
+            files
+                .entry(path.modules().to_vec())
+                .or_default()
+                .uses(&uses);
+            item
+        } else {
+            continue;
+        };
+        let file = files.entry(path.modules().to_vec()).or_default();
+        file.push(&item);
+        file.uses(&[
+            "serde::Deserialize".to_string(),
+            "serde::Serialize".to_string(),
+        ]);
+    }
+
+    // Every module is declared by its parent when the parent is generated
+    // too. Top-level modules, and those whose parent is written by hand, are
+    // declared by hand.
+    let modules: Vec<Vec<String>> = files.keys().cloned().collect();
+    for module in modules {
+        if let Some((name, parent)) = module.split_last() {
+            if let Some(parent) = files.get_mut(parent) {
+                parent.declare_module(name);
+            }
+        }
+    }
+    files
+}
+
+/// Where the file for an entity module goes, under `src/bnet`.
+pub fn file_path(module: &[String]) -> PathBuf {
+    let mut path = PathBuf::from("entities");
+    for segment in module {
+        path.push(segment);
+    }
+    path.set_extension("rs");
+    path
+}
+
+/// The module's path from the crate root.
+pub fn module_path(module: &[String]) -> String {
+    let mut path = String::from("crate::bnet::entities");
+    for segment in module {
+        path.push_str("::");
+        path.push_str(segment);
+    }
+    path
+}
+
+fn header(full_name: &str, schema: &Value) -> String {
+    let mut out = String::new();
+    if let Some(description) = schema["description"].as_str() {
+        out.push_str(&doc_comment(description, ""));
+        if !out.is_empty() {
+            out.push_str("///\n");
+        }
+    }
+    out.push_str(&format!(
+        "/// https://bungie-net.github.io/#/components/schemas/{}\n",
+        full_name
+    ));
+    out
+}
+
+/// A struct with private fields in alphabetical order and a getter for each.
+/// Manifest definitions get optional fields, as not every definition fills
+/// them all in, and so do nullable properties; everything else defaults the
+/// fields the API leaves out.
+fn structure(
+    full_name: &str,
+    path: &SchemaPath,
+    schema: &Value,
+    spec: &Spec,
+) -> (String, BTreeSet<String>) {
+    let optional = schema.get("x-mobile-manifest-name").is_some();
+    let mut uses = BTreeSet::new();
+    let mut fields: Vec<(String, String, RustType, &Value)> = schema["properties"]
+        .as_object()
+        .map(|properties| {
+            properties
+                .iter()
+                .map(|(json_name, property)| {
+                    let ty = RustType::of(property, spec);
+                    (snake_case(json_name), json_name.clone(), ty, property)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    fields.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut declaration = header(full_name, schema);
+    declaration.push_str("#[derive(Debug, Deserialize, Serialize)]\n");
+    declaration.push_str("#[serde(rename_all = \"camelCase\")]\n");
+    declaration.push_str(&format!("pub struct {} {{\n", path.name()));
+    let mut getters = format!("impl {} {{\n", path.name());
+    for (i, (field, json_name, ty, property)) in fields.iter().enumerate() {
+        uses.extend(ty.imports().iter().cloned());
+        let optional = optional || property["nullable"].as_bool().unwrap_or_default();
+        let mut attributes = Vec::new();
+        if serde_camel_case(field) != *json_name {
+            attributes.push(format!("rename = \"{}\"", json_name));
+        }
+        let deserializer = ty.deserializer(optional);
+        // Options default to None by themselves, unless deserialized by hand.
+        if (ty.has_default() && !optional) || (optional && deserializer.is_some()) {
+            attributes.push("default".to_string());
+        }
+        if let Some(deserializer) = deserializer {
+            uses.insert(format!("crate::bnet::entities::wire::{}", deserializer));
+            attributes.push(format!("deserialize_with = \"{}\"", deserializer));
+        }
+        if !attributes.is_empty() {
+            declaration.push_str(&format!("    #[serde({})]\n", attributes.join(", ")));
+        }
+        if optional {
+            declaration.push_str(&format!("    {}: Option<{}>,\n", field, ty.name()));
+        } else {
+            declaration.push_str(&format!("    {}: {},\n", field, ty.name()));
+        }
+
+        if i > 0 {
+            getters.push('\n');
+        }
+        if let Some(description) = property["description"].as_str() {
+            getters.push_str(&doc_comment(description, "    "));
+        }
+        let (returns, body) = ty.getter(field, optional);
+        getters.push_str(&format!(
+            "    pub fn {}(&self) -> {} {{\n        {}\n    }}\n",
+            field, returns, body
+        ));
+    }
+    declaration.push_str("}\n");
+    getters.push_str("}\n");
+
+    if fields.is_empty() {
+        (declaration, uses)
+    } else {
+        (format!("{}\n{}", declaration, getters), uses)
+    }
+}
+
+/// An enum serialized as its integer value, keeping values it doesn't know
+/// of. Bitmasks can't be an enum, as values combine flags, so they become a
+/// newtype with a constant per flag.
+fn enumeration(full_name: &str, path: &SchemaPath, schema: &Value) -> String {
+    let name = path.name();
+    let repr = match schema["format"].as_str() {
+        Some("int64") => "i64",
+        Some("uint32") => "u32",
+        Some("int16") => "i16",
+        Some("byte") => "u8",
+        _ => "i32",
+    };
+    let values: Vec<(String, String, Option<&str>)> = schema["x-enum-values"]
+        .as_array()
+        .map(|values| {
+            values
+                .iter()
+                .map(|value| {
+                    (
+                        pascal_case(value["identifier"].as_str().unwrap_or_default()),
+                        match &value["numericValue"] {
+                            Value::String(number) => number.clone(),
+                            number => number.to_string(),
+                        },
+                        value["description"].as_str(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut out = header(full_name, schema);
+    if schema["x-enum-is-bitmask"].as_bool().unwrap_or_default() {
+        out.push_str(
+            "#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]\n",
+        );
+        out.push_str(&format!("pub struct {}({});\n\n", name, repr));
+        out.push_str(&format!("impl {} {{\n", name));
+        for (variant, value, description) in &values {
+            if let Some(description) = description {
+                out.push_str(&doc_comment(description, "    "));
+            }
+            out.push_str(&format!(
+                "    pub const {}: Self = Self({});\n",
+                snake_case(variant).to_uppercase(),
+                value
+            ));
+        }
+        out.push_str(&format!(
+            "\n    pub fn bits(&self) -> {repr} {{\n        self.0\n    }}\n\n    \
+             pub fn contains(&self, other: Self) -> bool {{\n        \
+             self.0 & other.0 == other.0\n    }}\n}}\n\n\
+             impl From<{name}> for {repr} {{\n    fn from(value: {name}) -> Self {{\n        \
+             value.0\n    }}\n}}\n"
+        ));
+        return out;
+    }
+
+    // Values the spec doesn't list are kept in an Unknown variant, which
+    // takes the place of the spec's own Unknown if it has one.
+    let unknown = values
+        .iter()
+        .find(|(variant, _, _)| variant == "Unknown")
+        .map(|(_, value, _)| value.clone());
+    let values: Vec<_> = values
+        .into_iter()
+        .filter(|(variant, _, _)| variant != "Unknown")
+        .collect();
+
+    out.push_str("#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]\n");
+    out.push_str(&format!(
+        "#[serde(from = \"{repr}\", into = \"{repr}\")]\npub enum {name} {{\n"
+    ));
+    for (variant, _, description) in &values {
+        if let Some(description) = description {
+            out.push_str(&doc_comment(description, "    "));
+        }
+        out.push_str(&format!("    {},\n", variant));
+    }
+    match &unknown {
+        Some(value) => out.push_str(&format!(
+            "    /// Any other value, including the API's own Unknown ({}).\n",
+            value
+        )),
+        None => out.push_str(
+            "    /// A value not known of when this was written, kept as it was sent.\n",
+        ),
+    }
+    out.push_str(&format!("    Unknown({}),\n}}\n\n", repr));
+
+    out.push_str(&format!(
+        "impl From<{name}> for {repr} {{\n    fn from(value: {name}) -> Self {{\n        \
+         match value {{\n"
+    ));
+    for (variant, value, _) in &values {
+        out.push_str(&format!(
+            "            {}::{} => {},\n",
+            name, variant, value
+        ));
+    }
+    out.push_str(&format!(
+        "            {}::Unknown(value) => value,\n        }}\n    }}\n}}\n\n",
+        name
+    ));
+    out.push_str(&format!(
+        "impl From<{repr}> for {name} {{\n    fn from(value: {repr}) -> Self {{\n        \
+         match value {{\n"
+    ));
+    for (variant, value, _) in &values {
+        out.push_str(&format!(
+            "            {} => {}::{},\n",
+            value, name, variant
+        ));
+    }
+    out.push_str(&format!(
+        "            _ => {}::Unknown(value),\n        }}\n    }}\n}}\n",
+        name
+    ));
+    out
+}
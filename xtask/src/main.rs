@@ -0,0 +1,206 @@
+//! Development tasks for the crate, run through `cargo xtask`.
+//!
+//! `cargo xtask codegen` generates entity and endpoint code from Bungie's
+//! OpenAPI spec, vendored at `xtask/openapi.json`, into `src/bnet`. Every
+//! generated file starts with [`output::MARKER`]; files without it are
+//! written by hand and never overwritten. `cargo xtask codegen --check`
+//! regenerates the code in memory and fails when it differs from what's
+//! checked in, so changes to the generator or a newly vendored spec show up
+//! as a diff to review.
+//!
+//! The vendored spec is an excerpt of Bungie's: the Fireteam schemas and
+//! endpoints, which the crate didn't model by hand. Everything else in
+//! `src/bnet` is still written by hand, so it isn't generated and can still
+//! drift from the spec. Moving it over takes vendoring Bungie's full spec,
+//! then deleting each hand-written module and reviewing the generated code
+//! that replaces it, since the generator won't overwrite a file without the
+//! marker.
+
+mod endpoints;
+mod entities;
+mod naming;
+mod output;
+mod spec;
+mod types;
+
+use std::{
+    collections::BTreeSet,
+    env,
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use output::{format, Files, MARKER};
+use spec::Spec;
+
+const USAGE: &str = "usage: cargo xtask codegen [--spec <openapi.json>] [--out <dir>] [--check]";
+
+fn main() -> ExitCode {
+    match run(env::args().skip(1).collect()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Vec<String>) -> Result<bool, Box<dyn Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut spec = root.join("openapi.json");
+    let mut out = root.parent().ok_or("xtask has no workspace")?.join("src/bnet");
+    let mut check = false;
+
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("codegen") => {}
+        _ => return Err(USAGE.into()),
+    }
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--spec" => spec = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--out" => out = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--check" => check = true,
+            _ => return Err(USAGE.into()),
+        }
+    }
+
+    let spec = Spec::load(&spec)
+        .map_err(|e| format!("couldn't load the spec from {}: {}", spec.display(), e))?;
+    let files = generate(&spec)?;
+    if check {
+        check_files(&files, &out)
+    } else {
+        write(&files, &out)?;
+        println!("generated {} files into {}", files.len(), out.display());
+        Ok(true)
+    }
+}
+
+fn generate(spec: &Spec) -> Result<Files, Box<dyn Error>> {
+    let mut files = Files::new();
+    for (module, file) in entities::generate(spec) {
+        let source = file.render(&entities::module_path(&module));
+        files.insert(entities::file_path(&module), format(&source)?);
+    }
+    for (path, file) in endpoints::generate(spec) {
+        let module = path.with_extension("").to_string_lossy().replace('/', "::");
+        let source = file.render(&format!("crate::bnet::{}", module));
+        files.insert(path, format(&source)?);
+    }
+    Ok(files)
+}
+
+/// Writes the generated files, and removes generated files the spec no
+/// longer has. Refuses to overwrite a file written by hand.
+fn write(files: &Files, out: &Path) -> Result<(), Box<dyn Error>> {
+    for path in files.keys() {
+        let path = out.join(path);
+        if path.exists() && !is_generated(&path)? {
+            return Err(format!("{} is written by hand", path.display()).into());
+        }
+    }
+    for path in generated(out)? {
+        if !files.contains_key(&path) {
+            fs::remove_file(out.join(path))?;
+        }
+    }
+    for (path, source) in files {
+        let path = out.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, source)?;
+    }
+    Ok(())
+}
+
+/// Compares the generated files with those checked in, reporting every file
+/// that changed, is new or is gone. True when they all match.
+fn check_files(files: &Files, out: &Path) -> Result<bool, Box<dyn Error>> {
+    let mut matches = true;
+    for (path, source) in files {
+        match fs::read_to_string(out.join(path)) {
+            Ok(old) if old == *source => {}
+            Ok(old) => {
+                matches = false;
+                println!("changed: {}", path.display());
+                print_diff(&old, source);
+            }
+            Err(_) => {
+                matches = false;
+                println!("new: {}", path.display());
+            }
+        }
+    }
+    for path in generated(out)? {
+        if !files.contains_key(&path) {
+            matches = false;
+            println!("removed: {}", path.display());
+        }
+    }
+
+    if !matches {
+        println!(
+            "the generated code differs from {}; run `cargo xtask codegen` to update it",
+            out.display()
+        );
+    }
+    Ok(matches)
+}
+
+fn is_generated(path: &Path) -> Result<bool, Box<dyn Error>> {
+    Ok(fs::read_to_string(path)?.starts_with(MARKER))
+}
+
+/// The generated Rust files under `out`, relative to it.
+fn generated(out: &Path) -> Result<BTreeSet<PathBuf>, Box<dyn Error>> {
+    let mut paths = BTreeSet::new();
+    if out.exists() {
+        collect(out, out, &mut paths)?;
+    }
+    let mut generated = BTreeSet::new();
+    for path in paths {
+        if path.extension().is_some_and(|extension| extension == "rs")
+            && is_generated(&out.join(&path))?
+        {
+            generated.insert(path);
+        }
+    }
+    Ok(generated)
+}
+
+fn collect(root: &Path, dir: &Path, paths: &mut BTreeSet<PathBuf>) -> Result<(), Box<dyn Error>> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(root, &path, paths)?;
+        } else {
+            paths.insert(path.strip_prefix(root)?.to_path_buf());
+        }
+    }
+    Ok(())
+}
+
+/// Prints the lines that differ, from the first to the last difference.
+fn print_diff(old: &str, new: &str) {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    println!("@@ line {} @@", prefix + 1);
+    for line in &old[prefix..old.len() - suffix] {
+        println!("-{}", line);
+    }
+    for line in &new[prefix..new.len() - suffix] {
+        println!("+{}", line);
+    }
+}
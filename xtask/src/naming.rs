@@ -0,0 +1,113 @@
+/// Words that can't be used as field or function names as they are.
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while",
+];
+
+/// Turns a camelCase or PascalCase name into snake_case, keeping runs of
+/// capitals together: `mobileGearCDN` becomes `mobile_gear_cdn`.
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let previous = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1);
+            let starts_word = previous.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit())
+                || (previous.is_some_and(|p| p.is_uppercase())
+                    && next.is_some_and(|n| n.is_lowercase()));
+            if starts_word && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else if c.is_alphanumeric() {
+            out.push(*c);
+        } else if !out.ends_with('_') {
+            out.push('_');
+        }
+    }
+    let out = out.trim_matches('_').to_string();
+    if KEYWORDS.contains(&out.as_str()) {
+        format!("{}_", out)
+    } else if out.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", out)
+    } else {
+        out
+    }
+}
+
+/// The name serde's `rename_all = "camelCase"` gives a snake_case field.
+pub fn serde_camel_case(field: &str) -> String {
+    let mut out = String::new();
+    for (i, word) in field.split('_').enumerate() {
+        if i == 0 {
+            out.push_str(word);
+        } else {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                out.extend(first.to_uppercase());
+                out.push_str(chars.as_str());
+            }
+        }
+    }
+    out
+}
+
+/// Turns an identifier from the spec into a PascalCase Rust type or variant
+/// name.
+pub fn pascal_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut upper = true;
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            if upper {
+                out.extend(c.to_uppercase());
+            } else {
+                out.push(c);
+            }
+            upper = false;
+        } else {
+            upper = true;
+        }
+    }
+    if out.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", out)
+    } else {
+        out
+    }
+}
+
+/// Wraps text into `///` doc comment lines no wider than 80 columns,
+/// including the indentation.
+pub fn doc_comment(text: &str, indent: &str) -> String {
+    let width = 80 - indent.len() - 4;
+    let mut out = String::new();
+    for (i, paragraph) in text
+        .split("\r\n\r\n")
+        .flat_map(|p| p.split("\n\n"))
+        .map(|p| p.split_whitespace().collect::<Vec<_>>())
+        .filter(|words| !words.is_empty())
+        .enumerate()
+    {
+        if i > 0 {
+            out.push_str(&format!("{}///\n", indent));
+        }
+        let mut line = String::new();
+        for word in paragraph {
+            if !line.is_empty() && line.len() + 1 + word.len() > width {
+                out.push_str(&format!("{}/// {}\n", indent, line));
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        if !line.is_empty() {
+            out.push_str(&format!("{}/// {}\n", indent, line));
+        }
+    }
+    out
+}
@@ -0,0 +1,149 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+/// The first line of every generated file. Files without it are written by
+/// hand, and codegen leaves them alone.
+pub const MARKER: &str =
+    "// @generated by `cargo xtask codegen` from xtask/openapi.json. Do not edit.";
+
+/// A generated Rust source file, put together from the items written into
+/// it and the paths they use.
+#[derive(Default)]
+pub struct SourceFile {
+    body: String,
+    modules: BTreeSet<String>,
+    uses: BTreeSet<String>,
+}
+
+impl SourceFile {
+    pub fn push(&mut self, item: &str) {
+        if !self.body.is_empty() {
+            self.body.push('\n');
+        }
+        self.body.push_str(item);
+    }
+
+    pub fn declare_module(&mut self, module: &str) {
+        self.modules.insert(module.to_string());
+    }
+
+    pub fn uses<'a>(&mut self, paths: impl IntoIterator<Item = &'a String>) {
+        self.uses.extend(paths.into_iter().cloned());
+    }
+
+    /// The file's source, leaving out imports of the module's own items.
+    /// `module` is the file's own path, such as `crate::bnet::entities::user`.
+    pub fn render(&self, module: &str) -> String {
+        let mut out = format!("{}\n\n", MARKER);
+        for module in &self.modules {
+            out.push_str(&format!("pub mod {};\n", module));
+        }
+        if !self.modules.is_empty() {
+            out.push('\n');
+        }
+
+        // Imports grouped the way the crate lays them out: std, then other
+        // crates, then the crate itself and last the parent module, each
+        // crate's paths nested into a single `use`.
+        let mut groups: [UseTree; 4] = Default::default();
+        for path in &self.uses {
+            if path
+                .rsplit_once("::")
+                .is_some_and(|(parent, _)| parent == module)
+            {
+                continue;
+            }
+            let group = if path.starts_with("std::") {
+                0
+            } else if path.starts_with("crate::") {
+                2
+            } else if path.starts_with("super::") {
+                3
+            } else {
+                1
+            };
+            groups[group].insert(path.split("::"));
+        }
+        for group in groups.iter().filter(|group| !group.children.is_empty()) {
+            for (root, tree) in &group.children {
+                out.push_str(&format!("use {};\n", tree.render(root)));
+            }
+            out.push('\n');
+        }
+
+        out.push_str(&self.body);
+        out
+    }
+}
+
+/// The paths imported from a module, as a tree of their segments.
+#[derive(Default)]
+struct UseTree {
+    children: BTreeMap<String, UseTree>,
+    leaf: bool,
+}
+
+impl UseTree {
+    fn insert<'a>(&mut self, mut segments: impl Iterator<Item = &'a str>) {
+        match segments.next() {
+            Some(segment) => self
+                .children
+                .entry(segment.to_string())
+                .or_default()
+                .insert(segments),
+            None => self.leaf = true,
+        }
+    }
+
+    fn render(&self, name: &str) -> String {
+        if self.children.is_empty() {
+            return name.to_string();
+        }
+        let mut items: Vec<String> = self
+            .children
+            .iter()
+            .map(|(child, tree)| tree.render(child))
+            .collect();
+        if self.leaf {
+            items.insert(0, "self".to_string());
+        }
+        if items.len() == 1 {
+            format!("{}::{}", name, items[0])
+        } else {
+            format!("{}::{{{}}}", name, items.join(", "))
+        }
+    }
+}
+
+/// Every generated file, keyed by its path under `src/bnet`.
+pub type Files = BTreeMap<PathBuf, String>;
+
+/// Formats a generated file with rustfmt, so it reads like the rest of the
+/// crate and the checked-in files only change when the code does.
+pub fn format(source: &str) -> Result<String, Box<dyn Error>> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout", "--quiet"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    rustfmt
+        .stdin
+        .take()
+        .ok_or("rustfmt has no stdin")?
+        .write_all(source.as_bytes())?;
+    let output = rustfmt.wait_with_output()?;
+    if !output.status.success() {
+        return Err(format!(
+            "rustfmt failed on generated code: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
@@ -0,0 +1,93 @@
+use std::{collections::BTreeMap, error::Error, fs, path::Path};
+
+use serde_json::{Map, Value};
+
+use crate::naming::{pascal_case, snake_case};
+
+/// The parts of Bungie's OpenAPI document the generator reads.
+pub struct Spec {
+    paths: Map<String, Value>,
+    responses: Map<String, Value>,
+    schemas: BTreeMap<String, Value>,
+}
+
+impl Spec {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let document: Value = serde_json::from_slice(&fs::read(path)?)?;
+        let components = &document["components"];
+        Ok(Self {
+            paths: document["paths"].as_object().cloned().unwrap_or_default(),
+            responses: components["responses"]
+                .as_object()
+                .cloned()
+                .unwrap_or_default(),
+            schemas: components["schemas"]
+                .as_object()
+                .map(|schemas| schemas.clone().into_iter().collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    pub fn paths(&self) -> &Map<String, Value> {
+        &self.paths
+    }
+
+    /// Every schema, ordered by its full name.
+    pub fn schemas(&self) -> &BTreeMap<String, Value> {
+        &self.schemas
+    }
+
+    pub fn schema(&self, name: &str) -> Option<&Value> {
+        self.schemas.get(name)
+    }
+
+    /// The schema of the `Response` property of a response envelope, which
+    /// is what the endpoint methods return wrapped in `Response<T>`.
+    pub fn response_body(&self, reference: &str) -> Option<&Value> {
+        let name = reference.rsplit('/').next()?;
+        self.responses.get(name)?["content"]["application/json"]["schema"]["properties"]
+            .get("Response")
+    }
+}
+
+/// Where a schema lives in the crate, worked out from its dotted name:
+/// `Destiny.Definitions.DestinyInventoryItemDefinition` is the type
+/// `DestinyInventoryItemDefinition` in `entities::destiny::definitions`.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SchemaPath {
+    modules: Vec<String>,
+    name: String,
+}
+
+impl SchemaPath {
+    pub fn new(full_name: &str) -> Self {
+        let mut segments: Vec<&str> = full_name.split('.').collect();
+        let name = pascal_case(segments.pop().unwrap_or_default());
+        Self {
+            modules: segments.into_iter().map(snake_case).collect(),
+            name,
+        }
+    }
+
+    pub fn from_reference(reference: &str) -> Self {
+        Self::new(reference.rsplit('/').next().unwrap_or_default())
+    }
+
+    pub fn modules(&self) -> &[String] {
+        self.modules.as_ref()
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// The type's path from the crate root.
+    pub fn crate_path(&self) -> String {
+        let mut path = String::from("crate::bnet::entities");
+        for module in &self.modules {
+            path.push_str("::");
+            path.push_str(module);
+        }
+        format!("{}::{}", path, self.name)
+    }
+}
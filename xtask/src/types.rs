@@ -0,0 +1,176 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use crate::spec::{SchemaPath, Spec};
+
+/// A Rust type for a schema, along with what using it takes.
+#[derive(Clone, Debug)]
+pub struct RustType {
+    name: String,
+    kind: Kind,
+    imports: BTreeSet<String>,
+    /// Whether it's an enum, or a vector of one.
+    enumeration: bool,
+    /// Whether it has a default, for fields the API leaves out.
+    default: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    /// Returned by value from getters.
+    Copy,
+    String,
+    Vec,
+    /// Anything else, returned by reference.
+    Owned,
+}
+
+impl RustType {
+    /// Maps a property, parameter or response schema to the type it's
+    /// deserialized into. Integer properties holding an enum are marked by
+    /// `x-enum-reference`, and take the enum's type. Hashes marked by
+    /// `x-mapped-definition` become a `Hash` of the definition they point at.
+    pub fn of(schema: &Value, spec: &Spec) -> Self {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let is_enum = spec
+                .schema(reference.rsplit('/').next().unwrap_or_default())
+                .is_some_and(|schema| schema.get("enum").is_some());
+            let kind = if is_enum { Kind::Copy } else { Kind::Owned };
+            return Self::named(SchemaPath::from_reference(reference), kind);
+        }
+        if let Some(inner) = schema["allOf"].as_array().and_then(|all| all.first()) {
+            return Self::of(inner, spec);
+        }
+        if let Some(reference) = schema["x-enum-reference"]["$ref"].as_str() {
+            return Self::named(SchemaPath::from_reference(reference), Kind::Copy);
+        }
+        if let Some(reference) = schema["x-mapped-definition"]["$ref"].as_str() {
+            let definition = SchemaPath::from_reference(reference);
+            let mut hash = Self::plain(&format!("Hash<{}>", definition.name()), Kind::Copy);
+            hash.imports
+                .insert("crate::bnet::entities::destiny::hashes::Hash".to_string());
+            hash.imports.insert(definition.crate_path());
+            return hash;
+        }
+        match (schema["type"].as_str(), schema["format"].as_str()) {
+            (Some("boolean"), _) => Self::plain("bool", Kind::Copy),
+            (Some("integer"), Some("int64")) => Self::plain("i64", Kind::Copy),
+            (Some("integer"), Some("uint32")) => Self::plain("u32", Kind::Copy),
+            (Some("integer"), Some("int16")) => Self::plain("i16", Kind::Copy),
+            (Some("integer"), Some("byte")) => Self::plain("u8", Kind::Copy),
+            (Some("integer"), _) => Self::plain("i32", Kind::Copy),
+            (Some("number"), Some("float")) => Self::plain("f32", Kind::Copy),
+            (Some("number"), _) => Self::plain("f64", Kind::Copy),
+            (Some("string"), Some("date-time")) => {
+                let mut date = Self::plain("DateTime<Utc>", Kind::Copy);
+                date.default = false;
+                date.imports.insert("chrono::DateTime".to_string());
+                date.imports.insert("chrono::Utc".to_string());
+                date
+            }
+            (Some("string"), _) => Self::plain("String", Kind::String),
+            (Some("array"), _) => {
+                let item = Self::of(&schema["items"], spec);
+                Self {
+                    name: format!("Vec<{}>", item.name),
+                    kind: Kind::Vec,
+                    imports: item.imports,
+                    enumeration: item.enumeration,
+                    default: true,
+                }
+            }
+            (Some("object"), _) if schema.get("additionalProperties").is_some() => {
+                let key = Self::of(&schema["x-dictionary-key"], spec);
+                let value = Self::of(&schema["additionalProperties"], spec);
+                let mut imports = key.imports;
+                imports.extend(value.imports);
+                imports.insert("std::collections::HashMap".to_string());
+                Self {
+                    name: format!("HashMap<{}, {}>", key.name, value.name),
+                    kind: Kind::Owned,
+                    imports,
+                    enumeration: false,
+                    default: true,
+                }
+            }
+            _ => {
+                let mut value = Self::plain("Value", Kind::Owned);
+                value.imports.insert("serde_json::Value".to_string());
+                value
+            }
+        }
+    }
+
+    fn plain(name: &str, kind: Kind) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            imports: BTreeSet::new(),
+            enumeration: false,
+            default: true,
+        }
+    }
+
+    fn named(path: SchemaPath, kind: Kind) -> Self {
+        let mut imports = BTreeSet::new();
+        imports.insert(path.crate_path());
+        Self {
+            name: path.name().to_string(),
+            kind,
+            imports,
+            enumeration: kind == Kind::Copy,
+            default: false,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// The full paths of the types to bring into scope.
+    pub fn imports(&self) -> &BTreeSet<String> {
+        &self.imports
+    }
+
+    pub fn is_enum(&self) -> bool {
+        self.enumeration
+    }
+
+    pub fn has_default(&self) -> bool {
+        self.default
+    }
+
+    /// The function in `entities::wire` a field of this type is deserialized
+    /// with, as Bungie sends int64 values as strings. None for anything that
+    /// isn't an int64 or a vector of them.
+    pub fn deserializer(&self, optional: bool) -> Option<&'static str> {
+        match (self.name.as_str(), optional) {
+            ("i64", false) => Some("deserialize_int64"),
+            ("i64", true) => Some("deserialize_optional_int64"),
+            ("Vec<i64>", false) => Some("deserialize_int64s"),
+            _ => None,
+        }
+    }
+
+    /// The return type and body of the getter for a field of this type,
+    /// following the crate's getters: copies by value, strings and vectors
+    /// as slices, everything else by reference. Optional fields hand back an
+    /// `Option` of a reference instead.
+    pub fn getter(&self, field: &str, optional: bool) -> (String, String) {
+        match (optional, self.kind) {
+            (true, Kind::Copy) => (format!("Option<{}>", self.name), format!("self.{}", field)),
+            (true, _) => (
+                format!("Option<&{}>", self.name),
+                format!("self.{}.as_ref()", field),
+            ),
+            (false, Kind::Copy) => (self.name.clone(), format!("self.{}", field)),
+            (false, Kind::String) => ("&str".to_string(), format!("self.{}.as_ref()", field)),
+            (false, Kind::Vec) => (
+                format!("&[{}]", &self.name[4..self.name.len() - 1]),
+                format!("self.{}.as_ref()", field),
+            ),
+            (false, Kind::Owned) => (format!("&{}", self.name), format!("&self.{}", field)),
+        }
+    }
+}
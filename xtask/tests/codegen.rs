@@ -0,0 +1,103 @@
+//! The generated code checked into `src/bnet` against what the vendored spec
+//! generates today.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+use serde_json::{json, Value};
+
+fn spec_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("openapi.json")
+}
+
+fn codegen(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_xtask"))
+        .arg("codegen")
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// A scratch directory of its own for each test.
+fn scratch(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("xtask-{}-{}", name, std::process::id()));
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn the_checked_in_code_is_up_to_date() {
+    let output = codegen(&["--check"]);
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn a_changed_spec_fails_the_check() {
+    let mut spec: Value = serde_json::from_slice(&fs::read(spec_path()).unwrap()).unwrap();
+    let platform = &mut spec["components"]["schemas"]["Fireteam.FireteamPlatform"];
+    platform["enum"].as_array_mut().unwrap().push(json!("7"));
+    platform["x-enum-values"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!({"numericValue": "7", "identifier": "Xbox"}));
+    let dir = scratch("changed-spec");
+    let changed = dir.join("openapi.json");
+    fs::write(&changed, serde_json::to_vec(&spec).unwrap()).unwrap();
+
+    let output = codegen(&["--spec", changed.to_str().unwrap(), "--check"]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("changed: entities/fireteam.rs"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("+    Xbox,"), "{}", stdout);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn files_written_by_hand_are_left_alone() {
+    let dir = scratch("hand-written");
+    let entities = dir.join("entities");
+    fs::create_dir_all(&entities).unwrap();
+    fs::write(entities.join("user.rs"), "pub struct UserInfoCard;\n").unwrap();
+    fs::write(
+        entities.join("fireteam.rs"),
+        "pub struct FireteamSummary;\n",
+    )
+    .unwrap();
+
+    let output = codegen(&["--out", dir.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("is written by hand"));
+    assert_eq!(
+        fs::read_to_string(entities.join("fireteam.rs")).unwrap(),
+        "pub struct FireteamSummary;\n"
+    );
+
+    // Once the clashing file is gone, the rest are generated around the
+    // hand-written one.
+    fs::remove_file(entities.join("fireteam.rs")).unwrap();
+    let output = codegen(&["--out", dir.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(entities.join("user.rs")).unwrap(),
+        "pub struct UserInfoCard;\n"
+    );
+    assert!(codegen(&["--out", dir.to_str().unwrap(), "--check"])
+        .status
+        .success());
+    fs::remove_dir_all(dir).unwrap();
+}